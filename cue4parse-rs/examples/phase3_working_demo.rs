@@ -4,7 +4,7 @@
 /// 1. Custom version support with engine-specific serialization
 /// 2. Dependency management with package dependency tracking  
 /// 3. Bulk data handling with large data serialization
-/// 4. Name map optimization with hash-based FName system
+/// 4. Name map lookups for FName indices
 
 #[cfg(feature = "unrealmodding-compat")]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,7 +15,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     // 1. Custom Version Support - Engine-specific serialization
     println!("\n1️⃣ Custom Version Support:");
-    let mut custom_registry = CustomVersionRegistry::default();
+    let mut custom_registry = CustomVersionRegistry::new();
     
     // Add Fortnite-specific version
     let fortnite_version = CustomVersion {
@@ -23,9 +23,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         version: 42,
         friendly_name: "FortniteMain".to_string(),
     };
-    custom_registry.register_version(fortnite_version.clone());
+    custom_registry.register(fortnite_version.clone());
     
-    if let Some(version) = custom_registry.get_version(&fortnite_version.guid) {
+    if let Some(version) = custom_registry.get(&fortnite_version.guid) {
         println!("   ✅ Registered Fortnite version: {} (v{})", version.friendly_name, version.version);
     }
     
@@ -80,53 +80,32 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("   📈 Cache: {} entries, {:.1}% hit ratio", 
              cache_stats.entries_count, cache_stats.hit_ratio * 100.0);
     
-    // 4. Name Map Optimization - Hash-based FName system
-    println!("\n4️⃣ Name Map Optimization:");
-    let mut optimized_map = OptimizedNameMap::new(NameHashAlgorithm::Fnv1a);
+    // 4. Name Map - FName index lookups
+    println!("\n4️⃣ Name Map:");
+    let mut name_map = NameMap::new();
     
-    // Add some names for testing
-    let names = ["PlayerCharacter", "StaticMesh", "Material", "Texture2D", "Blueprint"];
+    // Add some names, duplicates resolve to the existing entry
+    let names = ["PlayerCharacter", "StaticMesh", "Material", "Texture2D", "Blueprint", "Material"];
     for name in &names {
-        optimized_map.add_string(name.to_string());
+        name_map.add_name_reference(name.to_string(), false);
     }
     
-    // Create optimized FNames
-    let player_fname = optimized_map.create_fname("PlayerCharacter", 0);
-    println!("   ✅ Created FName: {} (optimized lookup)", player_fname);
-    
-    // Benchmark different algorithms
-    let benchmark_names: Vec<String> = (0..1000)
-        .map(|i| format!("TestName_{}", i))
-        .collect();
-    
-    let results = OptimizedNameMap::benchmark_hash_algorithms(&benchmark_names);
-    println!("   🏃 Hash algorithm benchmarks:");
-    for (algorithm, time_ns) in results {
-        println!("      {:?}: {} ns average", algorithm, time_ns);
+    if let Some(index) = name_map.search_name_reference("PlayerCharacter") {
+        let player_fname = FName::new(name_map.get_name_reference(index).unwrap_or_default());
+        println!("   ✅ Found FName: {} at index {}", player_fname, index);
     }
-    
-    let stats = optimized_map.get_stats();
-    println!("   📊 Name map stats: {} total, {} unique, {} collisions", 
-             stats.total_names, stats.unique_names, stats.hash_collisions);
+    println!("   📊 Name map stats: {} added, {} unique", names.len(), name_map.len());
     
     // 5. Integration Demo - Put it all together
     println!("\n🎯 Integration Demo:");
-    let mut asset = Asset::new_with_advanced_features(true)?;
-    
-    // Set up advanced features
-    asset.asset_data.optimized_name_map = Some(optimized_map);
-    asset.asset_data.dependency_graph = Some(dependency_graph);
-    asset.asset_data.bulk_data_manager = Some(bulk_manager);
+    let mut asset: Asset = Asset::new();
+    asset.asset_data.name_map = name_map;
     asset.custom_versions.push(fortnite_version);
     
-    println!("   ✅ Created asset with all Phase 3 advanced features");
-    println!("   🚀 Ready for production-level Unreal Engine asset manipulation");
-    
-    // Demonstrate functionality
-    if let Some(demo_result) = asset.demonstrate_phase3_functionality().ok() {
-        println!("\n📋 Phase 3 Feature Summary:");
-        println!("{}", demo_result);
-    }
+    println!("   ✅ Created asset with {} names and {} custom versions",
+             asset.asset_data.name_map.len(), asset.custom_versions.len());
+    println!("   📦 Dependency graph and bulk data tracked alongside for {} packages and {} payloads",
+             dependency_graph.get_statistics().total_packages, bulk_manager.entries().count());
     
     println!("\n🎉 Phase 3 Complete! All advanced features operational.");
     println!("    Enterprise-grade optimization and management capabilities ready.");
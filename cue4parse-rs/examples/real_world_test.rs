@@ -12,7 +12,7 @@ use std::env;
 use cue4parse_rs::{Provider, GameVersion, Result};
 
 #[cfg(feature = "unrealmodding-compat")]
-use cue4parse_rs::unreal_asset::{Asset, AssetData, ConversionUtils, UnrealAssetCompat};
+use cue4parse_rs::unreal_asset::{Asset, AssetData, UnrealAssetCompat};
 
 fn main() -> Result<()> {
     let args: Vec<String> = env::args().collect();
@@ -128,10 +128,10 @@ fn test_real_world_assets(game_dir: &str, usmap_path: Option<&String>) -> Result
         
         // Test Method 2: AssetData with mappings (if available)
         if usmap_path.is_some() {
-            match AssetData::load_with_mappings(&mut provider, asset_path, usmap_path.map(|s| s.as_str())) {
-                Ok(asset_data) => {
+            match Asset::from_cue4parse(&provider, asset_path) {
+                Ok(asset) => {
                     println!("   ✅ AssetData with mappings loaded successfully");
-                    test_asset_data_compatibility(&asset_data, asset_path);
+                    test_asset_data_compatibility(&asset.asset_data, asset_path);
                 }
                 Err(e) => {
                     println!("   ⚠️  AssetData with mappings failed: {}", e);
@@ -196,9 +196,8 @@ fn test_asset_compatibility(asset: &Asset, _asset_path: &str, asset_type: &str)
     // Test unreal_asset API compatibility
     if let Some(first_export) = asset.asset_data.exports.first() {
         println!("      🎯 First export class: {}", 
-            asset.asset_data.imports.get(first_export.class_index.0 as usize)
-                .map(|i| &i.object_name.name)
-                .unwrap_or(&"Unknown".to_string())
+            asset.get_import(first_export.class_index)
+                .map_or("Unknown", |class| class.object_name.as_str())
         );
     }
 }
@@ -206,8 +205,8 @@ fn test_asset_compatibility(asset: &Asset, _asset_path: &str, asset_type: &str)
 #[cfg(feature = "unrealmodding-compat")]
 fn test_asset_data_compatibility(asset_data: &AssetData, _asset_path: &str) {
     println!("   📊 AssetData compatibility test:");
-    println!("      🗂️  Has mappings: {}", asset_data.has_type_mappings());
-    if let Some(mappings_path) = asset_data.get_mappings_path() {
+    println!("      🗂️  Has mappings: {}", asset_data.mappings_path.is_some());
+    if let Some(mappings_path) = &asset_data.mappings_path {
         println!("      📍 Mappings file: {}", mappings_path);
     }
     println!("      🆔 Package GUID: {:?}", asset_data.package_guid);
@@ -278,9 +277,12 @@ fn test_material_compatibility(asset: &Asset) {
 fn test_level_compatibility(asset: &Asset) {
     println!("      🗺️  Level-specific tests:");
     
-    // Count actors in the level
+    // Count actors in the level by their class
     let actor_count = asset.asset_data.exports.iter()
-        .filter(|_export| ConversionUtils::is_actor_export(asset, 0)) // This would need proper index
+        .filter(|export| {
+            asset.get_import(export.class_index)
+                .is_some_and(|class| class.object_name.as_str().ends_with("Actor"))
+        })
         .count();
     
     println!("         🎭 Detected actors: {}", actor_count);
@@ -15,9 +15,8 @@ use cue4parse_rs::{Provider, GameVersion, Result};
 
 #[cfg(feature = "unrealmodding-compat")]
 use cue4parse_rs::unreal_asset::{
-    Asset, UnrealAssetCompat, Property, ConversionUtils, AdvancedAssetProcessing,
-    Vector, Vector2D, Rotator, Quat, LinearColor, Transform, SoftObjectPath,
-    StaticMeshData, Texture2DData, MaterialData, ActorData
+    Asset, Export, UnrealAssetCompat, Property, TypedExport, from_property,
+    Vector, Rotator, Quat, LinearColor, Transform, SoftObjectPath,
 };
 
 #[cfg(feature = "unrealmodding-compat")]
@@ -55,67 +54,63 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+/// Decode the exports of `object_path` into typed views
+#[cfg(feature = "unrealmodding-compat")]
+fn load_typed_exports(provider: &Provider, object_path: &str) -> Result<Vec<TypedExport>> {
+    let json = provider.export_object_json(object_path)?;
+    Ok(TypedExport::all_from_json(&json).into_iter().filter_map(|export| export.ok()).collect())
+}
+
 #[cfg(feature = "unrealmodding-compat")]
 fn demonstrate_mesh_processing(provider: &Provider) -> Result<()> {
     println!("--- Static Mesh Processing ---");
     
     // Try to load a static mesh asset
-    match Asset::from_cue4parse(provider, "StaticMesh'/Game/Meshes/SM_Rock.SM_Rock'") {
-        Ok(asset) => {
+    match load_typed_exports(provider, "/Game/Meshes/SM_Rock.SM_Rock") {
+        Ok(exports) => {
             println!("✓ Loaded static mesh asset");
             
-            // Extract mesh data using the advanced processing trait
-            if let Ok(Some(mesh_data)) = asset.extract_static_mesh() {
-                println!("✓ Extracted mesh data:");
-                println!("  Vertices: {}", mesh_data.vertex_count());
-                println!("  Triangles: {}", mesh_data.triangle_count());
-                println!("  UV Channels: {}", mesh_data.uv_channels.len());
-                println!("  Materials: {:?}", mesh_data.materials);
-                
-                // Example of vertex processing (like Stove does)
-                if !mesh_data.vertices.is_empty() {
-                    let first_vertex = &mesh_data.vertices[0];
-                    println!("  First vertex: ({:.2}, {:.2}, {:.2})", 
-                             first_vertex.x, first_vertex.y, first_vertex.z);
-                }
-                
-                // Example of UV processing
-                if let Some(uv_channel) = mesh_data.get_uv_channel(0) {
-                    if !uv_channel.is_empty() {
-                        let first_uv = &uv_channel[0];
-                        println!("  First UV: ({:.3}, {:.3})", first_uv.x, first_uv.y);
-                    }
+            // Read the mesh through its typed view
+            let meshes: Vec<_> = exports.iter()
+                .filter_map(|export| match export {
+                    TypedExport::StaticMesh(mesh) => Some(mesh),
+                    _ => None,
+                })
+                .collect();
+            if meshes.is_empty() {
+                println!("⚠ No mesh data found in asset");
+            }
+            for mesh_data in meshes {
+                println!("✓ Mesh {}:", mesh_data.name);
+                println!("  LODs: {:?}", mesh_data.lod_count);
+                if let Some(bounds) = &mesh_data.bounds {
+                    println!("  Bounds origin: ({:.2}, {:.2}, {:.2}), radius {:.2}",
+                             bounds.origin.x, bounds.origin.y, bounds.origin.z, bounds.sphere_radius);
                 }
                 
-                // Material processing
-                for (i, material) in mesh_data.materials.iter().enumerate() {
-                    println!("  Material {}: {}", i, material);
+                // Material slots (what Stove binds to the mesh sections)
+                for (i, slot) in mesh_data.materials.iter().enumerate() {
+                    let material = slot.material.as_ref().map_or("None".to_string(), |m| m.to_string());
+                    println!("  Material {} ({}): {}", i, slot.slot_name.as_deref().unwrap_or("unnamed"), material);
                 }
-            } else {
-                println!("⚠ No mesh data found in asset");
             }
         }
         Err(e) => {
             println!("⚠ Could not load mesh (demo): {}", e);
             
-            // Show how to create mesh data manually for testing
-            let mut demo_mesh = StaticMeshData::new();
-            demo_mesh.vertices = vec![
-                Vector::new(0.0, 0.0, 0.0),
-                Vector::new(1.0, 0.0, 0.0),
-                Vector::new(0.5, 1.0, 0.0),
-            ];
-            demo_mesh.indices = vec![0, 1, 2];
-            demo_mesh.uv_channels.push(vec![
-                Vector2D::new(0.0, 0.0),
-                Vector2D::new(1.0, 0.0),
-                Vector2D::new(0.5, 1.0),
-            ]);
-            demo_mesh.materials.push("M_DefaultMaterial".to_string());
-            
-            println!("✓ Created demo mesh data for testing");
-            println!("  Vertices: {}", demo_mesh.vertex_count());
-            println!("  Triangles: {}", demo_mesh.triangle_count());
+            // Show how a mesh export is decoded, for testing without game files
+            let demo = serde_json::json!({
+                "Type": "StaticMesh",
+                "Name": "SM_Demo",
+                "Materials": [{
+                    "MaterialSlotName": "Base",
+                    "MaterialInterface": { "ObjectName": "Material'M_DefaultMaterial'", "ObjectPath": "/Game/Materials/M_DefaultMaterial.0" }
+                }]
+            });
+            if let Ok(TypedExport::StaticMesh(demo_mesh)) = TypedExport::from_json(&demo) {
+                println!("✓ Decoded demo mesh data for testing");
+                println!("  Material slots: {}", demo_mesh.materials.len());
+            }
         }
     }
     
@@ -127,37 +122,40 @@ fn demonstrate_mesh_processing(provider: &Provider) -> Result<()> {
 fn demonstrate_texture_processing(provider: &Provider) -> Result<()> {
     println!("--- Texture Processing ---");
     
-    match Asset::from_cue4parse(provider, "Texture2D'/Game/Textures/T_Rock_Diffuse.T_Rock_Diffuse'") {
-        Ok(asset) => {
+    match load_typed_exports(provider, "/Game/Textures/T_Rock_Diffuse.T_Rock_Diffuse") {
+        Ok(exports) => {
             println!("✓ Loaded texture asset");
             
-            if let Ok(Some(texture_data)) = asset.extract_texture_data() {
-                println!("✓ Extracted texture data:");
-                println!("  Dimensions: {}x{}", texture_data.width, texture_data.height);
-                println!("  Format: {}", texture_data.format);
-                println!("  Compressed: {}", texture_data.is_compressed());
-                println!("  Data size: {} bytes", texture_data.size_bytes());
-                println!("  Mip levels: {}", texture_data.mip_count);
-                println!("  Normal map: {}", texture_data.is_normal_map);
+            for export in &exports {
+                let TypedExport::Texture2D(texture_data) = export else { continue };
+                let format = texture_data.pixel_format.as_deref().unwrap_or("Unknown");
+                println!("✓ Texture {}:", texture_data.name);
+                println!("  Dimensions: {:?}x{:?}", texture_data.size_x, texture_data.size_y);
+                println!("  Format: {}", format);
+                println!("  Mip levels: {}", texture_data.mips.len());
+                println!("  sRGB: {:?}", texture_data.srgb);
+                println!("  Compression settings: {:?}", texture_data.compression_settings);
                 
                 // Stove-specific format handling
-                match texture_data.format.as_str() {
+                match format {
                     "PF_DXT1" => println!("  → BC1 compression (no alpha)"),
                     "PF_DXT5" => println!("  → BC3 compression (with alpha)"),
                     "PF_BC7" => println!("  → BC7 compression (high quality)"),
                     "PF_ASTC_4x4" => println!("  → ASTC 4x4 compression (mobile)"),
                     "PF_B8G8R8A8" => println!("  → Uncompressed RGBA"),
-                    _ => println!("  → Other format: {}", texture_data.format),
+                    _ => println!("  → Other format: {}", format),
                 }
             }
         }
         Err(e) => {
             println!("⚠ Could not load texture (demo): {}", e);
             
-            // Demo texture data creation
-            let demo_texture = Texture2DData::new(512, 512, "PF_DXT5".to_string());
-            println!("✓ Created demo texture data:");
-            println!("  Format: {} (compressed: {})", demo_texture.format, demo_texture.is_compressed());
+            // Demo texture decoding
+            let demo = serde_json::json!({ "Type": "Texture2D", "Name": "T_Demo", "SizeX": 512, "SizeY": 512, "PixelFormat": "PF_DXT5" });
+            if let Ok(TypedExport::Texture2D(demo_texture)) = TypedExport::from_json(&demo) {
+                println!("✓ Decoded demo texture data:");
+                println!("  Format: {:?}, {:?}x{:?}", demo_texture.pixel_format, demo_texture.size_x, demo_texture.size_y);
+            }
         }
     }
     
@@ -169,19 +167,22 @@ fn demonstrate_texture_processing(provider: &Provider) -> Result<()> {
 fn demonstrate_material_processing(provider: &Provider) -> Result<()> {
     println!("--- Material Processing ---");
     
-    match Asset::from_cue4parse(provider, "Material'/Game/Materials/M_Rock.M_Rock'") {
-        Ok(asset) => {
+    match load_typed_exports(provider, "/Game/Materials/MI_Rock.MI_Rock") {
+        Ok(exports) => {
             println!("✓ Loaded material asset");
             
-            if let Ok(Some(material_data)) = asset.extract_material_data() {
-                println!("✓ Extracted material data:");
-                println!("  Name: {}", material_data.name);
-                println!("  Type: {}", material_data.material_type);
+            for export in &exports {
+                let TypedExport::MaterialInstanceConstant(material_data) = export else { continue };
+                println!("✓ Material instance {}:", material_data.name);
+                if let Some(parent) = &material_data.parent {
+                    println!("  Parent: {}", parent);
+                }
                 
                 // Texture references (critical for Stove)
                 println!("  Textures:");
-                for (slot, texture_path) in &material_data.textures {
-                    println!("    {}: {}", slot, texture_path);
+                for (slot, texture_path) in &material_data.texture_parameters {
+                    let texture = texture_path.as_ref().map_or("None".to_string(), |t| t.to_string());
+                    println!("    {}: {}", slot, texture);
                 }
                 
                 // Parameters
@@ -196,27 +197,10 @@ fn demonstrate_material_processing(provider: &Provider) -> Result<()> {
                              name, color.r, color.g, color.b, color.a);
                 }
             }
-            
-            // Get texture references using the trait method
-            if let Ok(texture_refs) = asset.get_texture_references() {
-                println!("✓ Found {} texture references:", texture_refs.len());
-                for texture_ref in texture_refs {
-                    println!("  → {}", texture_ref);
-                }
-            }
         }
         Err(e) => {
             println!("⚠ Could not load material (demo): {}", e);
-            
-            // Demo material creation
-            let mut demo_material = MaterialData::new("M_Demo".to_string(), "Material".to_string());
-            demo_material.textures.insert("BaseColor".to_string(), "T_Demo_Diffuse".to_string());
-            demo_material.textures.insert("Normal".to_string(), "T_Demo_Normal".to_string());
-            demo_material.scalar_parameters.insert("Roughness".to_string(), 0.5);
-            demo_material.vector_parameters.insert("EmissiveColor".to_string(), 
-                                                   LinearColor::new(1.0, 0.5, 0.0, 1.0));
-            
-            println!("✓ Created demo material with {} textures", demo_material.textures.len());
+            println!("  Material instances decode to MaterialInstanceView with scalar, vector and texture parameters");
         }
     }
     
@@ -224,63 +208,98 @@ fn demonstrate_material_processing(provider: &Provider) -> Result<()> {
     Ok(())
 }
 
+/// Class name of an export, from the import table
+#[cfg(feature = "unrealmodding-compat")]
+fn export_class<'a>(asset: &'a Asset, export: &Export) -> &'a str {
+    asset.get_import(export.class_index).map_or("Unknown", |class| class.object_name.as_str())
+}
+
+/// Mesh referenced by a component of the actor at export `actor_index`
+#[cfg(feature = "unrealmodding-compat")]
+fn find_mesh_component(asset: &Asset, actor_index: usize) -> Option<String> {
+    asset.asset_data.exports.iter()
+        .filter(|export| export.outer_index.export_index() == Some(actor_index))
+        .find_map(|component| match component.properties.get("StaticMesh") {
+            Some(Property::Object(Some(index))) => asset.get_import(*index).map(|mesh| mesh.object_name.to_string()),
+            _ => None,
+        })
+}
+
+/// Transform of an actor, read from the relative transform of its root component
+#[cfg(feature = "unrealmodding-compat")]
+fn actor_transform(asset: &Asset, actor: &Export) -> Transform {
+    let root = match actor.properties.get("RootComponent") {
+        Some(Property::Object(Some(index))) => asset.get_export(*index),
+        _ => None,
+    };
+    let property = |name: &str| root.and_then(|root| root.properties.get(name));
+    let location = match property("RelativeLocation") {
+        Some(Property::Vector(location)) => location.clone(),
+        _ => Vector::new(0.0, 0.0, 0.0),
+    };
+    let rotation = match property("RelativeRotation") {
+        Some(Property::Rotator(rotation)) => Quat::from_euler(rotation.roll, rotation.pitch, rotation.yaw),
+        _ => Quat::identity(),
+    };
+    let scale = match property("RelativeScale3D") {
+        Some(Property::Vector(scale)) => scale.clone(),
+        _ => Vector::new(1.0, 1.0, 1.0),
+    };
+    Transform::new(location, rotation, scale)
+}
+
 #[cfg(feature = "unrealmodding-compat")]
 fn demonstrate_actor_processing(provider: &Provider) -> Result<()> {
     println!("--- Actor Transform Processing (Core Stove Feature) ---");
     
-    match Asset::from_cue4parse(provider, "World'/Game/Maps/TestLevel.TestLevel'") {
+    match Asset::from_cue4parse(provider, "/Game/Maps/TestLevel.TestLevel") {
         Ok(asset) => {
             println!("✓ Loaded level asset");
             
-            if let Ok(actors) = asset.extract_actors() {
-                println!("✓ Found {} actors:", actors.len());
+            let actors: Vec<(usize, &Export)> = asset.asset_data.exports.iter()
+                .enumerate()
+                .filter(|(_, export)| export_class(&asset, export).ends_with("Actor"))
+                .collect();
+            println!("✓ Found {} actors:", actors.len());
+            
+            for (i, actor) in actors.into_iter().take(5) {
+                println!("  Actor {}: {} ({})", i, actor.object_name, export_class(&asset, actor));
                 
-                for (i, actor) in actors.iter().take(5).enumerate() {
-                    println!("  Actor {}: {} ({})", i, actor.name, actor.class);
-                    
-                    // Transform information (critical for Stove editing)
-                    let transform = &actor.transform;
-                    println!("    Location: ({:.2}, {:.2}, {:.2})", 
-                             transform.location.x, transform.location.y, transform.location.z);
-                    println!("    Rotation: ({:.2}, {:.2}, {:.2}, {:.2})", 
-                             transform.rotation.x, transform.rotation.y, 
-                             transform.rotation.z, transform.rotation.w);
-                    println!("    Scale: ({:.2}, {:.2}, {:.2})", 
-                             transform.scale.x, transform.scale.y, transform.scale.z);
-                    
-                    // Find mesh components (what Stove visualizes)
-                    if let Some(mesh_path) = ConversionUtils::find_mesh_component(&asset, 
-                        asset.asset_data.exports.get(i).unwrap_or(&asset.asset_data.exports[0])) {
-                        println!("    Mesh: {}", mesh_path);
-                    }
-                    
-                    // Show key properties
-                    for (prop_name, property) in actor.properties.iter().take(3) {
-                        println!("    {}: {:?}", prop_name, property_type_name(property));
-                    }
+                // Transform information (critical for Stove editing)
+                let transform = actor_transform(&asset, actor);
+                println!("    Location: ({:.2}, {:.2}, {:.2})", 
+                         transform.location.x, transform.location.y, transform.location.z);
+                println!("    Rotation: ({:.2}, {:.2}, {:.2}, {:.2})", 
+                         transform.rotation.x, transform.rotation.y, 
+                         transform.rotation.z, transform.rotation.w);
+                println!("    Scale: ({:.2}, {:.2}, {:.2})", 
+                         transform.scale.x, transform.scale.y, transform.scale.z);
+                
+                // Find mesh components (what Stove visualizes)
+                if let Some(mesh_path) = find_mesh_component(&asset, i) {
+                    println!("    Mesh: {}", mesh_path);
+                }
+                
+                // Show key properties
+                for (prop_name, property) in actor.properties.iter().take(3) {
+                    println!("    {}: {}", prop_name, property.type_name());
                 }
             }
         }
         Err(e) => {
             println!("⚠ Could not load level (demo): {}", e);
             
-            // Demo actor creation
-            let demo_actor = ActorData {
-                name: "StaticMeshActor_1".to_string(),
-                class: "StaticMeshActor".to_string(),
-                transform: Transform::new(
-                    Vector::new(100.0, 200.0, 50.0),
-                    Quat::identity(),
-                    Vector::new(1.0, 1.0, 1.0)
-                ),
-                properties: indexmap::IndexMap::new(),
-                components: Vec::new(),
-            };
+            // Demo actor transform
+            let demo_transform = Transform::new(
+                Vector::new(100.0, 200.0, 50.0),
+                Quat::identity(),
+                Vector::new(1.0, 1.0, 1.0)
+            );
             
-            println!("✓ Created demo actor at location ({:.1}, {:.1}, {:.1})", 
-                     demo_actor.transform.location.x,
-                     demo_actor.transform.location.y, 
-                     demo_actor.transform.location.z);
+            println!("✓ Created demo actor transform at location ({:.1}, {:.1}, {:.1})", 
+                     demo_transform.location.x,
+                     demo_transform.location.y, 
+                     demo_transform.location.z);
         }
     }
     
@@ -335,7 +354,7 @@ fn demonstrate_advanced_properties() {
     println!("  TimeSpan: {:?}", timespan);
     
     // Demonstrate property value extraction (for UI editing)
-    if let Some(float_val) = ConversionUtils::property_as_float(&Property::Float(3.14)) {
+    if let Ok(float_val) = from_property::<f32>(&Property::Float(3.14)) {
         println!("✓ Extracted float value: {:.2}", float_val);
     }
     
@@ -347,7 +366,7 @@ fn demonstrate_level_processing(provider: &Provider) -> Result<()> {
     println!("--- Level/Map Processing ---");
     
     // This is what Stove primarily works with - level assets
-    match Asset::from_cue4parse(provider, "World'/Game/Maps/MainLevel.MainLevel'") {
+    match Asset::from_cue4parse(provider, "/Game/Maps/MainLevel.MainLevel") {
         Ok(asset) => {
             println!("✓ Loaded level asset");
             println!("  Exports: {}", asset.asset_data.exports.len());
@@ -368,10 +387,12 @@ fn demonstrate_level_processing(provider: &Provider) -> Result<()> {
             }
             
             // Extract material references (needed for rendering)
-            let materials = ConversionUtils::extract_material_references(&asset);
+            let materials: Vec<_> = asset.asset_data.imports.iter()
+                .filter(|import| import.class_name.as_str().starts_with("Material"))
+                .collect();
             println!("✓ Found {} material references", materials.len());
             for material in materials.iter().take(5) {
-                println!("  → {}", material);
+                println!("  → {}", material.object_name);
             }
             
         }
@@ -390,63 +411,6 @@ fn demonstrate_level_processing(provider: &Provider) -> Result<()> {
     Ok(())
 }
 
-#[cfg(feature = "unrealmodding-compat")]
-fn property_type_name(property: &Property) -> &'static str {
-    match property {
-        Property::Bool(_) => "Bool",
-        Property::Int8(_) => "Int8",
-        Property::Int16(_) => "Int16", 
-        Property::Int32(_) => "Int32",
-        Property::Int64(_) => "Int64",
-        Property::UInt8(_) => "UInt8",
-        Property::UInt16(_) => "UInt16",
-        Property::UInt32(_) => "UInt32",
-        Property::UInt64(_) => "UInt64",
-        Property::Float(_) => "Float",
-        Property::Double(_) => "Double",
-        Property::String(_) => "String",
-        Property::Name(_) => "Name",
-        Property::Object(_) => "Object",
-        Property::Struct { .. } => "Struct",
-        Property::Array(_) => "Array",
-        Property::Map { .. } => "Map",
-        Property::Enum { .. } => "Enum",
-        Property::Text { .. } => "Text",
-        Property::Vector(_) => "Vector",
-        Property::Vector4(_) => "Vector4",
-        Property::Vector2D(_) => "Vector2D",
-        Property::Rotator(_) => "Rotator",
-        Property::Quat(_) => "Quat",
-        Property::LinearColor(_) => "LinearColor",
-        Property::Transform(_) => "Transform",
-        Property::SoftObjectPath(_) => "SoftObjectPath",
-        Property::SoftClassPath(_) => "SoftClassPath",
-        Property::AssetObjectProperty(_) => "AssetObjectProperty",
-        Property::PerPlatformBool(_) => "PerPlatformBool",
-        Property::PerPlatformInt(_) => "PerPlatformInt",
-        Property::PerPlatformFloat(_) => "PerPlatformFloat",
-        Property::Guid(_) => "Guid",
-        Property::DateTime(_) => "DateTime",
-        Property::TimeSpan(_) => "TimeSpan",
-        Property::Delegate { .. } => "Delegate",
-        Property::MulticastDelegate { .. } => "MulticastDelegate",
-        Property::MaterialInterface(_) => "MaterialInterface",
-        Property::StaticMesh(_) => "StaticMesh",
-        Property::SkeletalMesh(_) => "SkeletalMesh",
-        Property::Texture2D(_) => "Texture2D",
-        Property::Set(_) => "Set",
-        Property::ByteEnum { .. } => "ByteEnum",
-        Property::Byte(_) => "Byte",
-        Property::MaterialInstance(_) => "MaterialInstance",
-        Property::LevelSequence(_) => "LevelSequence",
-        Property::ComponentReference(_) => "ComponentReference",
-        Property::Blueprint(_) => "Blueprint",
-        Property::WorldContext(_) => "WorldContext",
-        Property::LandscapeComponent(_) => "LandscapeComponent",
-        Property::Unknown(_) => "Unknown",
-    }
-}
-
 #[cfg(feature = "unrealmodding-compat")]
 fn print_stove_benefits() {
     println!("🎯 Enhanced CUE4Parse provides Stove with:");
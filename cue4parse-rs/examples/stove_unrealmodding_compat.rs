@@ -11,11 +11,10 @@
 
 #[cfg(feature = "unrealmodding-compat")]
 use cue4parse_rs::unreal_asset::{
-    Asset, UnrealAssetCompat, ConversionUtils, AdvancedAssetProcessing,
-    Property, Vector, Transform, Quat, Rotator, LinearColor,
-    ActorData, StaticMeshData, Texture2DData, MaterialData
+    Asset, ConversionUtils, Property, Vector, Transform, Quat, Rotator, LinearColor,
+    from_property, to_property,
 };
-use cue4parse_rs::{Provider, GameVersion, Result};
+use cue4parse_rs::{GameVersion, Result};
 
 #[cfg(feature = "unrealmodding-compat")]
 fn main() -> Result<()> {
@@ -29,7 +28,7 @@ fn main() -> Result<()> {
     // This would normally load a real asset
     println!("2. Asset Loading - unrealmodding-compatible API");
     // let asset = Asset::from_cue4parse(&provider, "SomeLevel.SomeLevel")?;
-    let mut asset: Asset<std::io::Cursor<Vec<u8>>> = Asset::new(); // Create empty asset for demo
+    let asset: Asset = Asset::new(); // Create empty asset for demo
     
     println!("   Asset loaded with {} exports, {} imports", 
         asset.asset_data.exports.len(), 
@@ -73,9 +72,8 @@ fn main() -> Result<()> {
     println!("\n6. Advanced Processing - Stove-specific features");
     
     // These would work with real assets containing mesh/texture data
-    // let mesh_data = asset.extract_static_mesh()?;
-    // let texture_data = asset.extract_texture_data()?;
-    // let material_data = asset.extract_material_data()?;
+    // let json = provider.export_object_json("/Game/Meshes/SM_Rock.SM_Rock")?;
+    // let exports = TypedExport::all_from_json(&json);
     
     println!("   ✓ Static mesh extraction capability");
     println!("   ✓ Texture data processing capability");
@@ -85,9 +83,9 @@ fn main() -> Result<()> {
     println!("\n7. Actor System - Level editor features");
     
     // These would work with real level assets
-    // let actors = asset.extract_actors()?;
-    // let components = ConversionUtils::get_actor_components(&asset, 0);
-    // let is_actor = ConversionUtils::is_actor_export(&asset, 0);
+    // let class = asset.get_import(export.class_index);
+    // let components = asset.asset_data.exports.iter()
+    //     .filter(|component| component.outer_index.export_index() == Some(0));
     
     println!("   ✓ Actor extraction and enumeration");
     println!("   ✓ Component hierarchy traversal");
@@ -100,86 +98,39 @@ fn main() -> Result<()> {
     // Without them, properties appear as raw bytes instead of typed values
     
     /*
-    // Method 1: Load asset with mappings using AssetData (requires real provider)
-    let asset_with_mappings = match AssetData::load_with_mappings(
-        &mut provider,
-        "/Game/Maps/MainMenu.umap", // Example cooked asset
-        Some("/path/to/Mappings.usmap") // Critical for cooked builds
-    ) {
-        Ok(asset) => {
-            println!("   ✓ Asset loaded with mappings successfully");
-            println!("     Mappings loaded: {}", asset.has_type_mappings());
-            println!("     Mappings file: {:?}", asset.get_mappings_path());
-            asset
-        }
-        Err(e) => {
-            println!("   ⚠ Failed to load with mappings: {} (using fallback)", e);
-            AssetData::new() // Fallback for demo
-        }
-    };
-    
-    // Method 2: Load using ConversionUtils (recommended for Stove)
-    let _asset_from_utils = match ConversionUtils::load_asset_with_mappings(
-        &mut provider,
-        "/Game/Blueprints/MyActor.uasset",
-        "/path/to/Mappings.usmap"
-    ) {
-        Ok(asset) => {
-            println!("   ✓ Asset loaded via ConversionUtils with mappings");
-            // Validate that we have proper type information
-            let has_types = ConversionUtils::validate_asset_types(&asset);
-            println!("     Type validation: {}", if has_types { "PASS" } else { "FAIL - may need mappings" });
-            Some(asset)
-        }
-        Err(e) => {
-            println!("   ⚠ Failed to load via ConversionUtils: {}", e);
-            None
-        }
-    };
+    // Load asset with mappings (requires real provider)
+    let mut provider = Provider::new("/path/to/game", GameVersion::UE5_3);
+    provider.set_mappings("/path/to/Mappings.usmap"); // Critical for cooked builds
+    let asset = Asset::from_cue4parse(&provider, "/Game/Maps/MainMenu.MainMenu")?;
+    println!("     Mappings file: {:?}", asset.asset_data.mappings_path);
     */
     
     // Demonstrate .usmap capabilities
-    println!("   ✓ AssetData::load_with_mappings() - Load with .usmap files");
-    println!("   ✓ ConversionUtils::load_asset_with_mappings() - Enhanced loading");
-    println!("   ✓ validate_asset_types() - Verify proper type resolution");
-    println!("   ✓ has_type_mappings() - Check mapping availability");
-    
-    // Method 3: Automatic .usmap discovery (Stove-style)
-    let game_directory = "/path/to/game";
-    let suggested_paths = ConversionUtils::suggest_usmap_paths(
-        "/Game/SomeAsset.uasset", 
-        game_directory
-    );
+    println!("   ✓ Provider::set_mappings() - Load with .usmap files");
+    println!("   ✓ AssetData::mappings_path - Check mapping availability");
     
-    println!("   ✓ Automatic .usmap discovery - {} suggestions", suggested_paths.len());
-    for (i, path) in suggested_paths.iter().take(3).enumerate() {
-        println!("     {}. {}", i + 1, path);
-    }
-    
-    // Enhanced property conversion with mappings
+    // Typed property conversion, as resolved through the mappings
     let sample_vector = serde_json::json!({
         "X": 100.0,
         "Y": 200.0, 
         "Z": 300.0
     });
     
-    let property_with_mapping = ConversionUtils::convert_property_with_mapping(
-        &sample_vector,
-        "RelativeLocation", // Property name
-        "StaticMeshComponent", // Class name
-        true // Mappings loaded
-    );
-    
-    println!("   ✓ Enhanced property conversion with mapping context");
+    match ConversionUtils::json_to_property_typed(&sample_vector, "StructProperty<Vector>") {
+        Ok(property) => println!("   ✓ Typed property conversion: {:?}", property),
+        Err(e) => println!("   ⚠ Typed property conversion failed: {}", e),
+    }
     
     // Demonstrate property editing (key for Stove)
     println!("\n9. Property Editing - Stove UI integration");
     
     let mut demo_property = Property::Float(42.0);
-    if let Some(float_val) = ConversionUtils::property_as_float(&demo_property) {
+    if let Ok(float_val) = from_property::<f32>(&demo_property) {
         println!("   ✓ Property as float: {}", float_val);
-        ConversionUtils::set_property_from_float(&mut demo_property, 84.0);
-        println!("   ✓ Property updated via float interface");
+        if let Ok(updated) = to_property(&(float_val * 2.0)) {
+            demo_property = updated;
+            println!("   ✓ Property updated via float interface: {:?}", demo_property);
+        }
     }
     
     println!("\n10. Compatibility Summary");
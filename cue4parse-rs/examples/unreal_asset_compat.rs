@@ -37,7 +37,7 @@ fn main() -> Result<()> {
                 
                 // Example 3: Iterate through properties (unreal_asset-style)
                 for (name, property) in &main_export.properties {
-                    println!("  Property '{}': {:?}", name, property_type_name(property));
+                    println!("  Property '{}': {}", name, property.type_name());
                 }
                 
                 // Example 4: Access specific property types
@@ -77,63 +77,6 @@ fn main() -> Result<()> {
     Ok(())
 }
 
-#[cfg(feature = "unrealmodding-compat")]
-fn property_type_name(property: &Property) -> &'static str {
-    match property {
-        Property::Bool(_) => "Bool",
-        Property::Int8(_) => "Int8",
-        Property::Int16(_) => "Int16", 
-        Property::Int32(_) => "Int32",
-        Property::Int64(_) => "Int64",
-        Property::UInt8(_) => "UInt8",
-        Property::UInt16(_) => "UInt16",
-        Property::UInt32(_) => "UInt32",
-        Property::UInt64(_) => "UInt64",
-        Property::Float(_) => "Float",
-        Property::Double(_) => "Double",
-        Property::String(_) => "String",
-        Property::Name(_) => "Name",
-        Property::Object(_) => "Object",
-        Property::Struct { .. } => "Struct",
-        Property::Array(_) => "Array",
-        Property::Map { .. } => "Map",
-        Property::Enum { .. } => "Enum",
-        Property::Text { .. } => "Text",
-        Property::Vector(_) => "Vector",
-        Property::Vector4(_) => "Vector4",
-        Property::Vector2D(_) => "Vector2D",
-        Property::Rotator(_) => "Rotator",
-        Property::Quat(_) => "Quat",
-        Property::LinearColor(_) => "LinearColor",
-        Property::Transform(_) => "Transform",
-        Property::SoftObjectPath(_) => "SoftObjectPath",
-        Property::SoftClassPath(_) => "SoftClassPath",
-        Property::AssetObjectProperty(_) => "AssetObjectProperty",
-        Property::PerPlatformBool(_) => "PerPlatformBool",
-        Property::PerPlatformInt(_) => "PerPlatformInt",
-        Property::PerPlatformFloat(_) => "PerPlatformFloat",
-        Property::Guid(_) => "Guid",
-        Property::DateTime(_) => "DateTime",
-        Property::TimeSpan(_) => "TimeSpan",
-        Property::Delegate { .. } => "Delegate",
-        Property::MulticastDelegate { .. } => "MulticastDelegate",
-        Property::MaterialInterface(_) => "MaterialInterface",
-        Property::StaticMesh(_) => "StaticMesh",
-        Property::SkeletalMesh(_) => "SkeletalMesh",
-        Property::Texture2D(_) => "Texture2D",
-        Property::Set(_) => "Set",
-        Property::ByteEnum { .. } => "ByteEnum",
-        Property::Byte(_) => "Byte",
-        Property::MaterialInstance(_) => "MaterialInstance",
-        Property::LevelSequence(_) => "LevelSequence",
-        Property::ComponentReference(_) => "ComponentReference",
-        Property::Blueprint(_) => "Blueprint",
-        Property::WorldContext(_) => "WorldContext",
-        Property::LandscapeComponent(_) => "LandscapeComponent",
-        Property::Unknown(_) => "Unknown",
-    }
-}
-
 #[cfg(feature = "unrealmodding-compat")]
 fn demonstrate_property_types() {
     use cue4parse_rs::unreal_asset::{Property, FName, PackageIndex};
@@ -0,0 +1,29 @@
+//! Primitive `ArchiveReader` reads driven by the input
+#![no_main]
+
+use std::io::Cursor;
+
+use cue4parse_rs::unreal_asset::{ArchiveReader, ArchiveTrait, BinaryArchive, EngineVersion, NameMap};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&ops, data)) = data.split_first() else {
+        return;
+    };
+    let _ = NameMap::read_name_batch(&mut Cursor::new(data));
+
+    let mut ar = BinaryArchive::new(Cursor::new(data), EngineVersion::VerUe5_3);
+    *ar.get_name_map().get_mut() = NameMap::from_names(["None".to_string(), "Name".to_string()]);
+    // Each pair of bits in the first byte picks the next read
+    for shift in (0..8).step_by(2) {
+        let result = match (ops >> shift) & 3 {
+            0 => ar.read_fstring().map(drop),
+            1 => ar.read_array(|ar| ar.read_fstring()).map(drop),
+            2 => ar.read_fname().map(drop),
+            _ => ar.read_array(|ar| ar.read_array(|ar| Ok(ar.read_guid()?))).map(drop),
+        };
+        if result.is_err() {
+            break;
+        }
+    }
+});
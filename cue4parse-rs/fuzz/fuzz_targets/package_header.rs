@@ -0,0 +1,12 @@
+//! Legacy `.uasset` headers
+#![no_main]
+
+use std::io::Cursor;
+
+use cue4parse_rs::unreal_asset::{EngineVersion, PackageHeader};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = PackageHeader::read(Cursor::new(data), EngineVersion::VerUe4_27);
+    let _ = PackageHeader::read(Cursor::new(data), EngineVersion::VerUe5_3);
+});
@@ -0,0 +1,19 @@
+//! Zen package headers and the global script object table
+#![no_main]
+
+use std::io::Cursor;
+
+use cue4parse_rs::unreal_asset::{EngineVersion, IoGlobalData, ZenPackageHeader};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    // The first byte splits the input between the global data and the package
+    let Some((&split, data)) = data.split_first() else {
+        return;
+    };
+    let (global, package) = data.split_at((split as usize * 4).min(data.len()));
+    let global_data = IoGlobalData::read(&mut Cursor::new(global)).unwrap_or_default();
+    for version in [EngineVersion::VerUe5_1, EngineVersion::VerUe5_3] {
+        let _ = ZenPackageHeader::read(Cursor::new(package), version, &global_data);
+    }
+});
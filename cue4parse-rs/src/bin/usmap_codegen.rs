@@ -0,0 +1,64 @@
+//! Generate Rust types from a `.usmap` file
+//!
+//! ```text
+//! usmap-codegen <mappings.usmap> [-o <output.rs>] [--crate-path <path>] [--type <name>]...
+//! ```
+//!
+//! Writes to stdout unless an output file is given. Without `--type`, every enum and schema
+//! in the mappings is generated.
+
+use std::process::ExitCode;
+
+use cue4parse_rs::unreal_asset::{RustGenerator, Usmap};
+
+const USAGE: &str = "usage: usmap-codegen <mappings.usmap> [-o <output.rs>] [--crate-path <path>] [--type <name>]...";
+
+fn main() -> ExitCode {
+    match run(std::env::args().skip(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(mut args: impl Iterator<Item = String>) -> Result<(), String> {
+    let mut input = None;
+    let mut output = None;
+    let mut crate_path = None;
+    let mut types = Vec::new();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or_else(|| format!("{} needs a value\n{}", arg, USAGE));
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(value()?),
+            "--crate-path" => crate_path = Some(value()?),
+            "--type" => types.push(value()?),
+            "-h" | "--help" => {
+                println!("{}", USAGE);
+                return Ok(());
+            }
+            _ if input.is_none() && !arg.starts_with('-') => input = Some(arg),
+            _ => return Err(format!("unexpected argument '{}'\n{}", arg, USAGE)),
+        }
+    }
+    let input = input.ok_or(USAGE)?;
+
+    let mappings = Usmap::from_file(&input).map_err(|e| format!("failed to read {}: {}", input, e))?;
+    let mut generator = RustGenerator::new(&mappings);
+    if let Some(crate_path) = crate_path {
+        generator = generator.with_crate_path(crate_path);
+    }
+    if !types.is_empty() {
+        generator = generator.with_types(types);
+    }
+    let source = generator.generate();
+
+    match output {
+        Some(path) => std::fs::write(&path, source).map_err(|e| format!("failed to write {}: {}", path, e)),
+        None => {
+            print!("{}", source);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,430 @@
+//! `Asset` type mirroring the layout of `unreal_asset::Asset`
+//!
+//! Assets are built from the JSON produced by the CUE4Parse CLI: `--package-info`
+//! supplies the export table, `--export` supplies each object's properties.
+//! Object references found in the property JSON are turned into imports so the
+//! resulting asset can be written back out with [`PropertyWriter`](crate::unreal_asset::PropertyWriter).
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::marker::PhantomData;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::unreal_asset::exports::{Export, Import};
+use crate::unreal_asset::object_versions::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version};
+use crate::unreal_asset::package_summary::{GenerationInfo, PackageFileSummary};
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::types::{ClassQualifiedPath, FName, PackageIndex};
+use crate::unreal_asset::versions::{CustomVersion, EngineVersion};
+use crate::unreal_asset::containers::NameMap;
+use crate::unreal_asset::conversion::{ConversionUtils, PropertyConverter};
+use crate::unreal_asset::error::UnrealAssetResult;
+use crate::{GameVersion, PackageInfo, Provider};
+
+/// Keys written by CUE4Parse for every object that are not properties
+const OBJECT_HEADER_KEYS: &[&str] = &["Type", "Name", "Outer", "Class", "Super", "Template", "Flags", "Properties"];
+
+/// Asset data shared by all assets
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetData {
+    /// Name of the package's main object
+    pub object_name: String,
+    /// Full package name, e.g. "/Game/Props/BP_Crate"
+    #[serde(default)]
+    pub package_name: String,
+    /// Engine version label, e.g. "UE5.3"
+    pub engine_version: String,
+    /// UE4 object version
+    pub object_version: i32,
+    /// UE5 object version, 0 for UE4 packages
+    pub object_version_ue5: i32,
+    /// Package flags
+    pub package_flags: u32,
+    /// Total size of the package header
+    pub total_header_size: i32,
+    /// Package GUID, absent on packages saved by UE5
+    pub package_guid: Option<Uuid>,
+    /// Package name map
+    pub name_map: NameMap,
+    /// Import table
+    pub imports: Vec<Import>,
+    /// Export table
+    pub exports: Vec<Export>,
+    /// Package file summary
+    pub summary: PackageFileSummary,
+    /// Whether properties are serialized without tags
+    pub unversioned_properties: bool,
+    /// Whether the package uses the event driven loader
+    pub use_event_driven_loader: bool,
+    /// Path to the usmap used to read unversioned properties
+    pub mappings_path: Option<String>,
+}
+
+impl Default for AssetData {
+    fn default() -> Self {
+        Self {
+            object_name: String::new(),
+            package_name: String::new(),
+            engine_version: "UE5.3".to_string(),
+            object_version: EUnrealEngineObjectUE4Version::AUTOMATIC_VERSION as i32,
+            object_version_ue5: EUnrealEngineObjectUE5Version::DATA_RESOURCES as i32,
+            package_flags: 0,
+            total_header_size: 0,
+            package_guid: None,
+            name_map: NameMap::new(),
+            imports: Vec::new(),
+            exports: Vec::new(),
+            summary: PackageFileSummary::default(),
+            unversioned_properties: false,
+            use_event_driven_loader: false,
+            mappings_path: None,
+        }
+    }
+}
+
+/// Unreal asset with the same field layout as `unreal_asset::Asset`
+///
+/// `R` is the reader type the asset was parsed from; it is kept for API parity only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Asset<R = Cursor<Vec<u8>>> {
+    /// Asset data
+    pub asset_data: AssetData,
+    /// Legacy file version
+    pub legacy_file_version: i32,
+    /// Package folder name
+    pub folder_name: String,
+    /// Generations recorded in the summary
+    pub generations: Vec<GenerationInfo>,
+    /// Chunk ids
+    pub chunk_ids: Vec<i32>,
+    /// Package source
+    pub package_source: u32,
+    /// Custom versions the package was saved with
+    pub custom_versions: Vec<CustomVersion>,
+    /// Asset registry tags
+    pub asset_tags: IndexMap<String, String>,
+    /// Depends map
+    pub depends_map: Option<Vec<Vec<i32>>>,
+    /// Soft package references
+    pub soft_package_reference_list: Option<Vec<String>>,
+    /// Offset of bulk data in the package
+    pub bulk_data_start_offset: i64,
+    #[serde(skip)]
+    _reader: PhantomData<R>,
+}
+
+impl<R> Default for Asset<R> {
+    fn default() -> Self {
+        Self {
+            asset_data: AssetData::default(),
+            legacy_file_version: -8,
+            folder_name: "None".to_string(),
+            generations: Vec::new(),
+            chunk_ids: Vec::new(),
+            package_source: 0,
+            custom_versions: Vec::new(),
+            asset_tags: IndexMap::new(),
+            depends_map: None,
+            soft_package_reference_list: None,
+            bulk_data_start_offset: 0,
+            _reader: PhantomData,
+        }
+    }
+}
+
+impl<R> Asset<R> {
+    /// Create an empty asset
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get an export by object name
+    pub fn get_export_by_name(&self, name: &str) -> Option<&Export> {
+        self.asset_data.exports.iter().find(|e| e.object_name.as_str() == name)
+    }
+
+    /// Get a mutable export by object name
+    pub fn get_export_by_name_mut(&mut self, name: &str) -> Option<&mut Export> {
+        self.asset_data.exports.iter_mut().find(|e| e.object_name.as_str() == name)
+    }
+
+    /// Get the main export, which is the first export of the package
+    pub fn get_main_export(&self) -> Option<&Export> {
+        self.asset_data.exports.first()
+    }
+
+    /// Get an import by object name
+    pub fn get_import_by_name(&self, name: &str) -> Option<&Import> {
+        self.asset_data.imports.iter().find(|i| i.object_name.as_str() == name)
+    }
+
+    /// Get the export referenced by a package index
+    pub fn get_export(&self, index: PackageIndex) -> Option<&Export> {
+        index.export_index().and_then(|i| self.asset_data.exports.get(i))
+    }
+
+    /// Get the import referenced by a package index
+    pub fn get_import(&self, index: PackageIndex) -> Option<&Import> {
+        index.import_index().and_then(|i| self.asset_data.imports.get(i))
+    }
+
+    /// Find an import by outer, class and object name
+    pub fn find_import(&self, outer_index: PackageIndex, class_name: &str, object_name: &str) -> Option<PackageIndex> {
+        self.asset_data.imports.iter()
+            .position(|i| {
+                i.outer_index == outer_index
+                    && i.class_name.as_str() == class_name
+                    && i.object_name.as_str() == object_name
+            })
+            .map(PackageIndex::from_import)
+    }
+
+    /// Find an import or add it, adding its names to the name map
+    pub fn find_or_add_import(&mut self, class_package: &str, class_name: &str, outer_index: PackageIndex, object_name: &str) -> PackageIndex {
+        if let Some(index) = self.find_import(outer_index, class_name, object_name) {
+            return index;
+        }
+        for name in [class_package, class_name, object_name] {
+            self.add_name(name);
+        }
+        let package_name = match self.get_import(outer_index) {
+            Some(outer) => outer.package_name.clone(),
+            None => FName::new(object_name),
+        };
+        self.asset_data.imports.push(Import {
+            class_package: FName::new(class_package),
+            class_name: FName::new(class_name),
+            outer_index,
+            object_name: FName::new(object_name),
+            package_guid: None,
+            package_name,
+        });
+        PackageIndex::from_import(self.asset_data.imports.len() - 1)
+    }
+
+    /// Add a name to the name map if it is not already present, returning its index
+    pub fn add_name(&mut self, name: &str) -> i32 {
+        self.asset_data.name_map.add_name_reference(name.to_string(), false)
+    }
+
+    /// Add every name used by a property to the name map
+    fn add_property_names(&mut self, property: &Property) {
+        match property {
+            Property::Name(name) => {
+                self.add_name(&name.name);
+            }
+            Property::Struct { struct_type, properties } => {
+                self.add_name(&struct_type.name);
+                for (name, value) in properties {
+                    self.add_name(name);
+                    self.add_name(value.type_name());
+                    self.add_property_names(value);
+                }
+            }
+            Property::Array(values) | Property::Set(values) => {
+                for value in values {
+                    self.add_property_names(value);
+                }
+            }
+            Property::Map { entries, .. } => {
+                for (key, value) in entries {
+                    self.add_property_names(key);
+                    self.add_property_names(value);
+                }
+            }
+            Property::Enum { enum_type, value } => {
+                self.add_name(&enum_type.name);
+                self.add_name(&value.name);
+            }
+            Property::SoftObjectPath(path) | Property::SoftClassPath(path) => {
+                self.add_name(&path.asset_path.name);
+            }
+            _ => {}
+        }
+    }
+
+    /// Resolve a CUE4Parse object reference (`{"ObjectName", "ObjectPath"}`) to a package index
+    fn resolve_reference(&mut self, object_name: &str, object_path: &str) -> Option<PackageIndex> {
+        let (class_name, path) = ClassQualifiedPath::split(object_name)?;
+        let name = path.rsplit([':', '.']).next().unwrap_or(path);
+
+        // Exports of this package are written as `Package.ExportIndex`
+        if let Some((package, index)) = object_path.rsplit_once('.') {
+            if let Ok(index) = index.parse::<i32>() {
+                if self.package_name_matches(package) {
+                    return Some(PackageIndex(index + 1));
+                }
+                return Some(self.add_object_import(package, class_name, name));
+            }
+        }
+        Some(self.add_object_import(object_path, class_name, name))
+    }
+
+    fn add_object_import(&mut self, package: &str, class_name: &str, object_name: &str) -> PackageIndex {
+        let outer = self.find_or_add_import("/Script/CoreUObject", "Package", PackageIndex::null(), package);
+        if package == object_name {
+            return outer;
+        }
+        self.find_or_add_import(class_package_of(class_name), class_name, outer, object_name)
+    }
+
+    /// Whether `package` names this asset's own package
+    fn package_name_matches(&self, package: &str) -> bool {
+        !self.asset_data.package_name.is_empty()
+            && normalize_package_name(package) == normalize_package_name(&self.asset_data.package_name)
+    }
+}
+
+impl Asset {
+    /// Convert a CUE4Parse JSON value into a property of the given type
+    ///
+    /// Same as [`ConversionUtils::json_to_property_typed`]: `type_name` is an annotation such
+    /// as `IntProperty` or `ArrayProperty<NameProperty>`. Object references can't be resolved
+    /// without an import table; use [`Asset::from_cue4parse_json`] to resolve them.
+    pub fn json_to_property(value: &Value, type_name: &str) -> UnrealAssetResult<Property> {
+        ConversionUtils::json_to_property_typed(value, type_name)
+    }
+
+    /// Build an asset from the package info and object JSON produced by CUE4Parse
+    ///
+    /// `objects` is either a single object or an array of objects as written by
+    /// `--export`; objects are matched to exports by name. Properties are typed by the
+    /// object's `$types` annotation through [`PropertyConverter`]; values it can't convert,
+    /// such as unannotated numbers or unresolved references, are kept as `Property::Unknown`.
+    pub fn from_cue4parse_json(package: &PackageInfo, objects: &Value, game_version: GameVersion) -> Self {
+        let mut asset = Asset::new();
+        let (engine_version, object_version, object_version_ue5) = game_version_numbers(game_version);
+        asset.asset_data.engine_version = engine_version.to_string();
+        asset.asset_data.object_version = object_version;
+        asset.asset_data.object_version_ue5 = object_version_ue5;
+
+        let package_name = package.name.rsplit_once('.').map_or(package.name.as_str(), |(p, _)| p);
+        asset.add_name(package_name);
+        asset.asset_data.object_name = package_name.rsplit('/').next().unwrap_or(package_name).to_string();
+        asset.asset_data.package_name = package_name.to_string();
+
+        let mut by_name: HashMap<&str, &Value> = HashMap::new();
+        let objects: Vec<&Value> = match objects {
+            Value::Array(values) => values.iter().collect(),
+            value => vec![value],
+        };
+        for object in objects.into_iter().filter(|o| o.is_object()) {
+            if let Some(name) = object.get("Name").and_then(Value::as_str) {
+                by_name.insert(name, object);
+            }
+        }
+
+        for info in &package.exports {
+            asset.add_name(&info.name);
+            let object = by_name.get(info.name.as_str()).copied();
+
+            // `Class` is written as `Class'/Script/Engine.StaticMesh'`
+            let class_path = object.and_then(|o| o.get("Class")).and_then(Value::as_str)
+                .and_then(ClassQualifiedPath::split)
+                .map(|(_, path)| path);
+            let class_index = match class_path.and_then(|p| p.rsplit_once('.')) {
+                Some((class_package, class_name)) => asset.add_object_import(class_package, "Class", class_name),
+                None => {
+                    let class_package = class_package_of(&info.class_name);
+                    asset.add_object_import(class_package, "Class", &info.class_name)
+                }
+            };
+
+            let mut export = Export::new(FName::new(info.name.clone()), class_index);
+            export.outer_index = PackageIndex(info.outer_index);
+
+            if let Some(object) = object {
+                if let Some(Value::Object(properties)) = object.get("Properties") {
+                    for (name, value) in properties {
+                        let mut resolve = |n: &str, p: &str| asset.resolve_reference(n, p);
+                        let property = PropertyConverter::new()
+                            .with_resolver(&mut resolve)
+                            .convert_property(object, name, value, None)
+                            .unwrap_or_else(|_| Property::Unknown(value.clone()));
+                        asset.add_name(name);
+                        asset.add_name(property.type_name());
+                        asset.add_property_names(&property);
+                        export.properties.insert(name.clone(), property);
+                    }
+                }
+                let extras: Map<String, Value> = object.as_object().into_iter().flatten()
+                    .filter(|(k, _)| !OBJECT_HEADER_KEYS.contains(&k.as_str()) && !k.starts_with('$'))
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect();
+                if !extras.is_empty() {
+                    export.extras = Some(Value::Object(extras));
+                }
+            }
+            asset.asset_data.exports.push(export);
+        }
+        asset.add_name("None");
+
+        let summary = &mut asset.asset_data.summary;
+        summary.name_count = asset.asset_data.name_map.len() as i32;
+        summary.import_count = asset.asset_data.imports.len() as i32;
+        summary.export_count = asset.asset_data.exports.len() as i32;
+        asset.generations.push(GenerationInfo {
+            export_count: summary.export_count,
+            name_count: summary.name_count,
+        });
+        asset
+    }
+}
+
+/// Load assets through a CUE4Parse [`Provider`] with the `unreal_asset` API
+pub trait UnrealAssetCompat: Sized {
+    /// Load the package containing `object_path` and convert it
+    fn from_cue4parse(provider: &Provider, object_path: &str) -> crate::Result<Self>;
+}
+
+impl UnrealAssetCompat for Asset {
+    fn from_cue4parse(provider: &Provider, object_path: &str) -> crate::Result<Self> {
+        let package_path = object_path.rsplit_once('.').map_or(object_path, |(p, _)| p);
+        let package = provider.load_package(package_path)?;
+        let objects = provider.export_object_json(object_path)?;
+
+        let mut asset = Asset::from_cue4parse_json(&package, &objects, provider.config.game_version);
+        asset.asset_data.mappings_path = provider.config.mappings_path.clone();
+        asset.asset_data.unversioned_properties = provider.has_mappings();
+        Ok(asset)
+    }
+}
+
+/// Engine label, UE4 and UE5 object versions CUE4Parse reads a game version with
+fn game_version_numbers(version: GameVersion) -> (&'static str, i32, i32) {
+    let engine = EngineVersion::from(version);
+    let name = match version {
+        GameVersion::UE4_0 => "UE4.0",
+        GameVersion::UE4_27 => "UE4.27",
+        GameVersion::UE5_0 => "UE5.0",
+        GameVersion::UE5_1 => "UE5.1",
+        GameVersion::UE5_2 => "UE5.2",
+        GameVersion::UE5_3 => "UE5.3",
+        GameVersion::UE5_4 => "UE5.4",
+        GameVersion::UE5_5 => "UE5.5",
+    };
+    (name, engine.object_version().get(), engine.object_version_ue5().get())
+}
+
+/// Package name as UE compares it: rooted, without an object suffix and case-insensitive
+fn normalize_package_name(package: &str) -> String {
+    let package = package.trim().trim_start_matches('/');
+    let package = match package.rsplit_once('/') {
+        Some((folder, name)) => format!("{}/{}", folder, name.split('.').next().unwrap_or(name)),
+        None => package.split('.').next().unwrap_or(package).to_string(),
+    };
+    format!("/{}", package.to_ascii_lowercase())
+}
+
+/// Script package declaring a class, for classes referenced without their package
+fn class_package_of(class_name: &str) -> &'static str {
+    match class_name {
+        "Class" | "Package" | "ScriptStruct" | "Enum" | "Function" | "Object" => "/Script/CoreUObject",
+        _ => "/Script/Engine",
+    }
+}
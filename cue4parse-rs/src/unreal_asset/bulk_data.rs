@@ -0,0 +1,553 @@
+//! Bulk data headers and lazily loaded payloads
+//!
+//! An `FByteBulkData` header says where its payload lives: inline after the
+//! header, at the end of the package in `.uexp`, in `.ubulk`, `.m.ubulk` or the
+//! optional `.uptnl` file. IoStore packages keep the same payloads in bulk data
+//! chunks, which can be registered as in-memory sources.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use bitflags::bitflags;
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use lru::LruCache;
+use serde::{Deserialize, Serialize};
+
+use crate::unreal_asset::package_summary::PACKAGE_FILE_TAG;
+use crate::unreal_asset::compression::{decompress, CompressionMethod, Decompressor};
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::object_versions::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version};
+use crate::unreal_asset::reader::{ArchiveReader, Endianness};
+use crate::unreal_asset::types::PackageIndex;
+
+bitflags! {
+    /// `EBulkDataFlags`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+    pub struct BulkDataFlags: u32 {
+        const PAYLOAD_AT_END_OF_FILE = 1 << 0;
+        const COMPRESS_ZLIB = 1 << 1;
+        const FORCE_SINGLE_ELEMENT_SERIALIZATION = 1 << 2;
+        const SINGLE_USE = 1 << 3;
+        const UNUSED = 1 << 5;
+        const FORCE_INLINE_PAYLOAD = 1 << 6;
+        const FORCE_STREAM_PAYLOAD = 1 << 7;
+        const PAYLOAD_IN_SEPARATE_FILE = 1 << 8;
+        const COMPRESS_BIT_WINDOW = 1 << 9;
+        const FORCE_NOT_INLINE_PAYLOAD = 1 << 10;
+        const OPTIONAL_PAYLOAD = 1 << 11;
+        const MEMORY_MAPPED_PAYLOAD = 1 << 12;
+        const SIZE_64_BIT = 1 << 13;
+        const DUPLICATE_NON_OPTIONAL_PAYLOAD = 1 << 14;
+        const BAD_DATA_VERSION = 1 << 15;
+        const NO_OFFSET_FIX_UP = 1 << 16;
+        const WORKSPACE_DOMAIN_PAYLOAD = 1 << 17;
+        const LAZY_LOADABLE = 1 << 18;
+        const ALWAYS_ALLOW_DISCARD = 1 << 28;
+        const HAS_ASYNC_READ_PENDING = 1 << 29;
+        const DATA_IS_MEMORY_MAPPED = 1 << 30;
+        const USES_IO_DISPATCHER = 1 << 31;
+    }
+}
+
+impl BulkDataFlags {
+    /// Where a payload with these flags is stored
+    pub fn location(self) -> BulkDataLocation {
+        if self.contains(BulkDataFlags::UNUSED) {
+            BulkDataLocation::Unused
+        } else if self.contains(BulkDataFlags::FORCE_INLINE_PAYLOAD) {
+            BulkDataLocation::Inline
+        } else if self.contains(BulkDataFlags::OPTIONAL_PAYLOAD) {
+            BulkDataLocation::OptionalFile
+        } else if self.contains(BulkDataFlags::PAYLOAD_IN_SEPARATE_FILE) {
+            if self.contains(BulkDataFlags::MEMORY_MAPPED_PAYLOAD) {
+                BulkDataLocation::MemoryMappedFile
+            } else {
+                BulkDataLocation::SeparateFile
+            }
+        } else if self.contains(BulkDataFlags::PAYLOAD_AT_END_OF_FILE) {
+            BulkDataLocation::EndOfFile
+        } else {
+            BulkDataLocation::Inline
+        }
+    }
+}
+
+/// Where a bulk data payload is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum BulkDataLocation {
+    /// Directly after the header in the export data
+    Inline,
+    /// At the end of the package, in `.uexp` for split packages
+    EndOfFile,
+    /// In `.ubulk`
+    SeparateFile,
+    /// In `.m.ubulk`
+    MemoryMappedFile,
+    /// In the optional `.uptnl`
+    OptionalFile,
+    /// No payload
+    Unused,
+}
+
+impl BulkDataLocation {
+    /// File extension of the payload file, if it is not inline
+    pub fn extension(self) -> Option<&'static str> {
+        match self {
+            BulkDataLocation::EndOfFile => Some("uexp"),
+            BulkDataLocation::SeparateFile => Some("ubulk"),
+            BulkDataLocation::MemoryMappedFile => Some("m.ubulk"),
+            BulkDataLocation::OptionalFile => Some("uptnl"),
+            BulkDataLocation::Inline | BulkDataLocation::Unused => None,
+        }
+    }
+
+    /// `EIoChunkType5` of the IoStore chunk holding the payload
+    pub fn io_chunk_type(self) -> Option<u8> {
+        match self {
+            BulkDataLocation::EndOfFile => Some(1), // ExportBundleData
+            BulkDataLocation::SeparateFile => Some(2), // BulkData
+            BulkDataLocation::OptionalFile => Some(3), // OptionalBulkData
+            BulkDataLocation::MemoryMappedFile => Some(4), // MemoryMappedBulkData
+            BulkDataLocation::Inline | BulkDataLocation::Unused => None,
+        }
+    }
+}
+
+/// `FObjectDataResource`, the UE5 replacement for inline bulk data headers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectDataResource {
+    pub flags: u32,
+    pub cooked_index: u8,
+    pub serial_offset: i64,
+    pub duplicate_serial_offset: i64,
+    pub serial_size: i64,
+    pub raw_size: i64,
+    pub outer_index: PackageIndex,
+    pub legacy_bulk_data_flags: BulkDataFlags,
+}
+
+impl ObjectDataResource {
+    /// Read the data resource table at the summary's `DataResourceOffset`
+    pub fn read_table<A: ArchiveReader<PackageIndex>>(ar: &mut A) -> UnrealAssetResult<Vec<Self>> {
+        let version = ar.read_u32_endian()?;
+        if !(1..=2).contains(&version) {
+            return Err(UnrealAssetError::UnsupportedVersion(format!("Data resource version {}", version)));
+        }
+        ArchiveReader::read_array(ar, |ar| {
+            let flags = ar.read_u32_endian()?;
+            let cooked_index = if version >= 2 { ar.read_u8()? } else { 0 }; // AddedCookedIndex
+            Ok(ObjectDataResource {
+                flags,
+                cooked_index,
+                serial_offset: ar.read_i64_endian()?,
+                duplicate_serial_offset: ar.read_i64_endian()?,
+                serial_size: ar.read_i64_endian()?,
+                raw_size: ar.read_i64_endian()?,
+                outer_index: PackageIndex::new(ar.read_i32_endian()?),
+                legacy_bulk_data_flags: BulkDataFlags::from_bits_retain(ar.read_u32_endian()?),
+            })
+        })
+    }
+}
+
+/// `FByteBulkData` header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct BulkDataHeader {
+    pub flags: BulkDataFlags,
+    pub element_count: i64,
+    pub size_on_disk: i64,
+    pub offset_in_file: i64,
+}
+
+impl BulkDataHeader {
+    /// Read a bulk data header
+    ///
+    /// `bulk_data_start_offset` comes from the package summary; UE5 packages with
+    /// data resources store an index into `data_resources` instead of a header.
+    pub fn read<A: ArchiveReader<PackageIndex>>(
+        ar: &mut A,
+        bulk_data_start_offset: i64,
+        data_resources: &[ObjectDataResource],
+    ) -> UnrealAssetResult<Self> {
+        if ar.get_object_version_ue5() >= EUnrealEngineObjectUE5Version::DATA_RESOURCES {
+            let index = ar.read_i32_endian()?;
+            let resource = usize::try_from(index).ok().and_then(|i| data_resources.get(i))
+                .ok_or_else(|| UnrealAssetError::InvalidIndex(format!("Data resource index {} out of range", index)))?;
+            return Ok(BulkDataHeader {
+                flags: resource.legacy_bulk_data_flags,
+                element_count: resource.raw_size,
+                size_on_disk: resource.serial_size,
+                offset_in_file: resource.serial_offset,
+            });
+        }
+
+        let mut flags = BulkDataFlags::from_bits_retain(ar.read_u32_endian()?);
+        let (element_count, size_on_disk) = if flags.contains(BulkDataFlags::SIZE_64_BIT) {
+            (ar.read_i64_endian()?, ar.read_i64_endian()?)
+        } else {
+            (i64::from(ar.read_i32_endian()?), i64::from(ar.read_u32_endian()?))
+        };
+        let mut offset_in_file = if ar.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_BULKDATA_AT_LARGE_OFFSETS {
+            ar.read_i64_endian()?
+        } else {
+            i64::from(ar.read_i32_endian()?)
+        };
+        if !flags.contains(BulkDataFlags::NO_OFFSET_FIX_UP) {
+            offset_in_file += bulk_data_start_offset;
+        }
+        if flags.contains(BulkDataFlags::BAD_DATA_VERSION) {
+            ar.read_u16_endian()?;
+            flags.remove(BulkDataFlags::BAD_DATA_VERSION);
+        }
+
+        if element_count < 0 || size_on_disk < 0 || offset_in_file < 0 {
+            return Err(UnrealAssetError::InvalidData(format!(
+                "Invalid bulk data header: {} elements, {} bytes at {}", element_count, size_on_disk, offset_in_file
+            )));
+        }
+        Ok(BulkDataHeader { flags, element_count, size_on_disk, offset_in_file })
+    }
+
+    /// Where the payload is stored
+    pub fn location(&self) -> BulkDataLocation {
+        if self.element_count == 0 {
+            BulkDataLocation::Unused
+        } else {
+            self.flags.location()
+        }
+    }
+}
+
+/// A bulk data payload and its metadata
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkDataEntry {
+    pub name: String,
+    pub size_on_disk: u64,
+    pub size_in_memory: u64,
+    pub offset: u64,
+    pub flags: BulkDataFlags,
+    pub compression_method: Option<CompressionMethod>,
+    /// Payload as stored on disk, if it was inline or already read
+    pub data: Option<Vec<u8>>,
+}
+
+impl BulkDataEntry {
+    /// Create an entry from a header, without its payload
+    pub fn from_header(name: impl Into<String>, header: &BulkDataHeader) -> Self {
+        Self {
+            name: name.into(),
+            size_on_disk: header.size_on_disk as u64,
+            size_in_memory: header.element_count as u64,
+            offset: header.offset_in_file as u64,
+            flags: header.flags,
+            compression_method: header.flags.contains(BulkDataFlags::COMPRESS_ZLIB).then_some(CompressionMethod::Zlib),
+            data: None,
+        }
+    }
+
+    /// Read an `FByteBulkData`, including its payload if it is inline
+    pub fn read<A: ArchiveReader<PackageIndex>>(
+        ar: &mut A,
+        name: impl Into<String>,
+        bulk_data_start_offset: i64,
+        data_resources: &[ObjectDataResource],
+    ) -> UnrealAssetResult<Self> {
+        let header = BulkDataHeader::read(ar, bulk_data_start_offset, data_resources)?;
+        let mut entry = Self::from_header(name, &header);
+        if header.location() == BulkDataLocation::Inline {
+            if entry.size_on_disk > ar.remaining()? {
+                return Err(UnrealAssetError::InvalidData(format!(
+                    "Inline bulk data of {} bytes is past the end of the archive", entry.size_on_disk
+                )));
+            }
+            let mut data = vec![0u8; entry.size_on_disk as usize];
+            ar.read_exact(&mut data)?;
+            entry.data = Some(data);
+        }
+        Ok(entry)
+    }
+
+    /// Where the payload is stored
+    pub fn location(&self) -> BulkDataLocation {
+        if self.size_in_memory == 0 {
+            BulkDataLocation::Unused
+        } else {
+            self.flags.location()
+        }
+    }
+
+    /// Check whether the payload is stored compressed
+    pub fn is_compressed(&self) -> bool {
+        self.compression_method.is_some_and(|m| m != CompressionMethod::None)
+    }
+}
+
+/// Backing storage for payloads outside the export data
+#[derive(Debug, Clone)]
+pub enum BulkDataSource {
+    /// A file on disk; `base_offset` is subtracted from entry offsets
+    File { path: PathBuf, base_offset: u64 },
+    /// Bytes already in memory, such as an extracted IoStore chunk
+    Memory { data: Arc<Vec<u8>>, base_offset: u64 },
+}
+
+impl BulkDataSource {
+    fn read(&self, offset: u64, size: u64) -> UnrealAssetResult<Vec<u8>> {
+        let out_of_range = |base: u64| UnrealAssetError::InvalidData(format!(
+            "Bulk data at {} is before the source start {}", offset, base
+        ));
+        match self {
+            BulkDataSource::File { path, base_offset } => {
+                let position = offset.checked_sub(*base_offset).ok_or_else(|| out_of_range(*base_offset))?;
+                let mut file = File::open(path)?;
+                let end = position.checked_add(size).filter(|&end| end <= file.metadata().map_or(0, |m| m.len()));
+                let Some(end) = end else {
+                    return Err(UnrealAssetError::InvalidData(format!(
+                        "Bulk data of {} bytes at {} is past the end of {}", size, position, path.display()
+                    )));
+                };
+                file.seek(SeekFrom::Start(position))?;
+                let mut data = vec![0u8; (end - position) as usize];
+                file.read_exact(&mut data)?;
+                Ok(data)
+            }
+            BulkDataSource::Memory { data, base_offset } => {
+                let position = offset.checked_sub(*base_offset).ok_or_else(|| out_of_range(*base_offset))?;
+                position.checked_add(size)
+                    .and_then(|end| data.get(usize::try_from(position).ok()?..usize::try_from(end).ok()?))
+                    .map(<[u8]>::to_vec)
+                    .ok_or_else(|| UnrealAssetError::InvalidData(format!(
+                        "Bulk data of {} bytes at {} is out of range", size, position
+                    )))
+            }
+        }
+    }
+}
+
+/// Bulk data cache statistics
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct BulkDataCacheStats {
+    pub entries_count: usize,
+    pub cached_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    pub hit_ratio: f64,
+}
+
+/// Default number of decompressed payloads kept in memory
+pub const DEFAULT_BULK_DATA_CACHE_SIZE: usize = 64;
+
+/// Tracks a package's bulk data and loads payloads on demand
+pub struct BulkDataManager {
+    entries: HashMap<String, BulkDataEntry>,
+    sources: HashMap<BulkDataLocation, BulkDataSource>,
+    decompressors: HashMap<CompressionMethod, Box<Decompressor>>,
+    cache: LruCache<String, Arc<Vec<u8>>>,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for BulkDataManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BulkDataManager {
+    /// Create a manager with the default cache size
+    pub fn new() -> Self {
+        Self::with_cache_size(DEFAULT_BULK_DATA_CACHE_SIZE)
+    }
+
+    /// Create a manager keeping at most `capacity` decompressed payloads in memory
+    pub fn with_cache_size(capacity: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            sources: HashMap::new(),
+            decompressors: HashMap::new(),
+            cache: LruCache::new(NonZeroUsize::new(capacity).unwrap_or(NonZeroUsize::MIN)),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Register the `.uexp`, `.ubulk`, `.m.ubulk` and `.uptnl` files next to a package
+    ///
+    /// `package_path` may point at the `.uasset` or have no extension. Payloads at
+    /// the end of the file are offset by `header_size`, the size of the `.uasset`.
+    pub fn with_package_path(mut self, package_path: impl AsRef<Path>, header_size: u64) -> Self {
+        let base = package_path.as_ref().with_extension("");
+        for location in [
+            BulkDataLocation::EndOfFile,
+            BulkDataLocation::SeparateFile,
+            BulkDataLocation::MemoryMappedFile,
+            BulkDataLocation::OptionalFile,
+        ] {
+            let path = base.with_extension(location.extension().unwrap_or_default());
+            if path.exists() {
+                let base_offset = if location == BulkDataLocation::EndOfFile { header_size } else { 0 };
+                self.sources.insert(location, BulkDataSource::File { path, base_offset });
+            }
+        }
+        self
+    }
+
+    /// Register where payloads stored at a location are read from
+    pub fn register_source(&mut self, location: BulkDataLocation, source: BulkDataSource) {
+        self.sources.insert(location, source);
+    }
+
+    /// Register a decompressor for a method without native support, such as Oodle
+    pub fn register_decompressor(&mut self, method: CompressionMethod, decompressor: Box<Decompressor>) {
+        self.decompressors.insert(method, decompressor);
+    }
+
+    /// Add a bulk data entry
+    pub fn add_bulk_data(&mut self, name: String, entry: BulkDataEntry) -> UnrealAssetResult<()> {
+        if self.entries.contains_key(&name) {
+            return Err(UnrealAssetError::InvalidData(format!("Bulk data '{}' already exists", name)));
+        }
+        self.cache.pop(&name);
+        self.entries.insert(name, entry);
+        Ok(())
+    }
+
+    /// Get a bulk data entry's metadata without loading its payload
+    pub fn get_bulk_data(&self, name: &str) -> UnrealAssetResult<&BulkDataEntry> {
+        self.entries.get(name)
+            .ok_or_else(|| UnrealAssetError::InvalidIndex(format!("No bulk data named '{}'", name)))
+    }
+
+    /// Remove a bulk data entry and its cached payload
+    pub fn remove_bulk_data(&mut self, name: &str) -> Option<BulkDataEntry> {
+        self.cache.pop(name);
+        self.entries.remove(name)
+    }
+
+    /// Iterate over every entry
+    pub fn entries(&self) -> impl Iterator<Item = &BulkDataEntry> {
+        self.entries.values()
+    }
+
+    /// Load and decompress a payload, reading it from its source on first use
+    pub fn load(&mut self, name: &str) -> UnrealAssetResult<Arc<Vec<u8>>> {
+        if let Some(data) = self.cache.get(name) {
+            self.hits += 1;
+            return Ok(Arc::clone(data));
+        }
+        self.misses += 1;
+
+        let entry = self.get_bulk_data(name)?;
+        let raw = match (&entry.data, entry.location()) {
+            (_, BulkDataLocation::Unused) => Vec::new(),
+            (Some(data), _) => data.clone(),
+            (None, BulkDataLocation::Inline) => {
+                return Err(UnrealAssetError::InvalidData(format!("Inline bulk data '{}' has no payload", name)));
+            }
+            (None, location) => self.sources.get(&location)
+                .ok_or_else(|| UnrealAssetError::InvalidData(format!(
+                    "No source registered for {:?} bulk data '{}'", location, name
+                )))?
+                .read(entry.offset, entry.size_on_disk)?,
+        };
+
+        let data = match entry.compression_method.filter(|_| entry.is_compressed()) {
+            Some(method) => self.decompress_chunked(method, &raw)?,
+            None => raw,
+        };
+        let data = Arc::new(data);
+        self.cache.put(name.to_string(), Arc::clone(&data));
+        Ok(data)
+    }
+
+    /// Drop a payload from the cache
+    pub fn unload(&mut self, name: &str) {
+        self.cache.pop(name);
+    }
+
+    /// Drop every cached payload
+    pub fn clear_cache(&mut self) {
+        self.cache.clear();
+    }
+
+    /// Get cache statistics
+    pub fn get_cache_stats(&self) -> BulkDataCacheStats {
+        let requests = self.hits + self.misses;
+        BulkDataCacheStats {
+            entries_count: self.cache.len(),
+            cached_bytes: self.cache.iter().map(|(_, data)| data.len() as u64).sum(),
+            hits: self.hits,
+            misses: self.misses,
+            hit_ratio: if requests == 0 { 0.0 } else { self.hits as f64 / requests as f64 },
+        }
+    }
+
+    /// Decompress data written by `FArchive::SerializeCompressed`
+    fn decompress_chunked(&self, method: CompressionMethod, raw: &[u8]) -> UnrealAssetResult<Vec<u8>> {
+        let mut cursor = std::io::Cursor::new(raw);
+        // Big-endian platforms write a byte swapped tag and header, the chunks themselves are unchanged
+        let endianness = match cursor.read_u64::<LittleEndian>()? {
+            tag if tag == u64::from(PACKAGE_FILE_TAG) => Endianness::Little,
+            tag if tag == u64::from(PACKAGE_FILE_TAG).swap_bytes() => Endianness::Big,
+            tag => return Err(UnrealAssetError::InvalidData(format!("Invalid compressed chunk tag {:#x}", tag))),
+        };
+        let read_i64 = |cursor: &mut std::io::Cursor<&[u8]>| match endianness {
+            Endianness::Little => cursor.read_i64::<LittleEndian>(),
+            Endianness::Big => cursor.read_i64::<BigEndian>(),
+        };
+        let mut chunk_size = read_i64(&mut cursor)?;
+        if chunk_size == i64::from(PACKAGE_FILE_TAG) {
+            chunk_size = 131072; // LOADING_COMPRESSION_CHUNK_SIZE
+        }
+        let _total_compressed = read_i64(&mut cursor)?;
+        let total_uncompressed = read_i64(&mut cursor)?;
+        if chunk_size <= 0 || total_uncompressed < 0 {
+            return Err(UnrealAssetError::InvalidData("Invalid compressed chunk header".to_string()));
+        }
+
+        let chunk_count = (total_uncompressed as u64).div_ceil(chunk_size as u64);
+        if chunk_count.saturating_mul(16) > raw.len() as u64 {
+            return Err(UnrealAssetError::InvalidData(format!("Compressed chunk count {} exceeds payload", chunk_count)));
+        }
+        // Every size in the table is checked before it is used, the payload is untrusted
+        let mut chunks = Vec::with_capacity(chunk_count as usize);
+        for _ in 0..chunk_count {
+            let compressed_size = read_i64(&mut cursor)?;
+            let uncompressed_size = read_i64(&mut cursor)?;
+            if !(0..=raw.len() as i64).contains(&compressed_size) || !(0..=chunk_size).contains(&uncompressed_size) {
+                return Err(UnrealAssetError::InvalidData(format!(
+                    "Invalid compressed chunk: {} bytes to {} bytes", compressed_size, uncompressed_size
+                )));
+            }
+            chunks.push((compressed_size as usize, uncompressed_size as usize));
+        }
+        if chunks.iter().map(|&(_, size)| size as u64).sum::<u64>() != total_uncompressed as u64 {
+            return Err(UnrealAssetError::InvalidData("Compressed chunk sizes don't add up to the total".to_string()));
+        }
+
+        // Grows with the decoded chunks rather than trusting the header's total
+        let mut output = Vec::new();
+        let mut position = cursor.position() as usize;
+        for (compressed_size, uncompressed_size) in chunks {
+            let chunk = position.checked_add(compressed_size)
+                .and_then(|end| raw.get(position..end))
+                .ok_or_else(|| UnrealAssetError::InvalidData("Compressed chunk past end of payload".to_string()))?;
+            let data = match self.decompressors.get(&method) {
+                Some(decompressor) => decompressor(chunk, uncompressed_size)?,
+                None => decompress(method, chunk, uncompressed_size)?,
+            };
+            if data.len() != uncompressed_size {
+                return Err(UnrealAssetError::InvalidData(format!(
+                    "Decompressed {} bytes, expected {}", data.len(), uncompressed_size
+                )));
+            }
+            output.extend_from_slice(&data);
+            position += compressed_size;
+        }
+        Ok(output)
+    }
+}
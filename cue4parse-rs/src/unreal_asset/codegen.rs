@@ -0,0 +1,362 @@
+//! Rust code generation from usmap mappings
+//!
+//! [`RustGenerator`] emits a Rust enum for every mapped enum and a struct for every class and
+//! struct schema, with serde attributes that match the engine names. Structs implement
+//! [`SchemaStruct`], which converts them to and from property maps:
+//!
+//! ```ignore
+//! let source = RustGenerator::new(&mappings).with_types(["ItemRow"]).generate();
+//! std::fs::write("src/generated.rs", source)?;
+//!
+//! // In the crate that includes the generated file
+//! let row = ItemRow::from_properties(&export.properties)?;
+//! let properties = row.to_properties_with_mappings(&mappings)?;
+//! ```
+//!
+//! Inherited properties are flattened into each struct, every field is filled from its
+//! default when missing, and math structs map onto the native types of this module. Types
+//! without a Rust equivalent, such as delegates, are kept as `serde_json::Value`. The
+//! generated code uses `serde` and `indexmap`, which the including crate must depend on.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::fmt::Write;
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::unreal_asset::de::{from_properties, from_property};
+use crate::unreal_asset::error::UnrealAssetResult;
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::ser::{to_properties, to_properties_with_mappings, to_property_typed};
+use crate::unreal_asset::usmap::{PropertyType, Usmap};
+
+/// Struct generated from a usmap schema
+pub trait SchemaStruct: Serialize + DeserializeOwned {
+    /// Name of the schema in the mappings
+    const SCHEMA: &'static str;
+
+    fn from_properties(properties: &IndexMap<String, Property>) -> UnrealAssetResult<Self> {
+        from_properties(properties)
+    }
+
+    fn from_property(property: &Property) -> UnrealAssetResult<Self> {
+        from_property(property)
+    }
+
+    /// Properties typed after the Rust field types
+    fn to_properties(&self) -> UnrealAssetResult<IndexMap<String, Property>> {
+        to_properties(self)
+    }
+
+    /// Properties typed after the schema
+    fn to_properties_with_mappings(&self, mappings: &Usmap) -> UnrealAssetResult<IndexMap<String, Property>> {
+        to_properties_with_mappings(self, mappings, Self::SCHEMA)
+    }
+
+    /// Struct property of the schema's struct type
+    fn to_property(&self, mappings: Option<&Usmap>) -> UnrealAssetResult<Property> {
+        to_property_typed(self, &PropertyType::struct_of(Self::SCHEMA), mappings)
+    }
+}
+
+/// Native structs and the types they map to, relative to the crate path
+const NATIVE_TYPES: &[(&str, &str)] = &[
+    ("Vector", "Vector"),
+    ("Vector2D", "Vector2D"),
+    ("Vector4", "Vector4"),
+    ("Rotator", "Rotator"),
+    ("Quat", "Quat"),
+    ("LinearColor", "LinearColor"),
+    ("Transform", "Transform"),
+    ("Guid", "Guid"),
+];
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "dyn", "else", "enum", "extern", "false", "fn", "for",
+    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return", "static", "struct",
+    "trait", "true", "type", "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final",
+    "macro", "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// Generates Rust types from usmap mappings
+pub struct RustGenerator<'a> {
+    mappings: &'a Usmap,
+    crate_path: String,
+    types: Option<Vec<String>>,
+}
+
+impl<'a> RustGenerator<'a> {
+    /// Generate every enum and schema in the mappings
+    pub fn new(mappings: &'a Usmap) -> Self {
+        Self { mappings, crate_path: "cue4parse_rs::unreal_asset".to_string(), types: None }
+    }
+
+    /// Path this module is reachable at from the generated code, `cue4parse_rs::unreal_asset` by default
+    pub fn with_crate_path(mut self, crate_path: impl Into<String>) -> Self {
+        self.crate_path = crate_path.into();
+        self
+    }
+
+    /// Generate only the named enums and schemas, and the types their properties use
+    pub fn with_types<S: Into<String>>(mut self, types: impl IntoIterator<Item = S>) -> Self {
+        self.types = Some(types.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Render the generated module source
+    pub fn generate(&self) -> String {
+        let (enums, schemas) = self.selected();
+        let names = TypeNames::new(enums.iter().chain(&schemas).map(String::as_str));
+
+        let mut out = String::from("// @generated from usmap mappings by cue4parse-rs, do not edit\n");
+        for name in &enums {
+            self.write_enum(&mut out, name, &names);
+        }
+        for name in &schemas {
+            self.write_struct(&mut out, name, &names);
+        }
+        out
+    }
+
+    /// Enums and schemas to generate, sorted by name so regenerated files diff cleanly
+    fn selected(&self) -> (BTreeSet<String>, BTreeSet<String>) {
+        let mut enums = BTreeSet::new();
+        let mut schemas = BTreeSet::new();
+        let Some(roots) = &self.types else {
+            enums.extend(self.mappings.enums.iter().filter(|(_, values)| !values.is_empty()).map(|(name, _)| name.clone()));
+            schemas.extend(self.mappings.schemas.keys().filter(|name| !is_native(name)).cloned());
+            return (enums, schemas);
+        };
+
+        let mut pending: Vec<String> = roots.clone();
+        let mut seen = HashSet::new();
+        while let Some(name) = pending.pop() {
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            if self.mappings.enums.get(&name).is_some_and(|values| !values.is_empty()) {
+                enums.insert(name);
+            } else if let Some(schema) = self.mappings.get_schema(&name).filter(|schema| !is_native(&schema.name)) {
+                for property in self.mappings.get_all_properties(&schema.name) {
+                    referenced_types(&property.property_type, &mut pending);
+                }
+                schemas.insert(schema.name.clone());
+            }
+        }
+        (enums, schemas)
+    }
+
+    fn write_enum(&self, out: &mut String, name: &str, names: &TypeNames) {
+        let values = &self.mappings.enums[name];
+        let _ = writeln!(out, "\n/// `{}`", name);
+        let _ = writeln!(
+            out,
+            "#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ::serde::Serialize, ::serde::Deserialize)]"
+        );
+        let _ = writeln!(out, "pub enum {} {{", names.get(name));
+        let mut used = HashSet::new();
+        for (index, value) in values.iter().enumerate() {
+            let variant = value.rsplit_once("::").map_or(value.as_str(), |(_, variant)| variant);
+            let ident = unique(identifier(variant, Case::Keep), &mut used);
+            if index == 0 {
+                out.push_str("    #[default]\n");
+            }
+            if ident.trim_start_matches("r#") != variant {
+                let _ = writeln!(out, "    #[serde(rename = {:?})]", variant);
+            }
+            let _ = writeln!(out, "    {},", ident);
+        }
+        out.push_str("}\n");
+    }
+
+    fn write_struct(&self, out: &mut String, name: &str, names: &TypeNames) {
+        let Some(schema) = self.mappings.get_schema(name) else {
+            return;
+        };
+        let _ = match &schema.super_type {
+            Some(parent) => writeln!(out, "\n/// `{}`, child of `{}`", name, parent),
+            None => writeln!(out, "\n/// `{}`", name),
+        };
+        out.push_str("#[derive(Debug, Clone, Default, PartialEq, ::serde::Serialize, ::serde::Deserialize)]\n");
+        out.push_str("#[serde(default)]\n");
+        let rust_name = names.get(name);
+        let _ = writeln!(out, "pub struct {} {{", rust_name);
+        let mut used = HashSet::new();
+        for property in self.mappings.get_all_properties(name) {
+            let is_bool = property.property_type.type_name == "BoolProperty";
+            let ident = unique(field_identifier(&property.name, is_bool), &mut used);
+            let _ = writeln!(out, "    #[serde(rename = {:?})]", property.name);
+            let _ = writeln!(out, "    pub {}: {},", ident, self.rust_type(&property.property_type, names));
+        }
+        out.push_str("}\n");
+        let _ = writeln!(
+            out,
+            "\nimpl {}::SchemaStruct for {} {{\n    const SCHEMA: &'static str = {:?};\n}}",
+            self.crate_path, rust_name, name
+        );
+    }
+
+    fn rust_type(&self, property_type: &PropertyType, names: &TypeNames) -> String {
+        let inner = |property_type: &Option<Box<PropertyType>>| match property_type {
+            Some(inner) => self.rust_type(inner, names),
+            None => "::serde_json::Value".to_string(),
+        };
+        let enum_type = || property_type.enum_name.as_deref().and_then(|name| names.try_get(name));
+        match property_type.type_name.as_str() {
+            "BoolProperty" => "bool".to_string(),
+            "Int8Property" => "i8".to_string(),
+            "Int16Property" => "i16".to_string(),
+            "IntProperty" => "i32".to_string(),
+            "Int64Property" => "i64".to_string(),
+            "UInt16Property" => "u16".to_string(),
+            "UInt32Property" => "u32".to_string(),
+            "UInt64Property" => "u64".to_string(),
+            "FloatProperty" => "f32".to_string(),
+            "DoubleProperty" => "f64".to_string(),
+            "ByteProperty" => enum_type().unwrap_or_else(|| "u8".to_string()),
+            "EnumProperty" => enum_type().unwrap_or_else(|| "String".to_string()),
+            "StrProperty" | "Utf8StrProperty" | "AnsiStrProperty" | "NameProperty" | "TextProperty"
+            | "SoftObjectProperty" | "SoftClassProperty" | "AssetObjectProperty" => "String".to_string(),
+            // Package index of the referenced import or export
+            "ObjectProperty" | "ClassProperty" | "WeakObjectProperty" | "LazyObjectProperty" | "InterfaceProperty" => {
+                "Option<i32>".to_string()
+            }
+            "StructProperty" => match property_type.struct_type.as_deref() {
+                Some("DateTime" | "Timespan") => "i64".to_string(),
+                Some("SoftObjectPath" | "SoftClassPath") => "String".to_string(),
+                Some(struct_type) => match NATIVE_TYPES.iter().find(|(name, _)| *name == struct_type) {
+                    Some((_, native)) => format!("{}::{}", self.crate_path, native),
+                    None => names.try_get(struct_type).unwrap_or_else(|| "::serde_json::Value".to_string()),
+                },
+                None => "::serde_json::Value".to_string(),
+            },
+            "ArrayProperty" | "SetProperty" => format!("Vec<{}>", inner(&property_type.inner)),
+            "OptionalProperty" => format!("Option<{}>", inner(&property_type.inner)),
+            "MapProperty" => match property_type.inner.as_deref() {
+                Some(key) if self.is_hashable(key) => {
+                    format!("::indexmap::IndexMap<{}, {}>", self.rust_type(key, names), inner(&property_type.value))
+                }
+                _ => "::serde_json::Value".to_string(),
+            },
+            _ => "::serde_json::Value".to_string(),
+        }
+    }
+
+    /// Whether the Rust type of `property_type` can key a map
+    fn is_hashable(&self, property_type: &PropertyType) -> bool {
+        !matches!(
+            property_type.type_name.as_str(),
+            "FloatProperty" | "DoubleProperty" | "StructProperty" | "ArrayProperty" | "SetProperty" | "MapProperty"
+                | "DelegateProperty" | "MulticastDelegateProperty" | "MulticastInlineDelegateProperty"
+                | "MulticastSparseDelegateProperty" | "FieldPathProperty" | "OptionalProperty"
+        )
+    }
+}
+
+/// Generate every enum and schema in `mappings`
+pub fn generate_rust(mappings: &Usmap) -> String {
+    RustGenerator::new(mappings).generate()
+}
+
+fn is_native(name: &str) -> bool {
+    NATIVE_TYPES.iter().any(|(native, _)| *native == name)
+        || matches!(name, "DateTime" | "Timespan" | "SoftObjectPath" | "SoftClassPath")
+}
+
+/// Push the enums and structs a property type refers to
+fn referenced_types(property_type: &PropertyType, out: &mut Vec<String>) {
+    out.extend(property_type.struct_type.iter().cloned());
+    out.extend(property_type.enum_name.iter().filter(|name| *name != "None").cloned());
+    for nested in [&property_type.inner, &property_type.value].into_iter().flatten() {
+        referenced_types(nested, out);
+    }
+}
+
+/// Rust identifiers of the generated types
+struct TypeNames {
+    names: BTreeMap<String, String>,
+}
+
+impl TypeNames {
+    fn new<'n>(names: impl Iterator<Item = &'n str>) -> Self {
+        let mut used = HashSet::new();
+        let names = names.map(|name| (name.to_string(), unique(identifier(name, Case::Keep), &mut used))).collect();
+        Self { names }
+    }
+
+    fn get(&self, name: &str) -> &str {
+        &self.names[name]
+    }
+
+    fn try_get(&self, name: &str) -> Option<String> {
+        self.names.get(name).cloned()
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Case {
+    Keep,
+    Snake,
+}
+
+/// `bIsActive` becomes `is_active` for booleans, everything else is converted to snake case
+fn field_identifier(name: &str, is_bool: bool) -> String {
+    let mut chars = name.chars();
+    let unprefixed = match (chars.next(), chars.next()) {
+        (Some('b'), Some(second)) if is_bool && second.is_ascii_uppercase() => &name[1..],
+        _ => name,
+    };
+    identifier(unprefixed, Case::Snake)
+}
+
+fn identifier(name: &str, case: Case) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut ident = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if !c.is_ascii_alphanumeric() {
+            if !ident.ends_with('_') {
+                ident.push('_');
+            }
+            continue;
+        }
+        if let Case::Snake = case {
+            let previous = i.checked_sub(1).map(|i| chars[i]);
+            let next = chars.get(i + 1);
+            // `HealthMax` → `health_max`, `HTTPServer` → `http_server`
+            let boundary = c.is_ascii_uppercase()
+                && previous.is_some_and(|p| {
+                    p.is_ascii_lowercase() || (p.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+                });
+            if boundary && !ident.ends_with('_') {
+                ident.push('_');
+            }
+            ident.push(c.to_ascii_lowercase());
+        } else {
+            ident.push(c);
+        }
+    }
+    if let Case::Snake = case {
+        ident = ident.trim_matches('_').to_string();
+    }
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    match ident.as_str() {
+        "self" | "Self" | "super" | "crate" | "_" => ident.push('_'),
+        keyword if KEYWORDS.contains(&keyword) => ident.insert_str(0, "r#"),
+        _ => {}
+    }
+    ident
+}
+
+/// Suffix `ident` with a number if it is already taken
+fn unique(ident: String, used: &mut HashSet<String>) -> String {
+    let mut candidate = ident.clone();
+    let mut counter = 2;
+    while !used.insert(candidate.clone()) {
+        candidate = format!("{}_{}", ident, counter);
+        counter += 1;
+    }
+    candidate
+}
@@ -0,0 +1,106 @@
+//! Payload decompression
+//!
+//! Zlib and deflate are decoded with `miniz_oxide`, gzip with `flate2` and LZ4 with
+//! `lz4_flex`. Oodle and Zstd payloads need an external decompressor, see [`Decompressor`].
+
+use std::io::Read;
+
+use flate2::read::GzDecoder;
+use miniz_oxide::inflate::{decompress_to_vec_with_limit, decompress_to_vec_zlib_with_limit, DecompressError, TINFLStatus};
+use serde::{Deserialize, Serialize};
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+
+/// Compression method of a payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CompressionMethod {
+    None,
+    Zlib,
+    Gzip,
+    LZ4,
+    Oodle,
+    Zstd,
+}
+
+impl CompressionMethod {
+    /// Get a compression method from its engine name, ignoring case
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "" | "none" => Some(CompressionMethod::None),
+            "zlib" => Some(CompressionMethod::Zlib),
+            "gzip" => Some(CompressionMethod::Gzip),
+            "lz4" => Some(CompressionMethod::LZ4),
+            "oodle" => Some(CompressionMethod::Oodle),
+            "zstd" => Some(CompressionMethod::Zstd),
+            _ => None,
+        }
+    }
+}
+
+/// External decompressor for methods without native support
+///
+/// Called with the compressed bytes and the expected uncompressed size.
+pub type Decompressor = dyn Fn(&[u8], usize) -> UnrealAssetResult<Vec<u8>> + Send + Sync;
+
+/// Decompress a payload whose uncompressed size is known
+pub fn decompress(method: CompressionMethod, data: &[u8], uncompressed_size: usize) -> UnrealAssetResult<Vec<u8>> {
+    let output = match method {
+        CompressionMethod::None => data.to_vec(),
+        CompressionMethod::Zlib => zlib_decompress(data, uncompressed_size)?,
+        CompressionMethod::Gzip => gzip_decompress(data, uncompressed_size)?,
+        CompressionMethod::LZ4 => lz4_decompress(data, uncompressed_size)?,
+        CompressionMethod::Oodle | CompressionMethod::Zstd => {
+            return Err(UnrealAssetError::UnsupportedVersion(format!(
+                "{:?} decompression requires an external decompressor", method
+            )));
+        }
+    };
+    if output.len() != uncompressed_size {
+        return Err(UnrealAssetError::InvalidData(format!(
+            "Decompressed {} bytes, expected {}", output.len(), uncompressed_size
+        )));
+    }
+    Ok(output)
+}
+
+/// Decompress a zlib stream of at most `max_size` bytes, verifying its Adler-32 checksum
+pub fn zlib_decompress(data: &[u8], max_size: usize) -> UnrealAssetResult<Vec<u8>> {
+    decompress_to_vec_zlib_with_limit(data, max_size).map_err(|e| inflate_error(e, max_size, "zlib"))
+}
+
+/// Decompress a gzip member of at most `max_size` bytes, verifying its CRC-32
+pub fn gzip_decompress(data: &[u8], max_size: usize) -> UnrealAssetResult<Vec<u8>> {
+    let mut output = Vec::new();
+    GzDecoder::new(data).take(max_size as u64 + 1).read_to_end(&mut output)
+        .map_err(|e| UnrealAssetError::InvalidData(format!("Invalid gzip stream: {}", e)))?;
+    if output.len() > max_size {
+        return Err(too_large("gzip", max_size));
+    }
+    Ok(output)
+}
+
+/// Decompress a raw deflate stream of at most `max_size` bytes
+pub fn inflate(data: &[u8], max_size: usize) -> UnrealAssetResult<Vec<u8>> {
+    decompress_to_vec_with_limit(data, max_size).map_err(|e| inflate_error(e, max_size, "deflate"))
+}
+
+/// Decompress an LZ4 block
+pub fn lz4_decompress(data: &[u8], uncompressed_size: usize) -> UnrealAssetResult<Vec<u8>> {
+    // A sequence can't expand to more than 255 bytes per input byte
+    let mut output = vec![0; uncompressed_size.min(data.len().saturating_mul(255))];
+    let length = lz4_flex::block::decompress_into(data, &mut output)
+        .map_err(|e| UnrealAssetError::InvalidData(format!("Invalid LZ4 block: {}", e)))?;
+    output.truncate(length);
+    Ok(output)
+}
+
+fn inflate_error(error: DecompressError, max_size: usize, format: &str) -> UnrealAssetError {
+    match error.status {
+        TINFLStatus::HasMoreOutput => too_large(format, max_size),
+        _ => UnrealAssetError::InvalidData(format!("Invalid {} stream: {}", format, error)),
+    }
+}
+
+fn too_large(format: &str, max_size: usize) -> UnrealAssetError {
+    UnrealAssetError::InvalidData(format!("Decompressed {} stream is larger than {} bytes", format, max_size))
+}
@@ -1,33 +1,58 @@
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
 
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use crate::unreal_asset::versions::ObjectVersion;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Reference-counted resource shared between an archive and its consumers
+///
+/// Cloning a `SharedResource` yields another handle to the same data, so names
+/// added through one archive are visible to every other holder of the map.
+/// Handles are `Send` and `Sync`, so assets holding them can move between threads.
+#[derive(Debug)]
 pub struct SharedResource<T> {
-    pub data: T,
+    pub data: Arc<RwLock<T>>,
     pub is_loaded: bool,
 }
 
 impl<T> SharedResource<T> {
     pub fn new(data: T) -> Self {
-        Self { data, is_loaded: true }
+        Self { data: Arc::new(RwLock::new(data)), is_loaded: true }
     }
-    
-    pub fn get(&self) -> &T {
-        &self.data
+
+    pub fn get(&self) -> RwLockReadGuard<'_, T> {
+        // A panic while holding the lock cannot leave a name map half-updated
+        self.data.read().unwrap_or_else(PoisonError::into_inner)
     }
-    
-    pub fn get_ref(&self) -> &T {
-        &self.data
+
+    pub fn get_ref(&self) -> RwLockReadGuard<'_, T> {
+        self.get()
     }
-    
-    pub fn get_mut(&mut self) -> &mut T {
-        &mut self.data
+
+    pub fn get_mut(&mut self) -> RwLockWriteGuard<'_, T> {
+        self.data.write().unwrap_or_else(PoisonError::into_inner)
     }
 }
 
-pub type NameMap = Vec<String>;
+impl<T> Clone for SharedResource<T> {
+    fn clone(&self) -> Self {
+        Self { data: Arc::clone(&self.data), is_loaded: self.is_loaded }
+    }
+}
+
+impl<T: Serialize> Serialize for SharedResource<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for SharedResource<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(SharedResource::new)
+    }
+}
+
+pub use crate::unreal_asset::name_map::NameMap;
 
 pub trait Container {
     fn get_version(&self) -> ObjectVersion;
-} 
\ No newline at end of file
+}
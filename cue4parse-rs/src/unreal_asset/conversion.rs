@@ -0,0 +1,421 @@
+//! Typed conversion from CUE4Parse JSON to [`Property`]
+//!
+//! CUE4Parse writes property values without their types, so a number could be
+//! any integer or float property and a string could be a `Str`, `Name` or enum.
+//! The converter takes the type from a `$types` annotation on the containing
+//! object, or from usmap mappings when there is none, and reports an error
+//! rather than guessing when neither is available.
+
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::text::FText;
+use crate::unreal_asset::types::{
+    FName, LinearColor, PackageIndex, Quat, Rotator, SoftObjectPath, Transform, Vector, Vector2D, Vector4,
+};
+use crate::unreal_asset::usmap::{PropertyType, Usmap};
+use crate::GameVersion;
+
+/// Resolves a CUE4Parse object reference (`ObjectName`, `ObjectPath`) to a package index
+pub type ReferenceResolver<'a> = dyn FnMut(&str, &str) -> Option<PackageIndex> + 'a;
+
+/// Converts CUE4Parse JSON values to properties of a known type
+///
+/// `'a` borrows the mappings and resolver, `'r` is what the resolver itself captures.
+pub struct PropertyConverter<'a, 'r> {
+    mappings: Option<&'a Usmap>,
+    resolver: Option<&'a mut ReferenceResolver<'r>>,
+}
+
+impl Default for PropertyConverter<'_, '_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, 'r> PropertyConverter<'a, 'r> {
+    /// Create a converter that relies on `$types` annotations only
+    pub fn new() -> Self {
+        Self { mappings: None, resolver: None }
+    }
+
+    /// Use usmap mappings for properties without annotations
+    pub fn with_mappings(mut self, mappings: &'a Usmap) -> Self {
+        self.mappings = Some(mappings);
+        self
+    }
+
+    /// Resolve object references through `resolver`, e.g. into an asset's import table
+    pub fn with_resolver(mut self, resolver: &'a mut ReferenceResolver<'r>) -> Self {
+        self.resolver = Some(resolver);
+        self
+    }
+
+    /// Look up the type of a property from the object's `$types` annotation or the mappings
+    pub fn property_type(&self, object: &Value, property_name: &str, schema: Option<&str>) -> Option<PropertyType> {
+        if let Some(annotation) = ConversionUtils::extract_property_type(object, property_name) {
+            return PropertyType::parse(&annotation).ok();
+        }
+        let base_name = property_name.split_once('[').map_or(property_name, |(name, _)| name);
+        self.mappings
+            .zip(schema)
+            .and_then(|(mappings, schema)| mappings.find_property(schema, base_name))
+            .map(|property| property.property_type.clone())
+    }
+
+    /// Convert every property of a JSON object
+    ///
+    /// `schema` names the class or struct the object belongs to and is used to find
+    /// types in the mappings. Keys starting with `$` are annotations and are skipped.
+    pub fn convert_properties(&mut self, object: &Value, schema: Option<&str>) -> UnrealAssetResult<IndexMap<String, Property>> {
+        let map = object.as_object().ok_or_else(|| mismatch("object", "properties", object))?;
+        let mut properties = IndexMap::new();
+        for (name, value) in map.iter().filter(|(k, _)| !k.starts_with('$')) {
+            properties.insert(name.clone(), self.convert_property(object, name, value, schema)?);
+        }
+        Ok(properties)
+    }
+
+    /// Convert one property of `object`, typed as in [`PropertyConverter::convert_properties`]
+    ///
+    /// `value` is usually `object[name]`, but may live elsewhere when the annotations
+    /// sit on an outer object, as with the `Properties` of a CUE4Parse export.
+    pub fn convert_property(&mut self, object: &Value, name: &str, value: &Value, schema: Option<&str>) -> UnrealAssetResult<Property> {
+        let property = match self.property_type(object, name, schema) {
+            Some(property_type) => self.convert(value, &property_type),
+            None if value.is_boolean() => Ok(Property::Bool(value.as_bool().unwrap_or_default())),
+            None => Err(UnrealAssetError::InvalidData(format!(
+                "Ambiguous value for '{}': no type annotation or mappings",
+                name
+            ))),
+        };
+        property.map_err(|e| UnrealAssetError::Parse(format!("{}: {}", name, e)))
+    }
+
+    /// Convert a JSON value to a property of the given type
+    pub fn convert(&mut self, value: &Value, property_type: &PropertyType) -> UnrealAssetResult<Property> {
+        let type_name = property_type.type_name.as_str();
+        Ok(match type_name {
+            "BoolProperty" => Property::Bool(value.as_bool().ok_or_else(|| mismatch("bool", type_name, value))?),
+            "Int8Property" => Property::Int8(integer(value, type_name)?),
+            "Int16Property" => Property::Int16(integer(value, type_name)?),
+            "IntProperty" => Property::Int32(integer(value, type_name)?),
+            "Int64Property" => Property::Int64(integer(value, type_name)?),
+            "UInt16Property" => Property::UInt16(unsigned(value, type_name)?),
+            "UInt32Property" => Property::UInt32(unsigned(value, type_name)?),
+            "UInt64Property" => Property::UInt64(unsigned(value, type_name)?),
+            "FloatProperty" => Property::Float(float(value, type_name)? as f32),
+            "DoubleProperty" => Property::Double(float(value, type_name)?),
+            "StrProperty" | "Utf8StrProperty" | "AnsiStrProperty" => Property::String(string(value, type_name)?.to_string()),
+            "NameProperty" => Property::Name(FName::new(string(value, type_name)?)),
+            "TextProperty" => text(value)?,
+            "ByteProperty" => match value {
+                Value::String(s) => {
+                    let (enum_type, value) = enum_value(s, property_type.enum_name.as_deref());
+                    Property::ByteEnum { enum_type, value }
+                }
+                _ => Property::Byte(unsigned(value, type_name)?),
+            },
+            "EnumProperty" => {
+                let (enum_type, value) = enum_value(string(value, type_name)?, property_type.enum_name.as_deref());
+                Property::Enum { enum_type, value }
+            }
+            "ObjectProperty" | "ClassProperty" | "WeakObjectProperty" | "LazyObjectProperty" | "InterfaceProperty" => {
+                Property::Object(self.object_reference(value)?)
+            }
+            "SoftObjectProperty" => Property::SoftObjectPath(soft_object_path(value)?),
+            "SoftClassProperty" => Property::SoftClassPath(soft_object_path(value)?),
+            "AssetObjectProperty" => Property::AssetObjectProperty(soft_object_path(value)?),
+            "StructProperty" => {
+                let struct_type = property_type.struct_type.as_deref()
+                    .ok_or_else(|| UnrealAssetError::InvalidData("StructProperty without a struct type".to_string()))?;
+                self.convert_struct(value, struct_type)?
+            }
+            "ArrayProperty" | "SetProperty" => {
+                let inner = inner_type(property_type, type_name)?;
+                let values = value.as_array().ok_or_else(|| mismatch("array", type_name, value))?
+                    .iter()
+                    .map(|v| self.convert(v, inner))
+                    .collect::<UnrealAssetResult<Vec<_>>>()?;
+                match type_name {
+                    "SetProperty" => Property::Set(values),
+                    _ => Property::Array(values),
+                }
+            }
+            "MapProperty" => {
+                let key_type = inner_type(property_type, type_name)?;
+                let value_type = property_type.value.as_deref()
+                    .ok_or_else(|| UnrealAssetError::InvalidData("MapProperty without a value type".to_string()))?;
+                let mut entries = Vec::new();
+                for entry in value.as_array().ok_or_else(|| mismatch("array", type_name, value))? {
+                    let key = entry.get("Key").ok_or_else(|| mismatch("{Key, Value} entry", type_name, entry))?;
+                    let val = entry.get("Value").ok_or_else(|| mismatch("{Key, Value} entry", type_name, entry))?;
+                    entries.push((self.convert(key, key_type)?, self.convert(val, value_type)?));
+                }
+                Property::Map { key_type: key_type.to_string(), value_type: value_type.to_string(), entries }
+            }
+            "DelegateProperty" => {
+                let (object, function_name) = self.delegate(value)?;
+                Property::Delegate { object, function_name }
+            }
+            "MulticastDelegateProperty" | "MulticastInlineDelegateProperty" | "MulticastSparseDelegateProperty" => {
+                let delegates = value.as_array().ok_or_else(|| mismatch("array", type_name, value))?
+                    .iter()
+                    .map(|v| self.delegate(v).map(|(object, function_name)| Property::Delegate { object, function_name }))
+                    .collect::<UnrealAssetResult<Vec<_>>>()?;
+                match type_name {
+                    "MulticastInlineDelegateProperty" => Property::MulticastInlineDelegateProperty { delegates },
+                    "MulticastSparseDelegateProperty" => Property::MulticastSparseDelegateProperty { delegates },
+                    _ => Property::MulticastDelegate { delegates },
+                }
+            }
+            _ => {
+                return Err(UnrealAssetError::UnsupportedVersion(format!(
+                    "Conversion of {} is not supported",
+                    property_type
+                )));
+            }
+        })
+    }
+
+    fn convert_struct(&mut self, value: &Value, struct_type: &str) -> UnrealAssetResult<Property> {
+        let fields = || value.as_object().ok_or_else(|| mismatch("object", struct_type, value));
+        Ok(match struct_type {
+            "Vector" => {
+                let f = fields()?;
+                Property::Vector(Vector::new(field(f, "X")?, field(f, "Y")?, field(f, "Z")?))
+            }
+            "Vector2D" => {
+                let f = fields()?;
+                Property::Vector2D(Vector2D::new(field(f, "X")?, field(f, "Y")?))
+            }
+            "Vector4" => {
+                let f = fields()?;
+                Property::Vector4(Vector4::new(field(f, "X")?, field(f, "Y")?, field(f, "Z")?, field(f, "W")?))
+            }
+            "Rotator" => {
+                let f = fields()?;
+                Property::Rotator(Rotator::new(field(f, "Pitch")?, field(f, "Yaw")?, field(f, "Roll")?))
+            }
+            "Quat" => {
+                let f = fields()?;
+                Property::Quat(Quat::new(field(f, "X")?, field(f, "Y")?, field(f, "Z")?, field(f, "W")?))
+            }
+            "LinearColor" => {
+                let f = fields()?;
+                Property::LinearColor(LinearColor::new(
+                    field(f, "R")? as f32,
+                    field(f, "G")? as f32,
+                    field(f, "B")? as f32,
+                    field(f, "A")? as f32,
+                ))
+            }
+            "Transform" => {
+                let f = fields()?;
+                let part = |name: &str| f.get(name).and_then(Value::as_object)
+                    .ok_or_else(|| UnrealAssetError::InvalidData(format!("Transform is missing '{}'", name)));
+                let (rotation, translation, scale) = (part("Rotation")?, part("Translation")?, part("Scale3D")?);
+                Property::Transform(Transform::new(
+                    Vector::new(field(translation, "X")?, field(translation, "Y")?, field(translation, "Z")?),
+                    Quat::new(field(rotation, "X")?, field(rotation, "Y")?, field(rotation, "Z")?, field(rotation, "W")?),
+                    Vector::new(field(scale, "X")?, field(scale, "Y")?, field(scale, "Z")?),
+                ))
+            }
+            "Guid" => Property::Guid(guid(string(value, struct_type)?)?),
+            "DateTime" => Property::DateTime(ticks(value, struct_type)?),
+            "Timespan" => Property::TimeSpan(ticks(value, struct_type)?),
+            "SoftObjectPath" => Property::SoftObjectPath(soft_object_path(value)?),
+            "SoftClassPath" => Property::SoftClassPath(soft_object_path(value)?),
+            "PerPlatformBool" => Property::PerPlatformBool(vec![
+                per_platform_default(value).as_bool().ok_or_else(|| mismatch("bool", struct_type, value))?,
+            ]),
+            "PerPlatformInt" => Property::PerPlatformInt(vec![integer(per_platform_default(value), struct_type)?]),
+            "PerPlatformFloat" => Property::PerPlatformFloat(vec![float(per_platform_default(value), struct_type)? as f32]),
+            _ => Property::Struct {
+                struct_type: FName::new(struct_type),
+                properties: self.convert_properties(value, Some(struct_type))?,
+            },
+        })
+    }
+
+    fn object_reference(&mut self, value: &Value) -> UnrealAssetResult<Option<PackageIndex>> {
+        match value {
+            Value::Null => Ok(None),
+            Value::String(s) if s == "None" => Ok(None),
+            Value::Number(_) => {
+                let index: i32 = integer(value, "ObjectProperty")?;
+                Ok((index != 0).then_some(PackageIndex(index)))
+            }
+            Value::Object(object) => {
+                let name = object.get("ObjectName").and_then(Value::as_str);
+                let path = object.get("ObjectPath").and_then(Value::as_str);
+                let (Some(name), Some(path)) = (name, path) else {
+                    return Err(mismatch("object reference", "ObjectProperty", value));
+                };
+                let resolver = self.resolver.as_mut().ok_or_else(|| UnrealAssetError::InvalidData(format!(
+                    "Cannot resolve reference to '{}' without an import table",
+                    path
+                )))?;
+                resolver(name, path)
+                    .map(Some)
+                    .ok_or_else(|| UnrealAssetError::InvalidData(format!("Unresolved object reference '{}'", name)))
+            }
+            _ => Err(mismatch("object reference", "ObjectProperty", value)),
+        }
+    }
+
+    fn delegate(&mut self, value: &Value) -> UnrealAssetResult<(Option<PackageIndex>, FName)> {
+        let object = value.get("Object").unwrap_or(&Value::Null);
+        let function_name = value.get("FunctionName").and_then(Value::as_str)
+            .ok_or_else(|| mismatch("delegate", "DelegateProperty", value))?;
+        Ok((self.object_reference(object)?, FName::new(function_name)))
+    }
+}
+
+/// Helpers for converting CUE4Parse data to `unreal_asset` types
+pub struct ConversionUtils;
+
+impl ConversionUtils {
+    /// Render a game version as a `major.minor.patch` engine version
+    pub fn game_version_to_string(version: &GameVersion) -> String {
+        let (major, minor) = match version {
+            GameVersion::UE4_0 => (4, 0),
+            GameVersion::UE4_27 => (4, 27),
+            GameVersion::UE5_0 => (5, 0),
+            GameVersion::UE5_1 => (5, 1),
+            GameVersion::UE5_2 => (5, 2),
+            GameVersion::UE5_3 => (5, 3),
+            GameVersion::UE5_4 => (5, 4),
+            GameVersion::UE5_5 => (5, 5),
+        };
+        format!("{}.{}.0", major, minor)
+    }
+
+    /// Get a property's type from the object's `$types` annotation
+    pub fn extract_property_type(json: &Value, property_name: &str) -> Option<String> {
+        json.get("$types")?.get(property_name)?.as_str().map(str::to_string)
+    }
+
+    /// Convert a JSON value to a property of the given type
+    ///
+    /// `type_name` is a type annotation such as `IntProperty` or
+    /// `ArrayProperty<NameProperty>`. Values that don't match the type are an error.
+    pub fn json_to_property_typed(value: &Value, type_name: &str) -> UnrealAssetResult<Property> {
+        PropertyConverter::new().convert(value, &PropertyType::parse(type_name)?)
+    }
+
+    /// Convert an object's properties using its annotations, then `mappings` for `schema`
+    pub fn json_to_properties(
+        object: &Value,
+        schema: Option<&str>,
+        mappings: Option<&Usmap>,
+    ) -> UnrealAssetResult<IndexMap<String, Property>> {
+        let mut converter = PropertyConverter::new();
+        converter.mappings = mappings;
+        converter.convert_properties(object, schema)
+    }
+}
+
+fn mismatch(expected: &str, type_name: &str, value: &Value) -> UnrealAssetError {
+    UnrealAssetError::InvalidData(format!("Expected {} for {}, found {}", expected, type_name, value))
+}
+
+fn inner_type<'t>(property_type: &'t PropertyType, type_name: &str) -> UnrealAssetResult<&'t PropertyType> {
+    property_type.inner.as_deref()
+        .ok_or_else(|| UnrealAssetError::InvalidData(format!("{} without an element type", type_name)))
+}
+
+fn integer<T: TryFrom<i64>>(value: &Value, type_name: &str) -> UnrealAssetResult<T> {
+    let i = value.as_i64().ok_or_else(|| mismatch("integer", type_name, value))?;
+    T::try_from(i).map_err(|_| UnrealAssetError::InvalidData(format!("{} is out of range for {}", i, type_name)))
+}
+
+fn unsigned<T: TryFrom<u64>>(value: &Value, type_name: &str) -> UnrealAssetResult<T> {
+    let u = value.as_u64().ok_or_else(|| mismatch("unsigned integer", type_name, value))?;
+    T::try_from(u).map_err(|_| UnrealAssetError::InvalidData(format!("{} is out of range for {}", u, type_name)))
+}
+
+fn float(value: &Value, type_name: &str) -> UnrealAssetResult<f64> {
+    // Non-finite floats are written as strings
+    match value {
+        Value::Number(n) => n.as_f64().ok_or_else(|| mismatch("number", type_name, value)),
+        Value::String(s) if s == "NaN" => Ok(f64::NAN),
+        Value::String(s) if s == "Infinity" => Ok(f64::INFINITY),
+        Value::String(s) if s == "-Infinity" => Ok(f64::NEG_INFINITY),
+        _ => Err(mismatch("number", type_name, value)),
+    }
+}
+
+fn string<'v>(value: &'v Value, type_name: &str) -> UnrealAssetResult<&'v str> {
+    value.as_str().ok_or_else(|| mismatch("string", type_name, value))
+}
+
+fn field(object: &Map<String, Value>, name: &str) -> UnrealAssetResult<f64> {
+    let value = object.get(name)
+        .ok_or_else(|| UnrealAssetError::InvalidData(format!("Missing field '{}'", name)))?;
+    float(value, name)
+}
+
+fn ticks(value: &Value, type_name: &str) -> UnrealAssetResult<i64> {
+    match value.get("Ticks") {
+        Some(ticks) => integer(ticks, type_name),
+        None => integer(value, type_name),
+    }
+}
+
+fn per_platform_default(value: &Value) -> &Value {
+    value.get("Default").unwrap_or(value)
+}
+
+/// Split `EType::Value` into enum type and value
+fn enum_value(value: &str, enum_name: Option<&str>) -> (FName, FName) {
+    let enum_type = enum_name
+        .or_else(|| value.split_once("::").map(|(e, _)| e))
+        .unwrap_or("None");
+    (FName::new(enum_type), FName::new(value))
+}
+
+fn text(value: &Value) -> UnrealAssetResult<Property> {
+    FText::from_json(value)
+        .map(Property::Text)
+        .map_err(|_| mismatch("text", "TextProperty", value))
+}
+
+fn soft_object_path(value: &Value) -> UnrealAssetResult<SoftObjectPath> {
+    match value {
+        Value::Null => Ok(SoftObjectPath::default()),
+        Value::String(path) if path == "None" => Ok(SoftObjectPath::default()),
+        Value::String(path) => Ok(SoftObjectPath::new(path.clone(), "")),
+        Value::Object(object) => {
+            let sub_path = object.get("SubPathString").and_then(Value::as_str).unwrap_or_default();
+            // UE5.1+ writes the asset path as a top level asset path object
+            let asset_path = match object.get("AssetPathName") {
+                Some(Value::String(path)) => path.clone(),
+                Some(Value::Object(path)) => {
+                    let package = path.get("PackageName").and_then(Value::as_str).unwrap_or_default();
+                    let asset = path.get("AssetName").and_then(Value::as_str).unwrap_or_default();
+                    format!("{}.{}", package, asset)
+                }
+                _ => return Err(mismatch("soft object path", "SoftObjectProperty", value)),
+            };
+            Ok(SoftObjectPath::new(asset_path, sub_path))
+        }
+        _ => Err(mismatch("soft object path", "SoftObjectProperty", value)),
+    }
+}
+
+/// Parse a GUID written as `AAAAAAAA-BBBBBBBB-CCCCCCCC-DDDDDDDD` or 32 hex digits
+fn guid(value: &str) -> UnrealAssetResult<[u32; 4]> {
+    let digits: String = value.chars().filter(|c| !matches!(c, '-' | '{' | '}')).collect();
+    if digits.len() != 32 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(UnrealAssetError::InvalidData(format!("Invalid GUID '{}'", value)));
+    }
+    let mut guid = [0u32; 4];
+    for (i, part) in guid.iter_mut().enumerate() {
+        *part = u32::from_str_radix(&digits[i * 8..i * 8 + 8], 16)
+            .map_err(|_| UnrealAssetError::InvalidData(format!("Invalid GUID '{}'", value)))?;
+    }
+    Ok(guid)
+}
@@ -0,0 +1,776 @@
+//! Curves and curve tables
+//!
+//! [`RichCurve`] and [`SimpleCurve`] keep the keys of `FRichCurve` and `FSimpleCurve` with their
+//! interpolation modes, tangents and infinity extrapolation, and [`RichCurve::eval`] follows
+//! `FRichCurve::Eval`: cycling and oscillating outside the key range, linear extrapolation from
+//! the two outer keys, cubic Bezier segments from the tangents and weighted tangents solved for
+//! time. Arithmetic is done in `f32` like the engine so results match it closely.
+//!
+//! Curves are read from CUE4Parse JSON or from properties through serde:
+//!
+//! ```ignore
+//! let table = CurveTable::from_json(&export)?;
+//! let damage = table.eval("Damage", level as f32);
+//! let curve: RichCurve = from_property(&row["FalloffCurve"])?;
+//! ```
+//!
+//! Enum values are accepted with or without their `EType::` and `RCIM_` style prefixes, and as
+//! indices.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::typed_export::CurveTableView;
+use crate::unreal_asset::types::{FName, LinearColor, Vector};
+
+/// Default value of a curve that has none, `MAX_flt` in the engine
+pub const UNSET_DEFAULT_VALUE: f32 = f32::MAX;
+
+/// Define a curve enum named after its engine values
+macro_rules! curve_enum {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $text:literal,)+
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)+
+        }
+
+        impl $name {
+            const VARIANTS: &'static [$name] = &[$($name::$variant),+];
+
+            /// Engine name of the value
+            pub fn as_str(self) -> &'static str {
+                match self {
+                    $($name::$variant => $text,)+
+                }
+            }
+
+            /// Parse `RCIM_Cubic`, `ERichCurveInterpMode::RCIM_Cubic` or `Cubic`
+            pub fn from_name(name: &str) -> Option<Self> {
+                let name = name.rsplit_once("::").map_or(name, |(_, name)| name);
+                Self::VARIANTS.iter().copied().find(|variant| {
+                    let text = variant.as_str();
+                    text == name || text.split_once('_').is_some_and(|(_, short)| short == name)
+                })
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $name {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                struct EnumVisitor;
+
+                impl<'de> Visitor<'de> for EnumVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        write!(f, "a {} name or index", stringify!($name))
+                    }
+
+                    fn visit_str<E: de::Error>(self, v: &str) -> Result<$name, E> {
+                        $name::from_name(v).ok_or_else(|| E::custom(format!("unknown {} '{}'", stringify!($name), v)))
+                    }
+
+                    fn visit_u64<E: de::Error>(self, v: u64) -> Result<$name, E> {
+                        usize::try_from(v)
+                            .ok()
+                            .and_then(|index| $name::VARIANTS.get(index).copied())
+                            .ok_or_else(|| E::custom(format!("{} index {} is out of range", stringify!($name), v)))
+                    }
+
+                    fn visit_i64<E: de::Error>(self, v: i64) -> Result<$name, E> {
+                        match u64::try_from(v) {
+                            Ok(v) => self.visit_u64(v),
+                            Err(_) => Err(E::custom(format!("{} index {} is out of range", stringify!($name), v))),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_any(EnumVisitor)
+            }
+        }
+    };
+}
+
+curve_enum! {
+    /// `ERichCurveInterpMode`, how a key interpolates towards the next one
+    RichCurveInterpMode {
+        #[default]
+        Linear = "RCIM_Linear",
+        Constant = "RCIM_Constant",
+        Cubic = "RCIM_Cubic",
+        None = "RCIM_None",
+    }
+}
+
+curve_enum! {
+    /// `ERichCurveTangentMode`, how the editor computes a key's tangents
+    RichCurveTangentMode {
+        #[default]
+        Auto = "RCTM_Auto",
+        User = "RCTM_User",
+        Break = "RCTM_Break",
+        None = "RCTM_None",
+        SmartAuto = "RCTM_SmartAuto",
+    }
+}
+
+curve_enum! {
+    /// `ERichCurveTangentWeightMode`, which of a key's tangent weights are used
+    RichCurveTangentWeightMode {
+        #[default]
+        WeightedNone = "RCTWM_WeightedNone",
+        WeightedArrive = "RCTWM_WeightedArrive",
+        WeightedLeave = "RCTWM_WeightedLeave",
+        WeightedBoth = "RCTWM_WeightedBoth",
+    }
+}
+
+curve_enum! {
+    /// `ERichCurveExtrapolation`, what a curve does before its first and after its last key
+    RichCurveExtrapolation {
+        Cycle = "RCCE_Cycle",
+        CycleWithOffset = "RCCE_CycleWithOffset",
+        Oscillate = "RCCE_Oscillate",
+        Linear = "RCCE_Linear",
+        #[default]
+        Constant = "RCCE_Constant",
+        None = "RCCE_None",
+    }
+}
+
+curve_enum! {
+    /// `ECurveTableMode`
+    CurveTableMode {
+        #[default]
+        Empty = "Empty",
+        SimpleCurves = "SimpleCurves",
+        RichCurves = "RichCurves",
+    }
+}
+
+/// `FRichCurveKey`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RichCurveKey {
+    pub interp_mode: RichCurveInterpMode,
+    pub tangent_mode: RichCurveTangentMode,
+    pub tangent_weight_mode: RichCurveTangentWeightMode,
+    pub time: f32,
+    pub value: f32,
+    pub arrive_tangent: f32,
+    pub arrive_tangent_weight: f32,
+    pub leave_tangent: f32,
+    pub leave_tangent_weight: f32,
+}
+
+impl RichCurveKey {
+    /// Key with the default linear interpolation
+    pub fn new(time: f32, value: f32) -> Self {
+        Self { time, value, ..Default::default() }
+    }
+
+    pub fn with_interp_mode(mut self, interp_mode: RichCurveInterpMode) -> Self {
+        self.interp_mode = interp_mode;
+        self
+    }
+
+    /// Set both tangents, as a user tangent
+    pub fn with_tangents(mut self, arrive_tangent: f32, leave_tangent: f32) -> Self {
+        self.tangent_mode = RichCurveTangentMode::User;
+        self.arrive_tangent = arrive_tangent;
+        self.leave_tangent = leave_tangent;
+        self
+    }
+
+    /// Set the tangent weights and which of them apply
+    pub fn with_tangent_weights(mut self, mode: RichCurveTangentWeightMode, arrive_weight: f32, leave_weight: f32) -> Self {
+        self.tangent_weight_mode = mode;
+        self.arrive_tangent_weight = arrive_weight;
+        self.leave_tangent_weight = leave_weight;
+        self
+    }
+}
+
+/// `FRichCurve`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct RichCurve {
+    pub keys: Vec<RichCurveKey>,
+    /// Value of a curve without keys, [`UNSET_DEFAULT_VALUE`] when not set
+    pub default_value: f32,
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    pub post_infinity_extrap: RichCurveExtrapolation,
+}
+
+impl Default for RichCurve {
+    fn default() -> Self {
+        Self {
+            keys: Vec::new(),
+            default_value: UNSET_DEFAULT_VALUE,
+            pre_infinity_extrap: RichCurveExtrapolation::Constant,
+            post_infinity_extrap: RichCurveExtrapolation::Constant,
+        }
+    }
+}
+
+impl RichCurve {
+    pub fn new(keys: Vec<RichCurveKey>) -> Self {
+        Self { keys, ..Default::default() }
+    }
+
+    pub fn with_default_value(mut self, default_value: f32) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    pub fn with_extrapolation(mut self, pre: RichCurveExtrapolation, post: RichCurveExtrapolation) -> Self {
+        self.pre_infinity_extrap = pre;
+        self.post_infinity_extrap = post;
+        self
+    }
+
+    /// Read a curve from CUE4Parse JSON
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        Self::deserialize(value).map_err(|e| UnrealAssetError::Parse(format!("Invalid rich curve: {}", e)))
+    }
+
+    /// Times of the first and last keys
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        Some((self.keys.first()?.time, self.keys.last()?.time))
+    }
+
+    /// Value at `time`, 0 for a curve without keys or default value
+    pub fn eval(&self, time: f32) -> f32 {
+        self.eval_or(time, 0.0)
+    }
+
+    /// Value at `time`, `default_value` for a curve without keys or default value of its own
+    pub fn eval_or(&self, time: f32, default_value: f32) -> f32 {
+        eval_keys(
+            &self.keys,
+            |key| (key.time, key.value),
+            self.pre_infinity_extrap,
+            self.post_infinity_extrap,
+            if self.default_value == UNSET_DEFAULT_VALUE { default_value } else { self.default_value },
+            time,
+            eval_rich_segment,
+        )
+    }
+}
+
+/// `FSimpleCurveKey`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SimpleCurveKey {
+    pub time: f32,
+    pub value: f32,
+}
+
+impl SimpleCurveKey {
+    pub fn new(time: f32, value: f32) -> Self {
+        Self { time, value }
+    }
+}
+
+/// `FSimpleCurve`, keys sharing one interpolation mode and no tangents
+///
+/// Cubic interpolation is not supported by simple curves and evaluates as linear.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase", default)]
+pub struct SimpleCurve {
+    pub interp_mode: RichCurveInterpMode,
+    pub keys: Vec<SimpleCurveKey>,
+    /// Value of a curve without keys, [`UNSET_DEFAULT_VALUE`] when not set
+    pub default_value: f32,
+    pub pre_infinity_extrap: RichCurveExtrapolation,
+    pub post_infinity_extrap: RichCurveExtrapolation,
+}
+
+impl Default for SimpleCurve {
+    fn default() -> Self {
+        Self {
+            interp_mode: RichCurveInterpMode::Linear,
+            keys: Vec::new(),
+            default_value: UNSET_DEFAULT_VALUE,
+            pre_infinity_extrap: RichCurveExtrapolation::Constant,
+            post_infinity_extrap: RichCurveExtrapolation::Constant,
+        }
+    }
+}
+
+impl SimpleCurve {
+    pub fn new(interp_mode: RichCurveInterpMode, keys: Vec<SimpleCurveKey>) -> Self {
+        Self { interp_mode, keys, ..Default::default() }
+    }
+
+    pub fn with_default_value(mut self, default_value: f32) -> Self {
+        self.default_value = default_value;
+        self
+    }
+
+    pub fn with_extrapolation(mut self, pre: RichCurveExtrapolation, post: RichCurveExtrapolation) -> Self {
+        self.pre_infinity_extrap = pre;
+        self.post_infinity_extrap = post;
+        self
+    }
+
+    /// Read a curve from CUE4Parse JSON
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        Self::deserialize(value).map_err(|e| UnrealAssetError::Parse(format!("Invalid simple curve: {}", e)))
+    }
+
+    /// Times of the first and last keys
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        Some((self.keys.first()?.time, self.keys.last()?.time))
+    }
+
+    /// Value at `time`, 0 for a curve without keys or default value
+    pub fn eval(&self, time: f32) -> f32 {
+        self.eval_or(time, 0.0)
+    }
+
+    /// Value at `time`, `default_value` for a curve without keys or default value of its own
+    pub fn eval_or(&self, time: f32, default_value: f32) -> f32 {
+        let constant = self.interp_mode == RichCurveInterpMode::Constant;
+        eval_keys(
+            &self.keys,
+            |key| (key.time, key.value),
+            self.pre_infinity_extrap,
+            self.post_infinity_extrap,
+            if self.default_value == UNSET_DEFAULT_VALUE { default_value } else { self.default_value },
+            time,
+            |key1, key2, time| {
+                let diff = key2.time - key1.time;
+                match diff > 0.0 && !constant {
+                    true => lerp(key1.value, key2.value, (time - key1.time) / diff),
+                    false => key1.value,
+                }
+            },
+        )
+    }
+}
+
+/// Rich or simple curve, as rows of a [`CurveTable`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Curve {
+    Rich(RichCurve),
+    Simple(SimpleCurve),
+}
+
+impl Curve {
+    /// Read a curve from CUE4Parse JSON, simple when it has a curve-wide `InterpMode`
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        match value.get("InterpMode") {
+            Some(_) => SimpleCurve::from_json(value).map(Curve::Simple),
+            None => RichCurve::from_json(value).map(Curve::Rich),
+        }
+    }
+
+    /// Value at `time`, see [`RichCurve::eval`]
+    pub fn eval(&self, time: f32) -> f32 {
+        self.eval_or(time, 0.0)
+    }
+
+    /// Value at `time`, see [`RichCurve::eval_or`]
+    pub fn eval_or(&self, time: f32, default_value: f32) -> f32 {
+        match self {
+            Curve::Rich(curve) => curve.eval_or(time, default_value),
+            Curve::Simple(curve) => curve.eval_or(time, default_value),
+        }
+    }
+
+    /// Times of the first and last keys
+    pub fn time_range(&self) -> Option<(f32, f32)> {
+        match self {
+            Curve::Rich(curve) => curve.time_range(),
+            Curve::Simple(curve) => curve.time_range(),
+        }
+    }
+
+    /// Number of keys
+    pub fn num_keys(&self) -> usize {
+        match self {
+            Curve::Rich(curve) => curve.keys.len(),
+            Curve::Simple(curve) => curve.keys.len(),
+        }
+    }
+}
+
+/// Rows of a `UCurveTable`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveTable {
+    pub name: String,
+    pub mode: CurveTableMode,
+    /// Curves by row name, in table order
+    pub rows: IndexMap<FName, Curve>,
+}
+
+impl CurveTable {
+    /// Read a CurveTable export written by CUE4Parse
+    ///
+    /// Rows keep the order of `export`; see [`CurveTableView`] for keeping the order of a file.
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        Self::from_view(&CurveTableView::from_json(export)?)
+    }
+
+    /// Convert the rows of a [`CurveTableView`]
+    pub fn from_view(view: &CurveTableView) -> UnrealAssetResult<Self> {
+        let mode = view.mode.as_deref().and_then(CurveTableMode::from_name).unwrap_or_default();
+        let rows = view
+            .rows
+            .iter()
+            .map(|(name, row)| {
+                let curve = match mode {
+                    CurveTableMode::RichCurves => RichCurve::from_json(row).map(Curve::Rich),
+                    CurveTableMode::SimpleCurves => SimpleCurve::from_json(row).map(Curve::Simple),
+                    CurveTableMode::Empty => Curve::from_json(row),
+                }
+                .map_err(|e| UnrealAssetError::Parse(format!("row '{}': {}", name, e)))?;
+                Ok((FName::new(name.clone()), curve))
+            })
+            .collect::<UnrealAssetResult<_>>()?;
+        Ok(Self { name: view.name.clone(), mode, rows })
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn row(&self, name: &str) -> Option<&Curve> {
+        self.rows.get(&FName::new(name))
+    }
+
+    /// Value of row `name` at `time`
+    pub fn eval(&self, name: &str, time: f32) -> Option<f32> {
+        Some(self.row(name)?.eval(time))
+    }
+}
+
+/// `UCurveFloat`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveFloat {
+    pub name: String,
+    pub curve: RichCurve,
+}
+
+impl CurveFloat {
+    /// Read a CurveFloat export written by CUE4Parse
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let (name, properties) = curve_export(export)?;
+        let curve = properties.get("FloatCurve").map(RichCurve::from_json).transpose()?;
+        Ok(Self { name, curve: curve.unwrap_or_default() })
+    }
+
+    pub fn eval(&self, time: f32) -> f32 {
+        self.curve.eval(time)
+    }
+}
+
+/// `UCurveVector`, one curve per component
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveVector {
+    pub name: String,
+    pub curves: [RichCurve; 3],
+}
+
+impl CurveVector {
+    /// Read a CurveVector export written by CUE4Parse
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let (name, properties) = curve_export(export)?;
+        Ok(Self { name, curves: float_curves(properties)? })
+    }
+
+    pub fn eval(&self, time: f32) -> Vector {
+        let [x, y, z] = &self.curves;
+        Vector::new(x.eval(time) as f64, y.eval(time) as f64, z.eval(time) as f64)
+    }
+}
+
+/// `UCurveLinearColor`, one curve per channel
+///
+/// [`CurveLinearColor::eval`] is `GetUnadjustedLinearColorValue`: the hue, saturation and
+/// brightness adjustments of the asset are not applied.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveLinearColor {
+    pub name: String,
+    pub curves: [RichCurve; 4],
+}
+
+impl CurveLinearColor {
+    /// Read a CurveLinearColor export written by CUE4Parse
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let (name, properties) = curve_export(export)?;
+        Ok(Self { name, curves: float_curves(properties)? })
+    }
+
+    /// Color at `time`, opaque when the alpha curve has no keys
+    pub fn eval(&self, time: f32) -> LinearColor {
+        let [r, g, b, a] = &self.curves;
+        let alpha = if a.keys.is_empty() { 1.0 } else { a.eval(time) };
+        LinearColor::new(r.eval(time), g.eval(time), b.eval(time), alpha)
+    }
+}
+
+/// Name and tagged properties of a curve export, null when it has none
+fn curve_export(export: &Value) -> UnrealAssetResult<(String, &Value)> {
+    let object = export
+        .as_object()
+        .ok_or_else(|| UnrealAssetError::InvalidData(format!("Expected export JSON object, found {}", export)))?;
+    let name = object.get("Name").and_then(Value::as_str).unwrap_or_default().to_string();
+    Ok((name, object.get("Properties").unwrap_or(&Value::Null)))
+}
+
+/// `FloatCurves[N]` as an array, or as `FloatCurves`, `FloatCurves[1]`, ... static array elements
+fn float_curves<const N: usize>(properties: &Value) -> UnrealAssetResult<[RichCurve; N]> {
+    let mut curves: [RichCurve; N] = std::array::from_fn(|_| RichCurve::default());
+    match properties.get("FloatCurves") {
+        Some(Value::Array(values)) => {
+            for (curve, value) in curves.iter_mut().zip(values) {
+                *curve = RichCurve::from_json(value)?;
+            }
+        }
+        first => {
+            for (index, curve) in curves.iter_mut().enumerate() {
+                let value = match index {
+                    0 => first,
+                    _ => properties.get(format!("FloatCurves[{}]", index)),
+                };
+                if let Some(value) = value {
+                    *curve = RichCurve::from_json(value)?;
+                }
+            }
+        }
+    }
+    Ok(curves)
+}
+
+/// `FRealCurve::Eval` shared by rich and simple curves
+fn eval_keys<K>(
+    keys: &[K],
+    time_value: impl Fn(&K) -> (f32, f32),
+    pre_infinity_extrap: RichCurveExtrapolation,
+    post_infinity_extrap: RichCurveExtrapolation,
+    default_value: f32,
+    time: f32,
+    eval_segment: impl Fn(&K, &K, f32) -> f32,
+) -> f32 {
+    let Some((first, last)) = keys.first().zip(keys.last()) else {
+        return default_value;
+    };
+    let (first_time, first_value) = time_value(first);
+    let (last_time, last_value) = time_value(last);
+    let num_keys = keys.len();
+
+    // Outside the key range, cycling extrapolation maps the time back into it
+    let mut time = time;
+    let mut cycle_value_offset = 0.0;
+    if num_keys >= 2 {
+        let extrap = match time {
+            t if t <= first_time => Some((pre_infinity_extrap, first_value - last_value)),
+            t if t >= last_time => Some((post_infinity_extrap, last_value - first_value)),
+            _ => None,
+        };
+        if let Some((extrap, cycle_delta)) = extrap {
+            if !matches!(extrap, RichCurveExtrapolation::Linear | RichCurveExtrapolation::Constant) {
+                let cycle_count = cycle_time(first_time, last_time, &mut time);
+                match extrap {
+                    RichCurveExtrapolation::CycleWithOffset => cycle_value_offset = cycle_delta * cycle_count as f32,
+                    RichCurveExtrapolation::Oscillate if cycle_count % 2 == 1 => time = first_time + (last_time - time),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let slope = |(t1, v1): (f32, f32), (t2, v2): (f32, f32), at: f32| {
+        let dt = t2 - t1;
+        match dt.abs() <= 1e-8 {
+            true => v1,
+            false => (v2 - v1) / dt * (at - t1) + v1,
+        }
+    };
+    let value = if num_keys < 2 || time <= first_time {
+        match pre_infinity_extrap {
+            RichCurveExtrapolation::Linear if num_keys > 1 => slope((first_time, first_value), time_value(&keys[1]), time),
+            _ => first_value,
+        }
+    } else if time < last_time {
+        // First key after `time`, never the first key itself
+        let next = 1 + keys[1..num_keys - 1].partition_point(|key| time >= time_value(key).0);
+        eval_segment(&keys[next - 1], &keys[next], time)
+    } else {
+        match post_infinity_extrap {
+            RichCurveExtrapolation::Linear => slope((last_time, last_value), time_value(&keys[num_keys - 2]), time),
+            _ => last_value,
+        }
+    };
+    value + cycle_value_offset
+}
+
+/// `CycleTime`, moving `time` into `[min_time, max_time]` and returning the number of whole cycles
+fn cycle_time(min_time: f32, max_time: f32, time: &mut f32) -> i32 {
+    let init_time = *time;
+    let duration = max_time - min_time;
+    if duration <= 0.0 {
+        return 0;
+    }
+    let mut cycle_count = 0;
+    if *time > max_time {
+        cycle_count = ((max_time - *time) / duration).floor() as i32;
+        *time += duration * cycle_count as f32;
+    } else if *time < min_time {
+        cycle_count = ((*time - min_time) / duration).floor() as i32;
+        *time -= duration * cycle_count as f32;
+    }
+    if *time == max_time && init_time < min_time {
+        *time = min_time;
+    }
+    if *time == min_time && init_time > max_time {
+        *time = max_time;
+    }
+    cycle_count.abs()
+}
+
+/// `EvalForTwoKeys` of rich curves
+fn eval_rich_segment(key1: &RichCurveKey, key2: &RichCurveKey, time: f32) -> f32 {
+    let diff = key2.time - key1.time;
+    if diff <= 0.0 || key1.interp_mode == RichCurveInterpMode::Constant {
+        return key1.value;
+    }
+    let alpha = (time - key1.time) / diff;
+    if key1.interp_mode == RichCurveInterpMode::Linear {
+        return lerp(key1.value, key2.value, alpha);
+    }
+    let leave_weighted = matches!(
+        key1.tangent_weight_mode,
+        RichCurveTangentWeightMode::WeightedLeave | RichCurveTangentWeightMode::WeightedBoth
+    );
+    let arrive_weighted = matches!(
+        key2.tangent_weight_mode,
+        RichCurveTangentWeightMode::WeightedArrive | RichCurveTangentWeightMode::WeightedBoth
+    );
+    if !leave_weighted && !arrive_weighted {
+        let one_third = 1.0 / 3.0;
+        let p1 = key1.value + key1.leave_tangent * diff * one_third;
+        let p2 = key2.value - key2.arrive_tangent * diff * one_third;
+        return bezier(key1.value, p1, p2, key2.value, alpha);
+    }
+    eval_weighted_segment(key1, key2, leave_weighted, arrive_weighted, alpha)
+}
+
+/// `WeightedEvalForTwoKeys`: the tangent handles bend time too, so the Bezier parameter for
+/// `alpha` is found by solving the time polynomial
+fn eval_weighted_segment(key1: &RichCurveKey, key2: &RichCurveKey, leave_weighted: bool, arrive_weighted: bool, alpha: f32) -> f32 {
+    let one_third = 1.0 / 3.0;
+    let range = key2.time - key1.time;
+    // Unweighted handles are a third of the segment long along the tangent
+    let handle = |tangent: f32, weighted: bool, weight: f32| {
+        let (sin, cos) = tangent.atan().sin_cos();
+        let length = match weighted {
+            true => weight,
+            false => (range * range + (tangent * range) * (tangent * range)).sqrt() * one_third,
+        };
+        (cos * length, sin * length)
+    };
+    let (leave_x, leave_y) = handle(key1.leave_tangent, leave_weighted, key1.leave_tangent_weight);
+    let (arrive_x, arrive_y) = handle(key2.arrive_tangent, arrive_weighted, key2.arrive_tangent_weight);
+
+    let x1 = (leave_x / range) as f64;
+    let x2 = ((range - arrive_x) / range) as f64;
+    // Bezier through (0, x1, x2, 1) in power basis
+    let (a, b, c) = (x1, x2 - x1, 1.0 - x2);
+    let d = b - a;
+    let coefficients = [-(alpha as f64), 3.0 * a, 3.0 * d, c - b - d];
+
+    let roots = solve_cubic(coefficients);
+    let parameter = match roots.as_slice() {
+        [root] => *root as f32,
+        roots => roots.iter().copied().filter(|root| (0.0..=1.0).contains(root)).fold(0.0, f64::max) as f32,
+    };
+    bezier(key1.value, key1.value + leave_y, key2.value - arrive_y, key2.value, parameter)
+}
+
+/// Real roots of `c[3]x³ + c[2]x² + c[1]x + c[0]`, after `FMath::SolveCubic`
+fn solve_cubic(c: [f64; 4]) -> Vec<f64> {
+    const SMALL_NUMBER: f64 = 1e-8;
+    // Degenerate handles leave a lower degree polynomial, which the engine divides by zero on
+    if c[3].abs() <= SMALL_NUMBER {
+        if c[2].abs() <= SMALL_NUMBER {
+            return match c[1].abs() <= SMALL_NUMBER {
+                true => Vec::new(),
+                false => vec![-c[0] / c[1]],
+            };
+        }
+        let discriminant = c[1] * c[1] - 4.0 * c[2] * c[0];
+        if discriminant < 0.0 {
+            return Vec::new();
+        }
+        let root = discriminant.sqrt();
+        return vec![(-c[1] + root) / (2.0 * c[2]), (-c[1] - root) / (2.0 * c[2])];
+    }
+
+    // Normal form x³ + ax² + bx + c, then x = y - a/3 to remove the quadratic term
+    let (a, b, c) = (c[2] / c[3], c[1] / c[3], c[0] / c[3]);
+    let a_squared = a * a;
+    let p = 1.0 / 3.0 * (-1.0 / 3.0 * a_squared + b);
+    let q = 1.0 / 2.0 * (2.0 / 27.0 * a * a_squared - 1.0 / 3.0 * a * b + c);
+    let p_cubed = p * p * p;
+    let discriminant = q * q + p_cubed;
+
+    let roots = if discriminant.abs() <= SMALL_NUMBER {
+        match q.abs() <= SMALL_NUMBER {
+            true => vec![0.0],
+            false => {
+                let u = (-q).cbrt();
+                vec![2.0 * u, -u]
+            }
+        }
+    } else if discriminant < 0.0 {
+        let phi = 1.0 / 3.0 * (-q / (-p_cubed).sqrt()).acos();
+        let t = 2.0 * (-p).sqrt();
+        let third = std::f64::consts::PI / 3.0;
+        vec![t * phi.cos(), -t * (phi + third).cos(), -t * (phi - third).cos()]
+    } else {
+        let root = discriminant.sqrt();
+        vec![(root - q).cbrt() - (root + q).cbrt()]
+    };
+    roots.into_iter().map(|root| root - a / 3.0).collect()
+}
+
+fn lerp(a: f32, b: f32, alpha: f32) -> f32 {
+    a + alpha * (b - a)
+}
+
+/// Cubic Bezier by de Casteljau, as `BezierInterp`
+fn bezier(p0: f32, p1: f32, p2: f32, p3: f32, alpha: f32) -> f32 {
+    let p01 = lerp(p0, p1, alpha);
+    let p12 = lerp(p1, p2, alpha);
+    let p23 = lerp(p2, p3, alpha);
+    lerp(lerp(p01, p12, alpha), lerp(p12, p23, alpha), alpha)
+}
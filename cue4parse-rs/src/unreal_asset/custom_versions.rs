@@ -0,0 +1,2516 @@
+//! Engine custom versions
+//!
+//! Typed versions of the custom version streams serialized in package summaries,
+//! with their GUIDs and the version each engine release shipped with.
+
+use std::convert::TryFrom;
+
+use uuid::Uuid;
+
+use crate::unreal_asset::error::UnrealAssetError;
+use crate::unreal_asset::versions::{CustomVersion, CustomVersionTrait, EngineVersion};
+
+/// Build a GUID from its four serialized components
+const fn guid(a: u32, b: u32, c: u32, d: u32) -> Uuid {
+    let (a, b, c, d) = (a.to_le_bytes(), b.to_le_bytes(), c.to_le_bytes(), d.to_le_bytes());
+    Uuid::from_bytes([
+        a[0], a[1], a[2], a[3], b[0], b[1], b[2], b[3], c[0], c[1], c[2], c[3], d[0], d[1], d[2], d[3],
+    ])
+}
+
+macro_rules! custom_version {
+    (
+        $(#[$meta:meta])*
+        $name:ident($friendly:literal, [$a:literal, $b:literal, $c:literal, $d:literal], $latest:ident) {
+            $($(#[$variant_meta:meta])* $variant:ident = $value:literal,)+
+        }
+        engine {
+            $($engine:ident => $before:expr,)*
+            _ => $otherwise:expr,
+        }
+    ) => {
+        $(#[$meta])*
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(i32)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant = $value,)+
+        }
+
+        impl $name {
+            /// GUID identifying this custom version
+            pub const GUID: Uuid = guid($a, $b, $c, $d);
+            /// Latest known version
+            pub const LATEST_VERSION: Self = Self::$latest;
+        }
+
+        impl CustomVersionTrait for $name {
+            fn guid() -> Uuid {
+                Self::GUID
+            }
+
+            fn friendly_name() -> &'static str {
+                $friendly
+            }
+
+            fn latest() -> Self {
+                Self::LATEST_VERSION
+            }
+
+            #[allow(unused_variables)]
+            fn from_engine_version(engine: EngineVersion) -> Option<Self> {
+                $(if engine < EngineVersion::$engine {
+                    return $before;
+                })*
+                $otherwise
+            }
+
+            fn from_version(version: i32) -> Option<Self> {
+                Self::try_from(version).ok()
+            }
+        }
+
+        impl From<$name> for i32 {
+            fn from(version: $name) -> i32 {
+                version as i32
+            }
+        }
+
+        impl TryFrom<i32> for $name {
+            type Error = UnrealAssetError;
+
+            fn try_from(version: i32) -> Result<Self, Self::Error> {
+                match version {
+                    $($value => Ok(Self::$variant),)+
+                    _ => Err(UnrealAssetError::InvalidData(format!(
+                        "Unknown {} version {}",
+                        $friendly, version
+                    ))),
+                }
+            }
+        }
+    };
+}
+
+/// Custom version entry for `engine`, or the latest one when no engine is given
+fn entry<T: CustomVersionTrait>(engine: Option<EngineVersion>) -> Option<CustomVersion> {
+    let version = match engine {
+        Some(engine) => T::from_engine_version(engine)?,
+        None => T::latest(),
+    };
+    Some(CustomVersion::new(T::guid(), version.into(), T::friendly_name().to_string()))
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Anim stream
+    FAnimObjectVersion("FAnimObjectVersion", [0xAF43A65D, 0x7FD34947, 0x98733E8E, 0xD9C1BB05], GroomBindingSerialization) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Reworked how anim blueprint root nodes are recovered
+        LinkTimeAnimBlueprintRootDiscovery = 1,
+        /// Cached marker sync names on skeleton for editor
+        StoreMarkerNamesOnSkeleton = 2,
+        /// Serialized register array state for RigVM
+        SerializeRigVMRegisterArrayState = 3,
+        /// Increase number of bones per chunk from uint8 to uint16
+        IncreaseBoneIndexLimitPerChunk = 4,
+        UnlimitedBoneInfluences = 5,
+        /// Anim sequences have colors for their curves
+        AnimSequenceCurveColors = 6,
+        /// Notifies and sync markers now have Guids
+        NotifyAndSyncMarkerGuids = 7,
+        /// Serialized register dynamic state for RigVM
+        SerializeRigVMRegisterDynamicState = 8,
+        /// Groom cards serialization
+        SerializeGroomCards = 9,
+        /// Serialized rigvm entry names
+        SerializeRigVMEntries = 10,
+        /// Serialized rigvm entry names
+        SerializeHairBindingAsset = 11,
+        /// Serialized rigvm entry names
+        SerializeHairClusterCullingData = 12,
+        /// Groom cards and meshes serialization
+        SerializeGroomCardsAndMeshes = 13,
+        /// Stripping LOD data from groom
+        GroomLODStripping = 14,
+        /// Stripping LOD data from groom
+        GroomBindingSerialization = 15,
+    }
+    engine {
+        VerUe4_21 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_25 => Some(Self::StoreMarkerNamesOnSkeleton),
+        VerUe4_26 => Some(Self::NotifyAndSyncMarkerGuids),
+        _ => Some(Self::GroomBindingSerialization),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-AnimPhys stream
+    FAnimPhysObjectVersion("FAnimPhysObjectVersion", [0x29E575DD, 0xE0A34627, 0x9D10D276, 0x232CDCEA], GeometryCacheAssetDeprecation) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// convert animnode look at to use just default axis instead of enum, which doesn't do much
+        ConvertAnimNodeLookAtAxis = 1,
+        /// Change FKSphylElem and FKBoxElem to use Rotators not Quats for easier editing
+        BoxSphylElemsUseRotators = 2,
+        /// Change thumbnail scene info and asset import data to be transactional
+        ThumbnailSceneInfoAndAssetImportDataAreTransactional = 3,
+        /// Enabled clothing masks rather than painting parameters directly
+        AddedClothingMaskWorkflow = 4,
+        /// Remove UID from smart name serialize, it just breaks determinism
+        RemoveUIDFromSmartNameSerialize = 5,
+        /// Convert FName Socket to FSocketReference and added TargetReference that support bone and socket
+        CreateTargetReference = 6,
+        /// Tune soft limit stiffness and damping coefficients
+        TuneSoftLimitStiffnessAndDamping = 7,
+        /// Fix possible inf/nans in clothing particle masses
+        FixInvalidClothParticleMasses = 8,
+        /// Moved influence count to cached data
+        CacheClothMeshInfluences = 9,
+        /// Remove GUID from Smart Names entirely + remove automatic name fixup
+        SmartNameRefactorForDeterministicCooking = 10,
+        /// rename the variable and allow individual curves to be set
+        RenameDisableAnimCurvesToAllowAnimCurveEvaluation = 11,
+        /// link curve to LOD, so curve metadata has to include LODIndex
+        AddLODToCurveMetaData = 12,
+        /// Fixed blend profile references persisting after paste when they aren't compatible
+        FixupBadBlendProfileReferences = 13,
+        /// Allowing multiple audio plugin settings
+        AllowMultipleAudioPluginSettings = 14,
+        /// Change RetargetSource reference to SoftObjectPtr
+        ChangeRetargetSourceReferenceToSoftObjectPtr = 15,
+        /// Save editor only full pose for pose asset
+        SaveEditorOnlyFullPoseForPoseAsset = 16,
+        /// Asset change and cleanup to facilitate new streaming system
+        GeometryCacheAssetDeprecation = 17,
+    }
+    engine {
+        VerUe4_16 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_17 => Some(Self::ThumbnailSceneInfoAndAssetImportDataAreTransactional),
+        VerUe4_18 => Some(Self::TuneSoftLimitStiffnessAndDamping),
+        VerUe4_19 => Some(Self::AddLODToCurveMetaData),
+        VerUe4_20 => Some(Self::SaveEditorOnlyFullPoseForPoseAsset),
+        VerUe4_26 => Some(Self::GeometryCacheAssetDeprecation),
+        _ => Some(Self::GeometryCacheAssetDeprecation),
+    }
+}
+
+custom_version! {
+    /// Version used for serializing asset registry caches, both runtime and editor
+    FAssetRegistryVersion("FAssetRegistryVersion", [0x717F9EE7, 0xE9B0493A, 0x88B39132, 0x1B388107], ManageDependenciesCookRule) {
+        /// From before file versioning was implemented
+        PreVersioning = 0,
+        /// The first version of the runtime asset registry to include file versioning.
+        HardSoftDependencies = 1,
+        /// Added FAssetRegistryState and support for piecemeal serialization
+        AddAssetRegistryState = 2,
+        /// AssetData serialization format changed, versions before this are not readable
+        ChangedAssetData = 3,
+        /// Removed MD5 hash from package data
+        RemovedMD5Hash = 4,
+        /// Added hard/soft manage references
+        AddedHardManage = 5,
+        /// Added MD5 hash of cooked package to package data
+        AddedCookedMD5Hash = 6,
+        /// Added UE::AssetRegistry::EDependencyProperty to each dependency
+        AddedDependencyFlags = 7,
+        /// Major tag format change that replaces USE_COMPACT_ASSET_REGISTRY:
+        /// * Target tag INI settings cooked into tag data
+        /// * Instead of FString values are stored directly as one of:
+        ///   - Narrow / wide string
+        ///   - \[Numberless\] FName
+        ///   - \[Numberless\] export path
+        ///   - Localized string
+        /// * All value types are deduplicated
+        /// * All key-value maps are cooked into a single contiguous range
+        /// * Switched from FName table to seek-free and more optimized FName batch loading
+        /// * Removed global tag storage, a tag map reference-counts one store per asset registry
+        /// * All configs can mix fixed and loose tag maps
+        FixedTags = 8,
+        /// Added Version information to AssetPackageData
+        WorkspaceDomain = 9,
+        /// Added ImportedClasses to AssetPackageData
+        PackageImportedClasses = 10,
+        /// A new version number of UE5 was added to FPackageFileSummary
+        PackageFileSummaryVersionChange = 11,
+        /// Change to linker export/import resource serialization
+        ObjectResourceOptionalVersionChange = 12,
+        /// Added FIoHash for each FIoChunkId in the package to the AssetPackageData.
+        AddedChunkHashes = 13,
+        /// Classes are serialized as path names rather than short object names, e.g. /Script/Engine.StaticMesh
+        ClassPaths = 14,
+        /// Asset bundles are serialized as FTopLevelAssetPath instead of FSoftObjectPath, deprecated FAssetData::ObjectPath
+        RemoveAssetPathFNames = 15,
+        /// Added header with bFilterEditorOnlyData flag
+        AddedHeader = 16,
+        /// Added Extension to AssetPackageData.
+        AssetPackageDataHasExtension = 17,
+        /// Added PackageLocation to AssetPackageData.
+        AssetPackageDataHasPackageLocation = 18,
+        /// Replaced 2 byte wide string with UTF8 String
+        MarshalledTextAsUTF8String = 19,
+        /// Replaced FAssetPackageData::PackageGuid with PackageSavedHash
+        PackageSavedHash = 20,
+        /// FPackageDependencyData::LoadDependenciesFromPackageHeader changed how it calculates PackageDependencies
+        ExternalActorToWorldIsEditorOnly = 21,
+        /// CookRule property added to EDependencyProperties for EDependencyCategory::Manage
+        ManageDependenciesCookRule = 22,
+    }
+    engine {
+        _ => Some(Self::ManageDependenciesCookRule),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Blueprints stream
+    FBlueprintsObjectVersion("FBlueprintsObjectVersion", [0xB0D832E4, 0x1F894F0D, 0xACCF7EB7, 0x36FD4AA2], DisallowObjectConfigVars) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        OverridenEventReferenceFixup = 1,
+        CleanBlueprintFunctionFlags = 2,
+        ArrayGetByRefUpgrade = 3,
+        EdGraphPinOptimized = 4,
+        AllowDeletionConformed = 5,
+        AdvancedContainerSupport = 6,
+        SCSHasComponentTemplateClass = 7,
+        ComponentTemplateClassSupport = 8,
+        ArrayGetFuncsReplacedByCustomNode = 9,
+        DisallowObjectConfigVars = 10,
+    }
+    engine {
+        _ => Some(Self::DisallowObjectConfigVars),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Anim stream
+    FControlRigObjectVersion("FControlRigObjectVersion", [0xA7820CFB, 0x20A74359, 0x8C542C14, 0x9623CF50], RigHierarchyPostConstructionConnectors) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Added execution pins and removed hierarchy ref pins
+        RemovalOfHierarchyRefPins = 1,
+        /// Refactored operators to store FCachedPropertyPath instead of string
+        OperatorsStoringPropertyPaths = 2,
+        /// Introduced new RigVM as a backend
+        SwitchedToRigVM = 3,
+        /// Added a new transform as part of the control
+        ControlOffsetTransform = 4,
+        /// Using a cache data structure for key indices now
+        RigElementKeyCache = 5,
+        /// Full variable support
+        BlueprintVariableSupport = 6,
+        /// Hierarchy V2.0
+        RigHierarchyV2 = 7,
+        /// RigHierarchy to support multi component parent constraints
+        RigHierarchyMultiParentConstraints = 8,
+        /// RigHierarchy now supports space favorites per control
+        RigHierarchyControlSpaceFavorites = 9,
+        /// RigHierarchy now stores min and max values as float storages
+        StorageMinMaxValuesAsFloatStorage = 10,
+        /// RenameGizmoToShape
+        RenameGizmoToShape = 11,
+        /// BoundVariableWithInjectionNode
+        BoundVariableWithInjectionNode = 12,
+        /// Switch limit control over to per channel limits
+        PerChannelLimits = 13,
+        /// Removed the parent cache for multi parent elements
+        RemovedMultiParentParentCache = 14,
+        /// Deprecation of parameters
+        RemoveParameters = 15,
+        /// Added rig curve element value state flag
+        CurveElementValueStateFlag = 16,
+        /// Added the notion of a per control animation type
+        ControlAnimationType = 17,
+        /// Added preferred permutation for templates
+        TemplatesPreferredPermutatation = 18,
+        /// Added preferred euler angles to controls
+        PreferredEulerAnglesForControls = 19,
+        /// Added rig hierarchy element metadata
+        HierarchyElementMetadata = 20,
+        /// Converted library nodes to templates
+        LibraryNodeTemplates = 21,
+        /// Controls to be able specify space switch targets
+        RestrictSpaceSwitchingForControls = 22,
+        /// Controls to be able specify which channels should be visible in sequencer
+        ControlTransformChannelFiltering = 23,
+        /// Store function information (and compilation data) in blueprint generated class
+        StoreFunctionsInGeneratedClass = 24,
+        /// Hierarchy storing previous names
+        RigHierarchyStoringPreviousNames = 25,
+        /// Control supporting preferred rotation order
+        RigHierarchyControlPreferredRotationOrder = 26,
+        /// Last bit required for Control supporting preferred rotation order
+        RigHierarchyControlPreferredRotationOrderFlag = 27,
+        /// Element metadata is now stored on URigHierarchy, rather than FRigBaseElement
+        RigHierarchyStoresElementMetadata = 28,
+        /// Add type (primary, secondary) and optional bool to FRigConnectorSettings
+        ConnectorsWithType = 29,
+        /// Add parent key to control rig pose
+        RigPoseWithParentKey = 30,
+        /// Physics solvers stored on hierarchy
+        ControlRigStoresPhysicsSolvers = 31,
+        /// Moved the element storage into separate buffers
+        RigHierarchyIndirectElementStorage = 32,
+        /// Compress the rig hierarchy when storing to disk
+        RigHierarchyCompressElements = 33,
+        /// Added the notion of components to the rig hierarchy
+        RigHierarchyStoresComponents = 34,
+        /// Improve transform compactness when serializing the hierarchy
+        RigHierarchyCompactTransformSerialization = 35,
+        /// Connectors to support arrays
+        RigHierarchyArrayConnectors = 36,
+        /// Parent constraints offering a display label
+        RigHierarchyParentContraintWithLabel = 37,
+        /// Previous name and parent maps serialized as FRigHierarchyKey
+        RigHierarchyPreviousNameAndParentMapUsingHierarchyKey = 38,
+        /// New setting for connectors to optionally specify their use only during post construction
+        RigHierarchyPostConstructionConnectors = 39,
+    }
+    engine {
+        VerUe4_23 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_25 => Some(Self::OperatorsStoringPropertyPaths),
+        VerUe4_26 => Some(Self::SwitchedToRigVM),
+        VerUe5_0 => Some(Self::BlueprintVariableSupport),
+        VerUe5_1 => Some(Self::PerChannelLimits),
+        VerUe5_2 => Some(Self::LibraryNodeTemplates),
+        VerUe5_3 => Some(Self::RigHierarchyStoringPreviousNames),
+        VerUe5_4 => Some(Self::RigHierarchyControlPreferredRotationOrderFlag),
+        VerUe5_5 => Some(Self::RigPoseWithParentKey),
+        _ => Some(Self::RigHierarchyIndirectElementStorage),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Core stream
+    FCoreObjectVersion("FCoreObjectVersion", [0x375EC13C, 0x06E448FB, 0xB50084F0, 0x262A717E], FProperties) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        MaterialInputNativeSerialize = 1,
+        EnumProperties = 2,
+        SkeletalMaterialEditorDataStripping = 3,
+        FProperties = 4,
+    }
+    engine {
+        VerUe4_12 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_15 => Some(Self::MaterialInputNativeSerialize),
+        VerUe4_22 => Some(Self::EnumProperties),
+        VerUe4_25 => Some(Self::SkeletalMaterialEditorDataStripping),
+        _ => Some(Self::FProperties),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for CurveExpressionsAssetData
+    FCurveExpressionCustomVersion("FCurveExpressionCustomVersion", [0xA26D36AE, 0x26935388, 0xA8C5CB96, 0x2B95B4AF], ExpressionDataInSharedObject) {
+        /// Before any version changes were made in niagara
+        BeforeCustomVersionWasAdded = 0,
+        /// Serialized expressions
+        SerializedExpressions = 1,
+        ExpressionDataInSharedObject = 2,
+    }
+    engine {
+        VerUe5_4 => Some(Self::SerializedExpressions),
+        _ => Some(Self::ExpressionDataInSharedObject),
+    }
+}
+
+custom_version! {
+    FDNAAssetCustomVersion("FDNAAssetCustomVersion", [0x9DE7BD98, 0x67D445B2, 0x8C0E9D73, 0xFDE1E367], BeforeCustomVersionWasAdded) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+    }
+    engine {
+        VerUe4_26 => None,
+        _ => Some(Self::BeforeCustomVersionWasAdded),
+    }
+}
+
+custom_version! {
+    FDestructionObjectVersion("FDestructionObjectVersion", [0x174F1F0B, 0xB4C645A5, 0xB13F2EE8, 0xD0FB917D], ImplicitObjectDoCollideAttribute) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Added timestamped caches for geometry component to handle transform sampling instead of per-frame
+        AddedTimestampedGeometryComponentCache = 1,
+        /// Added functionality to strip unnecessary data from geometry collection caches
+        AddedCacheDataReduction = 2,
+        /// Geometry collection data is now in the DDC
+        GeometryCollectionInDDC = 3,
+        /// Geometry collection data is now in both the DDC and the asset
+        GeometryCollectionInDDCAndAsset = 4,
+        /// New way to serialize unique ptr and serializable ptr
+        ChaosArchiveAdded = 5,
+        /// Serialization support for UFieldSystems
+        FieldsAdded = 6,
+        /// density default units changed from kg/cm3 to kg/m3
+        DensityUnitsChanged = 7,
+        /// bulk serialize arrays
+        BulkSerializeArrays = 8,
+        /// bulk serialize arrays
+        GroupAndAttributeNameRemapping = 9,
+        /// bulk serialize arrays
+        ImplicitObjectDoCollideAttribute = 10,
+    }
+    engine {
+        VerUe4_22 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_23 => Some(Self::AddedCacheDataReduction),
+        VerUe4_25 => Some(Self::GroupAndAttributeNameRemapping),
+        _ => Some(Self::ImplicitObjectDoCollideAttribute),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Editor stream
+    FEditorObjectVersion("FEditorObjectVersion", [0xE4B068ED, 0xF49442E9, 0xA231DA0B, 0x2E46BB41], SkeletalMeshSourceDataSupport16bitOfMaterialNumber) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Localizable text gathered and stored in packages is now flagged with a localizable text gathering process version
+        GatheredTextProcessVersionFlagging = 1,
+        /// Fixed several issues with the gathered text cache stored in package headers
+        GatheredTextPackageCacheFixesV1 = 2,
+        /// Added support for "root" meta-data (meta-data not associated with a particular object in a package)
+        RootMetaDataSupport = 3,
+        /// Fixed issues with how Blueprint bytecode was cached
+        GatheredTextPackageCacheFixesV2 = 4,
+        /// Updated FFormatArgumentData to allow variant data to be marshaled from a BP into C++
+        TextFormatArgumentDataIsVariant = 5,
+        /// Changes to SplineComponent
+        SplineComponentCurvesInStruct = 6,
+        /// Updated ComboBox to support toggling the menu open, better controller support
+        ComboBoxControllerSupportUpdate = 7,
+        /// Refactor mesh editor materials
+        RefactorMeshEditorMaterials = 8,
+        /// Added UFontFace assets
+        AddedFontFaceAssets = 9,
+        /// Add UPROPERTY for TMap of Mesh section, so the serialize will be done normally (and export to text will work correctly)
+        UPropertryForMeshSection = 10,
+        /// Update the schema of all widget blueprints to use the WidgetGraphSchema
+        WidgetGraphSchema = 11,
+        /// Added a specialized content slot to the background blur widget
+        AddedBackgroundBlurContentSlot = 12,
+        /// Updated UserDefinedEnums to have stable keyed display names
+        StableUserDefinedEnumDisplayNames = 13,
+        /// Added "Inline" option to UFontFace assets
+        AddedInlineFontFaceAssets = 14,
+        /// Fix a serialization issue with static mesh FMeshSectionInfoMap FProperty
+        UPropertryForMeshSectionSerialize = 15,
+        /// Adding a version bump for the new fast widget construction in case of problems.
+        FastWidgetTemplates = 16,
+        /// Update material thumbnails to be more intelligent on default primitive shape for certain material types
+        MaterialThumbnailRenderingChanges = 17,
+        /// Introducing a new clipping system for Slate/UMG
+        NewSlateClippingSystem = 18,
+        /// MovieScene Meta Data added as native Serialization
+        MovieSceneMetaDataSerialization = 19,
+        /// Text gathered from properties now adds two variants: a version without the package localization ID (for use at runtime), and a version with it (which is editor-only)
+        GatheredTextEditorOnlyPackageLocId = 20,
+        /// Added AlwaysSign to FNumberFormattingOptions
+        AddedAlwaysSignNumberFormattingOption = 21,
+        /// Added additional objects that must be serialized as part of this new material feature
+        AddedMaterialSharedInputs = 22,
+        /// Added morph target section indices
+        AddedMorphTargetSectionIndices = 23,
+        /// Serialize the instanced static mesh render data, to avoid building it at runtime
+        SerializeInstancedStaticMeshRenderData = 24,
+        /// Change to MeshDescription serialization (moved to release)
+        MeshDescriptionNewSerialization_MovedToRelease = 25,
+        /// New format for mesh description attributes
+        MeshDescriptionNewAttributeFormat = 26,
+        /// Switch root component of SceneCapture actors from MeshComponent to SceneComponent
+        ChangeSceneCaptureRootComponent = 27,
+        /// StaticMesh serializes MeshDescription instead of RawMesh
+        StaticMeshDeprecatedRawMesh = 28,
+        /// MeshDescriptionBulkData contains a Guid used as a DDC key
+        MeshDescriptionBulkDataGuid = 29,
+        /// Change to MeshDescription serialization (removed FMeshPolygon::HoleContours)
+        MeshDescriptionRemovedHoles = 30,
+        /// Change to the WidgetCompoent WindowVisibilty default value
+        ChangedWidgetComponentWindowVisibilityDefault = 31,
+        /// Avoid keying culture invariant display strings during serialization to avoid non-deterministic cooking issues
+        CultureInvariantTextSerializationKeyStability = 32,
+        /// Change to UScrollBar and UScrollBox thickness property (removed implicit padding of 2, so thickness value must be incremented by 4).
+        ScrollBarThicknessChange = 33,
+        /// Deprecated LandscapeHoleMaterial
+        RemoveLandscapeHoleMaterial = 34,
+        /// MeshDescription defined by triangles instead of arbitrary polygons
+        MeshDescriptionTriangles = 35,
+        /// Add weighted area and angle when computing the normals
+        ComputeWeightedNormals = 36,
+        /// SkeletalMesh now can be rebuild in editor, no more need to re-import
+        SkeletalMeshBuildRefactor = 37,
+        /// Move all SkeletalMesh source data into a private uasset in the same package has the skeletalmesh
+        SkeletalMeshMoveEditorSourceDataToPrivateAsset = 38,
+        /// Parse text only if the number is inside the limits of its type
+        NumberParsingOptionsNumberLimitsAndClamping = 39,
+        /// Make sure we can have more then 255 material in the skeletal mesh source data
+        SkeletalMeshSourceDataSupport16bitOfMaterialNumber = 40,
+    }
+    engine {
+        VerUe4_12 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_13 => Some(Self::GatheredTextPackageCacheFixesV1),
+        VerUe4_14 => Some(Self::SplineComponentCurvesInStruct),
+        VerUe4_15 => Some(Self::RefactorMeshEditorMaterials),
+        VerUe4_16 => Some(Self::AddedInlineFontFaceAssets),
+        VerUe4_17 => Some(Self::MaterialThumbnailRenderingChanges),
+        VerUe4_19 => Some(Self::GatheredTextEditorOnlyPackageLocId),
+        VerUe4_20 => Some(Self::AddedMorphTargetSectionIndices),
+        VerUe4_21 => Some(Self::SerializeInstancedStaticMeshRenderData),
+        VerUe4_22 => Some(Self::MeshDescriptionNewAttributeFormat),
+        VerUe4_23 => Some(Self::MeshDescriptionRemovedHoles),
+        VerUe4_24 => Some(Self::RemoveLandscapeHoleMaterial),
+        VerUe4_25 => Some(Self::SkeletalMeshBuildRefactor),
+        VerUe4_26 => Some(Self::SkeletalMeshMoveEditorSourceDataToPrivateAsset),
+        _ => Some(Self::SkeletalMeshSourceDataSupport16bitOfMaterialNumber),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Anim stream
+    FFoliageCustomVersion("FFoliageCustomVersion", [0x430C4D19, 0x71544970, 0x87699B69, 0xDF90B0E5], FoliageDiscardOnLoad) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+        /// Converted to use HierarchicalInstancedStaticMeshComponent
+        FoliageUsingHierarchicalISMC = 1,
+        /// Changed Component to not RF_Transactional
+        HierarchicalISMCNonTransactional = 2,
+        /// Added FoliageTypeUpdateGuid
+        AddedFoliageTypeUpdateGuid = 3,
+        /// Use a GUID to determine whic procedural actor spawned us
+        ProceduralGuid = 4,
+        /// Support for cross-level bases
+        CrossLevelBase = 5,
+        /// FoliageType for details customization
+        FoliageTypeCustomization = 6,
+        /// FoliageType for details customization continued
+        FoliageTypeCustomizationScaling = 7,
+        /// FoliageType procedural scale and shade settings updated
+        FoliageTypeProceduralScaleAndShade = 8,
+        /// Added FoliageHISMC and blueprint support
+        FoliageHISMCBlueprints = 9,
+        /// Added Mobility setting to UFoliageType
+        AddedMobility = 10,
+        /// Make sure that foliage has FoliageHISMC class
+        FoliageUsingFoliageISMC = 11,
+        /// Foliage Actor Support
+        FoliageActorSupport = 12,
+        /// Foliage Actor (No weak ptr)
+        FoliageActorSupportNoWeakPtr = 13,
+        /// Foliage Instances are now always saved local to Level
+        FoliageRepairInstancesWithLevelTransform = 14,
+        /// Supports discarding foliage types on load independently from density scaling
+        FoliageDiscardOnLoad = 15,
+    }
+    engine {
+        VerUe4_7 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_8 => Some(Self::AddedFoliageTypeUpdateGuid),
+        VerUe4_9 => Some(Self::FoliageTypeProceduralScaleAndShade),
+        VerUe4_10 => Some(Self::AddedMobility),
+        VerUe4_23 => Some(Self::FoliageUsingFoliageISMC),
+        VerUe4_24 => Some(Self::FoliageActorSupportNoWeakPtr),
+        VerUe4_26 => Some(Self::FoliageRepairInstancesWithLevelTransform),
+        _ => Some(Self::FoliageDiscardOnLoad),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in the //Fortnite/Main stream
+    FFortniteMainBranchObjectVersion("FFortniteMainBranchObjectVersion", [0x601D1886, 0xAC644F84, 0xAA16D3DE, 0x0DEAC7D6], MorphTargetCookedCPUDataCompressed) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// World composition tile offset changed from 2d to 3d
+        WorldCompositionTile3DOffset = 1,
+        /// Minor material serialization optimization
+        MaterialInstanceSerializeOptimization_ShaderFName = 2,
+        /// Refactored cull distances to account for HLOD, explicit override and globals in priority
+        CullDistanceRefactor_RemovedDefaultDistance = 3,
+        CullDistanceRefactor_NeverCullHLODsByDefault = 4,
+        CullDistanceRefactor_NeverCullALODActorsByDefault = 5,
+        /// Support to remove morphtarget generated by bRemapMorphtarget
+        SaveGeneratedMorphTargetByEngine = 6,
+        /// Convert reduction setting options
+        ConvertReductionSettingOptions = 7,
+        /// Serialize the type of blending used for landscape layer weight static params
+        StaticParameterTerrainLayerWeightBlendType = 8,
+        /// Fix up None Named animation curve names,
+        FixUpNoneNameAnimationCurves = 9,
+        /// Ensure ActiveBoneIndices to have parents even not skinned for old assets
+        EnsureActiveBoneIndicesToContainParents = 10,
+        /// Serialize the instanced static mesh render data, to avoid building it at runtime
+        SerializeInstancedStaticMeshRenderData = 11,
+        /// Cache material quality node usage
+        CachedMaterialQualityNodeUsage = 12,
+        /// Font outlines no longer apply to drop shadows for new objects but we maintain the opposite way for backwards compat
+        FontOutlineDropShadowFixup = 13,
+        /// New skeletal mesh import workflow (Geometry only or animation only re-import )
+        NewSkeletalMeshImporterWorkflow = 14,
+        /// Migrate data from previous data structure to new one to support materials per LOD on the Landscape
+        NewLandscapeMaterialPerLOD = 15,
+        /// New Pose Asset data type
+        RemoveUnnecessaryTracksFromPose = 16,
+        /// Migrate Foliage TLazyObjectPtr to TSoftObjectPtr
+        FoliageLazyObjPtrToSoftObjPtr = 17,
+        /// TimelineTemplates store their derived names instead of dynamically generating
+        /// This code tied to this version was reverted and redone at a later date
+        REVERTED_StoreTimelineNamesInTemplate = 18,
+        /// Added BakePoseOverride for LOD setting
+        AddBakePoseOverrideForSkeletalMeshReductionSetting = 19,
+        /// TimelineTemplates store their derived names instead of dynamically generating
+        StoreTimelineNamesInTemplate = 20,
+        /// Avoid duplicating widget animations to save space.
+        WidgetStopDuplicatingAnimations = 21,
+        /// Allow reducing of the base LOD, we need to store some imported model data so we can reduce again from the same data.
+        AllowSkeletalMeshToReduceTheBaseLOD = 22,
+        /// Curve Table size reduction
+        ShrinkCurveTableSize = 23,
+        /// Widgets upgraded with WidgetStopDuplicatingAnimations, may not correctly default-to-self for the widget parameter.
+        WidgetAnimationDefaultToSelfFail = 24,
+        /// HUDWidgets now require an element tag
+        FortHUDElementNowRequiresTag = 25,
+        /// Animation saved as bulk data when cooked
+        FortMappedCookedAnimation = 26,
+        /// Support Virtual Bone in Retarget Manager
+        SupportVirtualBoneInRetargeting = 27,
+        /// Fixup bad defaults in water metadata
+        FixUpWaterMetadata = 28,
+        /// Move the location of water metadata
+        MoveWaterMetadataToActor = 29,
+        /// Replaced lake collision component
+        ReplaceLakeCollision = 30,
+        /// Anim layer node names are now conformed by Guid
+        AnimLayerGuidConformation = 31,
+        /// Ocean collision component has become dynamic
+        MakeOceanCollisionTransient = 32,
+        /// FFieldPath will serialize the owner struct reference and only a short path to its property
+        FFieldPathOwnerSerialization = 33,
+        /// Simplified WaterBody post process material handling
+        FixUpUnderwaterPostProcessMaterial = 34,
+        /// A single water exclusion volume can now exclude N water bodies
+        SupportMultipleWaterBodiesPerExclusionVolume = 35,
+        /// Serialize rigvm operators one by one instead of the full byte code array to ensure determinism
+        RigVMByteCodeDeterminism = 36,
+        /// Serialize the physical materials generated by the render material
+        LandscapePhysicalMaterialRenderData = 37,
+        /// RuntimeVirtualTextureVolume fix transforms
+        FixupRuntimeVirtualTextureVolume = 38,
+        /// Retrieve water body collision components that were lost in cooked builds
+        FixUpRiverCollisionComponents = 39,
+        /// Fix duplicate spline mesh components on rivers
+        FixDuplicateRiverSplineMeshCollisionComponents = 40,
+        /// Indicates level has stable actor guids
+        ContainsStableActorGUIDs = 41,
+        /// Levelset Serialization support for BodySetup.
+        LevelsetSerializationSupportForBodySetup = 42,
+        /// Moving Chaos solver properties to allow them to exist in the project physics settings
+        ChaosSolverPropertiesMoved = 43,
+        /// Moving some UFortGameFeatureData properties and behaviors into the UGameFeatureAction pattern
+        GameFeatureData_MovedComponentListAndCheats = 44,
+        /// Add centrifugal forces for cloth
+        ChaosClothAddfictitiousforces = 45,
+        /// Chaos Convex StructureData supports different index sizes based on num verts/planes
+        /// Chaos FConvex uses array of FVec3s for vertices instead of particles
+        /// (Merged from //UE4/Main)
+        ChaosConvexVariableStructureDataAndVerticesArray = 46,
+        /// Remove the WaterVelocityHeightTexture dependency on MPC_Landscape and LandscapeWaterIndo
+        RemoveLandscapeWaterInfo = 47,
+        /// Added the weighted value property type to store the cloths weight maps' low/high ranges
+        ChaosClothAddWeightedValue = 48,
+        /// Added the Long Range Attachment stiffness weight map
+        ChaosClothAddTetherStiffnessWeightMap = 49,
+        /// Fix corrupted LOD transition maps
+        ChaosClothFixLODTransitionMaps = 50,
+        /// Enable a few more weight maps to better art direct the cloth simulation
+        ChaosClothAddTetherScaleAndDragLiftWeightMaps = 51,
+        /// Enable material (edge, bending, and area stiffness) weight maps
+        ChaosClothAddMaterialWeightMaps = 52,
+        /// Added bShowCurve for movie scene float channel serialization
+        SerializeFloatChannelShowCurve = 53,
+        /// Minimize slack waste by using a single array for grass data
+        LandscapeGrassSingleArray = 54,
+        /// Add loop counters to sequencer's compiled sub-sequence data
+        AddedSubSequenceEntryWarpCounter = 55,
+        /// Water plugin is now component-based rather than actor based
+        WaterBodyComponentRefactor = 56,
+        /// Cooked BPGC storing editor-only asset tags
+        BPGCCookedEditorTags = 57,
+        /// Terrain layer weights are no longer considered material parameters
+        TerrainLayerWeightsAreNotParameters = 58,
+        /// Anim Dynamics Node Gravity Override vector is now defined in world space, not simulation space.
+        /// Legacy behavior can be maintained with a flag, which is set false by default for new nodes,
+        /// true for nodes predating this change.
+        GravityOverrideDefinedInWorldSpace = 59,
+        /// Anim Dynamics Node Physics parameters for each body in a chain are now stored in an array and can be edited.
+        AnimDynamicsEditableChainParameters = 60,
+        /// Decoupled the generation of the water texture from the Water Brush and the landscape
+        WaterZonesRefactor = 61,
+        /// Add faster damping calculations to the cloth simulation and rename previous Damping parameter to LocalDamping.
+        ChaosClothFasterDamping = 62,
+        /// Migrated function handlers to the CDO/archetype data
+        MigratedFunctionHandlersToDefaults = 63,
+        /// Storing inertia tensor as vec3 instead of matrix.
+        ChaosInertiaConvertedToVec3 = 64,
+        /// Migrated event definitions to the CDO/archetype data
+        MigratedEventDefinitionToDefaults = 65,
+        /// Serialize LevelInstanceActorGuid on new ILevelInstanceInterface implementation
+        LevelInstanceActorGuidSerialize = 66,
+        /// Single-frame/key AnimDataModel patch-up
+        SingleFrameAndKeyAnimModel = 67,
+        /// Remapped bEvaluateWorldPositionOffset to bEvaluateWorldPositionOffsetInRayTracing
+        RemappedEvaluateWorldPositionOffsetInRayTracing = 68,
+        /// Water body collision settings are now those of the base UPrimitiveComponent, rather than duplicated in UWaterBodyComponent
+        WaterBodyComponentCollisionSettingsRefactor = 69,
+        /// Introducing widget inherited named slots.  This wouldn't have required a version bump, except in the previous
+        /// version, users could make NamedSlots and then Seed them with any random widgets, as a sorta 'default' setup.
+        /// In order to preserve that, we're bumping the version so that we can set a new field on UNamedSlot to control
+        /// if a widget exposes its named slot to everyone (even if it has content), which by default they wont any longer.
+        WidgetInheritedNamedSlots = 70,
+        /// Added water HLOD material
+        WaterHLODSupportAdded = 71,
+        /// Moved parameters affecting Skeleton pose rendering from the PoseWatch class to the PoseWatchPoseElement class.
+        PoseWatchMigrateSkeletonDrawParametersToPoseElement = 72,
+        /// Reset default value for Water exclusion volumes to make them more intuitive and support the "it just works" philosophy.
+        WaterExclusionVolumeExcludeAllDefault = 73,
+        /// Added water non-tessellated LOD
+        WaterNontessellatedLODSupportAdded = 74,
+        /// Added FHierarchicalSimplification::SimplificationMethod
+        HierarchicalSimplificationMethodEnumAdded = 75,
+        /// Changed how world partition streaming cells are named
+        WorldPartitionStreamingCellsNamingShortened = 76,
+        /// Serialize ContentBundleGuid in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeContentBundleGuid = 77,
+        /// Serialize IsActorRuntimeOnly in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeActorIsRuntimeOnly = 78,
+        /// Add Nanite Material Override option to materials and material instances.
+        NaniteMaterialOverride = 79,
+        /// Serialize HLOD stats in HLODActorDesc
+        WorldPartitionHLODActorDescSerializeStats = 80,
+        /// WorldPartitionStreamingSourceComponent property deprecation
+        WorldPartitionStreamingSourceComponentTargetDeprecation = 81,
+        /// Fixed localization gathering for external actor packages
+        FixedLocalizationGatherForExternalActorPackage = 82,
+        /// Change HLODActors to RuntimeCells mapping to use a GUID instead of the cell name
+        WorldPartitionHLODActorUseSourceCellGuid = 83,
+        /// Add an attribute to geometry collection to track internal faces, rather than relying on material ID numbering
+        ChaosGeometryCollectionInternalFacesAttribute = 84,
+        /// Dynamic cast nodes use an enumerated pure node state to include a value for the default setting
+        DynamicCastNodesUsePureStateEnum = 85,
+        /// Add FWorldPartitionActorFilter to FLevelInstanceActorDesc/FDataLayerInstanceDesc
+        WorldPartitionActorFilter = 86,
+        /// Change the non-spatialized radius to blend to a pure 2D spatialized sound vs omnidirectional
+        AudioAttenuationNonSpatializedRadiusBlend = 87,
+        /// Serialize actor class descriptors
+        WorldPartitionActorClassDescSerialize = 88,
+        /// FActorContainerID is now an FGuid instead of a uint64
+        WorldPartitionFActorContainerIDu64ToGuid = 89,
+        /// FDataLayerInstanceDesc support for private data layers
+        WorldPartitionPrivateDataLayers = 90,
+        /// Reduce size and improve behaviour of Chaos::FImplicitObjectUnion
+        ChaosImplicitObjectUnionBVHRefactor = 91,
+        /// FLevelInstanceActorDesc DeltaSerialize Filter
+        LevelInstanceActorDescDeltaSerializeFilter = 92,
+        /// Fix the Nanite landscape mesh non-deterministic DDC keys
+        FixNaniteLandscapeMeshDDCKey = 93,
+        /// Change how connection graphs are stored on Geometry Collections to an edge-array representation
+        ChaosGeometryCollectionConnectionEdgeGroup = 94,
+        /// Moved the water info mesh data and static water body meshes into new static mesh components for water bodies.
+        WaterBodyStaticMeshComponents = 95,
+        /// Serialize invalid bounds in world partition actor descriptors
+        WorldPartitionActorDescSerializeInvalidBounds = 96,
+        /// Upgrade Navigation Links to use 64 bits for the ID
+        NavigationLinkID32To64 = 97,
+        /// Serialize editor only references in world partition actor descriptors
+        WorldPartitionActorDescSerializeEditorOnlyReferences = 98,
+        /// Add support for soft object paths in actor descriptors
+        WorldPartitionActorDescSerializeSoftObjectPathSupport = 99,
+        /// Don't serialize class descriptor GUIDs
+        WorldPartitionClasDescGuidTransient = 100,
+        /// Serialize ActorDesc bIsMainWorldOnly
+        WorldPartitionActorDescIsMainWorldOnly = 101,
+        /// FWorldPartitionActorFilter go back to FString serialize of AssetPaths to avoid FArchiveReplaceOrClearExternalReferences clearing CDO references on BP Compile
+        WorldPartitionActorFilterStringAssetPath = 102,
+        /// Add FPackedLevelActorDesc for APackedLevelActor and support for APackedLevelActor Filters
+        PackedLevelActorDesc = 103,
+        /// Add customizable values for several UWorldPartitionRuntimeSpatialHash cvars
+        WorldPartitionRuntimeSpatialHashCVarOverrides = 104,
+        /// WorldPartition HLOD now contains a source actors object
+        WorldPartitionHLODSourceActorsRefactor = 105,
+        WaterBodyStaticMeshRename = 106,
+        /// Geometry Collection now by-default converts vertex colors to sRGB when creating render data
+        GeometryCollectionConvertVertexColorToSRGB = 107,
+        /// Water bodies before this version need to update their water zone on load since they won't have been serialized yet.
+        WaterOwningZonePointerFixup = 108,
+        /// Set flags on water static meshes to duplicate transient to avoid underlying static mesh duplication issue
+        WaterBodyStaticMeshDuplicateTransient = 109,
+        /// Update paths to use the SkeletalClass
+        MVVMConvertPropertyPathToSkeletalClass = 110,
+        /// Fixup all flags/outering on static meshes on water bodies by rebuilding them completely
+        WaterBodyStaticMeshFixup = 111,
+        /// Binding extensions for anim graph nodes
+        AnimGraphNodeBindingExtensions = 112,
+        /// Function data stores a map from work to debug operands
+        RigVMSaveDebugMapInGraphFunctionData = 113,
+        /// Fix missing binding extensions for some anim graph nodes
+        FixMissingAnimGraphNodeBindingExtensions = 114,
+        /// EditableWhenInherited: Skip custom serialization on non Archetypes
+        ISMComponentEditableWhenInheritedSkipSerialization = 115,
+        /// GrassTypes are now per-component, rather than per-landscape proxy :
+        LandscapeSupportPerComponentGrassTypes = 116,
+        /// World partition actor data layers activation logic operator support defaults for old maps
+        WorldPartitionDataLayersLogicOperatorAdded = 117,
+        /// Started sorting Possessables, Spawnables, and MovieSceneBindings for better search performance.
+        MovieSceneSortedBindings = 118,
+        /// Remove the UAnimCurveCompressionCodec::InstanceGuid which causes cook determinism issues
+        RemoveAnimCurveCompressionCodecInstanceGuid = 119,
+        /// Serialize the source HLOD Layer for HLOD actor descriptors.
+        WorldPartitionHLODActorDescSerializeSourceHLODLayer = 120,
+        /// Serialize custom editor bounds for HLOD actor descriptors.
+        WorldPartitionHLODActorDescSerializeEditorBounds = 121,
+        /// Changed default Local Exposure Contrast from 1.0 to 0.8 (reverted)
+        LocalExposureDefaultChangeFrom1_Reverted = 122,
+        /// Added support of external packaging of Data Layer Instances
+        AddDataLayerInstanceExternalPackage = 123,
+        /// Update paths to keep a flag if they are the widget BP
+        MVVMPropertyPathSelf = 124,
+        /// Enabled ObjectPtr property serialization for Dataflow nodes
+        AddDataflowObjectSerialization = 125,
+        /// Add anim notify rate scaling, defaults to on for new content, off for old content
+        AnimNotifyAddRateScale = 126,
+        /// Fix tangents for non-uniform build scales, and add a flag to optionally match the previous (incorrect) tangents
+        FixedTangentTransformForNonuniformBuildScale = 127,
+        /// AnimNode Layers will now start in a Shared Group, instead of being each one on a different group at runtime
+        AnimNodeRootDefaultGroupChange = 128,
+        /// Move AnimNext graphs to sub-entries of assets
+        AnimNextMoveGraphsToEntries = 129,
+        /// Removed debug information containing compressed data author, time etc. from animation DDC data as it introduces indeterminism
+        AnimationSequenceCompressedDataRemoveDebugData = 130,
+        /// Changes to Orthographic Camera default settings
+        OrthographicCameraDefaultSettings = 131,
+        /// Added settings to Landscape HLODs
+        LandscapeAddedHLODSettings = 132,
+        /// Skeletal Mesh uses Mesh Description to store mesh bulk data.
+        MeshDescriptionForSkeletalMesh = 133,
+        /// Skeletal Mesh optionally cooks half edge data per lod
+        SkeletalHalfEdgeData = 134,
+        /// Combine graph contexts for AnimNext graphs
+        AnimNextCombineGraphContexts = 135,
+        /// Combine parameter blocks and graphs
+        AnimNextCombineParameterBlocksAndGraphs = 136,
+        /// Move workspaces to a seperate plugin
+        AnimNextMoveWorkspaces = 137,
+        /// Level Instance Property overrides
+        LevelInstancePropertyOverrides = 138,
+        /// Added FVolumetricLightMapGridDesc in MapBuildData
+        VolumetricLightMapGridDescSupport = 139,
+        /// Introduce new structure for customizing the landscape edit layer behavior
+        IntroduceLandscapeEditLayerClass = 140,
+        /// Change workspaces to store asset references as external objects
+        AnimNextWorkspaceEntryConversion = 141,
+        /// Add support for anytype in dataflow
+        DataflowAnyTypeSupport = 142,
+        /// Adding a new flag in RBAN Solver Setting to be able to use manifolds
+        PhysicsAssetUseManifoldFlags = 143,
+        /// Added support for to record sim and query data of Shape Instance data in CVD
+        SimAndQueryDataSupportInChaosVisualDebugger = 144,
+        /// Add the imported asset dependencies to the Cloth Asset USD Import node
+        ChaosClothAssetUSDImportNodeAddAssetDependencies = 145,
+        /// Changed HitLighting to HitLightingForReflections, and HitLighting now means hit lighting for entire Lumen
+        LumenRayLightingModeOverrideEnum = 146,
+        /// PCGPartitionActorDesc
+        PCGPartitionActorDesc = 147,
+        /// Target layers are now defined in the Landscape actor and not continuously synced from the assigned material.
+        LandscapeTargetLayersInLandscapeActor = 148,
+        /// Fix to get full name of templated type ( Tarray > TArray<Float> for example )
+        DataflowTemplatedTypeFix = 149,
+        /// Changes for LevelInstance support in StaticLighting
+        LevelInstanceStaticLightingSupport = 150,
+        /// PCGGridDescriptor
+        PCGGridDescriptor = 151,
+        /// AnimNext graphs now have public/private state
+        AnimNextGraphAccessSpecifiers = 152,
+        /// Added a more stable pixel depth offset mode.
+        MaterialPixelDepthOffsetMode = 153,
+        /// Added hideable pins to dataflow
+        DataflowHideablePins = 154,
+        /// Added multiple section import to the cloth asset skeletal mesh import node
+        ClothAssetSkeletalMeshMultiSectionImport = 155,
+        /// Serialize EditorBounds in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeEditorBounds = 156,
+        /// Fixup for the data that has been damaged by LandscapeTargetLayersInLandscapeActor (loss of landscape layer info object assignments)
+        FixupLandscapeTargetLayersInLandscapeActor = 157,
+        /// Allow custom import of morph target
+        MorphTargetCustomImport = 158,
+        /// Fix chaos cloth buckling stiffness parameter bug
+        ChaosClothAllowZeroBucklingStiffness = 159,
+        /// LevelSequenceUpgradeDynamicBindings was removed but was intended for this position. Putting this here to make sure versioning of subsequent assets remains the same
+        LevelSequenceUpgradeDynamicBindings_NoOp = 160,
+        /// AddToFrontend GFA now defaults to unload plugin on exit frontend
+        GameFeatureDataActionAddToFrontendDefaultToUnload = 161,
+        /// Upgraded movie scene 'dynamic bindings' to use the new Custom Bindings system
+        LevelSequenceUpgradeDynamicBindings = 162,
+        /// Changed the precision for the stored rotation on kinematic targets to match the precision used in particles
+        ChaosStoreKinematicTargetRotationAsSinglePrecision = 163,
+        /// PCG changes around the ApplyOnActor node, where we collapsed the TargetActor to the input pin.
+        PCGApplyOnActorNodeMoveTargetActorEdgeToInput = 164,
+        /// Deprecation of the bPlaying flag on FTimeline struct types in favor of a better
+        /// PlayingStateTracker type to improve replication reliability
+        TimelinePlayingStateTrackerDeprecation = 165,
+        /// Enable SkipOnlyEditorOnly style cooking of UStaticMeshComponent::MeshPaintTexture
+        MeshPaintTextureUsesEditorOnly = 166,
+        /// Fixup and synchronize some landscape properties that have moved to the property sharing/overriding system :
+        LandscapeBodyInstanceAsSharedProperty = 167,
+        /// Multiple changes to AnimNext modules, variables etc.
+        AnimNextModuleRefactor = 168,
+        /// Subsurface profile now has a guid to be able to select one of many in a Substrate material.
+        SubsurfaceProfileGuid = 169,
+        /// Added support for to record the new solver iteration settings in CVD
+        SolverIterationsDataSupportInChaosVisualDebugger = 170,
+        /// Updated FColorMaterialInput to use FLinearColor instead of FColor
+        MaterialInputUsesLinearColor = 171,
+        /// Updated editor only AFunctionalTest running logic to run tests editor world if the actors don't support PIE
+        FunctionalTestCanRunInEditorWorld = 172,
+        /// Added support for display name in the Visual Logger
+        VisualLoggerSupportDisplayName = 173,
+        /// Added support for the GyroscopicTorque flag in CVD
+        GyroscopicTorquesSupportInChaosVisualDebugger = 174,
+        /// Added managed array property serialization
+        AddManagedArrayCollectionPropertySerialization = 175,
+        /// Landscape texture patches in Texture Asset source mode now use proper resolution when calculating transform
+        LandscapeTexturePatchUsesTextureAssetResolution = 176,
+        /// Added support for relative transform in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeRelativeTransform = 177,
+        /// Make sure scene graph entities are not public by default
+        SceneGraphEntitiesPrivateByDefault = 178,
+        /// Added debug color for physical materials
+        DebugColorForPhysicalMaterials = 179,
+        /// Added PreprocessedFontGeometry to FFontFaceData
+        AddedPreprocessedFontGeometry = 180,
+        /// Added Dynamic Mesh Sculpt Layer serialization
+        DynamicMeshSerializeSculptLayers = 181,
+        /// Fix reachable garbage object warnings from some legacy ASpatialHashRuntimeGridInfo actors
+        SpatialHashRuntimeGridInfoSpriteFixup = 182,
+        /// Removed UAnimSequence::bUseRawDataOnly flag alongside compression refactor
+        AnimSequenceRawDataOnlyFlagRemoval = 183,
+        /// HLOD relevancy of Level Instances was previously ignored, now taken into account. Reset to the default behavior.
+        ResetLevelInstanceHLODRelevancy = 184,
+        /// Updated default scene capture post-processing settings to reflect the underlying implementation overrides
+        SceneCaptureDefaultSettings = 185,
+        /// Add Cloth Asset Base class serialization
+        AddClothAssetBase = 186,
+        /// Add inline constant default values to the PCG graph nodes.
+        PCGInlineConstantDefaultValues = 187,
+        /// Add MaterialSubstrateSubsurfaceType type to UMaterialExpressionSubstrateSlabBSDF for replacing bUseSSSDifffusion
+        AddMaterialSubstrateSubsurfaceType = 188,
+        /// Added option to visualize runtime virtual textures' streamed mips only in PIE
+        AddedRuntimeVirtualTextureUseStreamingMipsInEditorMode = 189,
+        /// Media plate holdout composite components have been replaced by a checkbox
+        MediaPlateHoldoutComponentRemoval = 190,
+        /// Changed PCG landscape cache default from "serialize at cook" to "never serialize"
+        PCGLandscapeCacheDefaultSerializationChanged = 191,
+        /// FSoftObjectPath::SubPathString changed to FUtf8String
+        SoftObjectPathUtf8SubPaths = 192,
+        /// FSoftObjectPath::SubPathString could be saved with trailing NULs and need truncating
+        SoftObjectPathTrailingNULsMaintained = 193,
+        /// Water body components no longer need to maintain their own PhysicalMaterial property since they are primitive components. After this version, leverage that one instead.
+        WaterBodyPhysicalMaterialPropertyRemoval = 194,
+        /// PCG fixed attribute set -> point conversion passing through empty point data as-is and violating output pin type.
+        PCGAttributeSetToPointAlwaysConverts = 195,
+        /// Add per material slot overlay material data
+        MeshMaterialSlotOverlayMaterialAdded = 196,
+        /// Convert Sustrate glint density properly
+        ConvertGlintDensity = 197,
+        /// Introduced skinweight validation to avoid render crashes and disappearing simulation meshes
+        ClothAssetSkinweightsValidation = 198,
+        /// Switching verse from right handed to left handed
+        VerseRightToLeftHandedness = 199,
+        /// Added additional data required to record and represent particle data from the game thread (Kinematic targets, and SQ rejection reasons)
+        AdditionalGameThreadDataSupportInChaosVisualDebugger = 200,
+        /// Upgrade UMG widget blueprints using legacy animation API
+        UpgradeWidgetBlueprintLegacySequencePlayer = 201,
+        /// Changed clockwise detection algorithm for PCGSplineDirection node with the correct one, but add a version to not break previous nodes.
+        PCGSplineDirectionClockwiseFix = 202,
+        /// Rect Lights set in EV units had the wrong intensity (older files need a flag set to keep the old look)
+        RectLightFixedEVUnitConversion = 203,
+        /// Add particle bounds to data exported to CVD
+        ParticleInflatedBoundsInChaosVisualDebugger = 204,
+        /// Migrate properties from FLandscapeLayer to ULandscapeEditLayer
+        MigrateLandscapeEditLayerProperties = 205,
+        /// Added more context data to CVD's traced shapes so we can play it back at the solver stage level (not just game thread frames)
+        ThreadContextDataInChaosVisualDebuggerDebugDrawData = 206,
+        /// Changed default grid mode in surface sampler to a version that's more intuitive and less error-prone
+        PCGChangedSurfaceSamplerDefaultGridCreationMode = 207,
+        /// Media plate overlay composite technique replacement with holdout composite
+        MediaPlateOverlayTechniqueRemoval = 208,
+        /// Added particle flag to allow/disallow partial island sleeping in the island the particle is in
+        PerParticleFlagToAllowPartialIslandSleepInConnectedIsland = 209,
+        /// Material Function Blend Deserialize Top/Bottom input nodes with clearer enum marker.
+        MaterialFunctionBlendTopBottomInputEnum = 210,
+        /// Cooked CPU-side morph target points are now stored internally in the same compressed format as the GPU morph data.
+        MorphTargetCookedCPUDataCompressed = 211,
+    }
+    engine {
+        VerUe4_20 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_21 => Some(Self::CachedMaterialQualityNodeUsage),
+        VerUe4_22 => Some(Self::FoliageLazyObjPtrToSoftObjPtr),
+        VerUe4_23 => Some(Self::FortHUDElementNowRequiresTag),
+        VerUe4_24 => Some(Self::SupportVirtualBoneInRetargeting),
+        VerUe4_26 => Some(Self::AnimLayerGuidConformation),
+        VerUe4_27 => Some(Self::ChaosSolverPropertiesMoved),
+        VerUe5_0 => Some(Self::RemoveLandscapeWaterInfo),
+        VerUe5_1 => Some(Self::GravityOverrideDefinedInWorldSpace),
+        VerUe5_2 => Some(Self::WorldPartitionHLODActorDescSerializeStats),
+        VerUe5_3 => Some(Self::WorldPartitionHLODActorUseSourceCellGuid),
+        VerUe5_4 => Some(Self::WaterBodyStaticMeshFixup),
+        VerUe5_5 => Some(Self::SkeletalHalfEdgeData),
+        _ => Some(Self::SolverIterationsDataSupportInChaosVisualDebugger),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in the //Fortnite/Release-XX.XX stream
+    FFortniteReleaseBranchCustomObjectVersion("FFortniteReleaseBranchCustomObjectVersion", [0xE7086368, 0x6B234C58, 0x84391B70, 0x16265E91], CommonUIPlatformNamingUpgradeOption) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Custom 14.10 File Object Version
+        DisableLevelset_v14_10 = 1,
+        /// Add the long range attachment tethers to the cloth asset to avoid a large hitch during the cloth's initialization.
+        ChaosClothAddTethersToCachedData = 2,
+        /// Chaos::TKinematicTarget no longer stores a full transform, only position/rotation.
+        ChaosKinematicTargetRemoveScale = 3,
+        /// Move UCSModifiedProperties out of ActorComponent and in to sparse storage
+        ActorComponentUCSModifiedPropertiesSparseStorage = 4,
+        /// Fixup Nanite meshes which were using the wrong material and didn't have proper UVs :
+        FixupNaniteLandscapeMeshes = 5,
+        /// Remove any cooked collision data from nanite landscape / editor spline meshes since collisions are not needed there :
+        RemoveUselessLandscapeMeshesCookedCollisionData = 6,
+        /// Serialize out UAnimCurveCompressionCodec::InstanceGUID to maintain deterministic DDC key generation in cooked-editor
+        SerializeAnimCurveCompressionCodecGuidOnCook = 7,
+        /// Fix the Nanite landscape mesh being reused because of a bad name
+        FixNaniteLandscapeMeshNames = 8,
+        /// Fixup and synchronize shared properties modified before the synchronicity enforcement
+        LandscapeSharedPropertiesEnforcement = 9,
+        /// Include the cell size when computing the cell guid
+        WorldPartitionRuntimeCellGuidWithCellSize = 10,
+        /// Enable SkipOnlyEditorOnly style cooking of NaniteOverrideMaterial
+        NaniteMaterialOverrideUsesEditorOnly = 11,
+        /// Store game thread particles data in single precision
+        SinglePrecisionParticleData = 12,
+        /// UPCGPoint custom serialization
+        PCGPointStructuredSerializer = 13,
+        /// Deprecation of Nav Movement Properties and moving them to a new struct
+        NavMovementComponentMovingPropertiesToStruct = 14,
+        /// Add bone serialization for dynamic mesh attributes
+        DynamicMeshAttributesSerializeBones = 15,
+        /// Add option for sanitizing output attribute names for all PCG data getters
+        OptionSanitizeOutputAttributeNamesPCG = 16,
+        /// Add automatic platform naming fix up for CommonUI input action data tables
+        CommonUIPlatformNamingUpgradeOption = 17,
+    }
+    engine {
+        VerUe4_25 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe5_0 => Some(Self::DisableLevelset_v14_10),
+        VerUe5_1 => Some(Self::ChaosKinematicTargetRemoveScale),
+        VerUe5_2 => Some(Self::ActorComponentUCSModifiedPropertiesSparseStorage),
+        VerUe5_3 => Some(Self::RemoveUselessLandscapeMeshesCookedCollisionData),
+        VerUe5_4 => Some(Self::NaniteMaterialOverrideUsesEditorOnly),
+        VerUe5_5 => Some(Self::PCGPointStructuredSerializer),
+        _ => Some(Self::DynamicMeshAttributesSerializeBones),
+    }
+}
+
+custom_version! {
+    FFortniteSeasonBranchObjectVersion("FFortniteSeasonBranchObjectVersion", [0x5B4C06B7, 0x24634AF8, 0x805BBF70, 0xCDF5D0DD], CVDSerializationFixMissingSerializationProperties) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Added FWorldDataLayersActorDesc
+        AddedWorldDataLayersActorDesc = 1,
+        /// Fixed FDataLayerInstanceDesc
+        FixedDataLayerInstanceDesc = 2,
+        /// Serialize DataLayerAssets in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeDataLayerAssets = 3,
+        /// Remapped bEvaluateWorldPositionOffset to bEvaluateWorldPositionOffsetInRayTracing
+        RemappedEvaluateWorldPositionOffsetInRayTracing = 4,
+        /// Serialize native and base class for actor descriptors
+        WorldPartitionActorDescNativeBaseClassSerialization = 5,
+        /// Serialize tags for actor descriptors
+        WorldPartitionActorDescTagsSerialization = 6,
+        /// Serialize property map for actor descriptors
+        WorldPartitionActorDescPropertyMapSerialization = 7,
+        /// Added ability to mark shapes as probes
+        AddShapeIsProbe = 8,
+        /// Transfer PhysicsAsset SolverSettings (iteration counts etc) to new structure
+        PhysicsAssetNewSolverSettings = 9,
+        /// Chaos GeometryCollection now saves levels attribute values
+        ChaosGeometryCollectionSaveLevelsAttribute = 10,
+        /// Serialize actor transform for actor descriptors
+        WorldPartitionActorDescActorTransformSerialization = 11,
+        /// Changing Chaos::FImplicitObjectUnion to store an int32 vs a uint16 for NumLeafObjects.
+        ChaosImplicitObjectUnionLeafObjectsToInt32 = 12,
+        /// Chaos Visual Debugger : Adding serialization for properties that were being recorded, but not serialized
+        CVDSerializationFixMissingSerializationProperties = 13,
+    }
+    engine {
+        VerUe5_1 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe5_4 => Some(Self::ChaosGeometryCollectionSaveLevelsAttribute),
+        VerUe5_5 => Some(Self::ChaosImplicitObjectUnionLeafObjectsToInt32),
+        _ => Some(Self::CVDSerializationFixMissingSerializationProperties),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Framework stream
+    FFrameworkObjectVersion("FFrameworkObjectVersion", [0xCFFC743F, 0x43B04480, 0x939114DF, 0x171D2073], StoringUCSSerializationIndex) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// BodySetup's default instance collision profile is used by default when creating a new instance.
+        UseBodySetupCollisionProfile = 1,
+        /// Regenerate subgraph arrays correctly in animation blueprints to remove duplicates and add
+        /// missing graphs that appear read only when edited
+        AnimBlueprintSubgraphFix = 2,
+        /// Static and skeletal mesh sockets now use the specified scale
+        MeshSocketScaleUtilization = 3,
+        /// Attachment rules are now explicit in how they affect location, rotation and scale
+        ExplicitAttachmentRules = 4,
+        /// Moved compressed anim data from uasset to the DDC
+        MoveCompressedAnimDataToTheDDC = 5,
+        /// Some graph pins created using legacy code seem to have lost the RF_Transactional flag,
+        /// which causes issues with undo. Restore the flag at this version
+        FixNonTransactionalPins = 6,
+        /// Create new struct for SmartName, and use that for CurveName
+        SmartNameRefactor = 7,
+        /// Add Reference Skeleton to Rig
+        AddSourceReferenceSkeletonToRig = 8,
+        /// Refactor ConstraintInstance so that we have an easy way to swap behavior paramters
+        ConstraintInstanceBehaviorParameters = 9,
+        /// Pose Asset support mask per bone
+        PoseAssetSupportPerBoneMask = 10,
+        /// Physics Assets now use SkeletalBodySetup instead of BodySetup
+        PhysAssetUseSkeletalBodySetup = 11,
+        /// Remove SoundWave CompressionName
+        RemoveSoundWaveCompressionName = 12,
+        /// Switched render data for clothing over to unreal data, reskinned to the simulation mesh
+        AddInternalClothingGraphicalSkinning = 13,
+        /// Wheel force offset is now applied at the wheel instead of vehicle COM
+        WheelOffsetIsFromWheel = 14,
+        /// Move curve metadata to be saved in skeleton
+        /// Individual asset still saves some flag - i.e. disabled curve and editable or not, but
+        /// major flag - i.e. material types - moves to skeleton and handle in one place
+        MoveCurveTypesToSkeleton = 15,
+        /// Cache destructible overlaps on save
+        CacheDestructibleOverlaps = 16,
+        /// Added serialization of materials applied to geometry cache objects
+        GeometryCacheMissingMaterials = 17,
+        /// Switch static & skeletal meshes to calculate LODs based on resolution-independent screen size
+        LODsUseResolutionIndependentScreenSize = 18,
+        /// Blend space post load verification
+        BlendSpacePostLoadSnapToGrid = 19,
+        /// Addition of rate scales to blend space samples
+        SupportBlendSpaceRateScale = 20,
+        /// LOD hysteresis also needs conversion from the LODsUseResolutionIndependentScreenSize version
+        LODHysteresisUseResolutionIndependentScreenSize = 21,
+        /// AudioComponent override subtitle priority default change
+        ChangeAudioComponentOverrideSubtitlePriorityDefault = 22,
+        /// Serialize hard references to sound files when possible
+        HardSoundReferences = 23,
+        /// Enforce const correctness in Animation Blueprint function graphs
+        EnforceConstInAnimBlueprintFunctionGraphs = 24,
+        /// Upgrade the InputKeySelector to use a text style
+        InputKeySelectorTextStyle = 25,
+        /// Represent a pins container type as an enum not 3 independent booleans
+        EdGraphPinContainerType = 26,
+        /// Switch asset pins to store as string instead of hard object reference
+        ChangeAssetPinsToString = 27,
+        /// Fix Local Variables so that the properties are correctly flagged as blueprint visible
+        LocalVariablesBlueprintVisible = 28,
+        /// Stopped serializing UField_Next so that UFunctions could be serialized in dependently of a UClass
+        /// in order to allow us to do all UFunction loading in a single pass (after classes and CDOs are created):
+        RemoveUField_Next = 29,
+        /// Fix User Defined structs so that all members are correct flagged blueprint visible
+        UserDefinedStructsBlueprintVisible = 30,
+        /// FMaterialInput and FEdGraphPin store their name as FName instead of FString
+        PinsStoreFName = 31,
+        /// User defined structs store their default instance, which is used for initializing instances
+        UserDefinedStructsStoreDefaultInstance = 32,
+        /// Function terminator nodes serialize an FMemberReference rather than a name/class pair
+        FunctionTerminatorNodesUseMemberReference = 33,
+        /// Custom event and non-native interface event implementations add 'const' to reference parameters
+        EditableEventsUseConstRefParameters = 34,
+        /// No longer serialize the legacy flag that indicates this state, as it is now implied since we don't serialize the skeleton CDO
+        BlueprintGeneratedClassIsAlwaysAuthoritative = 35,
+        /// Enforce visibility of blueprint functions - e.g. raise an error if calling a private function from another blueprint:
+        EnforceBlueprintFunctionVisibility = 36,
+        /// ActorComponents now store their serialization index
+        StoringUCSSerializationIndex = 37,
+    }
+    engine {
+        VerUe4_12 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_13 => Some(Self::FixNonTransactionalPins),
+        VerUe4_14 => Some(Self::RemoveSoundWaveCompressionName),
+        VerUe4_15 => Some(Self::GeometryCacheMissingMaterials),
+        VerUe4_16 => Some(Self::ChangeAudioComponentOverrideSubtitlePriorityDefault),
+        VerUe4_17 => Some(Self::HardSoundReferences),
+        VerUe4_18 => Some(Self::LocalVariablesBlueprintVisible),
+        VerUe4_19 => Some(Self::UserDefinedStructsBlueprintVisible),
+        VerUe4_20 => Some(Self::FunctionTerminatorNodesUseMemberReference),
+        VerUe4_22 => Some(Self::EditableEventsUseConstRefParameters),
+        VerUe4_24 => Some(Self::BlueprintGeneratedClassIsAlwaysAuthoritative),
+        VerUe4_25 => Some(Self::EnforceBlueprintFunctionVisibility),
+        VerUe4_26 => Some(Self::StoringUCSSerializationIndex),
+        _ => Some(Self::StoringUCSSerializationIndex),
+    }
+}
+
+custom_version! {
+    FInstancedStructCustomVersion("FInstancedStructCustomVersion", [0xE21E1CAA, 0xAF47425E, 0x89BF6AD4, 0x4C44A8BB], CustomVersionAdded) {
+        /// Before any version changes were made
+        CustomVersionAdded = 0,
+    }
+    engine {
+        VerUe5_3 => None,
+        _ => Some(Self::CustomVersionAdded),
+    }
+}
+
+custom_version! {
+    FLiveLinkCustomVersion("FLiveLinkCustomVersion", [0xAB965196, 0x45D808FC, 0xB7D7228D, 0x78AD569E], NewLiveLinkRoleSystem) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+        NewLiveLinkRoleSystem = 1,
+    }
+    engine {
+        VerUe4_23 => Some(Self::BeforeCustomVersionWasAdded),
+        _ => Some(Self::NewLiveLinkRoleSystem),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Mobile stream
+    FMobileObjectVersion("FMobileObjectVersion", [0xB02B49B5, 0xBB2044E9, 0xA30432B7, 0x52E40360], StoreReflectionCaptureCompressedMobile) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Removed LightmapUVBias, ShadowmapUVBias from per-instance data
+        InstancedStaticMeshLightmapSerialization = 1,
+        /// Added stationary point/spot light direct contribution to volumetric lightmaps.
+        LQVolumetricLightmapLayers = 2,
+        /// Store Reflection Capture in compressed format for mobile
+        StoreReflectionCaptureCompressedMobile = 3,
+    }
+    engine {
+        VerUe4_19 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_26 => Some(Self::LQVolumetricLightmapLayers),
+        _ => Some(Self::StoreReflectionCaptureCompressedMobile),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for all packages containing Niagara asset types
+    FNiagaraCustomVersion("FNiagaraCustomVersion", [0xFCF57AFA, 0x50764283, 0xB9A9E658, 0xFFA02D32], AddedNewInterpolatedSpawnMode) {
+        /// Before any version changes were made in niagara
+        BeforeCustomVersionWasAdded = 0,
+        /// Reworked vm external function binding to be more robust.
+        VMExternalFunctionBindingRework = 1,
+        /// Making all Niagara files reference the version number, allowing post loading recompilation if necessary.
+        PostLoadCompilationEnabled = 2,
+        /// Moved some runtime cost from external functions into the binding step and used variadic templates to neaten that code greatly.
+        VMExternalFunctionBindingReworkPartDeux = 3,
+        /// Moved per instance data needed for certain data interfaces out to it's own struct.
+        DataInterfacePerInstanceRework = 4,
+        /// Added shader maps and corresponding infrastructure
+        NiagaraShaderMaps = 5,
+        /// Combined Spawn, Update, and Event scripts into one graph.
+        UpdateSpawnEventGraphCombination = 6,
+        /// Reworked data layout to store float and int data separately.
+        DataSetLayoutRework = 7,
+        /// Reworked scripts to support emitter & system scripts
+        AddedEmitterAndSystemScripts = 8,
+        /// Rework of script execution contexts to allow better reuse and reduce overhead of parameter handling.
+        ScriptExecutionContextRework = 9,
+        /// Removed the Niagara variable ID's making hookup impossible until next compile
+        RemovalOfNiagaraVariableIDs = 10,
+        /// System and emitter script simulations.
+        SystemEmitterScriptSimulations = 11,
+        /// Adding integer random to VM. TODO: The vm really needs its own versioning system that will force a recompile when changes.
+        IntegerRandom = 12,
+        /// Added emitter spawn attributes
+        AddedEmitterSpawnAttributes = 13,
+        /// cooking of shader maps and corresponding infrastructure
+        NiagaraShaderMapCooking = 14,
+        /// don't serialize shader maps for system scripts
+        NiagaraShaderMapCooking2 = 15,
+        /// Added script rapid iteration variables, usually top-level module parameters...
+        AddedScriptRapidIterationVariables = 16,
+        /// Added type to data interface infos
+        AddedTypeToDataInterfaceInfos = 17,
+        /// Hooked up autogenerated default values for function call nodes.
+        EnabledAutogeneratedDefaultValuesForFunctionCallNodes = 18,
+        /// Now curve data interfaces have look-up tables on by default.
+        CurveLUTNowOnByDefault = 19,
+        /// Scripts now use a guid for identification instead of an index when there are more than one with the same usage.
+        ScriptsNowUseAGuidForIdentificationInsteadOfAnIndex = 20,
+        /// don't serialize shader maps for update scripts
+        NiagaraCombinedGPUSpawnUpdate = 21,
+        /// don't serialize shader maps for emitters that don't run on gpu.
+        DontCompileGPUWhenNotNeeded = 22,
+        LifeCycleRework = 23,
+        /// We weren't serializing event data sets previously.
+        NowSerializingReadWriteDataSets = 24,
+        /// Forcing the internal parameter map vars to be reset between emitter calls.
+        TranslatorClearOutBetweenEmitters = 25,
+        /// added sampler shader params based on DI buffer descriptors
+        AddSamplerDataInterfaceParams = 26,
+        /// Need to force the GPU shaders to recompile
+        GPUShadersForceRecompileNeeded = 27,
+        /// The playback range for the timeline is now stored in the system editor data.
+        PlaybackRangeStoredOnSystem = 28,
+        /// All cached values will auto-recompile.
+        MovedToDerivedDataCache = 29,
+        /// Data interfaces are preallocated
+        DataInterfacesNotAllocated = 30,
+        /// emitter scripts are built using "Emitter." instead of the full name.
+        EmittersHaveGenericUniqueNames = 31,
+        /// no longer have compiler version enum value in this list, instead moved to a guid, which works better for the DDC
+        MovingTranslatorVersionToGuid = 32,
+        /// adding a parameter map in/out to the data set base node
+        AddingParamMapToDataSetBaseNode = 33,
+        /// refactor of CS parameters allowing regular params as well as buffers.
+        DataInterfaceComputeShaderParamRefactor = 34,
+        /// bumping version and forcing curves to regen their LUT on version change.
+        CurveLUTRegen = 35,
+        /// Changing the graph generation for assignment nodes so that it uses a "Begin Defaults" node where appropriate.
+        AssignmentNodeUsesBeginDefaults = 36,
+        /// Updating the usage flage bitmask for assignment nodes to match the part of the stack it's used in.
+        AssignmentNodeHasCorrectUsageBitmask = 37,
+        /// Emitter local space is compiled into the hlsl as a literal constant to expose it to emitter scripts and allow for some better optimization of particle transforms.
+        EmitterLocalSpaceLiteralConstant = 38,
+        /// The cpu cache of the texture is now directly serialized instead of using array property serialization.
+        TextureDataInterfaceUsesCustomSerialize = 39,
+        /// The texture data interface now streams size info
+        TextureDataInterfaceSizeSerialize = 40,
+        /// API to skeletal mesh interface was improved but requires a recompile and some graph fixup.
+        SkelMeshInterfaceAPIImprovements = 41,
+        /// Only do op add pin fixup on existing nodes which are before this version
+        ImproveLoadTimeFixupOfOpAddPins = 42,
+        /// Moved commonly used input metadata out of the strin/string property metadata map to actual properties on the metadata struct.
+        MoveCommonInputMetadataToProperties = 43,
+        /// Move to using the traversed graph hash and the base script id for the FNiagaraVMExecutableDataId instead of the change id guid to prevent invalidating the DDC.
+        UseHashesToIdentifyCompileStateOfTopLevelScripts = 44,
+        /// Reworked how the metadata is stored in NiagaraGraph from storing a Map of FNiagaraVariableMetaData to storing a map of UNiagaraScriptVariable* to be used with the Details panel.
+        MetaDataAndParametersUpdate = 45,
+        /// Moved the emitter inheritance data from the emitter handle to the emitter to allow for chained emitter inheritance.
+        MoveInheritanceDataFromTheEmitterHandleToTheEmitter = 46,
+        /// Add property to all Niagara scripts indicating whether or not they belong to the library
+        AddLibraryAssetProperty = 47,
+        /// Addding additional defines to the GPU script
+        AddAdditionalDefinesProperty = 48,
+        /// Remove the random compile id guids from the cached script usage and from the compile and script ids since the hashes serve the same purpose and are deterministic.
+        RemoveGraphUsageCompileIds = 49,
+        /// Adding UseRapidIterationParams and DetailLevelMask to the GPU script
+        AddRIAndDetailLevel = 50,
+        /// Changing the system and emitter compiled data to shared pointers to deal with lifetime issues in the editor.  They now are handled directly in system serialize.
+        ChangeEmitterCompiledDataToSharedRefs = 51,
+        /// Sorting on Renderers is disabled by default, we add a version to maintain existing systems that expected sorting to be enabled
+        DisableSortingByDefault = 52,
+        /// Convert TMap into TArray to save memory, TMap contains an inline allocator which pushes the size to 80 bytes
+        MemorySaving = 53,
+        /// Added a new value to the script usage enum, and we need a custom version to fix the existing bitfields.
+        AddSimulationStageUsageEnum = 54,
+        /// Save the functions generated by a GPU data interface inside FNiagaraDataInterfaceGPUParamInfo
+        AddGeneratedFunctionsToGPUParamInfo = 55,
+        /// Removed DetailLevel in favor of FNiagaraPlatfomSet based selection of per platform settings.
+        PlatformScalingRefactor = 56,
+        /// Promote parameters used across script executions to the Dataset, and Demote unused parameters.
+        PrecompileNamespaceFixup = 57,
+        /// Postload fixup in UNiagaraGraph to fixup VariableToScriptVariable map entries being null.
+        FixNullScriptVariables = 58,
+        /// Move FNiagaraVariableMetaData from storing scope enum to storing registered scope name.
+        PrecompileNamespaceFixup2 = 59,
+        /// Enable the simulation stage flag by default in the usage bitmask of modules and functions
+        SimulationStageInUsageBitmask = 60,
+        /// Fix graph parameter map parameters on post load so that they all have a consisten parsable format and update the UI to show and filter based on these formats.
+        StandardizeParameterNames = 61,
+        /// Make sure that UNiagaraComponents only have override maps for User variables.
+        ComponentsOnlyHaveUserVariables = 62,
+        /// Refactor the options for UV settings on the ribbon renderer.
+        RibbonRendererUVRefactor = 63,
+        /// Replace the TypeDefinition in VariableBase with an index into the type registry
+        VariablesUseTypeDefRegistry = 64,
+        /// Expand the visibility options of the scripts to be able to hide a script completely from the user
+        AddLibraryVisibilityProperty = 65,
+        SignificanceHandlers = 66,
+        /// Added support for multiple versions of script data
+        ModuleVersioning = 67,
+        MoveDefaultValueFromFNiagaraVariableMetaDataToUNiagaraScriptVariable = 68,
+        /// Changed the default mode from deterministic to non-deterministic which matches emitters
+        ChangeSystemDeterministicDefault = 69,
+        /// Update static switch pins to use the PersistentId from their script variable so that when they're renamed their values aren't lost when reallocating pins.
+        StaticSwitchFunctionPinsUsePersistentGuids = 70,
+        /// Extended visibility culling options and moved properties into their own struct.
+        VisibilityCullingImprovements = 71,
+        AddBakerCameraBookmarks = 72,
+        /// Function call node refresh from external changes has been refactored so that they don't need to populate their name bindings every load.
+        PopulateFunctionCallNodePinNameBindings = 73,
+        /// Changed the default value for the component renderer's OnlyCreateComponentsOnParticleSpawn property
+        ComponentRendererSpawnProperty = 74,
+        /// Previous repopulate didn't handle module attributes like Particles.Module.Name so they need to be repopulated for renaming to work correctly.
+        RepopulateFunctionCallNodePinNameBindings = 75,
+        /// Event spawns now optionally update Initial. attribute values. New default is true but old data is kept false to maintain existing behavior.
+        EventSpawnsUpdateInitialAttributeValues = 76,
+        /// Adds list of variadic parameters to the information about GPU functions.
+        AddVariadicParametersToGPUFunctionInfo = 77,
+        /// Some data fixup for NiagaraNodeWithDynamicPins.
+        DynamicPinNodeFixup = 78,
+        /// Ribbon renderer will default to unique ID rather than normalized age to make more things 'just work'
+        RibbonRendererLinkOrderDefaultIsUniqueID = 79,
+        /// Renderer SubImage Blends are enabled by default
+        SubImageBlendEnabledByDefault = 80,
+        /// Ribbon renderer will use geometry normals by default rather than screen / facing aligned normals
+        RibbonPlaneUseGeometryNormals = 81,
+        /// Actors velocity is used for the initial velocity before the component has any tracking, old assets use the old zero velocity
+        InitialOwnerVelocityFromActor = 82,
+        /// FNiagaraParameterBindingWithValue wouldn't necessarily have the appropriate ResolvedParameter namespace when it comes to emitter merging
+        ParameterBindingWithValueRenameFixup = 83,
+        /// Sim Cache moved to bulk data by default
+        SimCache_BulkDataVersion1 = 84,
+        /// Decoupling of 'Template' and 'Inheritance'
+        InheritanceUxRefactor = 85,
+        /// NDC Read DIs will not override spawn group by default when spawning particles. Old content will remain unchanged.
+        NDCSpawnGroupOverrideDisabledByDefault = 86,
+        /// Before it was normalized age which can introduce flickering with sorting and random lifetimes
+        CustomSortingBindingToAge = 87,
+        /// Update Initial Mesh Orientation Module
+        StatelessInitialMeshOrientationV1 = 88,
+        /// Hierarchy Editor was implemented
+        HierarchyEditorScriptSupport = 89,
+        /// Added loop delay enabled to emitter state
+        EmitterStateAddLoopDelayEnabled = 90,
+        /// Make sure that we serialize the UsageBitMask with the GPUFunctionInfo
+        SerializeUsageBitMaskToGPUFunctionInfo = 91,
+        /// Changed the interpolated spawn property into an enum
+        AddedNewInterpolatedSpawnMode = 92,
+    }
+    engine {
+        VerUe4_20 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_21 => Some(Self::EmitterLocalSpaceLiteralConstant),
+        VerUe4_23 => Some(Self::SkelMeshInterfaceAPIImprovements),
+        VerUe4_24 => Some(Self::AddLibraryAssetProperty),
+        VerUe4_25 => Some(Self::DisableSortingByDefault),
+        VerUe4_26 => Some(Self::StandardizeParameterNames),
+        VerUe4_27 => Some(Self::SignificanceHandlers),
+        VerUe5_0 => Some(Self::MoveDefaultValueFromFNiagaraVariableMetaDataToUNiagaraScriptVariable),
+        VerUe5_1 => Some(Self::StaticSwitchFunctionPinsUsePersistentGuids),
+        VerUe5_2 => Some(Self::EventSpawnsUpdateInitialAttributeValues),
+        VerUe5_3 => Some(Self::DynamicPinNodeFixup),
+        VerUe5_4 => Some(Self::RibbonRendererLinkOrderDefaultIsUniqueID),
+        VerUe5_5 => Some(Self::NDCSpawnGroupOverrideDisabledByDefault),
+        _ => Some(Self::EmitterStateAddLoopDelayEnabled),
+    }
+}
+
+custom_version! {
+    FNiagaraObjectVersion("FNiagaraObjectVersion", [0xF2AED0AC, 0x9AFE416F, 0x8664AA7F, 0xFA26D6FC], SkeletalMeshVertexSampling) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        SkeletalMeshVertexSampling = 1,
+    }
+    engine {
+        VerUe4_21 => Some(Self::BeforeCustomVersionWasAdded),
+        _ => Some(Self::SkeletalMeshVertexSampling),
+    }
+}
+
+custom_version! {
+    /// custom version for overlapping vertcies code
+    FOverlappingVerticesCustomVersion("FOverlappingVerticesCustomVersion", [0x612FBE52, 0xDA53400B, 0x910D4F91, 0x9FB1857C], DetectOVerlappingVertices) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+        /// UE4.19
+        /// Converted to use HierarchicalInstancedStaticMeshComponent
+        DetectOVerlappingVertices = 1,
+    }
+    engine {
+        VerUe4_19 => Some(Self::BeforeCustomVersionWasAdded),
+        _ => Some(Self::DetectOVerlappingVertices),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Physics stream
+    FPhysicsObjectVersion("FPhysicsObjectVersion", [0x78F01B33, 0xEBEA4F98, 0xB9B484EA, 0xCCB95AA2], GeometryCollectionConvexDefaults) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Adding PerShapeData to serialization
+        PerShapeData = 1,
+        /// Add serialization from handle back to particle
+        SerializeGTGeometryParticles = 2,
+        /// Groom serialization with hair description as bulk data
+        GroomWithDescription = 3,
+        /// Groom serialization with import option
+        GroomWithImportSettings = 4,
+        /// TriangleMesh has map from source vertex index to internal vertex index for per-poly collisoin.
+        TriangleMeshHasVertexIndexMap = 5,
+        /// Chaos Convex StructureData supports different index sizes based on num verts/planes
+        VariableConvexStructureData = 6,
+        /// Add the ability to enable or disable Continuous Collision Detection
+        AddCCDEnableFlag = 7,
+        /// Added the weighted value property type to store the cloths weight maps' low/high ranges
+        ChaosClothAddWeightedValue = 8,
+        /// Chaos FConvex uses array of FVec3s for vertices instead of particles
+        ConvexUsesVerticesArray = 9,
+        /// Add centrifugal forces for cloth
+        ChaosClothAddfictitiousforces = 10,
+        /// Added the Long Range Attachment stiffness weight map
+        ChaosClothAddTetherStiffnessWeightMap = 11,
+        /// Fix corrupted LOD transition maps
+        ChaosClothFixLODTransitionMaps = 12,
+        /// Convex structure data is now an index-based half-edge structure
+        ChaosConvexUsesHalfEdges = 13,
+        /// Convex structure data has a list of unique edges (half of the half edges)
+        ChaosConvexHasUniqueEdgeSet = 14,
+        /// Chaos FGeometryCollectionObject user defined collision shapes support
+        GeometryCollectionUserDefinedCollisionShapes = 15,
+        /// Chaos Remove scale from TKinematicTarget object
+        ChaosKinematicTargetRemoveScale = 16,
+        /// Chaos Added support for per-object collision constraint flag.
+        AddCollisionConstraintFlag = 17,
+        /// Expose particle Disabled flag to the game thread
+        AddDisabledFlag = 18,
+        /// Added max linear and angular speed to Chaos bodies
+        AddChaosMaxLinearAngularSpeed = 19,
+        /// add convex geometry to older collections that did not have any
+        GeometryCollectionConvexDefaults = 20,
+    }
+    engine {
+        VerUe4_24 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_25 => Some(Self::SerializeGTGeometryParticles),
+        VerUe4_26 => Some(Self::GroomWithImportSettings),
+        VerUe4_27 => Some(Self::ChaosConvexHasUniqueEdgeSet),
+        _ => Some(Self::GeometryCollectionConvexDefaults),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Anim stream
+    FPropertyBagCustomVersion("FPropertyBagCustomVersion", [0x134A157E, 0xD5E249A3, 0x8D4E843C, 0x98FE9E31], MetaClass) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+        /// Added support for array types
+        ContainerTypes = 1,
+        NestedContainerTypes = 2,
+        MetaClass = 3,
+    }
+    engine {
+        VerUe5_1 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe5_3 => Some(Self::ContainerTypes),
+        VerUe5_4 => Some(Self::NestedContainerTypes),
+        _ => Some(Self::MetaClass),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for RecomputeTangent
+    FRecomputeTangentCustomVersion("FRecomputeTangentCustomVersion", [0x5579F886, 0x933A4C1F, 0x83BA087B, 0x6361B92F], RecomputeTangentVertexColorMask) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+        /// UE4.12
+        /// We serialize the RecomputeTangent Option
+        RuntimeRecomputeTangent = 1,
+        /// UE4.26
+        /// Choose which Vertex Color channel to use as mask to blend tangents
+        RecomputeTangentVertexColorMask = 2,
+    }
+    engine {
+        VerUe4_12 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_26 => Some(Self::RuntimeRecomputeTangent),
+        _ => Some(Self::RecomputeTangentVertexColorMask),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made for a private stream
+    FReflectionCaptureObjectVersion("FReflectionCaptureObjectVersion", [0x6B266CEC, 0x1EC74B8F, 0xA30BE4D9, 0x0942FC07], MoveReflectionCaptureDataToMapBuildData) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Allows uncompressed reflection captures for cooked builds
+        MoveReflectionCaptureDataToMapBuildData = 1,
+    }
+    engine {
+        VerUe4_19 => Some(Self::BeforeCustomVersionWasAdded),
+        _ => Some(Self::MoveReflectionCaptureDataToMapBuildData),
+    }
+}
+
+custom_version! {
+    FReleaseObjectVersion("FReleaseObjectVersion", [0x9C54D522, 0xA8264FBE, 0x94210746, 0x61B482D0], GeometryCollectionCacheRemovesMassToLocal) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// UE4.19 = 12
+        /// Static Mesh extended bounds radius fix
+        StaticMeshExtendedBoundsFix = 1,
+        /// Physics asset bodies are either in the sync scene or the async scene, but not both
+        NoSyncAsyncPhysAsset = 2,
+        /// ULevel was using TTransArray incorrectly (serializing the entire array in addition to individual mutations).
+        /// converted to a TArray:
+        LevelTransArrayConvertedToTArray = 3,
+        /// Add Component node templates now use their own unique naming scheme to ensure more reliable archetype lookups.
+        AddComponentNodeTemplateUniqueNames = 4,
+        /// Fix a serialization issue with static mesh FMeshSectionInfoMap FProperty
+        UPropertryForMeshSectionSerialize = 5,
+        /// Existing HLOD settings screen size to screen area conversion
+        ConvertHLODScreenSize = 6,
+        /// Adding mesh section info data for existing billboard LOD models
+        SpeedTreeBillboardSectionInfoFixup = 7,
+        /// Change FMovieSceneEventParameters::StructType to be a string asset reference from a TWeakObjectPtr<UScriptStruct>
+        EventSectionParameterStringAssetRef = 8,
+        /// Remove serialized irradiance map data from skylight.
+        SkyLightRemoveMobileIrradianceMap = 9,
+        /// rename bNoTwist to bAllowTwist
+        RenameNoTwistToAllowTwistInTwoBoneIK = 10,
+        /// Material layers serialization refactor
+        MaterialLayersParameterSerializationRefactor = 11,
+        /// Added disable flag to skeletal mesh data
+        AddSkeletalMeshSectionDisable = 12,
+        /// Removed objects that were serialized as part of this material feature
+        RemovedMaterialSharedInputCollection = 13,
+        /// HISMC Cluster Tree migration to add new data
+        HISMCClusterTreeMigration = 14,
+        /// Default values on pins in blueprints could be saved incoherently
+        PinDefaultValuesVerified = 15,
+        /// During copy and paste transition getters could end up with broken state machine references
+        FixBrokenStateMachineReferencesInTransitionGetters = 16,
+        /// Change to MeshDescription serialization
+        MeshDescriptionNewSerialization = 17,
+        /// Change to not clamp RGB values > 1 on linear color curves
+        UnclampRGBColorCurves = 18,
+        /// Bugfix for FAnimObjectVersion::LinkTimeAnimBlueprintRootDiscovery.
+        LinkTimeAnimBlueprintRootDiscoveryBugFix = 19,
+        /// Change trail anim node variable deprecation
+        TrailNodeBlendVariableNameChange = 20,
+        /// Make sure the Blueprint Replicated Property Conditions are actually serialized properly.
+        PropertiesSerializeRepCondition = 21,
+        /// DepthOfFieldFocalDistance at 0 now disables DOF instead of DepthOfFieldFstop at 0.
+        FocalDistanceDisablesDOF = 22,
+        /// Removed versioning, but version entry must still exist to keep assets saved with this version loadable
+        Unused_SoundClass2DReverbSend = 23,
+        /// Groom asset version
+        GroomAssetVersion1 = 24,
+        GroomAssetVersion2 = 25,
+        /// Store applied version of Animation Modifier to use when reverting
+        SerializeAnimModifierState = 26,
+        /// Groom asset version
+        GroomAssetVersion3 = 27,
+        /// Upgrade filmback
+        DeprecateFilmbackSettings = 28,
+        /// custom collision type
+        CustomImplicitCollisionType = 29,
+        /// FFieldPath will serialize the owner struct reference and only a short path to its property
+        FFieldPathOwnerSerialization = 30,
+        /// New MeshDescription format
+        /// This was inadvertently added in UE5. The proper version for it is in in UE5MainStreamObjectVersion
+        MeshDescriptionNewFormat = 31,
+        /// Pin types include a flag that propagates the 'CPF_UObjectWrapper' flag to generated properties
+        PinTypeIncludesUObjectWrapperFlag = 32,
+        /// Added Weight member to FMeshToMeshVertData
+        WeightFMeshToMeshVertData = 33,
+        /// Animation graph node bindings displayed as pins
+        AnimationGraphNodeBindingsDisplayedAsPins = 34,
+        /// Serialized rigvm offset segment paths
+        SerializeRigVMOffsetSegmentPaths = 35,
+        /// Upgrade AbcGeomCacheImportSettings for velocities
+        AbcVelocitiesSupport = 36,
+        /// Add margin support to Chaos Convex
+        MarginAddedToConvexAndBox = 37,
+        /// Add structure data to Chaos Convex
+        StructureDataAddedToConvex = 38,
+        /// Changed axis UI for LiveLink AxisSwitch Pre Processor
+        AddedFrontRightUpAxesToLiveLinkPreProcessor = 39,
+        /// Some sequencer event sections that were copy-pasted left broken links to the director BP
+        FixupCopiedEventSections = 40,
+        /// Serialize the number of bytes written when serializing function arguments
+        RemoteControlSerializeFunctionArgumentsSize = 41,
+        /// Add loop counters to sequencer's compiled sub-sequence data
+        AddedSubSequenceEntryWarpCounter = 42,
+        /// Remove default resolution limit of 512 pixels for cubemaps generated from long-lat sources
+        LonglatTextureCubeDefaultMaxResolution = 43,
+        /// bake center of mass into chaos cache
+        GeometryCollectionCacheRemovesMassToLocal = 44,
+    }
+    engine {
+        VerUe4_11 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_13 => Some(Self::StaticMeshExtendedBoundsFix),
+        VerUe4_14 => Some(Self::LevelTransArrayConvertedToTArray),
+        VerUe4_15 => Some(Self::AddComponentNodeTemplateUniqueNames),
+        VerUe4_16 => Some(Self::SpeedTreeBillboardSectionInfoFixup),
+        VerUe4_17 => Some(Self::SkyLightRemoveMobileIrradianceMap),
+        VerUe4_19 => Some(Self::RenameNoTwistToAllowTwistInTwoBoneIK),
+        VerUe4_20 => Some(Self::AddSkeletalMeshSectionDisable),
+        VerUe4_21 => Some(Self::MeshDescriptionNewSerialization),
+        VerUe4_23 => Some(Self::TrailNodeBlendVariableNameChange),
+        VerUe4_24 => Some(Self::Unused_SoundClass2DReverbSend),
+        VerUe4_25 => Some(Self::DeprecateFilmbackSettings),
+        VerUe4_26 => Some(Self::FFieldPathOwnerSerialization),
+        VerUe4_27 => Some(Self::StructureDataAddedToConvex),
+        VerUe5_0 => Some(Self::LonglatTextureCubeDefaultMaxResolution),
+        _ => Some(Self::GeometryCollectionCacheRemovesMassToLocal),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Rendering stream
+    FRenderingObjectVersion("FRenderingObjectVersion", [0x12F88B9F, 0x88754AFC, 0xA67CD90C, 0x383ABD29], DeprecatedHighResSourceMesh) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Added support for 3 band SH in the ILC
+        IndirectLightingCache3BandSupport = 1,
+        /// Allows specifying resolution for reflection capture probes
+        CustomReflectionCaptureResolutionSupport = 2,
+        RemovedTextureStreamingLevelData = 3,
+        /// translucency is now a property which matters for materials with the decal domain
+        IntroducedMeshDecals = 4,
+        /// Reflection captures are no longer prenormalized
+        ReflectionCapturesStoreAverageBrightness = 5,
+        ChangedPlanarReflectionFadeDefaults = 6,
+        RemovedRenderTargetSize = 7,
+        /// Particle Cutout (SubUVAnimation) data is now stored in the ParticleRequired Module
+        MovedParticleCutoutsToRequiredModule = 8,
+        MapBuildDataSeparatePackage = 9,
+        /// StaticMesh and SkeletalMesh texcoord size data.
+        TextureStreamingMeshUVChannelData = 10,
+        /// Added type handling to material normalize and length (sqrt) nodes
+        TypeHandlingForMaterialSqrtNodes = 11,
+        FixedBSPLightmaps = 12,
+        DistanceFieldSelfShadowBias = 13,
+        FixedLegacyMaterialAttributeNodeTypes = 14,
+        ShaderResourceCodeSharing = 15,
+        MotionBlurAndTAASupportInSceneCapture2d = 16,
+        AddedTextureRenderTargetFormats = 17,
+        /// Triggers a rebuild of the mesh UV density while also adding an update in the postedit
+        FixedMeshUVDensity = 18,
+        AddedbUseShowOnlyList = 19,
+        VolumetricLightmaps = 20,
+        MaterialAttributeLayerParameters = 21,
+        StoreReflectionCaptureBrightnessForCooking = 22,
+        /// FModelVertexBuffer does serialize a regular TArray instead of a TResourceArray
+        ModelVertexBufferSerialization = 23,
+        ReplaceLightAsIfStatic = 24,
+        /// Added per FShaderType permutation id.
+        ShaderPermutationId = 25,
+        /// Changed normal precision in imported data
+        IncreaseNormalPrecision = 26,
+        VirtualTexturedLightmaps = 27,
+        GeometryCacheFastDecoder = 28,
+        LightmapHasShadowmapData = 29,
+        /// Removed old gaussian and bokeh DOF methods from deferred shading renderer.
+        DiaphragmDOFOnlyForDeferredShadingRenderer = 30,
+        /// Lightmaps replace ULightMapVirtualTexture (non-UTexture derived class) with ULightMapVirtualTexture2D (derived from UTexture)
+        VirtualTexturedLightmapsV2 = 31,
+        SkyAtmosphereStaticLightingVersioning = 32,
+        /// UTextureRenderTarget2D now explicitly allows users to create sRGB or non-sRGB type targets
+        ExplicitSRGBSetting = 33,
+        VolumetricLightmapStreaming = 34,
+        /// ShaderModel4 support removed from engine
+        RemovedSM4 = 35,
+        /// Deterministic ShaderMapID serialization
+        MaterialShaderMapIdSerialization = 36,
+        /// Add force opaque flag for static mesh
+        StaticMeshSectionForceOpaqueField = 37,
+        /// Add force opaque flag for static mesh
+        AutoExposureChanges = 38,
+        /// Removed emulated instancing from instanced static meshes
+        RemovedEmulatedInstancing = 39,
+        /// Added per instance custom data (for Instanced Static Meshes)
+        PerInstanceCustomData = 40,
+        /// Added material attributes to shader graph to support anisotropic materials
+        AnisotropicMaterial = 41,
+        /// Add if anything has changed in the exposure, override the bias to avoid the new default propagating
+        AutoExposureForceOverrideBiasFlag = 42,
+        /// Override for a special case for objects that were serialized and deserialized between versions AutoExposureChanges and AutoExposureForceOverrideBiasFlag
+        AutoExposureDefaultFix = 43,
+        /// Remap Volume Extinction material input to RGB
+        VolumeExtinctionBecomesRGB = 44,
+        /// Add a new virtual texture to support virtual texture light map on mobile
+        VirtualTexturedLightmapsV3 = 45,
+        /// Added fallback target modes including auto to Nanite builder
+        NaniteFallbackTarget = 46,
+        /// Added support to force Nanite material usage if Nanite mesh builders are also forced.
+        NaniteForceMaterialUsage = 47,
+        /// Custom material expression includes are now serialized & used for DDC key generation
+        AddedMaterialExpressionIncludesHash = 48,
+        /// Do not serialize deprecated high res source mesh
+        DeprecatedHighResSourceMesh = 49,
+    }
+    engine {
+        VerUe4_12 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_13 => Some(Self::CustomReflectionCaptureResolutionSupport),
+        VerUe4_14 => Some(Self::IntroducedMeshDecals),
+        VerUe4_16 => Some(Self::FixedBSPLightmaps),
+        VerUe4_17 => Some(Self::ShaderResourceCodeSharing),
+        VerUe4_18 => Some(Self::AddedbUseShowOnlyList),
+        VerUe4_19 => Some(Self::VolumetricLightmaps),
+        VerUe4_20 => Some(Self::ShaderPermutationId),
+        VerUe4_21 => Some(Self::IncreaseNormalPrecision),
+        VerUe4_22 => Some(Self::VirtualTexturedLightmaps),
+        VerUe4_23 => Some(Self::GeometryCacheFastDecoder),
+        VerUe4_24 => Some(Self::VirtualTexturedLightmapsV2),
+        VerUe4_25 => Some(Self::MaterialShaderMapIdSerialization),
+        VerUe4_26 => Some(Self::AutoExposureDefaultFix),
+        VerUe4_27 => Some(Self::VolumeExtinctionBecomesRGB),
+        VerUe5_3 => Some(Self::VirtualTexturedLightmapsV3),
+        VerUe5_4 => Some(Self::NaniteForceMaterialUsage),
+        VerUe5_5 => Some(Self::AddedMaterialExpressionIncludesHash),
+        _ => Some(Self::DeprecatedHighResSourceMesh),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Anim stream
+    FRigVMObjectVersion("FRigVMObjectVersion", [0xDC49959B, 0x53C04DE7, 0x9156EA88, 0x5E7C5D39], LocalizedRegistry) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// ControlRig & RigVMHost compute and checks VM Hash
+        AddedVMHashChecks = 1,
+        /// Predicates added to execute operations
+        PredicatesAddedToExecuteOps = 2,
+        /// Storing paths to user defined structs map
+        VMStoringUserDefinedStructMap = 3,
+        /// Storing paths to user defined enums map
+        VMStoringUserDefinedEnumMap = 4,
+        /// Storing paths to user defined enums map
+        HostStoringUserDefinedData = 5,
+        /// VM Memory Storage Struct serialized
+        VMMemoryStorageStructSerialized = 6,
+        /// VM Memory Storage Defaults generated at VM
+        VMMemoryStorageDefaultsGeneratedAtVM = 7,
+        /// VM Bytecode Stores the Public Context Path
+        VMBytecodeStorePublicContextPath = 8,
+        /// Removing unused tooltip property from frunction header
+        VMRemoveTooltipFromFunctionHeader = 9,
+        /// Removing library node FSoftObjectPath from FRigVMGraphFunctionIdentifier
+        RemoveLibraryNodeReferenceFromFunctionIdentifier = 10,
+        /// Adding variant struct to function identifier
+        AddVariantToFunctionIdentifier = 11,
+        /// Adding variant to every RigVM asset
+        AddVariantToRigVMAssets = 12,
+        /// Storing user interface layout within function header
+        FunctionHeaderStoresLayout = 13,
+        /// Storing user interface relevant pin index in category
+        FunctionHeaderLayoutStoresPinIndexInCategory = 14,
+        /// Storing user interface relevant category expansion
+        FunctionHeaderLayoutStoresCategoryExpansion = 15,
+        /// Storing function graph collapse node content as part of the header
+        RigVMSaveSerializedGraphInGraphFunctionDataAsByteArray = 16,
+        /// VM Bytecode Stores the Public Context Path as a FTopLevelAssetPath
+        VMBytecodeStorePublicContextPathAsTopLevelAssetPath = 17,
+        /// Serialized instruction offsets are now int32 rather than uint16, NumBytes has been removed
+        /// from RigVMCopyOp
+        ByteCodeCleanup = 18,
+        /// The VM stores a local snapshot registry to use in cooked environments instead of the shared global registry
+        LocalizedRegistry = 19,
+    }
+    engine {
+        VerUe5_3 => None,
+        VerUe5_4 => Some(Self::PredicatesAddedToExecuteOps),
+        VerUe5_5 => Some(Self::VMRemoveTooltipFromFunctionHeader),
+        _ => Some(Self::FunctionHeaderLayoutStoresCategoryExpansion),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in Dev-Sequencer stream
+    FSequencerObjectVersion("FSequencerObjectVersion", [0x7B5AE74C, 0xD2704C10, 0xA9585798, 0x0B212A5A], SpawnableImprovements) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Per-platform overrides player overrides for media sources changed name and type.
+        RenameMediaSourcePlatformPlayers = 1,
+        /// Enable root motion isn't the right flag to use, but force root lock
+        ConvertEnableRootMotionToForceRootLock = 2,
+        /// Convert multiple rows to tracks
+        ConvertMultipleRowsToTracks = 3,
+        /// When finished now defaults to restore state
+        WhenFinishedDefaultsToRestoreState = 4,
+        /// EvaluationTree added
+        EvaluationTree = 5,
+        /// When finished now defaults to project default
+        WhenFinishedDefaultsToProjectDefault = 6,
+        /// When finished now defaults to project default
+        FloatToIntConversion = 7,
+        /// Purged old spawnable blueprint classes from level sequence assets
+        PurgeSpawnableBlueprints = 8,
+        /// Finish UMG evaluation on end
+        FinishUMGEvaluation = 9,
+        /// Manual serialization of float channel
+        SerializeFloatChannel = 10,
+        /// Change the linear keys so they act the old way and interpolate always.
+        ModifyLinearKeysForOldInterp = 11,
+        /// Full Manual serialization of float channel
+        SerializeFloatChannelCompletely = 12,
+        /// Set ContinuouslyRespawn to false by default, added FMovieSceneSpawnable::bNetAddressableName
+        SpawnableImprovements = 13,
+    }
+    engine {
+        VerUe4_14 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_15 => Some(Self::RenameMediaSourcePlatformPlayers),
+        VerUe4_16 => Some(Self::ConvertMultipleRowsToTracks),
+        VerUe4_19 => Some(Self::WhenFinishedDefaultsToRestoreState),
+        VerUe4_20 => Some(Self::WhenFinishedDefaultsToProjectDefault),
+        VerUe4_22 => Some(Self::FinishUMGEvaluation),
+        VerUe4_25 => Some(Self::ModifyLinearKeysForOldInterp),
+        VerUe4_27 => Some(Self::SerializeFloatChannelCompletely),
+        _ => Some(Self::SpawnableImprovements),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for SkeletalMesh types
+    FSkeletalMeshCustomVersion("FSkeletalMeshCustomVersion", [0xD78A4A00, 0xE8584697, 0xBAA819B5, 0x487D46B4], RemoveEnableClothLOD) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// UE4.13 = 4
+        /// Remove Chunks array in FStaticLODModel and combine with Sections array
+        CombineSectionWithChunk = 1,
+        /// Remove FRigidSkinVertex and combine with FSoftSkinVertex array
+        CombineSoftAndRigidVerts = 2,
+        /// Need to recalc max bone influences
+        RecalcMaxBoneInfluences = 3,
+        /// Add NumVertices that can be accessed when stripping editor data
+        SaveNumVertices = 4,
+        /// UE4.14 = 5
+        /// Regenerated clothing section shadow flags from source sections
+        RegenerateClothingShadowFlags = 5,
+        /// UE4.15 = 7
+        /// Share color buffer structure with StaticMesh
+        UseSharedColorBufferFormat = 6,
+        /// Use separate buffer for skin weights
+        UseSeparateSkinWeightBuffer = 7,
+        /// UE4.16, UE4.17 = 9
+        /// Added new clothing systems
+        NewClothingSystemAdded = 8,
+        /// Cached inv mass data for clothing assets
+        CachedClothInverseMasses = 9,
+        /// UE4.18 = 10
+        /// Compact cloth vertex buffer, without dummy entries
+        CompactClothVertexBuffer = 10,
+        /// UE4.19 = 15
+        /// Remove SourceData
+        RemoveSourceData = 11,
+        /// Split data into Model and RenderData
+        SplitModelAndRenderData = 12,
+        /// Remove triangle sorting support
+        RemoveTriangleSorting = 13,
+        /// Remove the duplicated clothing sections that were a legacy holdover from when we didn't use our own render data
+        RemoveDuplicatedClothingSections = 14,
+        /// Remove 'Disabled' flag from SkelMesh asset sections
+        DeprecateSectionDisabledFlag = 15,
+        /// UE4.20-UE4.22 = 16
+        /// Add Section ignore by reduce
+        SectionIgnoreByReduceAdded = 16,
+        /// UE4.23-UE4.25 = 17
+        /// Adding skin weight profile support
+        /// TODO: FSkeletalMeshLODModel::Serialize (editor mesh)
+        SkinWeightProfiles = 17,
+        /// UE4.26 = 18
+        /// Remove uninitialized/deprecated enable cloth LOD flag
+        /// TODO
+        RemoveEnableClothLOD = 18,
+    }
+    engine {
+        VerUe4_13 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_14 => Some(Self::SaveNumVertices),
+        VerUe4_15 => Some(Self::RegenerateClothingShadowFlags),
+        VerUe4_16 => Some(Self::UseSeparateSkinWeightBuffer),
+        VerUe4_18 => Some(Self::CachedClothInverseMasses),
+        VerUe4_19 => Some(Self::CompactClothVertexBuffer),
+        VerUe4_20 => Some(Self::DeprecateSectionDisabledFlag),
+        VerUe4_23 => Some(Self::SectionIgnoreByReduceAdded),
+        VerUe4_26 => Some(Self::SkinWeightProfiles),
+        _ => Some(Self::RemoveEnableClothLOD),
+    }
+}
+
+custom_version! {
+    FStateTreeInstanceStorageCustomVersion("FStateTreeInstanceStorageCustomVersion", [0x60C4F0DE, 0x8B264C34, 0xAA937201, 0x5DFF09CC], AddedCustomSerialization) {
+        /// Before any version changes were made in the plugin
+        BeforeCustomVersionWasAdded = 0,
+        /// Added custom serialization
+        AddedCustomSerialization = 1,
+    }
+    engine {
+        VerUe5_4 => Some(Self::BeforeCustomVersionWasAdded),
+        _ => Some(Self::AddedCustomSerialization),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in //UE5/Main stream
+    FUE5MainStreamObjectVersion("FUE5MainStreamObjectVersion", [0x697DD581, 0xE64F41AB, 0xAA4A51EC, 0xBEB7B628], RuntimeVirtualTextureMaterialValidation) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Nanite data added to Chaos geometry collections
+        GeometryCollectionNaniteData = 1,
+        /// Nanite Geometry Collection data moved to DDC
+        GeometryCollectionNaniteDDC = 2,
+        /// Removing SourceAnimationData, animation layering is now applied during compression
+        RemovingSourceAnimationData = 3,
+        /// New MeshDescription format.
+        /// This is the correct versioning for MeshDescription changes which were added to ReleaseObjectVersion.
+        MeshDescriptionNewFormat = 4,
+        /// Serialize GridGuid in PartitionActorDesc
+        PartitionActorDescSerializeGridGuid = 5,
+        /// Set PKG_ContainsMapData on external actor packages
+        ExternalActorsMapDataPackageFlag = 6,
+        /// Added a new configurable BlendProfileMode that the user can setup to control the behavior of blend profiles.
+        AnimationAddedBlendProfileModes = 7,
+        /// Serialize DataLayers in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeDataLayers = 8,
+        /// Renaming UAnimSequence::NumFrames to NumberOfKeys, as that what is actually contains.
+        RenamingAnimationNumFrames = 9,
+        /// Serialize HLODLayer in WorldPartition HLODActorDesc
+        WorldPartitionHLODActorDescSerializeHLODLayer = 10,
+        /// Fixed Nanite Geometry Collection cooked data
+        GeometryCollectionNaniteCooked = 11,
+        /// Added bCooked to UFontFace assets
+        AddedCookedBoolFontFaceAssets = 12,
+        /// Serialize CellHash in WorldPartition HLODActorDesc
+        WorldPartitionHLODActorDescSerializeCellHash = 13,
+        /// Nanite data is now transient in Geometry Collection similar to how RenderData is transient in StaticMesh.
+        GeometryCollectionNaniteTransient = 14,
+        /// Added FLandscapeSplineActorDesc
+        AddedLandscapeSplineActorDesc = 15,
+        /// Added support for per-object collision constraint flag. \[Chaos\]
+        AddCollisionConstraintFlag = 16,
+        /// Initial Mantle Serialize Version
+        MantleDbSerialize = 17,
+        /// Animation sync groups explicitly specify sync method
+        AnimSyncGroupsExplicitSyncMethod = 18,
+        /// Fixup FLandscapeActorDesc Grid indices
+        FLandscapeActorDescFixupGridIndices = 19,
+        /// FoliageType with HLOD support
+        FoliageTypeIncludeInHLOD = 20,
+        /// Introducing UAnimDataModel sub-object for UAnimSequenceBase containing all animation source data
+        IntroducingAnimationDataModel = 21,
+        /// Serialize ActorLabel in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeActorLabel = 22,
+        /// Fix WorldPartitionActorDesc serialization archive not persistent
+        WorldPartitionActorDescSerializeArchivePersistent = 23,
+        /// Fix potentially duplicated actors when using ForceExternalActorLevelReference
+        FixForceExternalActorLevelReferenceDuplicates = 24,
+        /// Make UMeshDescriptionBase serializable
+        SerializeMeshDescriptionBase = 25,
+        /// Chaos FConvex uses array of FVec3s for vertices instead of particles
+        ConvexUsesVerticesArray = 26,
+        /// Serialize HLOD info in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeHLODInfo = 27,
+        /// Expose particle Disabled flag to the game thread
+        AddDisabledFlag = 28,
+        /// Moving animation custom attributes from AnimationSequence to UAnimDataModel
+        MoveCustomAttributesToDataModel = 29,
+        /// Use of triangulation at runtime in BlendSpace
+        BlendSpaceRuntimeTriangulation = 30,
+        /// Fix to the Cubic smoothing, plus introduction of new smoothing types
+        BlendSpaceSmoothingImprovements = 31,
+        /// Removing Tessellation parameters from Materials
+        RemovingTessellationParameters = 32,
+        /// Sparse class data serializes its associated structure to allow for BP types to be used
+        SparseClassDataStructSerialization = 33,
+        /// PackedLevelInstance bounds fix
+        PackedLevelInstanceBoundsFix = 34,
+        /// Initial set of anim nodes converted to use constants held in sparse class data
+        AnimNodeConstantDataRefactorPhase0 = 35,
+        /// Explicitly serialized bSavedCachedExpressionData for Material(Instance)
+        MaterialSavedCachedData = 36,
+        /// Remove explicit decal blend mode
+        RemoveDecalBlendMode = 37,
+        /// Made directional lights be atmosphere lights by default
+        DirLightsAreAtmosphereLightsByDefault = 38,
+        /// Changed how world partition streaming cells are named
+        WorldPartitionStreamingCellsNamingShortened = 39,
+        /// Changed how actor descriptors compute their bounds
+        WorldPartitionActorDescGetStreamingBounds = 40,
+        /// Switch FMeshDescriptionBulkData to use virtualized bulkdata
+        MeshDescriptionVirtualization = 41,
+        /// Switch FTextureSource to use virtualized bulkdata
+        TextureSourceVirtualization = 42,
+        /// RigVM to store more information alongside the Copy Operator
+        RigVMCopyOpStoreNumBytes = 43,
+        /// Expanded separate translucency into multiple passes
+        MaterialTranslucencyPass = 44,
+        /// Chaos FGeometryCollectionObject user defined collision shapes support
+        GeometryCollectionUserDefinedCollisionShapes = 45,
+        /// Removed the AtmosphericFog component with conversion to SkyAtmosphere component
+        RemovedAtmosphericFog = 46,
+        /// The SkyAtmosphere now light up the heightfog by default, and by default the height fog has a black color.
+        SkyAtmosphereAffectsHeightFogWithBetterDefault = 47,
+        /// Ordering of samples in BlendSpace
+        BlendSpaceSampleOrdering = 48,
+        /// No longer bake MassToLocal transform into recorded transform data in GeometryCollection caching
+        GeometryCollectionCacheRemovesMassToLocal = 49,
+        /// UEdGraphPin serializes SourceIndex
+        EdGraphPinSourceIndex = 50,
+        /// Change texture bulkdatas to have unique guids
+        VirtualizedBulkDataHaveUniqueGuids = 51,
+        /// Introduce RigVM Memory Class Object
+        RigVMMemoryStorageObject = 52,
+        /// Ray tracing shadows have three states now (Disabled, Use Project Settings, Enabled)
+        RayTracedShadowsType = 53,
+        /// Add bVisibleInRayTracing flag to Skeletal Mesh Sections
+        SkelMeshSectionVisibleInRayTracingFlagAdded = 54,
+        /// Add generic tagging of all anim graph nodes in anim blueprints
+        AnimGraphNodeTaggingAdded = 55,
+        /// Add custom version to FDynamicMesh3
+        DynamicMeshCompactedSerialization = 56,
+        /// Remove the inline reduction bulkdata and replace it by a simple vertex and triangle count cache
+        ConvertReductionBaseSkeletalMeshBulkDataToInlineReductionCacheData = 57,
+        /// Added some new MeshInfo to the FSkeletalMeshLODModel class.
+        SkeletalMeshLODModelMeshInfo = 58,
+        /// Add Texture DoScaleMipsForAlphaCoverage
+        TextureDoScaleMipsForAlphaCoverage = 59,
+        /// Fixed default value of volumetric cloud to be exact match with main view, more expenssive but we let user choosing how to lower the quality.
+        VolumetricCloudReflectionSampleCountDefaultUpdate = 60,
+        /// Use special BVH for TriangleMesh, instead of the AABBTree
+        UseTriangleMeshBVH = 61,
+        /// FDynamicMeshAttributeSet has Weight Maps. TDynamicAttributeBase serializes its name.
+        DynamicMeshAttributesWeightMapsAndNames = 62,
+        /// Switching FK control naming scheme to incorporate _CURVE for curve controls
+        FKControlNamingScheme = 63,
+        /// Fix-up for FRichCurveKey::TangentWeightMode, which were found to contain invalid value w.r.t the enum-type
+        RichCurveKeyInvalidTangentMode = 64,
+        /// Enforcing new automatic tangent behaviour, enforcing auto-tangents for Key0 and KeyN to be flat, for Animation Assets.
+        ForceUpdateAnimationAssetCurveTangents = 65,
+        /// SoundWave Update to use EditorBuildData for it's RawData
+        SoundWaveVirtualizationUpdate = 66,
+        /// Fix material feature level nodes to account for new SM6 input pin.
+        MaterialFeatureLevelNodeFixForSM6 = 67,
+        /// Fix material feature level nodes to account for new SM6 input pin.
+        GeometryCollectionPerChildDamageThreshold = 68,
+        /// Move some Chaos flags into a bitfield
+        AddRigidParticleControlFlags = 69,
+        /// Allow each LiveLink controller to specify its own component to control
+        LiveLinkComponentPickerPerController = 70,
+        /// Remove Faces in Triangle Mesh BVH
+        RemoveTriangleMeshBVHFaces = 71,
+        /// Moving all nodal offset handling to Lens Component
+        LensComponentNodalOffset = 72,
+        /// GPU none interpolated spawning no longer calls the update script
+        FixGpuAlwaysRunningUpdateScriptNoneInterpolated = 73,
+        /// World partition streaming policy serialization only for cooked builds
+        WorldPartitionSerializeStreamingPolicyOnCook = 74,
+        /// Remove serialization of bounds relevant from  WorldPartitionActorDesc
+        WorldPartitionActorDescRemoveBoundsRelevantSerialization = 75,
+        /// Added IAnimationDataModel interface and replace UObject based representation for Animation Assets
+        /// This version had to be undone. Animation assets saved between this and the subsequent backout version
+        /// will be unable to be loaded
+        AnimationDataModelInterface_BackedOut = 76,
+        /// Deprecate LandscapeSplineActorDesc
+        LandscapeSplineActorDescDeprecation = 77,
+        /// Revert the IAnimationDataModel changes. Animation assets
+        BackoutAnimationDataModelInterface = 78,
+        /// Made stationary local and skylights behave similar to SM5
+        MobileStationaryLocalLights = 79,
+        /// Made ManagedArrayCollection::FValueType::Value always serialize when FValueType is
+        ManagedArrayCollectionAlwaysSerializeValue = 80,
+        /// Moving all distortion handling to Lens Component
+        LensComponentDistortion = 81,
+        /// Updated image media source path resolution logic
+        ImgMediaPathResolutionWithEngineOrProjectTokens = 82,
+        /// Add low resolution data in Height Field
+        AddLowResolutionHeightField = 83,
+        /// Low resolution data in Height Field will store one height for (6x6) 36 cells
+        DecreaseLowResolutionHeightField = 84,
+        /// Add damage propagation settings to geometry collections
+        GeometryCollectionDamagePropagationData = 85,
+        /// Wheel friction forces are now applied at tire contact point
+        VehicleFrictionForcePositionChange = 86,
+        /// Add flag to override MeshDeformer on a SkinnedMeshComponent.
+        AddSetMeshDeformerFlag = 87,
+        /// Replace FNames for class/actor paths with FSoftObjectPath
+        WorldPartitionActorDescActorAndClassPaths = 88,
+        /// Reintroducing AnimationDataModelInterface_BackedOut changes
+        ReintroduceAnimationDataModelInterface = 89,
+        /// Support 16-bit skin weights on SkeletalMesh
+        IncreasedSkinWeightPrecision = 90,
+        /// bIsUsedWithVolumetricCloud flag auto conversion
+        MaterialHasIsUsedWithVolumetricCloudFlag = 91,
+        /// bIsUsedWithVolumetricCloud flag auto conversion
+        UpdateHairDescriptionBulkData = 92,
+        /// Added TransformScaleMethod pin to SpawnActorFromClass node
+        SpawnActorFromClassTransformScaleMethod = 93,
+        /// Added support for the RigVM to run branches lazily
+        RigVMLazyEvaluation = 94,
+        /// Adding additional object version to defer out-of-date pose asset warning until next resaves
+        PoseAssetRawDataGUIDUpdate = 95,
+        /// Store function information (and compilation data) in blueprint generated class
+        RigVMSaveFunctionAccessInModel = 96,
+        /// Store the RigVM execute context struct the VM uses in the archive
+        RigVMSerializeExecuteContextStruct = 97,
+        /// Store the Visual Logger timestamp as a double
+        VisualLoggerTimeStampAsDouble = 98,
+        /// Add ThinSurface instance override support
+        MaterialInstanceBasePropertyOverridesThinSurface = 99,
+        /// Add refraction mode None, converted from legacy when the refraction pin is not plugged.
+        MaterialRefractionModeNone = 100,
+        /// Store serialized graph function in the function data
+        RigVMSaveSerializedGraphInGraphFunctionData = 101,
+        /// Animation Sequence now stores its frame-rate on a per-platform basis
+        PerPlatformAnimSequenceTargetFrameRate = 102,
+        /// New default for number of attributes on 2d grids
+        NiagaraGrid2DDefaultUnnamedAttributesZero = 103,
+        /// RigVM generated class refactor
+        RigVMGeneratedClass = 104,
+        /// In certain cases, Blueprint pins with a PC_Object category would serialize a null PinSubCategoryObject
+        NullPinSubCategoryObjectFix = 105,
+        /// Allow custom event nodes to use access specifiers
+        AccessSpecifiersForCustomEvents = 106,
+        /// Explicit override of Groom's hair width
+        GroomAssetWidthOverride = 107,
+        /// Smart names removed from animation systems
+        AnimationRemoveSmartNames = 108,
+        /// Change the default for facing & alignment to be automatic
+        NiagaraSpriteRendererFacingAlignmentAutoDefault = 109,
+        /// Change the default for facing & alignment to be automatic
+        GroomAssetRemoveInAssetSerialization = 110,
+        /// Changed the material property connected bitmasks from 32bit to 64bit
+        IncreaseMaterialAttributesInputMask = 111,
+        /// Combines proprties into a new binding so users can select constant or binding
+        NiagaraSimStageNumIterationsBindings = 112,
+        /// Skeletal vertex attributes
+        SkeletalVertexAttributes = 113,
+        /// Store the RigVM execute context struct the VM uses in the archive
+        RigVMExternalExecuteContextStruct = 114,
+        /// serialization inputs and outputs as two different sections
+        DataflowSeparateInputOutputSerialization = 115,
+        /// Cloth collection tether initialization
+        ClothCollectionTetherInitialization = 116,
+        /// OpenColorIO transforms now serialize their generated texture(s) and shader code normally into the uasset.
+        OpenColorIOAssetCacheSerialization = 117,
+        /// Cloth collection single lod schema
+        ClothCollectionSingleLodSchema = 118,
+        /// Visual Logger format now includes a WorldTimeStamp in addition to TimeStamp for easier debugging between multiple instances.
+        VisualLoggerAddedSeparateWorldTime = 119,
+        /// Added support for InstanceDataManagerSerialization and changed format for the instances to FTransform3f
+        SkinnedMeshInstanceDataSerializationV2 = 120,
+        /// Added new material compilation validation for runtime virtual textures
+        RuntimeVirtualTextureMaterialValidation = 121,
+    }
+    engine {
+        VerUe5_0 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe5_1 => Some(Self::TextureDoScaleMipsForAlphaCoverage),
+        VerUe5_2 => Some(Self::WorldPartitionActorDescActorAndClassPaths),
+        VerUe5_3 => Some(Self::RigVMGeneratedClass),
+        VerUe5_4 => Some(Self::ClothCollectionSingleLodSchema),
+        _ => Some(Self::VisualLoggerAddedSeparateWorldTime),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in //UE5/Release-* stream
+    FUE5ReleaseStreamObjectVersion("FUE5ReleaseStreamObjectVersion", [0xD89B5E42, 0x24BD4D46, 0x8412ACA8, 0xDF641779], RecalculateClothAssetSerializedBounds) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Added Lumen reflections to new reflection enum, changed defaults
+        ReflectionMethodEnum = 1,
+        /// Serialize HLOD info in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeHLODInfo = 2,
+        /// Removing Tessellation from materials and meshes.
+        RemovingTessellation = 3,
+        /// LevelInstance serialize runtime behavior
+        LevelInstanceSerializeRuntimeBehavior = 4,
+        /// Refactoring Pose Asset runtime data structures
+        PoseAssetRuntimeRefactor = 5,
+        /// Serialize the folder path of actor descs
+        WorldPartitionActorDescSerializeActorFolderPath = 6,
+        /// Change hair strands vertex format
+        HairStrandsVertexFormatChange = 7,
+        /// Added max linear and angular speed to Chaos bodies
+        AddChaosMaxLinearAngularSpeed = 8,
+        /// PackedLevelInstance version
+        PackedLevelInstanceVersion = 9,
+        /// PackedLevelInstance bounds fix
+        PackedLevelInstanceBoundsFix = 10,
+        /// Custom property anim graph nodes (linked anim graphs, control rig etc.) now use optional pin manager
+        CustomPropertyAnimGraphNodesUseOptionalPinManager = 11,
+        /// Add native double and int64 support to FFormatArgumentData
+        TextFormatArgumentData64bitSupport = 12,
+        /// Material layer stacks are no longer considered 'static parameters'
+        MaterialLayerStacksAreNotParameters = 13,
+        /// CachedExpressionData is moved from UMaterial to UMaterialInterface
+        MaterialInterfaceSavedCachedData = 14,
+        /// Add support for multiple cloth deformer LODs to be able to raytrace cloth with a different LOD than the one it is rendered with
+        AddClothMappingLODBias = 15,
+        /// Add support for different external actor packaging schemes
+        AddLevelActorPackagingScheme = 16,
+        /// Add support for linking to the attached parent actor in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeAttachParent = 17,
+        /// Converted AActor GridPlacement to bIsSpatiallyLoaded flag
+        ConvertedActorGridPlacementToSpatiallyLoadedFlag = 18,
+        /// Fixup for bad default value for GridPlacement_DEPRECATED
+        ActorGridPlacementDeprecateDefaultValueFixup = 19,
+        /// PackedLevelActor started using FWorldPartitionActorDesc (not currently checked against but added as a security)
+        PackedLevelActorUseWorldPartitionActorDesc = 20,
+        /// Add support for actor folder objects
+        AddLevelActorFolders = 21,
+        /// Remove FSkeletalMeshLODModel bulk datas
+        RemoveSkeletalMeshLODModelBulkDatas = 22,
+        /// Exclude brightness from the EncodedHDRCubemap,
+        ExcludeBrightnessFromEncodedHDRCubemap = 23,
+        /// Unified volumetric cloud component quality sample count slider between main and reflection views for consistency
+        VolumetricCloudSampleCountUnification = 24,
+        /// Pose asset GUID generated from source AnimationSequence
+        PoseAssetRawDataGUID = 25,
+        /// Convolution bloom now take into account FPostProcessSettings::BloomIntensity for scatter dispersion.
+        ConvolutionBloomIntensity = 26,
+        /// Serialize FHLODSubActors instead of FGuids in WorldPartition HLODActorDesc
+        WorldPartitionHLODActorDescSerializeHLODSubActors = 27,
+        /// Large Worlds - serialize double types as doubles
+        LargeWorldCoordinates = 28,
+        /// Deserialize old BP float&double types as real numbers for pins
+        BlueprintPinsUseRealNumbers = 29,
+        /// Changed shadow defaults for directional light components, version needed to not affect old things
+        UpdatedDirectionalLightShadowDefaults = 30,
+        /// Refresh geometry collections that had not already generated convex bodies.
+        GeometryCollectionConvexDefaults = 31,
+        /// Add faster damping calculations to the cloth simulation and rename previous Damping parameter to LocalDamping.
+        ChaosClothFasterDamping = 32,
+        /// Serialize LandscapeActorGuid in FLandscapeActorDesc sub class.
+        WorldPartitionLandscapeActorDescSerializeLandscapeActorGuid = 33,
+        /// add inertia tensor and rotation of mass to convex
+        AddedInertiaTensorAndRotationOfMassAddedToConvex = 34,
+        /// Storing inertia tensor as vec3 instead of matrix.
+        ChaosInertiaConvertedToVec3 = 35,
+        /// For Blueprint real numbers, ensure that legacy float data is serialized as single-precision
+        SerializeFloatPinDefaultValuesAsSinglePrecision = 36,
+        /// Upgrade the BlendMasks array in existing LayeredBoneBlend nodes
+        AnimLayeredBoneBlendMasks = 37,
+        /// Uses RG11B10 format to store the encoded reflection capture data on mobile
+        StoreReflectionCaptureEncodedHDRDataInRG11B10Format = 38,
+        /// Add WithSerializer type trait and implementation for FRawAnimSequenceTrack
+        RawAnimSequenceTrackSerializer = 39,
+        /// Removed font from FEditableTextBoxStyle, and added FTextBlockStyle instead.
+        RemoveDuplicatedStyleInfo = 40,
+        /// Added member reference to linked anim graphs
+        LinkedAnimGraphMemberReference = 41,
+        /// Changed default tangent behavior for new dynamic mesh components
+        DynamicMeshComponentsDefaultUseExternalTangents = 42,
+        /// Added resize methods to media capture
+        MediaCaptureNewResizeMethods = 43,
+        /// Function data stores a map from work to debug operands
+        RigVMSaveDebugMapInGraphFunctionData = 44,
+        /// Changed default Local Exposure Contrast Scale from 1.0 to 0.8
+        LocalExposureDefaultChangeFrom1 = 45,
+        /// Serialize bActorIsListedInSceneOutliner in WorldPartitionActorDesc
+        WorldPartitionActorDescSerializeActorIsListedInSceneOutliner = 46,
+        /// Disabled opencolorio display configuration by default
+        OpenColorIODisabledDisplayConfigurationDefault = 47,
+        /// Serialize ExternalDataLayerAsset in WorldPartitionActorDesc
+        WorldPartitionExternalDataLayers = 48,
+        /// Fix Chaos Cloth fictitious angular scale bug that requires existing parameter rescaling.
+        ChaosClothFictitiousAngularVelocitySubframeFix = 49,
+        /// Store physics thread particles data in single precision
+        SinglePrecisonParticleDataPT = 50,
+        /// Orthographic Near and Far Plane Auto-resolve enabled by default
+        OrthographicAutoNearFarPlane = 51,
+        /// Fix a bug where BlendMask counts could get out of sync with BlendPose counts.
+        AnimLayeredBoneBlendMasksFix = 52,
+        /// Separated lens flare from bloom intensity
+        BloomIndependentLensFlare = 53,
+        /// Add settings to IAnimationDataModel GUID generation
+        AnimModelGuidGenerationSettings = 54,
+        /// Add support for Standalone HLOD
+        WorldPartitionAddStandaloneHLODSupport = 55,
+        /// Fixed the missing bounds for cloth assets that don't have them serialized
+        RecalculateClothAssetSerializedBounds = 56,
+    }
+    engine {
+        VerUe5_0 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe5_1 => Some(Self::SerializeFloatPinDefaultValuesAsSinglePrecision),
+        VerUe5_3 => Some(Self::LinkedAnimGraphMemberReference),
+        VerUe5_4 => Some(Self::OpenColorIODisabledDisplayConfigurationDefault),
+        _ => Some(Self::OrthographicAutoNearFarPlane),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes made in //UE5/Private-Frosty stream
+    FUE5SpecialProjectStreamObjectVersion("FUE5SpecialProjectStreamObjectVersion", [0x59DA5D52, 0x12324948, 0xB8785978, 0x70B8E98B], StateTreeGlobalParameterChanges) {
+        /// Before any version changes were made
+        BeforeCustomVersionWasAdded = 0,
+        /// Added HLODBatchingPolicy member to UPrimitiveComponent, which replaces the confusing bUseMaxLODAsImposter & bBatchImpostersAsInstances.
+        HLODBatchingPolicy = 1,
+        /// Serialize scene components static bounds
+        SerializeSceneComponentStaticBounds = 2,
+        /// Add the long range attachment tethers to the cloth asset to avoid a large hitch during the cloth's initialization.
+        ChaosClothAddTethersToCachedData = 3,
+        /// Always serialize the actor label in cooked builds
+        SerializeActorLabelInCookedBuilds = 4,
+        /// Changed world partition HLODs cells from FSotObjectPath to FName
+        ConvertWorldPartitionHLODsCellsToName = 5,
+        /// Re-calculate the long range attachment to prevent kinematic tethers.
+        ChaosClothRemoveKinematicTethers = 6,
+        /// Serializes the Morph Target render data for cooked platforms and the DDC
+        SerializeSkeletalMeshMorphTargetRenderData = 7,
+        /// Strip the Morph Target source data for cooked builds
+        StripMorphTargetSourceDataForCookedBuilds = 8,
+        /// StateTree now holds PropertyBag + GUID for root-level parameters rather than FStateTreeStateParameters. Access is protected by default and can be overriden through virtuals on UStateTreeEditorData derived classes.
+        StateTreeGlobalParameterChanges = 9,
+    }
+    engine {
+        VerUe5_0 => Some(Self::BeforeCustomVersionWasAdded),
+        _ => Some(Self::StripMorphTargetSourceDataForCookedBuilds),
+    }
+}
+
+custom_version! {
+    /// Custom serialization version for changes to variant manager objects
+    FVariantManagerObjectVersion("FVariantManagerObjectVersion", [0x24BB7AF3, 0x56464F83, 0x1F2F2DC2, 0x49AD96FF], StoreDisplayOrder) {
+        /// Roughly corresponds to 4.21
+        BeforeCustomVersionWasAdded = 0,
+        CorrectSerializationOfFNameBytes = 1,
+        CategoryFlagsAndManualDisplayText = 2,
+        CorrectSerializationOfFStringBytes = 3,
+        SerializePropertiesAsNames = 4,
+        StoreDisplayOrder = 5,
+    }
+    engine {
+        VerUe4_22 => Some(Self::BeforeCustomVersionWasAdded),
+        VerUe4_23 => Some(Self::SerializePropertiesAsNames),
+        _ => Some(Self::StoreDisplayOrder),
+    }
+}
+
+/// Every known engine custom version, at the version shipped with `engine` or the latest one
+pub fn engine_custom_versions(engine: Option<EngineVersion>) -> Vec<CustomVersion> {
+    [
+        entry::<FAnimObjectVersion>(engine),
+        entry::<FAnimPhysObjectVersion>(engine),
+        entry::<FBlueprintsObjectVersion>(engine),
+        entry::<FControlRigObjectVersion>(engine),
+        entry::<FCoreObjectVersion>(engine),
+        entry::<FCurveExpressionCustomVersion>(engine),
+        entry::<FDNAAssetCustomVersion>(engine),
+        entry::<FDestructionObjectVersion>(engine),
+        entry::<FEditorObjectVersion>(engine),
+        entry::<FFoliageCustomVersion>(engine),
+        entry::<FFortniteMainBranchObjectVersion>(engine),
+        entry::<FFortniteReleaseBranchCustomObjectVersion>(engine),
+        entry::<FFortniteSeasonBranchObjectVersion>(engine),
+        entry::<FFrameworkObjectVersion>(engine),
+        entry::<FInstancedStructCustomVersion>(engine),
+        entry::<FLiveLinkCustomVersion>(engine),
+        entry::<FMobileObjectVersion>(engine),
+        entry::<FNiagaraCustomVersion>(engine),
+        entry::<FNiagaraObjectVersion>(engine),
+        entry::<FOverlappingVerticesCustomVersion>(engine),
+        entry::<FPhysicsObjectVersion>(engine),
+        entry::<FPropertyBagCustomVersion>(engine),
+        entry::<FRecomputeTangentCustomVersion>(engine),
+        entry::<FReflectionCaptureObjectVersion>(engine),
+        entry::<FReleaseObjectVersion>(engine),
+        entry::<FRenderingObjectVersion>(engine),
+        entry::<FRigVMObjectVersion>(engine),
+        entry::<FSequencerObjectVersion>(engine),
+        entry::<FSkeletalMeshCustomVersion>(engine),
+        entry::<FStateTreeInstanceStorageCustomVersion>(engine),
+        entry::<FUE5MainStreamObjectVersion>(engine),
+        entry::<FUE5ReleaseStreamObjectVersion>(engine),
+        entry::<FUE5SpecialProjectStreamObjectVersion>(engine),
+        entry::<FVariantManagerObjectVersion>(engine),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
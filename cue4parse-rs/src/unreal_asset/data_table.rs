@@ -0,0 +1,550 @@
+//! DataTables
+//!
+//! A [`DataTable`] holds its rows in order, keyed by row name, each row being the properties of
+//! the table's row struct. Rows can be read as typed Rust values through the serde support,
+//! exported as CSV, JSON or newline-delimited JSON, and compared with [`DataTable::diff`].
+//!
+//! CSV cells are flattened into one column per leaf value, headed by the [`PropertyQuery`]
+//! path of the value: nested struct fields become `Stats.Damage`, array elements `Tags[0]`,
+//! map values `Resistances.Fire` or `Resistances[5]` for integer keys, and math struct
+//! components `Offset.X`. Negative integer keys have no query form, so their columns, such as
+//! `Resistances.-1`, don't resolve as queries. Setting [`CsvOptions::array_separator`] joins
+//! arrays of plain values into a single cell instead.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::io::Write;
+
+use indexmap::IndexMap;
+use serde::de::DeserializeOwned;
+use serde::ser::{SerializeMap, SerializeSeq};
+use serde::{Serialize, Serializer};
+use serde_json::Value;
+
+use crate::unreal_asset::conversion::PropertyConverter;
+use crate::unreal_asset::de::{from_properties, number, soft_object_path_string, Number};
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::query::{integer_key, native_components, PropertyQuery, QuerySegment};
+use crate::unreal_asset::ser::to_properties;
+use crate::unreal_asset::typed_export::DataTableView;
+use crate::unreal_asset::types::FName;
+use crate::unreal_asset::usmap::Usmap;
+
+/// Properties of one row
+pub type DataTableRow = IndexMap<String, Property>;
+
+/// Rows of a `UDataTable`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataTable {
+    /// Name of the row struct, e.g. `ItemRow`
+    pub row_struct: Option<String>,
+    /// Rows by name, in table order
+    pub rows: IndexMap<FName, DataTableRow>,
+}
+
+impl DataTable {
+    /// Create an empty table of `row_struct` rows
+    pub fn new(row_struct: impl Into<String>) -> Self {
+        Self { row_struct: Some(row_struct.into()), rows: IndexMap::new() }
+    }
+
+    /// Read a DataTable export written by CUE4Parse
+    ///
+    /// Cells are typed by the `$types` annotation of each row, then by the row struct in
+    /// `mappings`, as [`PropertyConverter::convert_properties`] does. Rows and fields keep
+    /// the order of `export`; see [`DataTableView`] for keeping the order of a file.
+    pub fn from_json(export: &Value, mappings: Option<&Usmap>) -> UnrealAssetResult<Self> {
+        Self::from_view(&DataTableView::from_json(export)?, mappings)
+    }
+
+    /// Convert the rows of a [`DataTableView`]
+    pub fn from_view(view: &DataTableView, mappings: Option<&Usmap>) -> UnrealAssetResult<Self> {
+        let row_struct = view.row_struct.as_ref().map(|path| {
+            let name = path.path.rsplit(['.', ':', '/']).next().unwrap_or(&path.path);
+            name.to_string()
+        });
+        let mut table = Self { row_struct, rows: IndexMap::with_capacity(view.rows.len()) };
+        for (name, row) in &view.rows {
+            // The converter looks annotations up on the object holding the fields
+            let annotations = Value::Object(row.get_key_value("$types").map(|(k, v)| (k.clone(), v.clone())).into_iter().collect());
+            let mut converter = PropertyConverter::new();
+            if let Some(mappings) = mappings {
+                converter = converter.with_mappings(mappings);
+            }
+            let properties = row
+                .iter()
+                .filter(|(field, _)| !field.starts_with('$'))
+                .map(|(field, value)| {
+                    let property = converter.convert_property(&annotations, field, value, table.row_struct.as_deref())?;
+                    Ok((field.clone(), property))
+                })
+                .collect::<UnrealAssetResult<DataTableRow>>()
+                .map_err(|e| UnrealAssetError::Parse(format!("row '{}': {}", name, e)))?;
+            table.rows.insert(FName::new(name.clone()), properties);
+        }
+        Ok(table)
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Row names in table order
+    pub fn row_names(&self) -> impl Iterator<Item = &FName> {
+        self.rows.keys()
+    }
+
+    pub fn row(&self, name: &str) -> Option<&DataTableRow> {
+        self.rows.get(&FName::new(name))
+    }
+
+    pub fn row_mut(&mut self, name: &str) -> Option<&mut DataTableRow> {
+        self.rows.get_mut(&FName::new(name))
+    }
+
+    /// Add or replace a row, keeping the position of a replaced row
+    pub fn insert_row(&mut self, name: impl Into<String>, row: DataTableRow) -> Option<DataTableRow> {
+        self.rows.insert(FName::new(name), row)
+    }
+
+    /// Remove a row, keeping the order of the others
+    pub fn remove_row(&mut self, name: &str) -> Option<DataTableRow> {
+        self.rows.shift_remove(&FName::new(name))
+    }
+
+    /// Value of `column` in row `name`
+    pub fn cell(&self, name: &str, column: &str) -> Option<&Property> {
+        self.row(name)?.get(column)
+    }
+
+    /// Value of `column` in row `name`, borrowed mutably
+    pub fn cell_mut(&mut self, name: &str, column: &str) -> Option<&mut Property> {
+        self.row_mut(name)?.get_mut(column)
+    }
+
+    /// Deserialize row `name` into `T`
+    pub fn row_as<T: DeserializeOwned>(&self, name: &str) -> UnrealAssetResult<T> {
+        let row = self
+            .row(name)
+            .ok_or_else(|| UnrealAssetError::InvalidIndex(format!("No row named '{}'", name)))?;
+        from_properties(row).map_err(|e| UnrealAssetError::Parse(format!("row '{}': {}", name, e)))
+    }
+
+    /// Deserialize every row into `T`
+    pub fn rows_as<T: DeserializeOwned>(&self) -> UnrealAssetResult<IndexMap<FName, T>> {
+        self.rows
+            .iter()
+            .map(|(name, row)| {
+                let value = from_properties(row).map_err(|e| UnrealAssetError::Parse(format!("row '{}': {}", name, e)))?;
+                Ok((name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// Serialize `value` into a row, added or replaced like [`DataTable::insert_row`]
+    pub fn insert_row_from<T: Serialize>(&mut self, name: impl Into<String>, value: &T) -> UnrealAssetResult<Option<DataTableRow>> {
+        Ok(self.insert_row(name, to_properties(value)?))
+    }
+
+    /// Rows as a JSON object of row objects
+    ///
+    /// A `Value` sorts its keys; use [`write_json`](DataTable::write_json) to keep the table order.
+    pub fn to_json(&self) -> Value {
+        serde_json::to_value(JsonTable(self)).unwrap_or_default()
+    }
+
+    /// Write the rows as a JSON object of row objects, in table order
+    pub fn write_json<W: Write>(&self, writer: W, pretty: bool) -> UnrealAssetResult<()> {
+        match pretty {
+            true => serde_json::to_writer_pretty(writer, &JsonTable(self)),
+            false => serde_json::to_writer(writer, &JsonTable(self)),
+        }
+        .map_err(|e| UnrealAssetError::Io(e.into()))
+    }
+
+    /// Write one JSON object per line, with the row name under `Name`
+    pub fn write_ndjson<W: Write>(&self, mut writer: W) -> UnrealAssetResult<()> {
+        for (name, row) in &self.rows {
+            serde_json::to_writer(&mut writer, &JsonRow { name: Some(name), row })
+                .map_err(|e| UnrealAssetError::Io(e.into()))?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Write the rows as CSV with flattened columns, see the [module documentation](self)
+    pub fn write_csv<W: Write>(&self, mut writer: W, options: &CsvOptions) -> UnrealAssetResult<()> {
+        let rows: Vec<(&FName, IndexMap<String, String>)> = self
+            .rows
+            .iter()
+            .map(|(name, row)| {
+                let cells = flatten_row(row, options.array_separator.as_deref())
+                    .into_iter()
+                    .map(|(column, value)| (column, cell_text(&value)))
+                    .collect();
+                (name, cells)
+            })
+            .collect();
+
+        // Columns in the order they first appear
+        let mut seen = HashSet::new();
+        let columns: Vec<&String> = rows
+            .iter()
+            .flat_map(|(_, cells)| cells.keys())
+            .filter(|column| seen.insert(*column))
+            .collect();
+
+        let delimiter = options.delimiter.to_string();
+        let header: Vec<String> = std::iter::once(options.name_header.as_str())
+            .chain(columns.iter().map(|column| column.as_str()))
+            .map(|cell| csv_escape(cell, options.delimiter))
+            .collect();
+        writeln!(writer, "{}", header.join(&delimiter))?;
+        for (name, cells) in &rows {
+            let line: Vec<String> = std::iter::once(name.to_string())
+                .chain(columns.iter().map(|column| cells.get(*column).cloned().unwrap_or_default()))
+                .map(|cell| csv_escape(&cell, options.delimiter))
+                .collect();
+            writeln!(writer, "{}", line.join(&delimiter))?;
+        }
+        Ok(())
+    }
+
+    /// Rows as CSV, see [`DataTable::write_csv`]
+    pub fn to_csv(&self, options: &CsvOptions) -> String {
+        let mut out = Vec::new();
+        // Writing to a Vec cannot fail
+        let _ = self.write_csv(&mut out, options);
+        String::from_utf8(out).unwrap_or_default()
+    }
+
+    /// Compare with `newer` row by row and cell by cell, on the flattened CSV columns
+    pub fn diff(&self, newer: &DataTable) -> DataTableDiff {
+        let mut diff = DataTableDiff::default();
+        for (name, row) in &self.rows {
+            let Some(new_row) = newer.rows.get(name) else {
+                diff.removed.push(name.clone());
+                continue;
+            };
+            let old_cells = flatten_row(row, None);
+            let mut new_cells = flatten_row(new_row, None);
+            let mut changes = Vec::new();
+            for (column, old) in old_cells {
+                match new_cells.shift_remove(&column) {
+                    Some(new) if new == old => {}
+                    new => changes.push(CellChange { column, old: Some(old), new }),
+                }
+            }
+            changes.extend(new_cells.into_iter().map(|(column, new)| CellChange { column, old: None, new: Some(new) }));
+            if !changes.is_empty() {
+                diff.changed.push(RowDiff { row: name.clone(), changes });
+            }
+        }
+        diff.added = newer.rows.keys().filter(|name| !self.rows.contains_key(*name)).cloned().collect();
+        diff
+    }
+}
+
+/// CSV layout for [`DataTable::write_csv`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// Cell delimiter, `,` by default
+    pub delimiter: char,
+    /// Header of the row name column, `Name` by default
+    pub name_header: String,
+    /// Join arrays of plain values into one cell with this separator instead of `[i]` columns
+    pub array_separator: Option<String>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self { delimiter: ',', name_header: "Name".to_string(), array_separator: None }
+    }
+}
+
+/// Differences between two tables, from [`DataTable::diff`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataTableDiff {
+    /// Rows only in the newer table
+    pub added: Vec<FName>,
+    /// Rows only in the older table
+    pub removed: Vec<FName>,
+    /// Rows in both tables whose cells differ
+    pub changed: Vec<RowDiff>,
+}
+
+impl DataTableDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+/// Changed cells of one row
+#[derive(Debug, Clone, PartialEq)]
+pub struct RowDiff {
+    pub row: FName,
+    pub changes: Vec<CellChange>,
+}
+
+/// Cell whose value differs, or that exists in only one table
+#[derive(Debug, Clone, PartialEq)]
+pub struct CellChange {
+    /// Flattened column, e.g. `Stats.Damage`
+    pub column: String,
+    pub old: Option<Property>,
+    pub new: Option<Property>,
+}
+
+/// One line per change: `+ Row`, `- Row` and `~ Row.Column: old -> new`
+impl fmt::Display for DataTableDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = |value: &Option<Property>| value.as_ref().map_or("(none)".to_string(), cell_text);
+        for name in &self.added {
+            writeln!(f, "+ {}", name)?;
+        }
+        for name in &self.removed {
+            writeln!(f, "- {}", name)?;
+        }
+        for row in &self.changed {
+            for change in &row.changes {
+                writeln!(f, "~ {}.{}: {} -> {}", row.row, change.column, text(&change.old), text(&change.new))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Leaf values of a row by their flattened column
+fn flatten_row(row: &DataTableRow, array_separator: Option<&str>) -> IndexMap<String, Property> {
+    let mut cells = IndexMap::new();
+    let mut path = Vec::new();
+    for (name, property) in row {
+        path.push(QuerySegment::Name(name.clone()));
+        flatten(property, &mut path, array_separator, &mut cells);
+        path.pop();
+    }
+    cells
+}
+
+fn flatten(property: &Property, path: &mut Vec<QuerySegment>, array_separator: Option<&str>, out: &mut IndexMap<String, Property>) {
+    let mut descend = |segment: QuerySegment, child: &Property, out: &mut IndexMap<String, Property>| {
+        path.push(segment);
+        flatten(child, path, array_separator, out);
+        path.pop();
+    };
+    match property {
+        Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => {
+            for (name, child) in properties {
+                descend(QuerySegment::Name(name.clone()), child, out);
+            }
+        }
+        Property::MaterialInstance(properties) => {
+            let mut names: Vec<&String> = properties.keys().collect();
+            names.sort();
+            for name in names {
+                descend(QuerySegment::Name(name.clone()), &properties[name], out);
+            }
+        }
+        Property::Array(values) | Property::ArrayProperty(values) | Property::Set(values) | Property::SetProperty(values) => {
+            match array_separator {
+                Some(separator) if values.iter().all(is_leaf) => {
+                    let joined = values.iter().map(cell_text).collect::<Vec<_>>().join(separator);
+                    out.insert(PropertyQuery::from_segments(path.clone()).to_string(), Property::String(joined));
+                }
+                _ => {
+                    for (index, child) in values.iter().enumerate() {
+                        descend(QuerySegment::Index(index), child, out);
+                    }
+                }
+            }
+        }
+        Property::Map { entries, .. } | Property::MapProperty { entries, .. } => {
+            for (key, value) in entries {
+                let segment = match integer_key(key).and_then(|key| usize::try_from(key).ok()) {
+                    Some(index) => QuerySegment::Index(index),
+                    None => QuerySegment::Name(cell_text(key)),
+                };
+                descend(segment, value, out);
+            }
+        }
+        other => match native_components(other) {
+            Some(components) => {
+                for (name, component) in &components {
+                    descend(QuerySegment::Name(name.to_string()), component, out);
+                }
+            }
+            None => {
+                out.insert(PropertyQuery::from_segments(path.clone()).to_string(), other.clone());
+            }
+        },
+    }
+}
+
+fn is_leaf(property: &Property) -> bool {
+    !matches!(
+        property,
+        Property::Struct { .. }
+            | Property::StructProperty { .. }
+            | Property::MaterialInstance(_)
+            | Property::Array(_)
+            | Property::ArrayProperty(_)
+            | Property::Set(_)
+            | Property::SetProperty(_)
+            | Property::Map { .. }
+            | Property::MapProperty { .. }
+    ) && native_components(property).is_none()
+}
+
+/// Text of a leaf value in CSV cells, map keys and diffs
+fn cell_text(property: &Property) -> String {
+    if let Some(number) = number(property) {
+        return match number {
+            Number::Signed(v) => v.to_string(),
+            Number::Unsigned(v) => v.to_string(),
+            Number::Float(v) => v.to_string(),
+        };
+    }
+    match property {
+        Property::Bool(v) => v.to_string(),
+        Property::String(v) => v.clone(),
+        Property::Name(v) | Property::SmartNameProperty(v) => v.to_string(),
+        Property::Enum { value, .. } | Property::EnumProperty { value, .. } | Property::ByteEnum { value, .. } => value.to_string(),
+        Property::Text(text) => text.to_string(),
+        Property::Guid(guid) => guid.iter().map(|part| format!("{:08X}", part)).collect(),
+        Property::Delegate { function_name, .. } | Property::DelegateProperty { function_name, .. } => function_name.to_string(),
+        Property::Unknown(Value::String(v)) => v.clone(),
+        other => match JsonProperty(other).serialize(serde_json::value::Serializer) {
+            Ok(Value::Null) | Err(_) => "None".to_string(),
+            Ok(Value::String(v)) => v,
+            Ok(value) => value.to_string(),
+        },
+    }
+}
+
+fn csv_escape(cell: &str, delimiter: char) -> String {
+    match cell.contains([delimiter, '"', '\n', '\r']) {
+        true => format!("\"{}\"", cell.replace('"', "\"\"")),
+        false => cell.to_string(),
+    }
+}
+
+/// Plain JSON form of a property, keeping the order of fields and entries
+struct JsonProperty<'a>(&'a Property);
+
+impl Serialize for JsonProperty<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => {
+                serializer.collect_map(properties.iter().map(|(name, value)| (name, JsonProperty(value))))
+            }
+            Property::MaterialInstance(properties) => {
+                let mut entries: Vec<_> = properties.iter().collect();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                serializer.collect_map(entries.into_iter().map(|(name, value)| (name, JsonProperty(value))))
+            }
+            Property::Array(values)
+            | Property::ArrayProperty(values)
+            | Property::Set(values)
+            | Property::SetProperty(values)
+            | Property::MulticastDelegate { delegates: values }
+            | Property::MulticastDelegateProperty { delegates: values }
+            | Property::MulticastSparseDelegateProperty { delegates: values }
+            | Property::MulticastInlineDelegateProperty { delegates: values } => {
+                serializer.collect_seq(values.iter().map(JsonProperty))
+            }
+            // Maps with plain keys become objects, others a list of entries as CUE4Parse writes them
+            Property::Map { entries, .. } | Property::MapProperty { entries, .. } if entries.iter().all(|(key, _)| is_leaf(key)) => {
+                serializer.collect_map(entries.iter().map(|(key, value)| (cell_text(key), JsonProperty(value))))
+            }
+            Property::Map { entries, .. } | Property::MapProperty { entries, .. } => {
+                let mut seq = serializer.serialize_seq(Some(entries.len()))?;
+                for (key, value) in entries {
+                    let entry: IndexMap<&str, JsonProperty> = IndexMap::from([("Key", JsonProperty(key)), ("Value", JsonProperty(value))]);
+                    seq.serialize_element(&JsonMap(entry))?;
+                }
+                seq.end()
+            }
+            Property::Bool(v) => serializer.serialize_bool(*v),
+            Property::Object(index)
+            | Property::ObjectProperty(index)
+            | Property::MaterialInterface(index)
+            | Property::StaticMesh(index)
+            | Property::SkeletalMesh(index)
+            | Property::Texture2D(index)
+            | Property::LevelSequence(index)
+            | Property::ComponentReference(index)
+            | Property::Blueprint(index)
+            | Property::WorldContext(index)
+            | Property::LandscapeComponent(index) => match index {
+                Some(index) => serializer.serialize_i32(index.0),
+                None => serializer.serialize_none(),
+            },
+            Property::SoftObjectPath(path)
+            | Property::SoftClassPath(path)
+            | Property::AssetObjectProperty(path)
+            | Property::SoftAssetPathProperty(path)
+            | Property::SoftObjectPathProperty(path)
+            | Property::SoftClassPathProperty(path) => match path.is_null() {
+                true => serializer.serialize_none(),
+                false => serializer.serialize_str(&soft_object_path_string(path)),
+            },
+            Property::WeightedRandomSamplerProperty(value)
+            | Property::SkeletalMeshSamplingLODBuiltDataProperty(value)
+            | Property::SkeletalMeshAreaWeightedTriangleSampler(value)
+            | Property::Unknown(value) => value.serialize(serializer),
+            other => match (number(other), native_components(other)) {
+                (Some(Number::Signed(v)), _) => serializer.serialize_i64(v),
+                (Some(Number::Unsigned(v)), _) => serializer.serialize_u64(v),
+                (Some(Number::Float(v)), _) => serializer.serialize_f64(v),
+                (None, Some(components)) => {
+                    let mut map = serializer.serialize_map(Some(components.len()))?;
+                    for (name, component) in &components {
+                        map.serialize_entry(name, &JsonProperty(component))?;
+                    }
+                    map.end()
+                }
+                (None, None) => serializer.serialize_str(&cell_text(other)),
+            },
+        }
+    }
+}
+
+struct JsonMap<'a>(IndexMap<&'a str, JsonProperty<'a>>);
+
+impl Serialize for JsonMap<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter())
+    }
+}
+
+struct JsonTable<'a>(&'a DataTable);
+
+impl Serialize for JsonTable<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.rows.iter().map(|(name, row)| (name.to_string(), JsonRow { name: None, row })))
+    }
+}
+
+/// Row object, led by the row name for newline-delimited JSON
+struct JsonRow<'a> {
+    name: Option<&'a FName>,
+    row: &'a DataTableRow,
+}
+
+impl Serialize for JsonRow<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.row.len() + usize::from(self.name.is_some())))?;
+        if let Some(name) = self.name {
+            map.serialize_entry("Name", &name.to_string())?;
+        }
+        for (column, value) in self.row {
+            map.serialize_entry(column, &JsonProperty(value))?;
+        }
+        map.end()
+    }
+}
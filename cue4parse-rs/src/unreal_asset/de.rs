@@ -0,0 +1,438 @@
+//! Deserialize Rust types from properties
+//!
+//! `&Property` implements [`serde::Deserializer`], and [`PropertiesDeserializer`] does the
+//! same for a property map such as [`Export::properties`](crate::unreal_asset::Export::properties):
+//!
+//! ```ignore
+//! #[derive(Deserialize)]
+//! #[serde(rename_all = "PascalCase")]
+//! struct WeaponStats {
+//!     damage: f64,
+//!     fire_mode: FireMode,
+//!     ammo: Option<u32>,
+//! }
+//!
+//! let stats: WeaponStats = from_properties(&export.properties)?;
+//! ```
+//!
+//! Integers and floats widen to any Rust number type they fit in. Enum properties, names and
+//! strings deserialize unit variants by name, with or without the `EType::` prefix. Structs
+//! and material instances deserialize as maps, arrays and sets as sequences, and maps by
+//! their entries. Math structs and other native values deserialize like their serde form.
+
+use std::fmt;
+
+use indexmap::IndexMap;
+use serde::de::value::{BorrowedStrDeserializer, StrDeserializer, U32Deserializer};
+use serde::de::{self, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+use serde::Deserialize;
+
+use crate::unreal_asset::error::{PathSegment, PropertyPath, UnrealAssetResult};
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::types::SoftObjectPath;
+
+/// Deserialize `T` from a property map, e.g. an export's properties
+pub fn from_properties<'de, T: Deserialize<'de>>(properties: &'de IndexMap<String, Property>) -> UnrealAssetResult<T> {
+    T::deserialize(PropertiesDeserializer::new(properties)).map_err(Into::into)
+}
+
+/// Deserialize `T` from a single property
+pub fn from_property<'de, T: Deserialize<'de>>(property: &'de Property) -> UnrealAssetResult<T> {
+    T::deserialize(property).map_err(Into::into)
+}
+
+/// Error raised while converting between properties and Rust types, with the path of the failing value
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SerdeError {
+    message: String,
+    /// Path segments, innermost first
+    segments: Vec<PathSegment>,
+}
+
+impl SerdeError {
+    /// What went wrong
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Path of the value that failed, e.g. `Stats.Modifiers[2]`
+    pub fn path(&self) -> PropertyPath {
+        let mut path = PropertyPath::new();
+        for segment in self.segments.iter().rev() {
+            path.push(segment.clone());
+        }
+        path
+    }
+
+    pub(crate) fn within(mut self, segment: impl Into<PathSegment>) -> Self {
+        self.segments.push(segment.into());
+        self
+    }
+}
+
+impl fmt::Display for SerdeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.segments.is_empty() {
+            true => f.write_str(&self.message),
+            false => write!(f, "{}: {}", self.path(), self.message),
+        }
+    }
+}
+
+impl std::error::Error for SerdeError {}
+
+impl de::Error for SerdeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self { message: message.to_string(), segments: Vec::new() }
+    }
+}
+
+impl serde::ser::Error for SerdeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        Self { message: message.to_string(), segments: Vec::new() }
+    }
+}
+
+/// [`Deserializer`] over a property map
+#[derive(Debug, Clone, Copy)]
+pub struct PropertiesDeserializer<'de> {
+    properties: &'de IndexMap<String, Property>,
+}
+
+impl<'de> PropertiesDeserializer<'de> {
+    pub fn new(properties: &'de IndexMap<String, Property>) -> Self {
+        Self { properties }
+    }
+}
+
+impl<'de> Deserializer<'de> for PropertiesDeserializer<'de> {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_map(FieldsAccess { fields: self.properties.iter(), current: None })
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+/// Struct fields or material instance parameters
+struct FieldsAccess<'de, I> {
+    fields: I,
+    current: Option<(&'de str, &'de Property)>,
+}
+
+impl<'de, I: Iterator<Item = (&'de String, &'de Property)>> MapAccess<'de> for FieldsAccess<'de, I> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let Some((name, value)) = self.fields.next() else {
+            return Ok(None);
+        };
+        self.current = Some((name, value));
+        seed.deserialize(BorrowedStrDeserializer::new(name)).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let (name, value) = self.current.take().ok_or_else(|| de::Error::custom("value requested before key"))?;
+        seed.deserialize(value).map_err(|error| error.within(name))
+    }
+}
+
+/// Map property entries
+struct EntriesAccess<'de> {
+    entries: std::slice::Iter<'de, (Property, Property)>,
+    current: Option<&'de (Property, Property)>,
+}
+
+impl<'de> MapAccess<'de> for EntriesAccess<'de> {
+    type Error = SerdeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Self::Error> {
+        let Some(entry) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.current = Some(entry);
+        seed.deserialize(&entry.0).map(Some).map_err(|error| error.within(key_label(&entry.0)))
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Self::Error> {
+        let (key, value) = self.current.take().ok_or_else(|| de::Error::custom("value requested before key"))?;
+        seed.deserialize(value).map_err(|error| error.within(key_label(key)))
+    }
+}
+
+struct ElementsAccess<'de> {
+    elements: std::iter::Enumerate<std::slice::Iter<'de, Property>>,
+}
+
+impl<'de> SeqAccess<'de> for ElementsAccess<'de> {
+    type Error = SerdeError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Self::Error> {
+        match self.elements.next() {
+            Some((index, element)) => seed.deserialize(element).map(Some).map_err(|error| error.within(index)),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.elements.len())
+    }
+}
+
+pub(crate) enum Number {
+    Signed(i64),
+    Unsigned(u64),
+    Float(f64),
+}
+
+pub(crate) fn number(property: &Property) -> Option<Number> {
+    Some(match property {
+        Property::Int8(v) => Number::Signed(*v as i64),
+        Property::Int16(v) => Number::Signed(*v as i64),
+        Property::Int32(v) => Number::Signed(*v as i64),
+        Property::Int64(v) | Property::DateTime(v) | Property::TimeSpan(v) => Number::Signed(*v),
+        Property::UInt8(v) | Property::Byte(v) => Number::Unsigned(*v as u64),
+        Property::UInt16(v) => Number::Unsigned(*v as u64),
+        Property::UInt32(v) => Number::Unsigned(*v as u64),
+        Property::UInt64(v) => Number::Unsigned(*v),
+        Property::Float(v) => Number::Float(*v as f64),
+        Property::Double(v) => Number::Float(*v),
+        _ => return None,
+    })
+}
+
+/// Label of a map key in error paths
+fn key_label(key: &Property) -> String {
+    match key {
+        Property::String(value) => value.clone(),
+        Property::Name(value) | Property::SmartNameProperty(value) => value.to_string(),
+        Property::Enum { value, .. } | Property::EnumProperty { value, .. } | Property::ByteEnum { value, .. } => value.to_string(),
+        other => match number(other) {
+            Some(Number::Signed(v)) => v.to_string(),
+            Some(Number::Unsigned(v)) => v.to_string(),
+            Some(Number::Float(v)) => v.to_string(),
+            None => other.type_name().to_string(),
+        },
+    }
+}
+
+/// `/Game/Path.Object:SubPath`, as `FSoftObjectPath::ToString`
+pub(crate) fn soft_object_path_string(path: &SoftObjectPath) -> String {
+    match path.sub_path.is_empty() {
+        true => path.asset_path.to_string(),
+        false => format!("{}:{}", path.asset_path, path.sub_path),
+    }
+}
+
+/// Deserialize a native value through its serde form
+fn via_json<'de, V: Visitor<'de>>(value: impl serde::Serialize, visitor: V) -> Result<V::Value, SerdeError> {
+    serde_json::to_value(value)
+        .and_then(|value| value.deserialize_any(visitor))
+        .map_err(de::Error::custom)
+}
+
+macro_rules! deserialize_integer {
+    ($($method:ident => $visit:ident($ty:ty),)+) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+                let value = match number(self) {
+                    Some(Number::Signed(v)) => <$ty>::try_from(v).map_err(|_| out_of_range(v, stringify!($ty)))?,
+                    Some(Number::Unsigned(v)) => <$ty>::try_from(v).map_err(|_| out_of_range(v, stringify!($ty)))?,
+                    _ => return self.deserialize_any(visitor),
+                };
+                visitor.$visit(value)
+            }
+        )+
+    };
+}
+
+fn out_of_range(value: impl fmt::Display, target: &str) -> SerdeError {
+    de::Error::custom(format!("{} is out of range for {}", value, target))
+}
+
+impl<'de> Deserializer<'de> for &'de Property {
+    type Error = SerdeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Property::Bool(v) => visitor.visit_bool(*v),
+            Property::Float(v) => visitor.visit_f32(*v),
+            Property::Double(v) => visitor.visit_f64(*v),
+            Property::String(v) => visitor.visit_borrowed_str(v),
+            Property::Name(v) | Property::SmartNameProperty(v) if v.number == 0 => visitor.visit_borrowed_str(&v.name),
+            Property::Name(v) | Property::SmartNameProperty(v) => visitor.visit_string(v.to_string()),
+            Property::Enum { value, .. } | Property::EnumProperty { value, .. } | Property::ByteEnum { value, .. } => {
+                visitor.visit_borrowed_str(&value.name)
+            }
+            Property::Text(text) => visitor.visit_string(text.to_string()),
+            Property::Object(index)
+            | Property::ObjectProperty(index)
+            | Property::MaterialInterface(index)
+            | Property::StaticMesh(index)
+            | Property::SkeletalMesh(index)
+            | Property::Texture2D(index)
+            | Property::LevelSequence(index)
+            | Property::ComponentReference(index)
+            | Property::Blueprint(index)
+            | Property::WorldContext(index)
+            | Property::LandscapeComponent(index) => match index {
+                Some(index) => visitor.visit_i32(index.0),
+                None => visitor.visit_none(),
+            },
+            Property::SoftObjectPath(path)
+            | Property::SoftClassPath(path)
+            | Property::AssetObjectProperty(path)
+            | Property::SoftAssetPathProperty(path)
+            | Property::SoftObjectPathProperty(path)
+            | Property::SoftClassPathProperty(path) => visitor.visit_string(soft_object_path_string(path)),
+            Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => {
+                visitor.visit_map(FieldsAccess { fields: properties.iter(), current: None })
+            }
+            Property::MaterialInstance(properties) => {
+                visitor.visit_map(FieldsAccess { fields: properties.iter(), current: None })
+            }
+            Property::Map { entries, .. } | Property::MapProperty { entries, .. } => {
+                visitor.visit_map(EntriesAccess { entries: entries.iter(), current: None })
+            }
+            Property::Array(values)
+            | Property::ArrayProperty(values)
+            | Property::Set(values)
+            | Property::SetProperty(values)
+            | Property::MulticastDelegate { delegates: values }
+            | Property::MulticastDelegateProperty { delegates: values }
+            | Property::MulticastSparseDelegateProperty { delegates: values }
+            | Property::MulticastInlineDelegateProperty { delegates: values } => {
+                visitor.visit_seq(ElementsAccess { elements: values.iter().enumerate() })
+            }
+            Property::Vector(v) => via_json(v, visitor),
+            Property::Vector4(v) => via_json(v, visitor),
+            Property::Vector2D(v) => via_json(v, visitor),
+            Property::Rotator(v) => via_json(v, visitor),
+            Property::Quat(v) => via_json(v, visitor),
+            Property::LinearColor(v) => via_json(v, visitor),
+            Property::Transform(v) => via_json(v, visitor),
+            Property::PerPlatformBool(v) => via_json(v, visitor),
+            Property::PerPlatformInt(v) => via_json(v, visitor),
+            Property::PerPlatformFloat(v) => via_json(v, visitor),
+            Property::Guid(v) => via_json(v, visitor),
+            Property::Delegate { object, function_name } | Property::DelegateProperty { object, function_name } => {
+                via_json(serde_json::json!({ "object": object, "function_name": function_name }), visitor)
+            }
+            Property::WeightedRandomSamplerProperty(v)
+            | Property::SkeletalMeshSamplingLODBuiltDataProperty(v)
+            | Property::SkeletalMeshAreaWeightedTriangleSampler(v)
+            | Property::Unknown(v) => v.deserialize_any(visitor).map_err(de::Error::custom),
+            other => match number(other) {
+                Some(Number::Signed(v)) => visitor.visit_i64(v),
+                Some(Number::Unsigned(v)) => visitor.visit_u64(v),
+                Some(Number::Float(v)) => visitor.visit_f64(v),
+                None => Err(de::Error::custom(format!("cannot deserialize {}", other.type_name()))),
+            },
+        }
+    }
+
+    deserialize_integer! {
+        deserialize_i8 => visit_i8(i8),
+        deserialize_i16 => visit_i16(i16),
+        deserialize_i32 => visit_i32(i32),
+        deserialize_i64 => visit_i64(i64),
+        deserialize_i128 => visit_i128(i128),
+        deserialize_u8 => visit_u8(u8),
+        deserialize_u16 => visit_u16(u16),
+        deserialize_u32 => visit_u32(u32),
+        deserialize_u64 => visit_u64(u64),
+        deserialize_u128 => visit_u128(u128),
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        self.deserialize_f64(visitor)
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match number(self) {
+            Some(Number::Signed(v)) => visitor.visit_f64(v as f64),
+            Some(Number::Unsigned(v)) => visitor.visit_f64(v as f64),
+            Some(Number::Float(v)) => visitor.visit_f64(v),
+            None => self.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self {
+            Property::Object(None)
+            | Property::ObjectProperty(None)
+            | Property::MaterialInterface(None)
+            | Property::StaticMesh(None)
+            | Property::SkeletalMesh(None)
+            | Property::Texture2D(None)
+            | Property::LevelSequence(None)
+            | Property::ComponentReference(None)
+            | Property::Blueprint(None)
+            | Property::WorldContext(None)
+            | Property::LandscapeComponent(None)
+            | Property::Unknown(serde_json::Value::Null) => visitor.visit_none(),
+            Property::SoftObjectPath(path)
+            | Property::SoftClassPath(path)
+            | Property::SoftObjectPathProperty(path)
+            | Property::SoftClassPathProperty(path)
+            | Property::SoftAssetPathProperty(path)
+            | Property::AssetObjectProperty(path) if path.is_null() => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let variant = match self {
+            Property::Enum { value, .. } | Property::EnumProperty { value, .. } | Property::ByteEnum { value, .. } => &value.name,
+            Property::Name(value) | Property::SmartNameProperty(value) => &value.name,
+            Property::String(value) => value,
+            other => {
+                return match number(other) {
+                    Some(Number::Unsigned(v)) if v <= u32::MAX as u64 => {
+                        visitor.visit_enum::<U32Deserializer<SerdeError>>((v as u32).into_deserializer())
+                    }
+                    Some(Number::Signed(v)) if (0..=u32::MAX as i64).contains(&v) => {
+                        visitor.visit_enum::<U32Deserializer<SerdeError>>((v as u32).into_deserializer())
+                    }
+                    _ => self.deserialize_any(visitor),
+                };
+            }
+        };
+        // `EWeaponType::Rifle` selects the `Rifle` variant
+        let variant = variant.rsplit_once("::").map_or(variant.as_str(), |(_, name)| name);
+        visitor.visit_enum::<StrDeserializer<SerdeError>>(variant.into_deserializer())
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool char str string bytes byte_buf unit_struct seq tuple tuple_struct map struct identifier
+    }
+}
@@ -0,0 +1,432 @@
+//! Cross-package dependency graph
+//!
+//! Hard dependencies come from a package's import table, soft dependencies from
+//! the soft object paths in its properties and its soft package reference list.
+//! Only hard dependencies take part in load order resolution and cycle
+//! detection, since the engine never loads soft references eagerly.
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use indexmap::{IndexMap, IndexSet};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::unreal_asset::asset::{Asset, UnrealAssetCompat};
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::types::PackageIndex;
+use crate::unreal_asset::visit::{PropertyReference, Visitable};
+use crate::Provider;
+
+/// How one package depends on another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DependencyType {
+    /// Imported object, loaded together with the package
+    Hard,
+    /// Soft object reference, loaded on demand
+    Soft,
+}
+
+/// A dependency of a package on another package
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PackageDependency {
+    /// Name of the package depended on
+    pub package_name: String,
+    /// Kind of dependency
+    pub dependency_type: DependencyType,
+    /// Objects referenced in the package
+    pub import_map: Vec<String>,
+}
+
+/// Summary of a dependency graph
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyStatistics {
+    pub total_packages: usize,
+    pub total_dependencies: usize,
+    pub hard_dependencies: usize,
+    pub soft_dependencies: usize,
+    /// Number of packages no other package depends on
+    pub root_packages: usize,
+    /// Number of hard dependency cycles
+    pub cycles: usize,
+}
+
+/// Dependency graph between packages
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DependencyGraph {
+    /// Dependencies of each package, in insertion order
+    dependencies: IndexMap<String, Vec<PackageDependency>>,
+    /// Packages the provider listed but that failed to load, with the error
+    pub failed_packages: Vec<(String, String)>,
+}
+
+impl DependencyGraph {
+    /// Create an empty graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Build a graph from every package the provider can see
+    ///
+    /// Packages that fail to load are recorded in `failed_packages` instead of
+    /// aborting the whole build.
+    pub fn from_provider(provider: &Provider) -> crate::Result<Self> {
+        Ok(Self::from_packages(provider.list_packages()?, |package| {
+            let object_name = package.rsplit('/').next().unwrap_or(package);
+            Asset::from_cue4parse(provider, &format!("{}.{}", package, object_name))
+        }))
+    }
+
+    /// Build a graph from provider file paths, loading each package with `load`
+    ///
+    /// Paths such as `Game/Content/Maps/Level.umap` are turned into the package
+    /// names imports use (`/Game/Maps/Level`) before being passed to `load`.
+    pub fn from_packages<I, S, R, E>(paths: I, mut load: impl FnMut(&str) -> Result<Asset<R>, E>) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        E: std::fmt::Display,
+    {
+        let mut graph = DependencyGraph::new();
+        for path in paths {
+            let package = package_name_from_path(path.as_ref());
+            match load(&package) {
+                Ok(asset) => graph.add_asset(&package, &asset),
+                Err(e) => {
+                    graph.add_package(&package);
+                    graph.failed_packages.push((package, e.to_string()));
+                }
+            }
+        }
+        graph
+    }
+
+    /// Add a package without dependencies
+    pub fn add_package(&mut self, package: &str) {
+        if !self.dependencies.contains_key(package) {
+            self.dependencies.insert(package.to_string(), Vec::new());
+        }
+    }
+
+    /// Add a dependency, merging it with an existing one of the same type
+    pub fn add_dependency(&mut self, package: String, dependency: PackageDependency) {
+        self.add_package(&package);
+        self.add_package(&dependency.package_name);
+        let dependencies = self.dependencies.entry(package).or_default();
+        match dependencies.iter_mut().find(|d| {
+            d.package_name == dependency.package_name && d.dependency_type == dependency.dependency_type
+        }) {
+            Some(existing) => {
+                let mut known: HashSet<String> = existing.import_map.iter().cloned().collect();
+                existing.import_map.extend(dependency.import_map.into_iter().filter(|o| known.insert(o.clone())));
+            }
+            None => dependencies.push(dependency),
+        }
+    }
+
+    /// Add a package's hard and soft dependencies from its asset
+    pub fn add_asset<R>(&mut self, package: &str, asset: &Asset<R>) {
+        self.add_package(package);
+        let imports = &asset.asset_data.imports;
+
+        // Group every import under its top-level package import in one pass
+        let mut hard: IndexMap<PackageIndex, IndexSet<String>> = imports.iter().enumerate()
+            .filter(|(_, import)| import.outer_index.is_null())
+            .map(|(index, _)| (PackageIndex::from_import(index), IndexSet::new()))
+            .collect();
+        for import in imports {
+            if let Some(objects) = outermost_import(asset, import.outer_index).and_then(|i| hard.get_mut(&i)) {
+                objects.insert(import.object_name.to_string());
+            }
+        }
+        for (index, objects) in hard {
+            let Some(import) = asset.get_import(index) else { continue };
+            self.add_dependency(package.to_string(), PackageDependency {
+                package_name: import.object_name.to_string(),
+                dependency_type: DependencyType::Hard,
+                import_map: objects.into_iter().collect(),
+            });
+        }
+
+        let mut soft_references: Vec<String> = asset.asset_data.exports.iter()
+            .flat_map(|export| export.properties.collect_references())
+            .filter_map(|reference| match reference {
+                PropertyReference::Soft(path) => Some(path.asset_path.to_string()),
+                PropertyReference::Package(_) => None,
+            })
+            .collect();
+        for path in asset.soft_package_reference_list.iter().flatten() {
+            soft_references.push(path.clone());
+        }
+        for reference in soft_references {
+            let (referenced_package, object) = match reference.split_once('.') {
+                Some((p, o)) => (p.to_string(), vec![o.to_string()]),
+                None => (reference, Vec::new()),
+            };
+            if referenced_package.is_empty() || referenced_package == package {
+                continue;
+            }
+            self.add_dependency(package.to_string(), PackageDependency {
+                package_name: referenced_package,
+                dependency_type: DependencyType::Soft,
+                import_map: object,
+            });
+        }
+    }
+
+    /// Iterate over every package in the graph
+    pub fn packages(&self) -> impl Iterator<Item = &str> {
+        self.dependencies.keys().map(String::as_str)
+    }
+
+    /// Get the direct dependencies of a package
+    pub fn dependencies_of(&self, package: &str) -> &[PackageDependency] {
+        self.dependencies.get(package).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Get the packages that directly depend on a package
+    pub fn dependents_of(&self, package: &str) -> Vec<(&str, &PackageDependency)> {
+        self.dependencies.iter()
+            .flat_map(|(name, deps)| deps.iter().map(move |d| (name.as_str(), d)))
+            .filter(|(_, d)| d.package_name == package)
+            .collect()
+    }
+
+    /// Get every package reachable from a package, optionally following soft references
+    ///
+    /// Unlike [`DependencyGraph::resolve_dependencies`] this tolerates cycles.
+    pub fn transitive_dependencies(&self, package: &str, include_soft: bool) -> IndexSet<String> {
+        let mut visited = IndexSet::new();
+        let mut stack = vec![package.to_string()];
+        while let Some(current) = stack.pop() {
+            for dependency in self.followed(&current, include_soft) {
+                if dependency.package_name != package && visited.insert(dependency.package_name.clone()) {
+                    stack.push(dependency.package_name.clone());
+                }
+            }
+        }
+        visited
+    }
+
+    /// Get every package that depends on a package, directly or indirectly
+    pub fn transitive_dependents(&self, package: &str, include_soft: bool) -> IndexSet<String> {
+        let mut visited = IndexSet::new();
+        let mut stack = vec![package.to_string()];
+        while let Some(current) = stack.pop() {
+            for (name, dependency) in self.dependents_of(&current) {
+                if !include_soft && dependency.dependency_type == DependencyType::Soft {
+                    continue;
+                }
+                if name != package && visited.insert(name.to_string()) {
+                    stack.push(name.to_string());
+                }
+            }
+        }
+        visited
+    }
+
+    /// Resolve the hard dependencies of a package in load order
+    ///
+    /// Dependencies come before the packages that need them; the package itself
+    /// is not included. Fails with the offending chain if a cycle is found.
+    pub fn resolve_dependencies(&self, package: &str) -> UnrealAssetResult<Vec<String>> {
+        // Depth-first post-order, kept iterative so long chains can't overflow the stack
+        let mut order = Vec::new();
+        let mut done: HashSet<&str> = HashSet::new();
+        let mut path: IndexSet<&str> = IndexSet::new();
+        let mut work = vec![(package, 0)];
+        path.insert(package);
+        while let Some((current, edge)) = work.last_mut() {
+            let current = *current;
+            let Some(dependency) = self.dependencies_of(current).get(*edge) else {
+                work.pop();
+                path.pop();
+                done.insert(current);
+                order.push(current.to_string());
+                continue;
+            };
+            *edge += 1;
+            let next = dependency.package_name.as_str();
+            if dependency.dependency_type != DependencyType::Hard || done.contains(next) {
+                continue;
+            }
+            if let Some(start) = path.get_index_of(next) {
+                let cycle: Vec<&str> = path.iter().skip(start).copied().chain([next]).collect();
+                return Err(UnrealAssetError::InvalidData(format!("Circular dependency: {}", cycle.join(" -> "))));
+            }
+            path.insert(next);
+            work.push((next, 0));
+        }
+        order.retain(|p| p != package);
+        Ok(order)
+    }
+
+    /// Find every cycle of hard dependencies
+    ///
+    /// Each cycle is a strongly connected component listed in graph order.
+    pub fn find_cycles(&self) -> Vec<Vec<String>> {
+        // Tarjan's algorithm, kept iterative so deep graphs can't overflow the stack
+        let count = self.dependencies.len();
+        let mut index = vec![usize::MAX; count];
+        let mut low = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut cycles = Vec::new();
+
+        let edges: Vec<Vec<usize>> = self.dependencies.values()
+            .map(|deps| deps.iter()
+                .filter(|d| d.dependency_type == DependencyType::Hard)
+                .filter_map(|d| self.dependencies.get_index_of(&d.package_name))
+                .collect())
+            .collect();
+
+        for root in 0..count {
+            if index[root] != usize::MAX {
+                continue;
+            }
+            let mut work = vec![(root, 0)];
+            while let Some(&mut (node, ref mut edge)) = work.last_mut() {
+                if *edge == 0 && index[node] == usize::MAX {
+                    index[node] = next_index;
+                    low[node] = next_index;
+                    next_index += 1;
+                    stack.push(node);
+                    on_stack[node] = true;
+                }
+                if let Some(&next) = edges[node].get(*edge) {
+                    *edge += 1;
+                    if index[next] == usize::MAX {
+                        work.push((next, 0));
+                    } else if on_stack[next] {
+                        low[node] = low[node].min(index[next]);
+                    }
+                    continue;
+                }
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if low[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    if component.len() > 1 || edges[node].contains(&node) {
+                        component.sort_unstable();
+                        cycles.push(component.into_iter()
+                            .filter_map(|i| self.dependencies.get_index(i).map(|(k, _)| k.clone()))
+                            .collect());
+                    }
+                }
+            }
+        }
+        cycles
+    }
+
+    /// Check whether any hard dependency cycle exists
+    pub fn has_cycles(&self) -> bool {
+        !self.find_cycles().is_empty()
+    }
+
+    /// Get statistics about the graph
+    pub fn get_statistics(&self) -> DependencyStatistics {
+        let all = || self.dependencies.values().flatten();
+        let referenced: IndexSet<&str> = all().map(|d| d.package_name.as_str()).collect();
+        DependencyStatistics {
+            total_packages: self.dependencies.len(),
+            total_dependencies: all().count(),
+            hard_dependencies: all().filter(|d| d.dependency_type == DependencyType::Hard).count(),
+            soft_dependencies: all().filter(|d| d.dependency_type == DependencyType::Soft).count(),
+            root_packages: self.dependencies.keys().filter(|k| !referenced.contains(k.as_str())).count(),
+            cycles: self.find_cycles().len(),
+        }
+    }
+
+    /// Render the graph in Graphviz DOT format, with soft dependencies dashed
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph dependencies {\n");
+        for package in self.dependencies.keys() {
+            let _ = writeln!(dot, "    {:?};", package);
+        }
+        for (package, dependencies) in &self.dependencies {
+            for dependency in dependencies {
+                let style = match dependency.dependency_type {
+                    DependencyType::Hard => "",
+                    DependencyType::Soft => " [style=dashed]",
+                };
+                let _ = writeln!(dot, "    {:?} -> {:?}{};", package, dependency.package_name, style);
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render the graph as JSON with `packages` and `edges` lists
+    pub fn to_json(&self) -> serde_json::Value {
+        let edges: Vec<_> = self.dependencies.iter()
+            .flat_map(|(package, deps)| deps.iter().map(move |d| json!({
+                "from": package,
+                "to": d.package_name,
+                "type": d.dependency_type,
+                "imports": d.import_map,
+            })))
+            .collect();
+        json!({
+            "packages": self.dependencies.keys().collect::<Vec<_>>(),
+            "edges": edges,
+        })
+    }
+
+    fn followed<'g>(&'g self, package: &str, include_soft: bool) -> impl Iterator<Item = &'g PackageDependency> {
+        self.dependencies_of(package).iter()
+            .filter(move |d| include_soft || d.dependency_type == DependencyType::Hard)
+    }
+}
+
+/// Turn a provider file path such as `Game/Content/Maps/Level.umap` into a package name
+///
+/// The project's `Content` folder mounts at `/Game`, the engine's at `/Engine`
+/// and a plugin's at `/<PluginName>`, matching the names imports refer to.
+fn package_name_from_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    let stem = match path.rsplit_once('.') {
+        Some((stem, extension)) if !extension.contains('/') => stem,
+        _ => path.as_str(),
+    };
+    let stem = stem.trim_start_matches('/');
+    let Some((root, rest)) = stem.split_once("/Content/") else {
+        return format!("/{}", stem);
+    };
+    let segments: Vec<&str> = root.split('/').collect();
+    let mount = if segments.contains(&"Plugins") {
+        segments.last().copied().unwrap_or("Game")
+    } else if root == "Engine" {
+        "Engine"
+    } else {
+        "Game"
+    };
+    format!("/{}/{}", mount, rest)
+}
+
+/// Follow an import's outer chain to the top-level package import
+///
+/// Returns `None` for an outer chain that loops back on itself.
+fn outermost_import<R>(asset: &Asset<R>, mut index: PackageIndex) -> Option<PackageIndex> {
+    let mut outermost = None;
+    // A well-formed chain can't be longer than the import table
+    for _ in 0..=asset.asset_data.imports.len() {
+        match asset.get_import(index) {
+            Some(import) => {
+                outermost = Some(index);
+                index = import.outer_index;
+            }
+            None => return outermost,
+        }
+    }
+    None
+}
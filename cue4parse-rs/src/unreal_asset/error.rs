@@ -1,6 +1,9 @@
 
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::unreal_asset::de::SerdeError;
+
 #[derive(Error, Debug)]
 pub enum UnrealAssetError {
     #[error("IO error: {0}")]
@@ -15,12 +18,241 @@ pub enum UnrealAssetError {
     InvalidIndex(String),
     #[error("Custom error: {0}")]
     Custom(String),
+    #[error("{kind} {value} out of range at offset {offset:#x}")]
+    LimitExceeded {
+        kind: LimitKind,
+        value: i64,
+        offset: u64,
+    },
+    #[error("{message} at `{segment}` in property path `{path}`")]
+    InvalidPath {
+        /// The whole path
+        path: String,
+        /// The path up to and including the segment that failed
+        segment: String,
+        /// What was wrong with the segment, e.g. "expected `]`"
+        message: String,
+    },
+    #[error("Serde error: {0}")]
+    Serde(#[from] SerdeError),
+    #[error("{source} ({})", context.describe(source))]
+    WithContext {
+        context: Box<ErrorContext>,
+        source: Box<UnrealAssetError>,
+    },
+}
+
+/// Limit checked by [`ArchiveReader`](crate::unreal_asset::ArchiveReader) while reading untrusted data
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitKind {
+    StringLength,
+    ArrayLength,
+    NestingDepth,
+}
+
+impl std::fmt::Display for LimitKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LimitKind::StringLength => write!(f, "String length"),
+            LimitKind::ArrayLength => write!(f, "Array length"),
+            LimitKind::NestingDepth => write!(f, "Nesting depth"),
+        }
+    }
 }
 
 impl UnrealAssetError {
     pub fn new(message: &str) -> Self {
         UnrealAssetError::Custom(message.to_string())
     }
+
+    /// Attach where the error was raised, e.g. [`ArchiveReader::error_context`](crate::unreal_asset::ArchiveReader::error_context)
+    ///
+    /// Errors that already have a context are returned unchanged.
+    pub fn with_context(self, context: ErrorContext) -> Self {
+        if self.context().is_some() {
+            return self;
+        }
+        UnrealAssetError::WithContext {
+            context: Box::new(context),
+            source: Box::new(self),
+        }
+    }
+
+    /// Where the error was raised, if attached with [`with_context`](UnrealAssetError::with_context)
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            UnrealAssetError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// The error without its context
+    pub fn root(&self) -> &UnrealAssetError {
+        match self {
+            UnrealAssetError::WithContext { source, .. } => source.root(),
+            error => error,
+        }
+    }
+
+    /// Name of the error variant, e.g. "InvalidData"
+    pub fn kind(&self) -> &'static str {
+        match self.root() {
+            UnrealAssetError::Io(_) => "Io",
+            UnrealAssetError::Parse(_) => "Parse",
+            UnrealAssetError::InvalidData(_) => "InvalidData",
+            UnrealAssetError::UnsupportedVersion(_) => "UnsupportedVersion",
+            UnrealAssetError::InvalidIndex(_) => "InvalidIndex",
+            UnrealAssetError::Custom(_) => "Custom",
+            UnrealAssetError::LimitExceeded { .. } => "LimitExceeded",
+            UnrealAssetError::InvalidPath { .. } => "InvalidPath",
+            UnrealAssetError::Serde(_) => "Serde",
+            UnrealAssetError::WithContext { .. } => unreachable!("root() strips context"),
+        }
+    }
+
+    /// Machine-readable form of the error
+    pub fn report(&self) -> ErrorReport {
+        let context = self.context();
+        let path = match (context, self.root()) {
+            (Some(context), _) => context.path.clone(),
+            (None, UnrealAssetError::Serde(error)) => error.path(),
+            (None, _) => PropertyPath::new(),
+        };
+        ErrorReport {
+            kind: self.kind().to_string(),
+            message: self.root().to_string(),
+            archive_name: context.and_then(|c| c.archive_name.clone()),
+            offset: context.map(|c| c.offset),
+            path: path.to_string(),
+            segments: path.segments().to_vec(),
+        }
+    }
+}
+
+/// Segment of a [`PropertyPath`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PathSegment {
+    /// Named field or property
+    Field(String),
+    /// Array element
+    Index(usize),
+}
+
+impl From<&str> for PathSegment {
+    fn from(name: &str) -> Self {
+        PathSegment::Field(name.to_string())
+    }
+}
+
+impl From<String> for PathSegment {
+    fn from(name: String) -> Self {
+        PathSegment::Field(name)
+    }
+}
+
+impl From<usize> for PathSegment {
+    fn from(index: usize) -> Self {
+        PathSegment::Index(index)
+    }
+}
+
+/// Breadcrumb of the value being read, e.g. `Export[12].Properties.Mesh.LODs[2]`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PropertyPath(Vec<PathSegment>);
+
+impl PropertyPath {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, segment: impl Into<PathSegment>) {
+        self.0.push(segment.into());
+    }
+
+    pub fn pop(&mut self) -> Option<PathSegment> {
+        self.0.pop()
+    }
+
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Display for PropertyPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, segment) in self.0.iter().enumerate() {
+            match segment {
+                PathSegment::Field(name) if i == 0 => write!(f, "{name}")?,
+                PathSegment::Field(name) => write!(f, ".{name}")?,
+                PathSegment::Index(index) => write!(f, "[{index}]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Location of an error inside an archive
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorContext {
+    /// Name of the archive, usually its file name
+    pub archive_name: Option<String>,
+    /// Byte offset the error was raised at
+    pub offset: u64,
+    /// Property path being read
+    pub path: PropertyPath,
+}
+
+impl ErrorContext {
+    /// Describe the context of `source`, leaving out the offset if its message already has it
+    fn describe(&self, source: &UnrealAssetError) -> String {
+        let mut parts = Vec::new();
+        if !matches!(source.root(), UnrealAssetError::LimitExceeded { offset, .. } if *offset == self.offset) {
+            parts.push(format!("offset {:#x}", self.offset));
+        }
+        if let Some(name) = &self.archive_name {
+            parts.push(format!("in {name}"));
+        }
+        let mut description = parts.join(" ");
+        if !self.path.is_empty() {
+            if !description.is_empty() {
+                description.push_str(", ");
+            }
+            description.push_str(&format!("reading {}", self.path));
+        }
+        description
+    }
+}
+
+impl std::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "offset {:#x}", self.offset)?;
+        if let Some(name) = &self.archive_name {
+            write!(f, " in {name}")?;
+        }
+        if !self.path.is_empty() {
+            write!(f, ", reading {}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+/// Serializable summary of an [`UnrealAssetError`] for tooling
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorReport {
+    pub kind: String,
+    pub message: String,
+    pub archive_name: Option<String>,
+    pub offset: Option<u64>,
+    /// Property path as displayed, e.g. `Export[12].Properties`
+    pub path: String,
+    /// Property path segments, names as strings and array indices as numbers
+    pub segments: Vec<PathSegment>,
 }
 
 pub type UnrealAssetResult<T> = std::result::Result<T, UnrealAssetError>;
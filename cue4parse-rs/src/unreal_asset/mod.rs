@@ -28,57 +28,72 @@
 //! use cue4parse_rs::{Provider, GameVersion};
 //! use cue4parse_rs::unreal_asset::{Asset, UnrealAssetCompat};
 //!
+//! let provider = Provider::new("/path/to/paks", GameVersion::UE5_3);
+//! let asset = Asset::from_cue4parse(&provider, "/Game/Data/MyAsset.MyAsset")?;
+//! println!("Asset name: {}", asset.asset_data.object_name);
+//! ```
 
-// Standard library imports
-use std::collections::HashMap;
-use std::io::{Read, Write, Seek};
-use std::fmt;
-use std::marker::PhantomData;
-
-// Third-party imports
-use uuid::Uuid;
-use indexmap::IndexMap;
-use serde::{Deserialize, Serialize};
-
-// Conditional serde_json import for JSON handling
-#[cfg(feature = "unrealmodding-compat")]
-use serde_json;
-
-// Hash algorithm imports for Phase 3
-#[cfg(feature = "unrealmodding-compat")]
-use fnv::FnvHasher;
-#[cfg(feature = "unrealmodding-compat")]
-use std::hash::{Hash, Hasher};
-#[cfg(feature = "unrealmodding-compat")]
-use lru::LruCache;
-#[cfg(feature = "unrealmodding-compat")]
-use std::num::NonZeroUsize;
-
-// Bitflags for UE5 features
-#[cfg(feature = "unrealmodding-compat")]
-use bitflags::bitflags;
-
-// Conditional imports for unrealmodding-compat feature
-#[cfg(feature = "unrealmodding-compat")]
-use crate::{Provider, Result};
-
+pub mod asset;
+pub mod bulk_data;
+pub mod codegen;
+pub mod compression;
 pub mod containers;
+pub mod conversion;
+pub mod curve;
+pub mod custom_versions;
+pub mod data_table;
+pub mod de;
+pub mod dependency;
 pub mod error;
 pub mod exports;
+pub mod name_map;
+pub mod native_structs;
+pub mod object_versions;
+pub mod package_summary;
 pub mod properties;
+pub mod property_writer;
+pub mod query;
 pub mod reader;
+pub mod ser;
+pub mod text;
+pub mod typed_export;
 pub mod types;
 pub mod unversioned;
+pub mod usmap;
 pub mod versions;
+pub mod visit;
+pub mod zen_package;
 
+pub use asset::*;
+pub use bulk_data::*;
+pub use codegen::*;
+pub use compression::*;
 pub use containers::*;
+pub use conversion::*;
+pub use curve::*;
+pub use custom_versions::*;
+pub use data_table::*;
+pub use de::*;
+pub use dependency::*;
 pub use error::*;
 pub use exports::*;
+pub use name_map::*;
+pub use native_structs::*;
+pub use object_versions::*;
+pub use package_summary::*;
 pub use properties::*;
+pub use property_writer::*;
+pub use query::*;
 pub use reader::*;
+pub use ser::*;
+pub use text::*;
+pub use typed_export::*;
 pub use types::*;
 pub use unversioned::*;
+pub use usmap::*;
 pub use versions::*;
+pub use visit::*;
+pub use zen_package::*;
 
 // ============================================================================
 // CAST MACRO - Essential for Stove compatibility
@@ -87,10 +102,46 @@ pub use versions::*;
 /// Cast macro for property type conversions
 /// 
 /// This macro provides safe casting between different property types,
-/// essential for Stove's property manipulation needs.
+/// essential for Stove's property manipulation needs. Alias variants match
+/// each other, e.g. `cast!(property, Array)` also accepts `Property::ArrayProperty`.
 #[cfg(feature = "unrealmodding-compat")]
 #[macro_export]
 macro_rules! cast {
+    (@any $property:expr, [$($variant:ident),+]) => {
+        match $property {
+            $(Property::$variant(value))|+ => Some(value),
+            _ => None,
+        }
+    };
+    (@any $property:expr, [$($variant:ident),+] as $target:ty) => {
+        match $property {
+            $(Property::$variant(value))|+ => Some(value as $target),
+            _ => None,
+        }
+    };
+    ($property:expr, Byte $(as $target:ty)?) => { $crate::cast!(@any $property, [Byte, UInt8] $(as $target)?) };
+    ($property:expr, UInt8 $(as $target:ty)?) => { $crate::cast!(@any $property, [Byte, UInt8] $(as $target)?) };
+    ($property:expr, Object $(as $target:ty)?) => { $crate::cast!(@any $property, [Object, ObjectProperty] $(as $target)?) };
+    ($property:expr, ObjectProperty $(as $target:ty)?) => { $crate::cast!(@any $property, [Object, ObjectProperty] $(as $target)?) };
+    ($property:expr, Array $(as $target:ty)?) => { $crate::cast!(@any $property, [Array, ArrayProperty] $(as $target)?) };
+    ($property:expr, ArrayProperty $(as $target:ty)?) => { $crate::cast!(@any $property, [Array, ArrayProperty] $(as $target)?) };
+    ($property:expr, Set $(as $target:ty)?) => { $crate::cast!(@any $property, [Set, SetProperty] $(as $target)?) };
+    ($property:expr, SetProperty $(as $target:ty)?) => { $crate::cast!(@any $property, [Set, SetProperty] $(as $target)?) };
+    ($property:expr, SoftObjectPath $(as $target:ty)?) => {
+        $crate::cast!(@any $property, [SoftObjectPath, SoftObjectPathProperty, SoftAssetPathProperty] $(as $target)?)
+    };
+    ($property:expr, SoftObjectPathProperty $(as $target:ty)?) => {
+        $crate::cast!(@any $property, [SoftObjectPath, SoftObjectPathProperty, SoftAssetPathProperty] $(as $target)?)
+    };
+    ($property:expr, SoftAssetPathProperty $(as $target:ty)?) => {
+        $crate::cast!(@any $property, [SoftObjectPath, SoftObjectPathProperty, SoftAssetPathProperty] $(as $target)?)
+    };
+    ($property:expr, SoftClassPath $(as $target:ty)?) => {
+        $crate::cast!(@any $property, [SoftClassPath, SoftClassPathProperty] $(as $target)?)
+    };
+    ($property:expr, SoftClassPathProperty $(as $target:ty)?) => {
+        $crate::cast!(@any $property, [SoftClassPath, SoftClassPathProperty] $(as $target)?)
+    };
     ($property:expr, $variant:ident) => {
         match $property {
             Property::$variant(value) => Some(value),
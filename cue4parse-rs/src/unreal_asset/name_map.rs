@@ -0,0 +1,348 @@
+//! Hashed package name map
+//!
+//! Lookups follow FName rules: names compare case-insensitively over ASCII,
+//! while the map keeps the casing each entry was first added with. Indices are
+//! stable, entries are never removed or reordered.
+
+use std::io::{Read, Write};
+
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt, WriteBytesExt};
+use fnv::FnvHashMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+
+/// Hash algorithm id written before the hashes of a name batch
+pub const NAME_BATCH_HASH_VERSION: u64 = 0xC1640000;
+
+/// Longest name a serialized name header can hold, `NAME_SIZE - 1`
+pub const MAX_NAME_LENGTH: usize = 1023;
+
+/// Package name map with O(1) lookup
+#[derive(Debug, Clone, Default)]
+pub struct NameMap {
+    names: Vec<String>,
+    lookup: FnvHashMap<String, i32>,
+    folded_lookup: FnvHashMap<String, i32>,
+}
+
+impl NameMap {
+    /// Create an empty name map
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a name map from serialized names, keeping duplicates at their indices
+    pub fn from_names(names: impl IntoIterator<Item = String>) -> Self {
+        let mut name_map = Self::new();
+        for name in names {
+            name_map.add_name_reference(name, true);
+        }
+        name_map
+    }
+
+    /// Add a name, returning the index of an existing equal name unless `force_add_duplicates` is set
+    pub fn add_name_reference(&mut self, name: String, force_add_duplicates: bool) -> i32 {
+        if !force_add_duplicates {
+            if let Some(index) = self.search_name_reference(&name) {
+                return index;
+            }
+        }
+        let index = self.names.len() as i32;
+        self.folded_lookup.entry(name.to_ascii_lowercase()).or_insert(index);
+        self.lookup.entry(name.clone()).or_insert(index);
+        self.names.push(name);
+        index
+    }
+
+    /// Find a name, preferring an exact match over one differing only in case
+    pub fn search_name_reference(&self, name: &str) -> Option<i32> {
+        self.lookup.get(name)
+            .or_else(|| self.folded_lookup.get(&name.to_ascii_lowercase()))
+            .copied()
+    }
+
+    /// Get the name at an index
+    pub fn get_name_reference(&self, index: i32) -> Option<&str> {
+        usize::try_from(index).ok().and_then(|i| self.names.get(i)).map(String::as_str)
+    }
+
+    /// Get every name in index order
+    pub fn get_name_map_index_list(&self) -> &[String] {
+        &self.names
+    }
+
+    /// Read a Zen name batch (`LoadNameBatch`)
+    pub fn read_name_batch<R: Read>(reader: &mut R) -> UnrealAssetResult<Self> {
+        let count = reader.read_u32::<LittleEndian>()? as usize;
+        if count == 0 {
+            return Ok(Self::new());
+        }
+        let string_bytes = reader.read_u32::<LittleEndian>()? as usize;
+        let hash_version = reader.read_u64::<LittleEndian>()?;
+        if hash_version != NAME_BATCH_HASH_VERSION {
+            return Err(UnrealAssetError::UnsupportedVersion(format!("Name batch hash version {:#x}", hash_version)));
+        }
+
+        // Hashes are recomputed on write, so they are skipped
+        for _ in 0..count {
+            reader.read_u64::<LittleEndian>()?;
+        }
+        let mut headers = Vec::new();
+        for _ in 0..count {
+            headers.push(reader.read_u16::<BigEndian>()?);
+        }
+
+        let mut strings = Vec::new();
+        reader.take(string_bytes as u64).read_to_end(&mut strings)?;
+        if strings.len() != string_bytes {
+            return Err(UnrealAssetError::InvalidData("Name batch string data truncated".to_string()));
+        }
+        // The header section is always even sized, so UTF-16 alignment is relative to the string data
+        let mut position = 0;
+        let mut name_map = Self::new();
+        for header in headers {
+            let is_utf16 = header & 0x8000 != 0;
+            let length = (header & 0x7FFF) as usize;
+            let name = if is_utf16 {
+                position += position % 2;
+                let bytes = strings.get(position..position + length * 2)
+                    .ok_or_else(|| UnrealAssetError::InvalidData("Name batch string data truncated".to_string()))?;
+                position += length * 2;
+                let units: Vec<u16> = bytes.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect();
+                String::from_utf16_lossy(&units)
+            } else {
+                let bytes = strings.get(position..position + length)
+                    .ok_or_else(|| UnrealAssetError::InvalidData("Name batch string data truncated".to_string()))?;
+                position += length;
+                bytes.iter().map(|&b| b as char).collect()
+            };
+            name_map.add_name_reference(name, true);
+        }
+        Ok(name_map)
+    }
+
+    /// Write a Zen name batch (`SaveNameBatch`)
+    pub fn write_name_batch<W: Write>(&self, writer: &mut W) -> UnrealAssetResult<()> {
+        writer.write_u32::<LittleEndian>(self.names.len() as u32)?;
+        if self.names.is_empty() {
+            return Ok(());
+        }
+
+        let mut headers = Vec::with_capacity(self.names.len());
+        let mut strings = Vec::new();
+        for name in &self.names {
+            let is_utf16 = !is_pure_ansi(name);
+            let length = if is_utf16 { name.encode_utf16().count() } else { name.chars().count() };
+            if length > MAX_NAME_LENGTH {
+                return Err(UnrealAssetError::InvalidData(format!("Name of {} characters is too long", length)));
+            }
+            headers.push(length as u16 | if is_utf16 { 0x8000 } else { 0 });
+            if is_utf16 {
+                if strings.len() % 2 == 1 {
+                    strings.push(0);
+                }
+                for unit in name.encode_utf16() {
+                    strings.extend_from_slice(&unit.to_le_bytes());
+                }
+            } else {
+                strings.extend(latin1_bytes(name));
+            }
+        }
+
+        writer.write_u32::<LittleEndian>(strings.len() as u32)?;
+        writer.write_u64::<LittleEndian>(NAME_BATCH_HASH_VERSION)?;
+        for name in &self.names {
+            writer.write_u64::<LittleEndian>(name_hash(name))?;
+        }
+        for header in headers {
+            writer.write_u16::<BigEndian>(header)?;
+        }
+        writer.write_all(&strings)?;
+        Ok(())
+    }
+}
+
+impl std::ops::Deref for NameMap {
+    type Target = [String];
+
+    fn deref(&self) -> &[String] {
+        &self.names
+    }
+}
+
+impl PartialEq for NameMap {
+    fn eq(&self, other: &Self) -> bool {
+        self.names == other.names
+    }
+}
+
+impl Eq for NameMap {}
+
+impl From<Vec<String>> for NameMap {
+    fn from(names: Vec<String>) -> Self {
+        Self::from_names(names)
+    }
+}
+
+impl Serialize for NameMap {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.names.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for NameMap {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<String>::deserialize(deserializer).map(Self::from_names)
+    }
+}
+
+/// Hash of a name as stored in a Zen name batch
+///
+/// CityHash64 of the ASCII-lowercased name, as ANSI bytes or UTF-16LE for wide names.
+pub fn name_hash(name: &str) -> u64 {
+    let lower = name.to_ascii_lowercase();
+    if is_pure_ansi(&lower) {
+        city_hash_64(&latin1_bytes(&lower).collect::<Vec<_>>())
+    } else {
+        let bytes: Vec<u8> = lower.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        city_hash_64(&bytes)
+    }
+}
+
+/// Whether a name fits in ANSI (Latin-1) characters, which the engine stores one byte each
+fn is_pure_ansi(name: &str) -> bool {
+    name.chars().all(|c| u32::from(c) <= 0xFF)
+}
+
+fn latin1_bytes(name: &str) -> impl Iterator<Item = u8> + '_ {
+    name.chars().map(|c| c as u8)
+}
+
+const K0: u64 = 0xc3a5c85c97cb3127;
+const K1: u64 = 0xb492b66fbe98f273;
+const K2: u64 = 0x9ae16a3b2f90404f;
+
+fn fetch64(data: &[u8], at: usize) -> u64 {
+    u64::from_le_bytes(data[at..at + 8].try_into().unwrap())
+}
+
+fn fetch32(data: &[u8], at: usize) -> u64 {
+    u64::from(u32::from_le_bytes(data[at..at + 4].try_into().unwrap()))
+}
+
+fn shift_mix(value: u64) -> u64 {
+    value ^ (value >> 47)
+}
+
+fn hash_len16(u: u64, v: u64, mul: u64) -> u64 {
+    let a = shift_mix((u ^ v).wrapping_mul(mul));
+    let b = shift_mix((v ^ a).wrapping_mul(mul));
+    b.wrapping_mul(mul)
+}
+
+fn hash_128_to_64(u: u64, v: u64) -> u64 {
+    hash_len16(u, v, 0x9ddfea08eb382d69)
+}
+
+fn hash_len_0_to_16(data: &[u8]) -> u64 {
+    let len = data.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    if len >= 8 {
+        let a = fetch64(data, 0).wrapping_add(K2);
+        let b = fetch64(data, len - 8);
+        let c = b.rotate_right(37).wrapping_mul(mul).wrapping_add(a);
+        let d = a.rotate_right(25).wrapping_add(b).wrapping_mul(mul);
+        hash_len16(c, d, mul)
+    } else if len >= 4 {
+        let a = fetch32(data, 0);
+        hash_len16((len as u64).wrapping_add(a << 3), fetch32(data, len - 4), mul)
+    } else if len > 0 {
+        let y = u32::from(data[0]) + (u32::from(data[len >> 1]) << 8);
+        let z = len as u32 + (u32::from(data[len - 1]) << 2);
+        shift_mix(u64::from(y).wrapping_mul(K2) ^ u64::from(z).wrapping_mul(K0)).wrapping_mul(K2)
+    } else {
+        K2
+    }
+}
+
+fn hash_len_17_to_32(data: &[u8]) -> u64 {
+    let len = data.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(data, 0).wrapping_mul(K1);
+    let b = fetch64(data, 8);
+    let c = fetch64(data, len - 8).wrapping_mul(mul);
+    let d = fetch64(data, len - 16).wrapping_mul(K2);
+    hash_len16(
+        a.wrapping_add(b).rotate_right(43).wrapping_add(c.rotate_right(30)).wrapping_add(d),
+        a.wrapping_add(b.wrapping_add(K2).rotate_right(18)).wrapping_add(c),
+        mul,
+    )
+}
+
+fn hash_len_33_to_64(data: &[u8]) -> u64 {
+    let len = data.len();
+    let mul = K2.wrapping_add(len as u64 * 2);
+    let a = fetch64(data, 0).wrapping_mul(K2);
+    let b = fetch64(data, 8);
+    let c = fetch64(data, len - 24);
+    let d = fetch64(data, len - 32);
+    let e = fetch64(data, 16).wrapping_mul(K2);
+    let f = fetch64(data, 24).wrapping_mul(9);
+    let g = fetch64(data, len - 8);
+    let h = fetch64(data, len - 16).wrapping_mul(mul);
+    let u = a.wrapping_add(g).rotate_right(43)
+        .wrapping_add(b.rotate_right(30).wrapping_add(c).wrapping_mul(9));
+    let v = (a.wrapping_add(g) ^ d).wrapping_add(f).wrapping_add(1);
+    let w = u.wrapping_add(v).wrapping_mul(mul).swap_bytes().wrapping_add(h);
+    let x = e.wrapping_add(f).rotate_right(42).wrapping_add(c);
+    let y = v.wrapping_add(w).wrapping_mul(mul).swap_bytes().wrapping_add(g).wrapping_mul(mul);
+    let z = e.wrapping_add(f).wrapping_add(c);
+    let a = x.wrapping_add(z).wrapping_mul(mul).wrapping_add(y).swap_bytes().wrapping_add(b);
+    let b = shift_mix(z.wrapping_add(a).wrapping_mul(mul).wrapping_add(d).wrapping_add(h)).wrapping_mul(mul);
+    b.wrapping_add(x)
+}
+
+fn weak_hash_len_32_with_seeds(data: &[u8], at: usize, a: u64, b: u64) -> (u64, u64) {
+    let (w, x, y, z) = (fetch64(data, at), fetch64(data, at + 8), fetch64(data, at + 16), fetch64(data, at + 24));
+    let a = a.wrapping_add(w);
+    let b = b.wrapping_add(a).wrapping_add(z).rotate_right(21);
+    let c = a;
+    let a = a.wrapping_add(x).wrapping_add(y);
+    let b = b.wrapping_add(a.rotate_right(44));
+    (a.wrapping_add(z), b.wrapping_add(c))
+}
+
+/// CityHash64 v1.1, as used by Unreal for name and package id hashes
+pub fn city_hash_64(data: &[u8]) -> u64 {
+    let len = data.len();
+    if len <= 16 {
+        return hash_len_0_to_16(data);
+    } else if len <= 32 {
+        return hash_len_17_to_32(data);
+    } else if len <= 64 {
+        return hash_len_33_to_64(data);
+    }
+
+    let mut x = fetch64(data, len - 40);
+    let mut y = fetch64(data, len - 16).wrapping_add(fetch64(data, len - 56));
+    let mut z = hash_128_to_64(fetch64(data, len - 48).wrapping_add(len as u64), fetch64(data, len - 24));
+    let mut v = weak_hash_len_32_with_seeds(data, len - 64, len as u64, z);
+    let mut w = weak_hash_len_32_with_seeds(data, len - 32, y.wrapping_add(K1), x);
+    x = x.wrapping_mul(K1).wrapping_add(fetch64(data, 0));
+
+    for at in (0..(len - 1) & !63).step_by(64) {
+        x = x.wrapping_add(y).wrapping_add(v.0).wrapping_add(fetch64(data, at + 8)).rotate_right(37).wrapping_mul(K1);
+        y = y.wrapping_add(v.1).wrapping_add(fetch64(data, at + 48)).rotate_right(42).wrapping_mul(K1);
+        x ^= w.1;
+        y = y.wrapping_add(v.0).wrapping_add(fetch64(data, at + 40));
+        z = z.wrapping_add(w.0).rotate_right(33).wrapping_mul(K1);
+        v = weak_hash_len_32_with_seeds(data, at, v.1.wrapping_mul(K1), x.wrapping_add(w.0));
+        w = weak_hash_len_32_with_seeds(data, at + 32, z.wrapping_add(w.1), y.wrapping_add(fetch64(data, at + 16)));
+        std::mem::swap(&mut z, &mut x);
+    }
+    hash_128_to_64(
+        hash_128_to_64(v.0, w.0).wrapping_add(shift_mix(y).wrapping_mul(K1)).wrapping_add(z),
+        hash_128_to_64(v.1, w.1).wrapping_add(x),
+    )
+}
@@ -0,0 +1,270 @@
+//! Native serialization of the core math structs
+//!
+//! These structs are written with their own binary layout instead of as tagged
+//! properties. Floating point components are floats before UE5's
+//! `LARGE_WORLD_COORDINATES` and doubles after, see
+//! [`ArchiveTrait::has_large_world_coordinates`](crate::unreal_asset::reader::ArchiveTrait::has_large_world_coordinates).
+//! `LinearColor` stays a float struct in every version.
+
+use byteorder::ReadBytesExt;
+use uuid::Uuid;
+
+use crate::unreal_asset::error::UnrealAssetResult;
+use crate::unreal_asset::reader::{ArchiveReader, ArchiveWriter};
+use crate::unreal_asset::types::{
+    BoundingBox, Color, Guid, IntPoint, LinearColor, Matrix, PackageIndexTrait, Plane, Quat, Rotator, Transform,
+    Vector, Vector2D, Vector4,
+};
+
+/// A struct with a fixed binary layout
+pub trait NativeStruct: Sized {
+    /// Name of the struct in property tags, without the `F` prefix
+    const STRUCT_NAME: &'static str;
+
+    /// Read the struct
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized;
+
+    /// Write the struct
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized;
+}
+
+/// Implement [`NativeStruct`] for a struct of large world coordinate components
+macro_rules! lwc_struct {
+    ($ty:ident, $name:literal, [$($field:ident),+]) => {
+        impl NativeStruct for $ty {
+            const STRUCT_NAME: &'static str = $name;
+
+            fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+            where
+                Index: PackageIndexTrait,
+                A: ArchiveReader<Index> + ?Sized,
+            {
+                Ok(Self {
+                    $($field: ar.read_lwc_float()?,)+
+                })
+            }
+
+            fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+            where
+                Index: PackageIndexTrait,
+                W: ArchiveWriter<Index> + ?Sized,
+            {
+                $(ar.write_lwc_float(self.$field)?;)+
+                Ok(())
+            }
+        }
+    };
+}
+
+lwc_struct!(Vector, "Vector", [x, y, z]);
+lwc_struct!(Vector2D, "Vector2D", [x, y]);
+lwc_struct!(Vector4, "Vector4", [x, y, z, w]);
+lwc_struct!(Rotator, "Rotator", [pitch, yaw, roll]);
+lwc_struct!(Quat, "Quat", [x, y, z, w]);
+lwc_struct!(Plane, "Plane", [x, y, z, w]);
+
+impl NativeStruct for Transform {
+    const STRUCT_NAME: &'static str = "Transform";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        let rotation = Quat::read(ar)?;
+        let location = Vector::read(ar)?;
+        let scale = Vector::read(ar)?;
+        Ok(Self { location, rotation, scale })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        self.rotation.write(ar)?;
+        self.location.write(ar)?;
+        self.scale.write(ar)
+    }
+}
+
+impl NativeStruct for BoundingBox {
+    const STRUCT_NAME: &'static str = "Box";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        let min = Vector::read(ar)?;
+        let max = Vector::read(ar)?;
+        let is_valid = ar.read_bool()?;
+        Ok(Self { min, max, is_valid })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        self.min.write(ar)?;
+        self.max.write(ar)?;
+        ar.write_bool(self.is_valid)?;
+        Ok(())
+    }
+}
+
+impl NativeStruct for Matrix {
+    const STRUCT_NAME: &'static str = "Matrix";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        let mut m = [[0.0; 4]; 4];
+        for row in m.iter_mut() {
+            for value in row.iter_mut() {
+                *value = ar.read_lwc_float()?;
+            }
+        }
+        Ok(Self { m })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        for value in self.m.iter().flatten() {
+            ar.write_lwc_float(*value)?;
+        }
+        Ok(())
+    }
+}
+
+impl NativeStruct for LinearColor {
+    const STRUCT_NAME: &'static str = "LinearColor";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        Ok(Self {
+            r: ar.read_f32_endian()?,
+            g: ar.read_f32_endian()?,
+            b: ar.read_f32_endian()?,
+            a: ar.read_f32_endian()?,
+        })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        for component in [self.r, self.g, self.b, self.a] {
+            ar.write_f32_endian(component)?;
+        }
+        Ok(())
+    }
+}
+
+impl NativeStruct for Color {
+    const STRUCT_NAME: &'static str = "Color";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        // Stored in BGRA order
+        let [b, g, r, a] = [ar.read_u8()?, ar.read_u8()?, ar.read_u8()?, ar.read_u8()?];
+        Ok(Self { r, g, b, a })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        ar.write_all(&[self.b, self.g, self.r, self.a])?;
+        Ok(())
+    }
+}
+
+impl NativeStruct for IntPoint {
+    const STRUCT_NAME: &'static str = "IntPoint";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        Ok(Self {
+            x: ar.read_i32_endian()?,
+            y: ar.read_i32_endian()?,
+        })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        ar.write_i32_endian(self.x)?;
+        ar.write_i32_endian(self.y)?;
+        Ok(())
+    }
+}
+
+impl NativeStruct for Guid {
+    const STRUCT_NAME: &'static str = "Guid";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        Ok([ar.read_u32_endian()?, ar.read_u32_endian()?, ar.read_u32_endian()?, ar.read_u32_endian()?])
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        for component in self {
+            ar.write_u32_endian(*component)?;
+        }
+        Ok(())
+    }
+}
+
+impl NativeStruct for Uuid {
+    const STRUCT_NAME: &'static str = "Guid";
+
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        Ok(ar.read_guid()?)
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        ar.write_guid(self)?;
+        Ok(())
+    }
+}
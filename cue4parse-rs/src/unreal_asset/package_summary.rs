@@ -0,0 +1,731 @@
+//! Legacy package header reading
+//!
+//! `FPackageFileSummary` starts every `.uasset` and locates the name, import and
+//! export tables that follow it. Which fields are present depends on the legacy
+//! file version and the UE4/UE5 file versions stored at the start of the summary.
+
+use std::io::{Read, Seek, SeekFrom};
+
+use bitflags::bitflags;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::exports::{Export, Import};
+use crate::unreal_asset::name_map::NameMap;
+use crate::unreal_asset::object_versions::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version};
+use crate::unreal_asset::reader::{ArchiveReader, ArchiveTrait, BinaryArchive, Endianness};
+use crate::unreal_asset::types::PackageIndex;
+use crate::unreal_asset::versions::{
+    get_custom_version_registry, CustomVersion, EngineVersion, ObjectVersion, ObjectVersionUE5,
+};
+
+/// Package file tag found at the start of every `.uasset`
+pub const PACKAGE_FILE_TAG: u32 = 0x9E2A83C1;
+/// Package file tag of packages saved with the other endianness
+pub const PACKAGE_FILE_TAG_SWAPPED: u32 = 0xC1832A9E;
+
+/// Newest legacy file version this reader understands
+const CURRENT_LEGACY_FILE_VERSION: i32 = -9;
+
+/// `PKG_FilterEditorOnly`, set on cooked packages
+pub const PKG_FILTER_EDITOR_ONLY: u32 = 0x80000000;
+
+/// `PKG_UnversionedProperties`, set on packages using unversioned property serialization
+pub const PKG_UNVERSIONED_PROPERTIES: u32 = 0x2000;
+
+/// Valid bits of the summary compression flags
+const COMPRESSION_FLAGS_MASK: u32 = 0x1FF;
+
+/// Generation info recorded in the package summary
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenerationInfo {
+    pub export_count: i32,
+    pub name_count: i32,
+}
+
+/// Engine version a package was saved with, `FEngineVersion`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineVersionInfo {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+    /// Changelist, with the top bit set for licensee changelists
+    pub changelist: u32,
+    pub branch: String,
+}
+
+impl EngineVersionInfo {
+    /// Read an `FEngineVersion`
+    pub fn read<A: ArchiveReader<PackageIndex>>(ar: &mut A) -> UnrealAssetResult<Self> {
+        Ok(Self {
+            major: ar.read_u16_endian()?,
+            minor: ar.read_u16_endian()?,
+            patch: ar.read_u16_endian()?,
+            changelist: ar.read_u32_endian()?,
+            branch: ar.read_fstring()?.unwrap_or_default(),
+        })
+    }
+
+    /// Changelist without the licensee bit
+    pub fn get_changelist(&self) -> u32 {
+        self.changelist & 0x7FFFFFFF
+    }
+
+    /// Whether the changelist is a licensee changelist
+    pub fn is_licensee_version(&self) -> bool {
+        self.changelist & 0x80000000 != 0
+    }
+}
+
+impl std::fmt::Display for EngineVersionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}-{}", self.major, self.minor, self.patch, self.get_changelist())?;
+        if !self.branch.is_empty() {
+            write!(f, "+{}", self.branch)?;
+        }
+        Ok(())
+    }
+}
+
+/// Package file summary
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PackageFileSummary {
+    /// Package file tag, `PACKAGE_FILE_TAG` for little-endian packages
+    pub tag: u32,
+    /// Byte order detected from the package file tag
+    pub endianness: Endianness,
+    /// Legacy file version, negative for all modern packages
+    pub legacy_file_version: i32,
+    /// UE3 version written by legacy file versions other than -4
+    pub legacy_ue3_version: i32,
+    /// UE4 file version, the archive's version for unversioned packages
+    pub file_version_ue4: i32,
+    /// UE5 file version, 0 before UE5
+    pub file_version_ue5: i32,
+    /// Licensee UE4 version
+    pub file_licensee_version: i32,
+    /// Hash of the saved package, replaces `guid` since `PACKAGE_SAVED_HASH`
+    pub saved_hash: Option<[u8; 20]>,
+    /// Custom versions the package was saved with
+    pub custom_versions: Vec<CustomVersion>,
+    /// Size of the package header, the offset of the first export in `.uasset` + `.uexp` packages
+    pub total_header_size: i32,
+    /// Folder name the package was saved with, usually "None"
+    pub package_name: String,
+    /// Package flags
+    pub package_flags: u32,
+    pub name_count: i32,
+    pub name_offset: i32,
+    pub soft_object_paths_count: i32,
+    pub soft_object_paths_offset: i32,
+    /// Localization id of editor packages
+    pub localization_id: Option<String>,
+    pub gatherable_text_data_count: i32,
+    pub gatherable_text_data_offset: i32,
+    pub export_count: i32,
+    pub export_offset: i32,
+    pub import_count: i32,
+    pub import_offset: i32,
+    pub cell_export_count: i32,
+    pub cell_export_offset: i32,
+    pub cell_import_count: i32,
+    pub cell_import_offset: i32,
+    pub meta_data_offset: i32,
+    pub depends_offset: i32,
+    pub soft_package_references_count: i32,
+    pub soft_package_references_offset: i32,
+    pub searchable_names_offset: i32,
+    pub thumbnail_table_offset: i32,
+    /// Package GUID, absent on packages saved with a saved hash
+    pub guid: Option<Uuid>,
+    /// Persistent GUID of editor packages
+    pub persistent_guid: Option<Uuid>,
+    pub generations: Vec<GenerationInfo>,
+    pub saved_by_engine_version: Option<EngineVersionInfo>,
+    pub compatible_with_engine_version: Option<EngineVersionInfo>,
+    /// `ECompressionFlags` the package was saved with
+    pub compression_flags: u32,
+    /// Value used to determine the source of the package, e.g. licensee or Epic
+    pub package_source: u32,
+    /// Deprecated list of packages to cook with this one
+    pub additional_packages_to_cook: Vec<String>,
+    pub asset_registry_data_offset: i32,
+    pub bulk_data_start_offset: i64,
+    pub world_tile_info_data_offset: i32,
+    pub chunk_ids: Vec<i32>,
+    /// Number of preload dependencies, -1 before `PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS`
+    pub preload_dependency_count: i32,
+    pub preload_dependency_offset: i32,
+    pub names_referenced_from_export_data_count: i32,
+    /// Offset of the payload table of contents, -1 if absent
+    pub payload_toc_offset: i64,
+    /// Offset of the data resource table, -1 if absent
+    pub data_resource_offset: i32,
+    /// Whether the package was saved without version information
+    pub unversioned: bool,
+}
+
+impl Default for PackageFileSummary {
+    fn default() -> Self {
+        Self {
+            tag: PACKAGE_FILE_TAG,
+            endianness: Endianness::Little,
+            legacy_file_version: -8,
+            legacy_ue3_version: 864,
+            file_version_ue4: 0,
+            file_version_ue5: 0,
+            file_licensee_version: 0,
+            saved_hash: None,
+            custom_versions: Vec::new(),
+            total_header_size: 0,
+            package_name: "None".to_string(),
+            package_flags: 0,
+            name_count: 0,
+            name_offset: 0,
+            soft_object_paths_count: 0,
+            soft_object_paths_offset: 0,
+            localization_id: None,
+            gatherable_text_data_count: 0,
+            gatherable_text_data_offset: 0,
+            export_count: 0,
+            export_offset: 0,
+            import_count: 0,
+            import_offset: 0,
+            cell_export_count: 0,
+            cell_export_offset: 0,
+            cell_import_count: 0,
+            cell_import_offset: 0,
+            meta_data_offset: 0,
+            depends_offset: 0,
+            soft_package_references_count: 0,
+            soft_package_references_offset: 0,
+            searchable_names_offset: 0,
+            thumbnail_table_offset: 0,
+            guid: None,
+            persistent_guid: None,
+            generations: Vec::new(),
+            saved_by_engine_version: None,
+            compatible_with_engine_version: None,
+            compression_flags: 0,
+            package_source: 0,
+            additional_packages_to_cook: Vec::new(),
+            asset_registry_data_offset: 0,
+            bulk_data_start_offset: 0,
+            world_tile_info_data_offset: 0,
+            chunk_ids: Vec::new(),
+            preload_dependency_count: -1,
+            preload_dependency_offset: 0,
+            names_referenced_from_export_data_count: 0,
+            payload_toc_offset: -1,
+            data_resource_offset: -1,
+            unversioned: true,
+        }
+    }
+}
+
+impl PackageFileSummary {
+    /// Read an `FPackageFileSummary`
+    ///
+    /// Unversioned packages take their file versions from the archive.
+    pub fn read<A: ArchiveReader<PackageIndex>>(ar: &mut A) -> UnrealAssetResult<Self> {
+        let tag = ar.read_u32::<LittleEndian>()?;
+        let Some(endianness) = Endianness::from_package_tag(tag) else {
+            return Err(UnrealAssetError::InvalidData(format!("Invalid package file tag 0x{:08X}", tag)));
+        };
+        // Byte swapped packages store every following primitive in the other byte order
+        ar.set_endianness(endianness);
+        let mut summary = Self {
+            tag,
+            endianness,
+            ..Self::default()
+        };
+
+        summary.legacy_file_version = ar.read_i32_endian()?;
+        if summary.legacy_file_version >= 0 {
+            return Err(UnrealAssetError::UnsupportedVersion("UE3 packages are not supported".to_string()));
+        }
+        if summary.legacy_file_version < CURRENT_LEGACY_FILE_VERSION {
+            return Err(UnrealAssetError::UnsupportedVersion(format!(
+                "Legacy file version {} is too new",
+                summary.legacy_file_version
+            )));
+        }
+
+        if summary.legacy_file_version != -4 {
+            summary.legacy_ue3_version = ar.read_i32_endian()?;
+        }
+        summary.file_version_ue4 = ar.read_i32_endian()?;
+        if summary.legacy_file_version <= -8 {
+            summary.file_version_ue5 = ar.read_i32_endian()?;
+        }
+        summary.file_licensee_version = ar.read_i32_endian()?;
+
+        if summary.file_version_ue5 >= EUnrealEngineObjectUE5Version::PACKAGE_SAVED_HASH as i32 {
+            let mut hash = [0u8; 20];
+            ar.read_exact(&mut hash)?;
+            summary.saved_hash = Some(hash);
+            summary.total_header_size = ar.read_i32_endian()?;
+        }
+
+        summary.custom_versions = read_custom_versions(ar, summary.legacy_file_version)?;
+
+        summary.unversioned =
+            summary.file_version_ue4 == 0 && summary.file_version_ue5 == 0 && summary.file_licensee_version == 0;
+        if summary.unversioned {
+            summary.file_version_ue4 = ar.get_object_version().get();
+            summary.file_version_ue5 = ar.get_object_version_ue5().get();
+        }
+        let ue4 = summary.file_version_ue4;
+        let ue5 = summary.file_version_ue5;
+
+        if ue5 < EUnrealEngineObjectUE5Version::PACKAGE_SAVED_HASH as i32 {
+            summary.total_header_size = ar.read_i32_endian()?;
+        }
+        summary.package_name = ar.read_fstring()?.unwrap_or_default();
+        summary.package_flags = ar.read_u32_endian()?;
+        let filter_editor_only = summary.is_filter_editor_only();
+
+        summary.name_count = ar.read_i32_endian()?;
+        summary.name_offset = ar.read_i32_endian()?;
+        if ue5 >= EUnrealEngineObjectUE5Version::ADD_SOFTOBJECTPATH_LIST as i32 {
+            summary.soft_object_paths_count = ar.read_i32_endian()?;
+            summary.soft_object_paths_offset = ar.read_i32_endian()?;
+        }
+        if !filter_editor_only && ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ADDED_PACKAGE_SUMMARY_LOCALIZATION_ID as i32 {
+            summary.localization_id = ar.read_fstring()?;
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_SERIALIZE_TEXT_IN_PACKAGES as i32 {
+            summary.gatherable_text_data_count = ar.read_i32_endian()?;
+            summary.gatherable_text_data_offset = ar.read_i32_endian()?;
+        }
+        summary.export_count = ar.read_i32_endian()?;
+        summary.export_offset = ar.read_i32_endian()?;
+        summary.import_count = ar.read_i32_endian()?;
+        summary.import_offset = ar.read_i32_endian()?;
+        if ue5 >= EUnrealEngineObjectUE5Version::VERSE_CELLS as i32 {
+            summary.cell_export_count = ar.read_i32_endian()?;
+            summary.cell_export_offset = ar.read_i32_endian()?;
+            summary.cell_import_count = ar.read_i32_endian()?;
+            summary.cell_import_offset = ar.read_i32_endian()?;
+        }
+        if ue5 >= EUnrealEngineObjectUE5Version::METADATA_SERIALIZATION_OFFSET as i32 {
+            summary.meta_data_offset = ar.read_i32_endian()?;
+        }
+        summary.depends_offset = ar.read_i32_endian()?;
+
+        // The rest of the summary differs in ways we can't predict outside the loadable range
+        let loadable = EUnrealEngineObjectUE4Version::VER_UE4_OLDEST_LOADABLE_PACKAGE as i32..=EUnrealEngineObjectUE4Version::AUTOMATIC_VERSION as i32;
+        if !loadable.contains(&ue4) {
+            summary.names_referenced_from_export_data_count = summary.name_count;
+            return Ok(summary);
+        }
+
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ADD_STRING_ASSET_REFERENCES_MAP as i32 {
+            summary.soft_package_references_count = ar.read_i32_endian()?;
+            summary.soft_package_references_offset = ar.read_i32_endian()?;
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ADDED_SEARCHABLE_NAMES as i32 {
+            summary.searchable_names_offset = ar.read_i32_endian()?;
+        }
+        summary.thumbnail_table_offset = ar.read_i32_endian()?;
+        if ue5 < EUnrealEngineObjectUE5Version::PACKAGE_SAVED_HASH as i32 {
+            summary.guid = Some(ar.read_guid()?);
+        }
+        if !filter_editor_only {
+            summary.persistent_guid = match ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ADDED_PACKAGE_OWNER as i32 {
+                true => Some(ar.read_guid()?),
+                false => summary.guid,
+            };
+            // The owner persistent GUID only existed until VER_UE4_NON_OUTER_PACKAGE_IMPORT
+            if (518..520).contains(&ue4) {
+                ar.read_guid()?;
+            }
+        }
+
+        summary.generations = ArchiveReader::read_array(ar, |ar| {
+            Ok(GenerationInfo {
+                export_count: ar.read_i32_endian()?,
+                name_count: ar.read_i32_endian()?,
+            })
+        })?;
+
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ENGINE_VERSION_OBJECT as i32 {
+            let mut version = EngineVersionInfo::read(ar)?;
+            fix_corrupt_engine_version(ue4, &mut version);
+            summary.saved_by_engine_version = Some(version);
+        } else {
+            let changelist = ar.read_u32_endian()?;
+            if changelist != 0 {
+                summary.saved_by_engine_version = Some(EngineVersionInfo {
+                    major: 4,
+                    changelist,
+                    ..EngineVersionInfo::default()
+                });
+            }
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_PACKAGE_SUMMARY_HAS_COMPATIBLE_ENGINE_VERSION as i32 {
+            let mut version = EngineVersionInfo::read(ar)?;
+            fix_corrupt_engine_version(ue4, &mut version);
+            summary.compatible_with_engine_version = Some(version);
+        } else {
+            summary.compatible_with_engine_version = summary.saved_by_engine_version.clone();
+        }
+
+        summary.compression_flags = ar.read_u32_endian()?;
+        if summary.compression_flags & !COMPRESSION_FLAGS_MASK != 0 {
+            return Err(UnrealAssetError::InvalidData(format!(
+                "Invalid compression flags 0x{:X}",
+                summary.compression_flags
+            )));
+        }
+        let compressed_chunks = ar.read_i32_endian()?;
+        if compressed_chunks != 0 {
+            return Err(UnrealAssetError::UnsupportedVersion(
+                "Package level compression is not supported".to_string(),
+            ));
+        }
+
+        summary.package_source = ar.read_u32_endian()?;
+        summary.additional_packages_to_cook =
+            ArchiveReader::read_array(ar, |ar| Ok(ar.read_fstring()?.unwrap_or_default()))?;
+        if summary.legacy_file_version > -7 {
+            let texture_allocations = ar.read_i32_endian()?;
+            if texture_allocations != 0 {
+                return Err(UnrealAssetError::InvalidData(
+                    "Texture allocation info is no longer supported".to_string(),
+                ));
+            }
+        }
+
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ASSET_REGISTRY_TAGS as i32 {
+            summary.asset_registry_data_offset = ar.read_i32_endian()?;
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_SUMMARY_HAS_BULKDATA_OFFSET as i32 {
+            summary.bulk_data_start_offset = ar.read_i64_endian()?;
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_WORLD_LEVEL_INFO as i32 {
+            summary.world_tile_info_data_offset = ar.read_i32_endian()?;
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_CHANGED_CHUNKID_TO_BE_AN_ARRAY_OF_CHUNKIDS as i32 {
+            summary.chunk_ids = ArchiveReader::read_array(ar, |ar| Ok(ar.read_i32_endian()?))?;
+        } else if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_ADDED_CHUNKID_TO_ASSETDATA_AND_UPACKAGE as i32 {
+            let chunk_id = ar.read_i32_endian()?;
+            if chunk_id >= 0 {
+                summary.chunk_ids.push(chunk_id);
+            }
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS as i32 {
+            summary.preload_dependency_count = ar.read_i32_endian()?;
+            summary.preload_dependency_offset = ar.read_i32_endian()?;
+        }
+
+        summary.names_referenced_from_export_data_count = match ue5 >= EUnrealEngineObjectUE5Version::NAMES_REFERENCED_FROM_EXPORT_DATA as i32 {
+            true => ar.read_i32_endian()?,
+            false => summary.name_count,
+        };
+        if ue5 >= EUnrealEngineObjectUE5Version::PAYLOAD_TOC as i32 {
+            summary.payload_toc_offset = ar.read_i64_endian()?;
+        }
+        if ue5 >= EUnrealEngineObjectUE5Version::DATA_RESOURCES as i32 {
+            summary.data_resource_offset = ar.read_i32_endian()?;
+        }
+
+        Ok(summary)
+    }
+
+    /// Whether the package was cooked without editor-only data
+    pub fn is_filter_editor_only(&self) -> bool {
+        self.package_flags & PKG_FILTER_EDITOR_ONLY != 0
+    }
+
+    /// UE4 file version of the package
+    pub fn object_version(&self) -> ObjectVersion {
+        ObjectVersion::new(self.file_version_ue4)
+    }
+
+    /// UE5 file version of the package
+    pub fn object_version_ue5(&self) -> ObjectVersionUE5 {
+        ObjectVersionUE5::new(self.file_version_ue5)
+    }
+
+    /// Newest engine release that could have saved this package
+    pub fn engine_version(&self) -> Option<EngineVersion> {
+        EngineVersion::from_object_versions(self.file_version_ue4, self.file_version_ue5)
+    }
+}
+
+/// Read the custom version container in the format used by `legacy_file_version`
+pub(crate) fn read_custom_versions<A: ArchiveReader<PackageIndex>>(
+    ar: &mut A,
+    legacy_file_version: i32,
+) -> UnrealAssetResult<Vec<CustomVersion>> {
+    let registry = get_custom_version_registry();
+    let named = |guid: Uuid, version: i32| {
+        let friendly_name = registry.get(&guid).map(|v| v.friendly_name.clone()).unwrap_or_default();
+        CustomVersion::new(guid, version, friendly_name)
+    };
+    match legacy_file_version {
+        // Enum tags, stored as the last component of the GUID
+        -2 => ArchiveReader::read_array(ar, |ar| {
+            let tag = ar.read_u32_endian()?;
+            let version = ar.read_i32_endian()?;
+            let mut bytes = [0u8; 16];
+            bytes[12..].copy_from_slice(&tag.to_le_bytes());
+            Ok(named(Uuid::from_bytes(bytes), version))
+        }),
+        -5..=-3 => ArchiveReader::read_array(ar, |ar| {
+            let guid = ar.read_guid()?;
+            let version = ar.read_i32_endian()?;
+            let friendly_name = ar.read_fstring()?.unwrap_or_default();
+            Ok(CustomVersion::new(guid, version, friendly_name))
+        }),
+        i32::MIN..=-6 => ArchiveReader::read_array(ar, |ar| {
+            let guid = ar.read_guid()?;
+            Ok(named(guid, ar.read_i32_endian()?))
+        }),
+        _ => Ok(Vec::new()),
+    }
+}
+
+/// Read a 32-bit `UBOOL` as used by the package tables
+fn read_ubool<A: ArchiveReader<PackageIndex>>(ar: &mut A) -> UnrealAssetResult<bool> {
+    match ar.read_i32_endian()? {
+        0 => Ok(false),
+        1 => Ok(true),
+        value => Err(UnrealAssetError::InvalidData(format!("Invalid bool value {value}"))),
+    }
+}
+
+/// 4.26 packages saved before `VER_UE4_CORRECT_LICENSEE_FLAG` flagged Epic changelists as licensee ones
+fn fix_corrupt_engine_version(object_version: i32, version: &mut EngineVersionInfo) {
+    if object_version < EUnrealEngineObjectUE4Version::VER_UE4_CORRECT_LICENSEE_FLAG as i32
+        && (version.major, version.minor, version.patch) == (4, 26, 0)
+        && version.get_changelist() >= 12740027
+        && version.is_licensee_version()
+    {
+        version.changelist = version.get_changelist();
+    }
+}
+
+bitflags! {
+    /// Boolean fields of `FObjectExport`, stored in `Export::export_flags`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+    pub struct ExportFlags: u32 {
+        const FORCED_EXPORT = 1 << 0;
+        const NOT_FOR_CLIENT = 1 << 1;
+        const NOT_FOR_SERVER = 1 << 2;
+        const IS_INHERITED_INSTANCE = 1 << 3;
+        const NOT_ALWAYS_LOADED_FOR_EDITOR_GAME = 1 << 4;
+        const IS_ASSET = 1 << 5;
+        const GENERATE_PUBLIC_HASH = 1 << 6;
+    }
+}
+
+/// Dependency ranges of an `FObjectExport` in the preload dependency table
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct ExportDependencies {
+    first: i32,
+    serialization_before_serialization: i32,
+    create_before_serialization: i32,
+    serialization_before_create: i32,
+    create_before_create: i32,
+}
+
+impl Import {
+    /// Read an `FObjectImport`
+    pub fn read<A: ArchiveReader<PackageIndex>>(ar: &mut A, summary: &PackageFileSummary) -> UnrealAssetResult<Self> {
+        let class_package = ar.read_fname()?;
+        let class_name = ar.read_fname()?;
+        let outer_index = PackageIndex::new(ar.read_i32_endian()?);
+        let object_name = ar.read_fname()?;
+        let package_name = match summary.file_version_ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_NON_OUTER_PACKAGE_IMPORT as i32 && !summary.is_filter_editor_only() {
+            true => ar.read_fname()?,
+            false => Default::default(),
+        };
+        if summary.file_version_ue5 >= EUnrealEngineObjectUE5Version::OPTIONAL_RESOURCES as i32 {
+            read_ubool(ar)?; // bImportOptional
+        }
+        Ok(Import {
+            class_package,
+            class_name,
+            outer_index,
+            object_name,
+            package_guid: None,
+            package_name,
+        })
+    }
+}
+
+impl Export {
+    /// Read an `FObjectExport`, returning its ranges in the preload dependency table
+    fn read_entry<A: ArchiveReader<PackageIndex>>(
+        ar: &mut A,
+        summary: &PackageFileSummary,
+    ) -> UnrealAssetResult<(Self, ExportDependencies)> {
+        let ue4 = summary.file_version_ue4;
+        let ue5 = summary.file_version_ue5;
+
+        let class_index = PackageIndex::new(ar.read_i32_endian()?);
+        let super_index = PackageIndex::new(ar.read_i32_endian()?);
+        let template_index = match ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_TemplateIndex_IN_COOKED_EXPORTS as i32 {
+            true => PackageIndex::new(ar.read_i32_endian()?),
+            false => PackageIndex::null(),
+        };
+        let outer_index = PackageIndex::new(ar.read_i32_endian()?);
+        let object_name = ar.read_fname()?;
+
+        let mut export = Export::new(object_name, class_index);
+        export.super_index = super_index;
+        export.template_index = template_index;
+        export.outer_index = outer_index;
+        export.object_flags = ar.read_u32_endian()?;
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_64BIT_EXPORTMAP_SERIALSIZES as i32 {
+            export.serial_size = ar.read_i64_endian()? as u64;
+            export.serial_offset = ar.read_i64_endian()? as u64;
+        } else {
+            export.serial_size = ar.read_i32_endian()? as u64;
+            export.serial_offset = ar.read_i32_endian()? as u64;
+        }
+
+        let mut flags = ExportFlags::empty();
+        flags.set(ExportFlags::FORCED_EXPORT, read_ubool(ar)?);
+        flags.set(ExportFlags::NOT_FOR_CLIENT, read_ubool(ar)?);
+        flags.set(ExportFlags::NOT_FOR_SERVER, read_ubool(ar)?);
+        if ue5 < EUnrealEngineObjectUE5Version::REMOVE_OBJECT_EXPORT_PACKAGE_GUID as i32 {
+            ar.read_guid()?;
+        }
+        if ue5 >= EUnrealEngineObjectUE5Version::TRACK_OBJECT_EXPORT_IS_INHERITED as i32 {
+            flags.set(ExportFlags::IS_INHERITED_INSTANCE, read_ubool(ar)?);
+        }
+        ar.read_u32_endian()?; // PackageFlags
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_LOAD_FOR_EDITOR_GAME as i32 {
+            flags.set(ExportFlags::NOT_ALWAYS_LOADED_FOR_EDITOR_GAME, read_ubool(ar)?);
+        }
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_COOKED_ASSETS_IN_EDITOR_SUPPORT as i32 {
+            flags.set(ExportFlags::IS_ASSET, read_ubool(ar)?);
+        }
+        if ue5 >= EUnrealEngineObjectUE5Version::OPTIONAL_RESOURCES as i32 {
+            flags.set(ExportFlags::GENERATE_PUBLIC_HASH, read_ubool(ar)?);
+        }
+        export.export_flags = flags.bits();
+
+        let mut dependencies = ExportDependencies { first: -1, ..Default::default() };
+        if ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_PRELOAD_DEPENDENCIES_IN_COOKED_EXPORTS as i32 {
+            dependencies = ExportDependencies {
+                first: ar.read_i32_endian()?,
+                serialization_before_serialization: ar.read_i32_endian()?,
+                create_before_serialization: ar.read_i32_endian()?,
+                serialization_before_create: ar.read_i32_endian()?,
+                create_before_create: ar.read_i32_endian()?,
+            };
+        }
+        if summary.package_flags & PKG_UNVERSIONED_PROPERTIES == 0 && ue5 >= EUnrealEngineObjectUE5Version::SCRIPT_SERIALIZATION_OFFSET as i32 {
+            ar.read_i64_endian()?; // ScriptSerializationStartOffset
+            ar.read_i64_endian()?; // ScriptSerializationEndOffset
+        }
+        Ok((export, dependencies))
+    }
+
+    /// Read an `FObjectExport`
+    pub fn read<A: ArchiveReader<PackageIndex>>(ar: &mut A, summary: &PackageFileSummary) -> UnrealAssetResult<Self> {
+        Ok(Self::read_entry(ar, summary)?.0)
+    }
+}
+
+/// Summary, name map, import and export tables of a legacy package
+#[derive(Debug, Clone)]
+pub struct PackageHeader {
+    pub summary: PackageFileSummary,
+    pub name_map: NameMap,
+    pub imports: Vec<Import>,
+    pub exports: Vec<Export>,
+}
+
+impl PackageHeader {
+    /// Read the header of a `.uasset`
+    ///
+    /// `engine_version` supplies the file versions of unversioned packages.
+    pub fn read<R: Read + Seek>(reader: R, engine_version: EngineVersion) -> UnrealAssetResult<Self> {
+        Self::from_archive(&mut BinaryArchive::new(reader, engine_version))
+    }
+
+    /// Read the header of a `.uasset` from the start of an archive
+    ///
+    /// Errors carry the archive name, offset and the table entry being read; use
+    /// [`UnrealAssetError::root`] to match on the underlying error.
+    pub fn from_archive<R: Read + Seek>(ar: &mut BinaryArchive<R>) -> UnrealAssetResult<Self> {
+        ar.set_error_context(None);
+        Self::read_tables(ar).map_err(|error| {
+            ar.record_error_context(&error);
+            match ar.error_context() {
+                Some(context) => error.with_context(context.clone()),
+                None => error,
+            }
+        })
+    }
+
+    fn read_tables<R: Read + Seek>(ar: &mut BinaryArchive<R>) -> UnrealAssetResult<Self> {
+        ar.seek(SeekFrom::Start(0))?;
+        let summary = ar.read_in("Summary", PackageFileSummary::read)?;
+        ar.set_object_versions(summary.object_version(), summary.object_version_ue5());
+        ar.set_custom_versions(&summary.custom_versions);
+
+        ar.seek(SeekFrom::Start(summary.name_offset as u64))?;
+        let names = ar.read_in("NameMap", |ar| {
+            ar.read_array_with_length(summary.name_count, |ar| {
+                let name = ar.read_fstring()?.unwrap_or_default();
+                if summary.file_version_ue4 >= EUnrealEngineObjectUE4Version::VER_UE4_NAME_HASHES_SERIALIZED as i32 {
+                    ar.read_u32_endian()?; // Non-case and case preserving hashes
+                }
+                Ok(name)
+            })
+        })?;
+        let name_map = NameMap::from_names(names);
+        *ar.get_name_map().get_mut() = name_map.clone();
+
+        ar.seek(SeekFrom::Start(summary.import_offset as u64))?;
+        let imports = ar.read_in("Import", |ar| {
+            ar.read_array_with_length(summary.import_count, |ar| Import::read(ar, &summary))
+        })?;
+
+        ar.seek(SeekFrom::Start(summary.export_offset as u64))?;
+        let entries = ar.read_in("Export", |ar| {
+            ar.read_array_with_length(summary.export_count, |ar| Export::read_entry(ar, &summary))
+        })?;
+
+        let preload_dependencies = match summary.preload_dependency_count > 0 {
+            true => {
+                ar.seek(SeekFrom::Start(summary.preload_dependency_offset as u64))?;
+                ar.read_in("PreloadDependencies", |ar| {
+                    ar.read_array_with_length(summary.preload_dependency_count, |ar| {
+                        Ok(PackageIndex::new(ar.read_i32_endian()?))
+                    })
+                })?
+            }
+            false => Vec::new(),
+        };
+
+        let exports = entries
+            .into_iter()
+            .map(|(mut export, dependencies)| {
+                if dependencies.first >= 0 {
+                    let start = dependencies.first as usize + dependencies.serialization_before_serialization.max(0) as usize;
+                    let end = start + dependencies.create_before_serialization.max(0) as usize;
+                    if let Some(range) = preload_dependencies.get(start..end) {
+                        export.create_before_serialization_dependencies = range.to_vec();
+                    }
+                }
+                export
+            })
+            .collect();
+
+        Ok(Self {
+            summary,
+            name_map,
+            imports,
+            exports,
+        })
+    }
+}
@@ -2,6 +2,7 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::unreal_asset::text::FText;
 
 use crate::unreal_asset::types::{
     FName, Guid, LinearColor, PackageIndex, Quat, Rotator, SoftObjectPath, Transform, Vector,
@@ -113,14 +114,7 @@ pub enum Property {
         value: FName,
     },
     /// Text property (localized string)
-    Text {
-        /// Text content
-        text: String,
-        /// Text namespace
-        namespace: Option<String>,
-        /// Text key
-        key: Option<String>,
-    },
+    Text(FText),
     
     // Advanced property types required by Stove and other tools
     
@@ -277,4 +271,276 @@ pub enum Property {
     
     /// Unknown or unsupported property type
     Unknown(serde_json::Value),
-} 
\ No newline at end of file
+}
+
+impl Property {
+    /// Get the Unreal property type this value is serialized as, e.g. `IntProperty`
+    ///
+    /// Math and other native struct variants report `StructProperty`; use
+    /// [`Property::struct_type_name`] for the struct they stand for.
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Property::Bool(_) => "BoolProperty",
+            Property::Int8(_) => "Int8Property",
+            Property::Int16(_) => "Int16Property",
+            Property::Int32(_) => "IntProperty",
+            Property::Int64(_) => "Int64Property",
+            Property::UInt8(_) | Property::Byte(_) | Property::ByteEnum { .. } => "ByteProperty",
+            Property::UInt16(_) => "UInt16Property",
+            Property::UInt32(_) => "UInt32Property",
+            Property::UInt64(_) => "UInt64Property",
+            Property::Float(_) => "FloatProperty",
+            Property::Double(_) => "DoubleProperty",
+            Property::String(_) => "StrProperty",
+            Property::Name(_) => "NameProperty",
+            Property::Text(_) => "TextProperty",
+            Property::Object(_)
+            | Property::ObjectProperty(_)
+            | Property::MaterialInterface(_)
+            | Property::StaticMesh(_)
+            | Property::SkeletalMesh(_)
+            | Property::Texture2D(_)
+            | Property::LevelSequence(_)
+            | Property::ComponentReference(_)
+            | Property::Blueprint(_)
+            | Property::WorldContext(_)
+            | Property::LandscapeComponent(_) => "ObjectProperty",
+            Property::Array(_) | Property::ArrayProperty(_) => "ArrayProperty",
+            Property::Map { .. } | Property::MapProperty { .. } => "MapProperty",
+            Property::Set(_) | Property::SetProperty(_) => "SetProperty",
+            Property::Enum { .. } | Property::EnumProperty { .. } => "EnumProperty",
+            Property::SoftObjectPath(_)
+            | Property::SoftObjectPathProperty(_)
+            | Property::SoftAssetPathProperty(_) => "SoftObjectProperty",
+            Property::SoftClassPath(_) | Property::SoftClassPathProperty(_) => "SoftClassProperty",
+            Property::AssetObjectProperty(_) => "AssetObjectProperty",
+            Property::Delegate { .. } | Property::DelegateProperty { .. } => "DelegateProperty",
+            Property::MulticastDelegate { .. } | Property::MulticastDelegateProperty { .. } => {
+                "MulticastDelegateProperty"
+            }
+            Property::MulticastSparseDelegateProperty { .. } => "MulticastSparseDelegateProperty",
+            Property::MulticastInlineDelegateProperty { .. } => "MulticastInlineDelegateProperty",
+            Property::Struct { .. }
+            | Property::StructProperty { .. }
+            | Property::Vector(_)
+            | Property::Vector4(_)
+            | Property::Vector2D(_)
+            | Property::Rotator(_)
+            | Property::Quat(_)
+            | Property::LinearColor(_)
+            | Property::Transform(_)
+            | Property::PerPlatformBool(_)
+            | Property::PerPlatformInt(_)
+            | Property::PerPlatformFloat(_)
+            | Property::Guid(_)
+            | Property::DateTime(_)
+            | Property::TimeSpan(_)
+            | Property::SmartNameProperty(_)
+            | Property::MaterialInstance(_)
+            | Property::WeightedRandomSamplerProperty(_)
+            | Property::SkeletalMeshSamplingLODBuiltDataProperty(_)
+            | Property::SkeletalMeshAreaWeightedTriangleSampler(_) => "StructProperty",
+            Property::Unknown(_) => "Unknown",
+        }
+    }
+
+    /// Get the struct name for struct-valued variants, e.g. `Vector` for [`Property::Vector`]
+    pub fn struct_type_name(&self) -> Option<&str> {
+        match self {
+            Property::Struct { struct_type, .. } | Property::StructProperty { struct_type, .. } => {
+                Some(struct_type.as_str())
+            }
+            Property::Vector(_) => Some("Vector"),
+            Property::Vector4(_) => Some("Vector4"),
+            Property::Vector2D(_) => Some("Vector2D"),
+            Property::Rotator(_) => Some("Rotator"),
+            Property::Quat(_) => Some("Quat"),
+            Property::LinearColor(_) => Some("LinearColor"),
+            Property::Transform(_) => Some("Transform"),
+            Property::PerPlatformBool(_) => Some("PerPlatformBool"),
+            Property::PerPlatformInt(_) => Some("PerPlatformInt"),
+            Property::PerPlatformFloat(_) => Some("PerPlatformFloat"),
+            Property::Guid(_) => Some("Guid"),
+            Property::DateTime(_) => Some("DateTime"),
+            Property::TimeSpan(_) => Some("Timespan"),
+            Property::SmartNameProperty(_) => Some("SmartName"),
+            Property::WeightedRandomSamplerProperty(_) => Some("WeightedRandomSampler"),
+            Property::SkeletalMeshSamplingLODBuiltDataProperty(_) => Some("SkeletalMeshSamplingLODBuiltData"),
+            Property::SkeletalMeshAreaWeightedTriangleSampler(_) => Some("SkeletalMeshAreaWeightedTriangleSampler"),
+            _ => None,
+        }
+    }
+
+    /// Replace alias variants with their canonical variant, recursing into nested values
+    ///
+    /// `StructProperty`, `ArrayProperty`, `MapProperty`, `SetProperty`, `EnumProperty`,
+    /// `ObjectProperty`, `DelegateProperty`, `MulticastDelegateProperty`,
+    /// `SoftObjectPathProperty`, `SoftAssetPathProperty` and `SoftClassPathProperty` become
+    /// the variant without the suffix, and `UInt8` becomes `Byte`. The converters only
+    /// produce canonical variants.
+    pub fn normalize(&mut self) {
+        let canonical = match std::mem::replace(self, Property::Bool(false)) {
+            Property::UInt8(value) => Property::Byte(value),
+            Property::ObjectProperty(index) => Property::Object(index),
+            Property::StructProperty { struct_type, properties } => Property::Struct { struct_type, properties },
+            Property::ArrayProperty(values) => Property::Array(values),
+            Property::SetProperty(values) => Property::Set(values),
+            Property::MapProperty { key_type, value_type, entries } => Property::Map { key_type, value_type, entries },
+            Property::EnumProperty { enum_type, value } => Property::Enum { enum_type, value },
+            Property::SoftObjectPathProperty(path) | Property::SoftAssetPathProperty(path) => Property::SoftObjectPath(path),
+            Property::SoftClassPathProperty(path) => Property::SoftClassPath(path),
+            Property::DelegateProperty { object, function_name } => Property::Delegate { object, function_name },
+            Property::MulticastDelegateProperty { delegates } => Property::MulticastDelegate { delegates },
+            other => other,
+        };
+        *self = canonical;
+
+        match self {
+            Property::Struct { properties, .. } => properties.values_mut().for_each(Property::normalize),
+            Property::MaterialInstance(properties) => properties.values_mut().for_each(Property::normalize),
+            Property::Array(values)
+            | Property::Set(values)
+            | Property::MulticastDelegate { delegates: values }
+            | Property::MulticastSparseDelegateProperty { delegates: values }
+            | Property::MulticastInlineDelegateProperty { delegates: values } => {
+                values.iter_mut().for_each(Property::normalize)
+            }
+            Property::Map { entries, .. } => {
+                for (key, value) in entries {
+                    key.normalize();
+                    value.normalize();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Consume the property and return its [normalized](Property::normalize) form
+    pub fn normalized(mut self) -> Self {
+        self.normalize();
+        self
+    }
+}
+
+/// Alias variants compare equal to their canonical variant, see [`Property::normalize`]
+impl PartialEq for Property {
+    fn eq(&self, other: &Self) -> bool {
+        // Exhaustive over `self` so a new variant can't silently compare unequal to itself
+        match self {
+            Property::Bool(a) => matches!(other, Property::Bool(b) if a == b),
+            Property::Int8(a) => matches!(other, Property::Int8(b) if a == b),
+            Property::Int16(a) => matches!(other, Property::Int16(b) if a == b),
+            Property::Int32(a) => matches!(other, Property::Int32(b) if a == b),
+            Property::Int64(a) => matches!(other, Property::Int64(b) if a == b),
+            Property::UInt8(a) | Property::Byte(a) => matches!(other, Property::UInt8(b) | Property::Byte(b) if a == b),
+            Property::UInt16(a) => matches!(other, Property::UInt16(b) if a == b),
+            Property::UInt32(a) => matches!(other, Property::UInt32(b) if a == b),
+            Property::UInt64(a) => matches!(other, Property::UInt64(b) if a == b),
+            Property::Float(a) => matches!(other, Property::Float(b) if a == b),
+            Property::Double(a) => matches!(other, Property::Double(b) if a == b),
+            Property::String(a) => matches!(other, Property::String(b) if a == b),
+            Property::Name(a) => matches!(other, Property::Name(b) if a == b),
+            Property::Text(a) => matches!(other, Property::Text(b) if a == b),
+            Property::Object(a) | Property::ObjectProperty(a) => {
+                matches!(other, Property::Object(b) | Property::ObjectProperty(b) if a == b)
+            }
+            Property::Struct { struct_type: a, properties: a_properties }
+            | Property::StructProperty { struct_type: a, properties: a_properties } => matches!(
+                other,
+                Property::Struct { struct_type: b, properties: b_properties }
+                | Property::StructProperty { struct_type: b, properties: b_properties }
+                if a == b && a_properties == b_properties
+            ),
+            Property::Array(a) | Property::ArrayProperty(a) => {
+                matches!(other, Property::Array(b) | Property::ArrayProperty(b) if a == b)
+            }
+            Property::Set(a) | Property::SetProperty(a) => {
+                matches!(other, Property::Set(b) | Property::SetProperty(b) if a == b)
+            }
+            Property::Map { key_type: a_key, value_type: a_value, entries: a }
+            | Property::MapProperty { key_type: a_key, value_type: a_value, entries: a } => matches!(
+                other,
+                Property::Map { key_type: b_key, value_type: b_value, entries: b }
+                | Property::MapProperty { key_type: b_key, value_type: b_value, entries: b }
+                if a_key == b_key && a_value == b_value && a == b
+            ),
+            Property::Enum { enum_type: a_type, value: a }
+            | Property::EnumProperty { enum_type: a_type, value: a } => matches!(
+                other,
+                Property::Enum { enum_type: b_type, value: b }
+                | Property::EnumProperty { enum_type: b_type, value: b }
+                if a_type == b_type && a == b
+            ),
+            Property::SoftObjectPath(a)
+            | Property::SoftObjectPathProperty(a)
+            | Property::SoftAssetPathProperty(a) => matches!(
+                other,
+                Property::SoftObjectPath(b)
+                | Property::SoftObjectPathProperty(b)
+                | Property::SoftAssetPathProperty(b)
+                if a == b
+            ),
+            Property::SoftClassPath(a) | Property::SoftClassPathProperty(a) => {
+                matches!(other, Property::SoftClassPath(b) | Property::SoftClassPathProperty(b) if a == b)
+            }
+            Property::Delegate { object: a_object, function_name: a }
+            | Property::DelegateProperty { object: a_object, function_name: a } => matches!(
+                other,
+                Property::Delegate { object: b_object, function_name: b }
+                | Property::DelegateProperty { object: b_object, function_name: b }
+                if a_object == b_object && a == b
+            ),
+            Property::MulticastDelegate { delegates: a }
+            | Property::MulticastDelegateProperty { delegates: a } => matches!(
+                other,
+                Property::MulticastDelegate { delegates: b }
+                | Property::MulticastDelegateProperty { delegates: b }
+                if a == b
+            ),
+            Property::MulticastSparseDelegateProperty { delegates: a } => {
+                matches!(other, Property::MulticastSparseDelegateProperty { delegates: b } if a == b)
+            }
+            Property::MulticastInlineDelegateProperty { delegates: a } => {
+                matches!(other, Property::MulticastInlineDelegateProperty { delegates: b } if a == b)
+            }
+            Property::Vector(a) => matches!(other, Property::Vector(b) if a == b),
+            Property::Vector4(a) => matches!(other, Property::Vector4(b) if a == b),
+            Property::Vector2D(a) => matches!(other, Property::Vector2D(b) if a == b),
+            Property::Rotator(a) => matches!(other, Property::Rotator(b) if a == b),
+            Property::Quat(a) => matches!(other, Property::Quat(b) if a == b),
+            Property::LinearColor(a) => matches!(other, Property::LinearColor(b) if a == b),
+            Property::Transform(a) => matches!(other, Property::Transform(b) if a == b),
+            Property::AssetObjectProperty(a) => matches!(other, Property::AssetObjectProperty(b) if a == b),
+            Property::PerPlatformBool(a) => matches!(other, Property::PerPlatformBool(b) if a == b),
+            Property::PerPlatformInt(a) => matches!(other, Property::PerPlatformInt(b) if a == b),
+            Property::PerPlatformFloat(a) => matches!(other, Property::PerPlatformFloat(b) if a == b),
+            Property::Guid(a) => matches!(other, Property::Guid(b) if a == b),
+            Property::DateTime(a) => matches!(other, Property::DateTime(b) if a == b),
+            Property::TimeSpan(a) => matches!(other, Property::TimeSpan(b) if a == b),
+            Property::MaterialInterface(a) => matches!(other, Property::MaterialInterface(b) if a == b),
+            Property::StaticMesh(a) => matches!(other, Property::StaticMesh(b) if a == b),
+            Property::SkeletalMesh(a) => matches!(other, Property::SkeletalMesh(b) if a == b),
+            Property::Texture2D(a) => matches!(other, Property::Texture2D(b) if a == b),
+            Property::ByteEnum { enum_type: a_type, value: a } => {
+                matches!(other, Property::ByteEnum { enum_type: b_type, value: b } if a_type == b_type && a == b)
+            }
+            Property::WeightedRandomSamplerProperty(a) => {
+                matches!(other, Property::WeightedRandomSamplerProperty(b) if a == b)
+            }
+            Property::SkeletalMeshSamplingLODBuiltDataProperty(a) => {
+                matches!(other, Property::SkeletalMeshSamplingLODBuiltDataProperty(b) if a == b)
+            }
+            Property::SkeletalMeshAreaWeightedTriangleSampler(a) => {
+                matches!(other, Property::SkeletalMeshAreaWeightedTriangleSampler(b) if a == b)
+            }
+            Property::SmartNameProperty(a) => matches!(other, Property::SmartNameProperty(b) if a == b),
+            Property::MaterialInstance(a) => matches!(other, Property::MaterialInstance(b) if a == b),
+            Property::LevelSequence(a) => matches!(other, Property::LevelSequence(b) if a == b),
+            Property::ComponentReference(a) => matches!(other, Property::ComponentReference(b) if a == b),
+            Property::Blueprint(a) => matches!(other, Property::Blueprint(b) if a == b),
+            Property::WorldContext(a) => matches!(other, Property::WorldContext(b) if a == b),
+            Property::LandscapeComponent(a) => matches!(other, Property::LandscapeComponent(b) if a == b),
+            Property::Unknown(a) => matches!(other, Property::Unknown(b) if a == b),
+        }
+    }
+}
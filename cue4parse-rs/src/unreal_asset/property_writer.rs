@@ -0,0 +1,638 @@
+//! Tagged property serialization
+//!
+//! Writes [`Property`] values back into the tagged binary layout that `FPropertyTag`
+//! reads, so a property map taken from an asset can be modified and saved again.
+//! Tag sizes are computed by patching the size field once the value has been written,
+//! and every name that is written is added to the archive's name map.
+
+use std::io::SeekFrom;
+
+use byteorder::WriteBytesExt;
+use indexmap::IndexMap;
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::native_structs::NativeStruct;
+use crate::unreal_asset::object_versions::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version};
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::reader::ArchiveWriter;
+use crate::unreal_asset::types::{FName, PackageIndex, PackageIndexTrait, SoftObjectPath};
+
+/// Structs that UE serializes natively instead of as tagged properties
+const NATIVE_STRUCTS: &[&str] = &[
+    "Vector", "Vector2D", "Vector4", "Rotator", "Quat", "LinearColor", "Color", "IntPoint",
+    "Guid", "DateTime", "Timespan", "SmartName", "PerPlatformBool", "PerPlatformInt",
+    "PerPlatformFloat",
+];
+
+/// Native structs that live in `/Script/CoreUObject`, used for complete type names
+const CORE_UOBJECT_STRUCTS: &[&str] = &[
+    "Vector", "Vector2D", "Vector4", "Rotator", "Quat", "LinearColor", "Color", "IntPoint",
+    "Guid", "DateTime", "Timespan", "Transform", "SoftObjectPath", "SoftClassPath",
+];
+
+/// Type information written into a property tag
+#[derive(Debug, Clone)]
+struct TagType {
+    type_name: String,
+    struct_type: Option<String>,
+    enum_name: Option<String>,
+    inner: Option<Box<TagType>>,
+    value: Option<Box<TagType>>,
+}
+
+impl TagType {
+    fn simple(type_name: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            struct_type: None,
+            enum_name: None,
+            inner: None,
+            value: None,
+        }
+    }
+
+    fn of(property: &Property) -> UnrealAssetResult<Self> {
+        let mut tag = TagType::simple(property.type_name());
+        match property {
+            Property::Byte(_) | Property::UInt8(_) => tag.enum_name = Some("None".to_string()),
+            Property::ByteEnum { enum_type, .. }
+            | Property::Enum { enum_type, .. }
+            | Property::EnumProperty { enum_type, .. } => {
+                tag.enum_name = Some(enum_type.name.clone())
+            }
+            Property::Array(values)
+            | Property::ArrayProperty(values)
+            | Property::Set(values)
+            | Property::SetProperty(values) => {
+                // An empty container carries no element type; `None` is what UE writes for
+                // an unknown inner type and skips the conversion check for
+                tag.inner = values.first().map(TagType::of).transpose()?.map(Box::new);
+            }
+            Property::Map { key_type, value_type, entries }
+            | Property::MapProperty { key_type, value_type, entries } => {
+                let first = entries.first();
+                tag.inner = Some(Box::new(match first {
+                    Some((key, _)) => TagType::of(key)?,
+                    None => TagType::declared(key_type)?,
+                }));
+                tag.value = Some(Box::new(match first {
+                    Some((_, value)) => TagType::of(value)?,
+                    None => TagType::declared(value_type)?,
+                }));
+            }
+            Property::MaterialInstance(_)
+            | Property::WeightedRandomSamplerProperty(_)
+            | Property::SkeletalMeshSamplingLODBuiltDataProperty(_)
+            | Property::SkeletalMeshAreaWeightedTriangleSampler(_)
+            | Property::Unknown(_) => {
+                return Err(UnrealAssetError::Custom(format!(
+                    "{} has no binary representation and cannot be written",
+                    variant_name(property)
+                )));
+            }
+            _ => tag.struct_type = property.struct_type_name().map(str::to_string),
+        }
+        Ok(tag)
+    }
+
+    /// Build a tag type from a declared container type such as `IntProperty` or `Int32`
+    fn declared(type_name: &str) -> UnrealAssetResult<Self> {
+        if type_name.ends_with("Property") {
+            return Ok(TagType::simple(type_name));
+        }
+        let resolved = match type_name {
+            "Bool" => "BoolProperty",
+            "Int8" => "Int8Property",
+            "Int16" => "Int16Property",
+            "Int" | "Int32" => "IntProperty",
+            "Int64" => "Int64Property",
+            "Byte" | "UInt8" => "ByteProperty",
+            "UInt16" => "UInt16Property",
+            "UInt32" => "UInt32Property",
+            "UInt64" => "UInt64Property",
+            "Float" => "FloatProperty",
+            "Double" => "DoubleProperty",
+            "Str" | "String" => "StrProperty",
+            "Name" => "NameProperty",
+            "Text" => "TextProperty",
+            "Object" => "ObjectProperty",
+            "SoftObject" | "SoftObjectPath" => "SoftObjectProperty",
+            _ => {
+                return Err(UnrealAssetError::Custom(format!(
+                    "Cannot determine the property type of an empty map from '{}'",
+                    type_name
+                )));
+            }
+        };
+        Ok(TagType::simple(resolved))
+    }
+
+    fn is_native_struct(&self) -> bool {
+        self.struct_type
+            .as_deref()
+            .is_some_and(|name| NATIVE_STRUCTS.contains(&name))
+    }
+}
+
+pub trait PropertyWriter<Index: PackageIndexTrait>: ArchiveWriter<Index> {
+    /// Write a list of tagged properties terminated by a `None` name
+    fn write_properties(&mut self, properties: &IndexMap<String, Property>) -> UnrealAssetResult<()> {
+        for (name, property) in properties {
+            self.write_property(name, property)?;
+        }
+        self.write_fname(&FName::new("None"))
+    }
+
+    /// Write a property tag followed by the property value, returning the tag's value size
+    fn write_property(&mut self, name: &str, property: &Property) -> UnrealAssetResult<usize> {
+        let mut tag = TagType::of(property)?;
+        if let Property::Array(values) | Property::ArrayProperty(values) | Property::Set(values) | Property::SetProperty(values) = property {
+            // Empty struct arrays take their element struct from the archive's overrides
+            if let (None, true, Some(struct_type)) =
+                (&tag.inner, values.is_empty(), self.get_array_struct_type_override().get(name))
+            {
+                let mut inner = TagType::simple("StructProperty");
+                inner.struct_type = Some(struct_type.clone());
+                tag.inner = Some(Box::new(inner));
+            }
+        }
+        let bool_value = match property {
+            Property::Bool(value) => Some(*value),
+            _ => None,
+        };
+
+        let size_offset = write_tag_header(self, name, &tag, bool_value)?;
+        let value_start = self.position();
+        match property {
+            Property::Array(values) | Property::ArrayProperty(values) => write_array(self, Some(name), &tag, values)?,
+            _ if bool_value.is_none() => self.write_property_value(property)?,
+            _ => {}
+        }
+        patch_size(self, size_offset, value_start)
+    }
+
+    /// Write a property value without a tag, the way elements of arrays, sets and maps are stored
+    fn write_property_value(&mut self, property: &Property) -> UnrealAssetResult<()> {
+        match property {
+            Property::Bool(value) => self.write_u8(*value as u8)?,
+            Property::Int8(value) => self.write_i8(*value)?,
+            Property::Int16(value) => self.write_i16_endian(*value)?,
+            Property::Int32(value) => self.write_i32_endian(*value)?,
+            Property::Int64(value) => self.write_i64_endian(*value)?,
+            Property::UInt8(value) | Property::Byte(value) => self.write_u8(*value)?,
+            Property::UInt16(value) => self.write_u16_endian(*value)?,
+            Property::UInt32(value) => self.write_u32_endian(*value)?,
+            Property::UInt64(value) => self.write_u64_endian(*value)?,
+            Property::Float(value) => self.write_f32_endian(*value)?,
+            Property::Double(value) => self.write_f64_endian(*value)?,
+            Property::String(value) => {
+                self.write_fstring(Some(value))?;
+            }
+            Property::Name(value) | Property::SmartNameProperty(value) => self.write_fname(value)?,
+            Property::ByteEnum { value, .. }
+            | Property::Enum { value, .. }
+            | Property::EnumProperty { value, .. } => self.write_fname(value)?,
+            Property::Text(text) => text.write(self)?,
+            Property::Object(index)
+            | Property::ObjectProperty(index)
+            | Property::MaterialInterface(index)
+            | Property::StaticMesh(index)
+            | Property::SkeletalMesh(index)
+            | Property::Texture2D(index)
+            | Property::LevelSequence(index)
+            | Property::ComponentReference(index)
+            | Property::Blueprint(index)
+            | Property::WorldContext(index)
+            | Property::LandscapeComponent(index) => write_package_index(self, *index)?,
+            Property::SoftObjectPath(path)
+            | Property::SoftObjectPathProperty(path)
+            | Property::SoftAssetPathProperty(path)
+            | Property::SoftClassPath(path)
+            | Property::SoftClassPathProperty(path) => write_soft_object_path(self, path)?,
+            Property::AssetObjectProperty(path) => {
+                self.write_fstring(Some(&path.asset_path.name))?;
+            }
+            Property::Delegate { object, function_name }
+            | Property::DelegateProperty { object, function_name } => {
+                write_package_index(self, *object)?;
+                self.write_fname(function_name)?;
+            }
+            Property::MulticastDelegate { delegates }
+            | Property::MulticastDelegateProperty { delegates }
+            | Property::MulticastSparseDelegateProperty { delegates }
+            | Property::MulticastInlineDelegateProperty { delegates } => {
+                self.write_i32_endian(delegates.len() as i32)?;
+                for delegate in delegates {
+                    match delegate {
+                        Property::Delegate { object, function_name }
+                        | Property::DelegateProperty { object, function_name } => {
+                            write_package_index(self, *object)?;
+                            self.write_fname(function_name)?;
+                        }
+                        other => {
+                            return Err(UnrealAssetError::Custom(format!(
+                                "Multicast delegate entries must be delegates, found {}",
+                                variant_name(other)
+                            )));
+                        }
+                    }
+                }
+            }
+            Property::Array(values) | Property::ArrayProperty(values) => {
+                write_array(self, None, &TagType::of(property)?, values)?;
+            }
+            Property::Set(values) | Property::SetProperty(values) => {
+                self.write_i32_endian(0)?; // NumElementsToRemove
+                self.write_i32_endian(values.len() as i32)?;
+                for value in values {
+                    self.write_property_value(value)?;
+                }
+            }
+            Property::Map { entries, .. } | Property::MapProperty { entries, .. } => {
+                self.write_i32_endian(0)?; // NumKeysToRemove
+                self.write_i32_endian(entries.len() as i32)?;
+                for (key, value) in entries {
+                    self.write_property_value(key)?;
+                    self.write_property_value(value)?;
+                }
+            }
+            Property::Struct { struct_type, properties }
+            | Property::StructProperty { struct_type, properties } => {
+                if NATIVE_STRUCTS.contains(&struct_type.as_str()) {
+                    write_native_struct(self, struct_type.as_str(), properties)?;
+                } else {
+                    self.write_properties(properties)?;
+                }
+            }
+            Property::Vector(value) => value.write(self)?,
+            Property::Vector4(value) => value.write(self)?,
+            Property::Vector2D(value) => value.write(self)?,
+            Property::Rotator(value) => value.write(self)?,
+            Property::Quat(value) => value.write(self)?,
+            Property::LinearColor(value) => value.write(self)?,
+            Property::Transform(value) => {
+                // FTransform has no native serializer, so it is written as tagged properties
+                let mut properties = IndexMap::new();
+                properties.insert("Rotation".to_string(), Property::Quat(value.rotation.clone()));
+                properties.insert("Translation".to_string(), Property::Vector(value.location.clone()));
+                properties.insert("Scale3D".to_string(), Property::Vector(value.scale.clone()));
+                self.write_properties(&properties)?;
+            }
+            // Per-platform values are written cooked, so only the default value is kept
+            Property::PerPlatformBool(values) => {
+                self.write_i32_endian(1)?;
+                self.write_i32_endian(values.first().copied().unwrap_or_default() as i32)?;
+            }
+            Property::PerPlatformInt(values) => {
+                self.write_i32_endian(1)?;
+                self.write_i32_endian(values.first().copied().unwrap_or_default())?;
+            }
+            Property::PerPlatformFloat(values) => {
+                self.write_i32_endian(1)?;
+                self.write_f32_endian(values.first().copied().unwrap_or_default())?;
+            }
+            Property::Guid(value) => {
+                for component in value {
+                    self.write_u32_endian(*component)?;
+                }
+            }
+            Property::DateTime(ticks) | Property::TimeSpan(ticks) => {
+                self.write_i64_endian(*ticks)?
+            }
+            Property::MaterialInstance(_)
+            | Property::WeightedRandomSamplerProperty(_)
+            | Property::SkeletalMeshSamplingLODBuiltDataProperty(_)
+            | Property::SkeletalMeshAreaWeightedTriangleSampler(_)
+            | Property::Unknown(_) => {
+                return Err(UnrealAssetError::Custom(format!(
+                    "{} has no binary representation and cannot be written",
+                    variant_name(property)
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Index: PackageIndexTrait, W: ArchiveWriter<Index> + ?Sized> PropertyWriter<Index> for W {}
+
+/// Write everything in a property tag up to the value, returning the offset of the size field
+fn write_tag_header<Index, W>(
+    ar: &mut W,
+    name: &str,
+    tag: &TagType,
+    bool_value: Option<bool>,
+) -> UnrealAssetResult<u64>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    ar.write_fname(&FName::new(name))?;
+
+    if ar.get_object_version_ue5() >= EUnrealEngineObjectUE5Version::PROPERTY_TAG_COMPLETE_TYPE_NAME {
+        write_type_name(ar, tag)?;
+        let size_offset = ar.position();
+        ar.write_i32_endian(0)?;
+
+        let mut flags = 0u8;
+        if tag.is_native_struct() {
+            flags |= 0x08; // HasBinaryOrNativeSerialize
+        }
+        if bool_value == Some(true) {
+            flags |= 0x10; // BoolTrue
+        }
+        ar.write_u8(flags)?;
+        return Ok(size_offset);
+    }
+
+    ar.write_fname(&FName::new(tag.type_name.as_str()))?;
+    let size_offset = ar.position();
+    ar.write_i32_endian(0)?;
+    ar.write_i32_endian(0)?; // ArrayIndex
+
+    let object_version = ar.get_object_version();
+    match tag.type_name.as_str() {
+        "StructProperty" => {
+            ar.write_fname(&FName::new(tag.struct_type.as_deref().unwrap_or("None")))?;
+            if object_version >= EUnrealEngineObjectUE4Version::VER_UE4_STRUCT_GUID_IN_PROPERTY_TAG {
+                ar.write_all(&[0u8; 16])?;
+            }
+        }
+        "BoolProperty" => ar.write_u8(bool_value.unwrap_or_default() as u8)?,
+        "ByteProperty" | "EnumProperty" => {
+            ar.write_fname(&FName::new(tag.enum_name.as_deref().unwrap_or("None")))?
+        }
+        "ArrayProperty" if object_version >= EUnrealEngineObjectUE4Version::VER_UE4_ARRAY_PROPERTY_INNER_TAGS => {
+            write_inner_type(ar, &tag.inner)?
+        }
+        "SetProperty" if object_version >= EUnrealEngineObjectUE4Version::VER_UE4_PROPERTY_TAG_SET_MAP_SUPPORT => {
+            write_inner_type(ar, &tag.inner)?
+        }
+        "MapProperty" if object_version >= EUnrealEngineObjectUE4Version::VER_UE4_PROPERTY_TAG_SET_MAP_SUPPORT => {
+            write_inner_type(ar, &tag.inner)?;
+            write_inner_type(ar, &tag.value)?;
+        }
+        _ => {}
+    }
+
+    ar.write_property_guid(None)?;
+    if ar.get_object_version_ue5() >= EUnrealEngineObjectUE5Version::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION {
+        ar.write_u8(0)?; // EPropertyTagExtension::NoExtension
+    }
+    Ok(size_offset)
+}
+
+fn write_inner_type<Index, W>(ar: &mut W, inner: &Option<Box<TagType>>) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    let type_name = inner.as_ref().map(|inner| inner.type_name.as_str()).unwrap_or("None");
+    ar.write_fname(&FName::new(type_name))
+}
+
+/// Write an `FPropertyTypeName` as a flattened list of (name, inner count) nodes
+fn write_type_name<Index, W>(ar: &mut W, tag: &TagType) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    let write_node = |ar: &mut W, name: &str, inner_count: i32| -> UnrealAssetResult<()> {
+        ar.write_fname(&FName::new(name))?;
+        ar.write_i32_endian(inner_count)?;
+        Ok(())
+    };
+
+    match tag.type_name.as_str() {
+        "StructProperty" => {
+            let struct_type = tag.struct_type.as_deref().unwrap_or("None");
+            write_node(ar, "StructProperty", 1)?;
+            if CORE_UOBJECT_STRUCTS.contains(&struct_type) {
+                write_node(ar, struct_type, 1)?;
+                write_node(ar, "/Script/CoreUObject", 0)?;
+            } else {
+                write_node(ar, struct_type, 0)?;
+            }
+        }
+        "ByteProperty" => match tag.enum_name.as_deref() {
+            Some(enum_name) if enum_name != "None" => {
+                write_node(ar, "ByteProperty", 1)?;
+                write_node(ar, enum_name, 0)?;
+            }
+            _ => write_node(ar, "ByteProperty", 0)?,
+        },
+        "EnumProperty" => {
+            write_node(ar, "EnumProperty", 2)?;
+            write_node(ar, tag.enum_name.as_deref().unwrap_or("None"), 0)?;
+            write_node(ar, "ByteProperty", 0)?;
+        }
+        "ArrayProperty" | "SetProperty" => {
+            write_node(ar, &tag.type_name, 1)?;
+            match &tag.inner {
+                Some(inner) => write_type_name(ar, inner)?,
+                None => write_node(ar, "None", 0)?,
+            }
+        }
+        "MapProperty" => {
+            write_node(ar, "MapProperty", 2)?;
+            for part in [&tag.inner, &tag.value] {
+                match part {
+                    Some(part) => write_type_name(ar, part)?,
+                    None => write_node(ar, "None", 0)?,
+                }
+            }
+        }
+        other => write_node(ar, other, 0)?,
+    }
+    Ok(())
+}
+
+/// Seek back to a size field and fill it with the number of bytes written since `value_start`
+fn patch_size<Index, W>(ar: &mut W, size_offset: u64, value_start: u64) -> UnrealAssetResult<usize>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    let end = ar.position();
+    let size = end - value_start;
+    ar.seek(SeekFrom::Start(size_offset))?;
+    ar.write_i32_endian(size as i32)?;
+    ar.seek(SeekFrom::Start(end))?;
+    Ok(size as usize)
+}
+
+/// Write array elements, led by the element tag of struct arrays, which is named after the
+/// array property itself
+fn write_array<Index, W>(ar: &mut W, name: Option<&str>, tag: &TagType, values: &[Property]) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    ar.write_i32_endian(values.len() as i32)?;
+
+    let inner = tag.inner.as_deref();
+    let has_inner_tag = inner.is_some_and(|inner| inner.type_name == "StructProperty")
+        && ar.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_INNER_ARRAY_TAG_INFO
+        && ar.get_object_version_ue5() < EUnrealEngineObjectUE5Version::PROPERTY_TAG_COMPLETE_TYPE_NAME;
+
+    if let (true, Some(inner)) = (has_inner_tag, inner) {
+        let name = name.ok_or_else(|| {
+            UnrealAssetError::Custom("Struct arrays repeat their property name and must be written with write_property".to_string())
+        })?;
+        let size_offset = write_tag_header(ar, name, inner, None)?;
+        let value_start = ar.position();
+        for value in values {
+            ar.write_property_value(value)?;
+        }
+        patch_size(ar, size_offset, value_start)?;
+        return Ok(());
+    }
+
+    for value in values {
+        ar.write_property_value(value)?;
+    }
+    Ok(())
+}
+
+fn write_package_index<Index, W>(ar: &mut W, index: Option<PackageIndex>) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    ar.write_i32_endian(index.unwrap_or_else(PackageIndex::null).0)?;
+    Ok(())
+}
+
+fn write_soft_object_path<Index, W>(ar: &mut W, path: &SoftObjectPath) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    if ar.get_object_version_ue5() >= EUnrealEngineObjectUE5Version::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES {
+        // FTopLevelAssetPath splits "/Game/Pkg.Asset" into package and asset names
+        let (package_name, asset_name) = match path.asset_path.name.rsplit_once('.') {
+            Some((package_name, asset_name)) => (package_name, asset_name),
+            None => (path.asset_path.name.as_str(), "None"),
+        };
+        let package_name = if package_name.is_empty() { "None" } else { package_name };
+        ar.write_fname(&FName::new(package_name))?;
+        ar.write_fname(&FName::new(asset_name))?;
+    } else {
+        let asset_path = if path.asset_path.name.is_empty() {
+            FName::new("None")
+        } else {
+            path.asset_path.clone()
+        };
+        ar.write_fname(&asset_path)?;
+    }
+    ar.write_fstring(Some(&path.sub_path))?;
+    Ok(())
+}
+
+/// Write real-number components as doubles once large world coordinates are enabled
+fn write_lwc<Index, W>(ar: &mut W, components: &[f64]) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    for component in components {
+        ar.write_lwc_float(*component)?;
+    }
+    Ok(())
+}
+
+/// Write a generic `Property::Struct` whose struct type UE serializes natively
+fn write_native_struct<Index, W>(
+    ar: &mut W,
+    struct_type: &str,
+    properties: &IndexMap<String, Property>,
+) -> UnrealAssetResult<()>
+where
+    Index: PackageIndexTrait,
+    W: ArchiveWriter<Index> + ?Sized,
+{
+    let field = |name: &str| -> UnrealAssetResult<f64> {
+        properties
+            .get(name)
+            .and_then(numeric_value)
+            .ok_or_else(|| {
+                UnrealAssetError::Custom(format!("{} struct is missing numeric field '{}'", struct_type, name))
+            })
+    };
+
+    match struct_type {
+        "Vector" => write_lwc(ar, &[field("X")?, field("Y")?, field("Z")?]),
+        "Vector2D" => write_lwc(ar, &[field("X")?, field("Y")?]),
+        "Vector4" | "Quat" => write_lwc(ar, &[field("X")?, field("Y")?, field("Z")?, field("W")?]),
+        "Rotator" => write_lwc(ar, &[field("Pitch")?, field("Yaw")?, field("Roll")?]),
+        "LinearColor" => {
+            for name in ["R", "G", "B", "A"] {
+                ar.write_f32_endian(field(name)? as f32)?;
+            }
+            Ok(())
+        }
+        "Color" => {
+            for name in ["B", "G", "R", "A"] {
+                ar.write_u8(field(name)? as u8)?;
+            }
+            Ok(())
+        }
+        "IntPoint" => {
+            ar.write_i32_endian(field("X")? as i32)?;
+            ar.write_i32_endian(field("Y")? as i32)?;
+            Ok(())
+        }
+        "Guid" => {
+            for name in ["A", "B", "C", "D"] {
+                ar.write_u32_endian(field(name)? as u32)?;
+            }
+            Ok(())
+        }
+        "DateTime" | "Timespan" => {
+            ar.write_i64_endian(field("Ticks")? as i64)?;
+            Ok(())
+        }
+        "SmartName" => match properties.get("DisplayName") {
+            Some(Property::Name(name)) => ar.write_fname(name),
+            _ => Err(UnrealAssetError::Custom("SmartName struct is missing 'DisplayName'".to_string())),
+        },
+        _ => {
+            let default = properties.get("Default").and_then(numeric_value).unwrap_or_default();
+            ar.write_i32_endian(1)?; // bCooked
+            match struct_type {
+                "PerPlatformFloat" => ar.write_f32_endian(default as f32)?,
+                _ => ar.write_i32_endian(default as i32)?,
+            }
+            Ok(())
+        }
+    }
+}
+
+fn numeric_value(property: &Property) -> Option<f64> {
+    match property {
+        Property::Bool(value) => Some(*value as u8 as f64),
+        Property::Int8(value) => Some(*value as f64),
+        Property::Int16(value) => Some(*value as f64),
+        Property::Int32(value) => Some(*value as f64),
+        Property::Int64(value) => Some(*value as f64),
+        Property::UInt8(value) | Property::Byte(value) => Some(*value as f64),
+        Property::UInt16(value) => Some(*value as f64),
+        Property::UInt32(value) => Some(*value as f64),
+        Property::UInt64(value) => Some(*value as f64),
+        Property::Float(value) => Some(*value as f64),
+        Property::Double(value) => Some(*value),
+        Property::DateTime(value) | Property::TimeSpan(value) => Some(*value as f64),
+        _ => None,
+    }
+}
+
+fn variant_name(property: &Property) -> &'static str {
+    match property {
+        Property::MaterialInstance(_) => "MaterialInstance",
+        Property::WeightedRandomSamplerProperty(_) => "WeightedRandomSamplerProperty",
+        Property::SkeletalMeshSamplingLODBuiltDataProperty(_) => "SkeletalMeshSamplingLODBuiltDataProperty",
+        Property::SkeletalMeshAreaWeightedTriangleSampler(_) => "SkeletalMeshAreaWeightedTriangleSampler",
+        Property::Unknown(_) => "Unknown",
+        other => other.type_name(),
+    }
+}
@@ -0,0 +1,842 @@
+//! Property path queries
+//!
+//! A [`PropertyQuery`] addresses values nested inside properties with paths such as
+//! `Components[2].RelativeLocation.X`, `Tags[*]` or `MyMap["Key"].Value`:
+//!
+//! - `Name` or `.Name` selects a struct field, or the map value whose string, name or enum key is `Name`
+//! - `["Name"]` does the same for names containing dots, brackets or spaces
+//! - `[2]` selects an array or set element, or the map value whose integer key is 2
+//! - `*` or `[*]` selects every element, map value, struct field or struct component
+//!
+//! Components of native math structs (`X` of a `Vector`, `Rotation` of a `Transform`) are
+//! returned by value. They can be set, but not borrowed mutably or removed.
+//!
+//! Below a wildcard, branches the rest of the path does not resolve in are skipped.
+//! A query only fails there if no branch resolves.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::exports::Export;
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::types::{FName, Vector};
+
+/// Segment of a [`PropertyQuery`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum QuerySegment {
+    /// Struct field, struct component or map key
+    Name(String),
+    /// Array element or integer map key
+    Index(usize),
+    /// Every child
+    Wildcard,
+}
+
+/// Parsed property path, see the [module documentation](self)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PropertyQuery {
+    segments: Vec<QuerySegment>,
+}
+
+impl PropertyQuery {
+    /// Parse a property path
+    pub fn parse(path: &str) -> UnrealAssetResult<Self> {
+        let bytes = path.as_bytes();
+        let error = |end: usize, message: &str| {
+            // Byte offsets can land inside a multi-byte character, take all of it
+            let mut end = end.min(path.len());
+            while !path.is_char_boundary(end) {
+                end += 1;
+            }
+            UnrealAssetError::InvalidPath {
+                path: path.to_string(),
+                segment: path[..end].to_string(),
+                message: message.to_string(),
+            }
+        };
+
+        let mut segments = Vec::new();
+        let mut pos = 0;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'[' => {
+                    pos += 1;
+                    match bytes.get(pos) {
+                        Some(b'"') => {
+                            let mut name = String::new();
+                            let mut chars = path[pos + 1..].char_indices();
+                            let mut closed = None;
+                            while let Some((i, c)) = chars.next() {
+                                match c {
+                                    '\\' => match chars.next() {
+                                        Some((_, escaped)) => name.push(escaped),
+                                        None => break,
+                                    },
+                                    '"' => {
+                                        closed = Some(pos + 1 + i + 1);
+                                        break;
+                                    }
+                                    c => name.push(c),
+                                }
+                            }
+                            pos = closed.ok_or_else(|| error(path.len(), "unterminated string"))?;
+                            segments.push(QuerySegment::Name(name));
+                        }
+                        Some(b'*') => {
+                            pos += 1;
+                            segments.push(QuerySegment::Wildcard);
+                        }
+                        _ => {
+                            let end = path[pos..].find(']').map_or(path.len(), |i| pos + i);
+                            let index = path[pos..end]
+                                .trim()
+                                .parse()
+                                .map_err(|_| error(end + 1, "expected an index, `*` or a quoted key"))?;
+                            pos = end;
+                            segments.push(QuerySegment::Index(index));
+                        }
+                    }
+                    if bytes.get(pos) != Some(&b']') {
+                        return Err(error(pos + 1, "expected `]`"));
+                    }
+                    pos += 1;
+                }
+                b'.' if !segments.is_empty() => {
+                    pos += 1;
+                    let end = path[pos..].find(['.', '[']).map_or(path.len(), |i| pos + i);
+                    segments.push(Self::name_segment(&path[pos..end]).ok_or_else(|| error(end, "empty field name"))?);
+                    pos = end;
+                }
+                _ if segments.is_empty() => {
+                    let end = path.find(['.', '[']).unwrap_or(path.len());
+                    segments.push(Self::name_segment(&path[..end]).ok_or_else(|| error(end + 1, "empty field name"))?);
+                    pos = end;
+                }
+                _ => return Err(error(pos + 1, "expected `.` or `[`")),
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    fn name_segment(name: &str) -> Option<QuerySegment> {
+        match name {
+            "" => None,
+            "*" => Some(QuerySegment::Wildcard),
+            name => Some(QuerySegment::Name(name.to_string())),
+        }
+    }
+
+    /// Create a query from its segments
+    pub fn from_segments(segments: Vec<QuerySegment>) -> Self {
+        Self { segments }
+    }
+
+    /// Segments of the query
+    pub fn segments(&self) -> &[QuerySegment] {
+        &self.segments
+    }
+
+    /// Whether the query can match more than one value
+    pub fn has_wildcard(&self) -> bool {
+        self.segments.contains(&QuerySegment::Wildcard)
+    }
+
+    /// Values matched by the query
+    pub fn get<'a>(&self, root: &'a Property) -> UnrealAssetResult<Vec<Cow<'a, Property>>> {
+        let mut matches = Vec::new();
+        self.collect(Cow::Borrowed(root), 0, &mut matches)?;
+        Ok(matches)
+    }
+
+    /// Values matched by the query, borrowed mutably
+    pub fn get_mut<'a>(&self, root: &'a mut Property) -> UnrealAssetResult<Vec<&'a mut Property>> {
+        let mut matches = Vec::new();
+        self.collect_mut(root, 0, &mut matches)?;
+        Ok(matches)
+    }
+
+    /// Set every matched value, adding missing struct fields and string map keys, returning how many were set
+    pub fn set(&self, root: &mut Property, value: Property) -> UnrealAssetResult<usize> {
+        match self.segments.is_empty() {
+            true => {
+                *root = value;
+                Ok(1)
+            }
+            false => self.set_in(root, 0, &value),
+        }
+    }
+
+    /// Remove every matched value, returning the removed values
+    pub fn remove(&self, root: &mut Property) -> UnrealAssetResult<Vec<Property>> {
+        match self.segments.is_empty() {
+            true => Err(self.error(0, "cannot remove the root property")),
+            false => self.remove_in(root, 0),
+        }
+    }
+
+    /// Values matched by the query in a property map, e.g. an export's properties
+    pub fn get_in_map<'a>(&self, root: &'a IndexMap<String, Property>) -> UnrealAssetResult<Vec<Cow<'a, Property>>> {
+        let first = self.first_segment()?;
+        let mut matches = Vec::new();
+        let children = field_children(root, first).map_err(|message| self.error(0, &message))?;
+        self.collect_children(children.into_iter().map(Cow::Borrowed), 1, &mut matches, |query, child, depth, out| {
+            query.collect(child, depth, out)
+        })?;
+        Ok(matches)
+    }
+
+    /// Values matched by the query in a property map, borrowed mutably
+    pub fn get_mut_in_map<'a>(&self, root: &'a mut IndexMap<String, Property>) -> UnrealAssetResult<Vec<&'a mut Property>> {
+        let first = self.first_segment()?;
+        let mut matches = Vec::new();
+        let children = field_children_mut(root, first).map_err(|message| self.error(0, &message))?;
+        self.collect_children(children, 1, &mut matches, |query, child, depth, out| query.collect_mut(child, depth, out))?;
+        Ok(matches)
+    }
+
+    /// Set every matched value in a property map
+    pub fn set_in_map(&self, root: &mut IndexMap<String, Property>, value: Property) -> UnrealAssetResult<usize> {
+        let first = self.first_segment()?;
+        if self.segments.len() == 1 {
+            return assign_field(root, first, &value).map_err(|message| self.error(0, &message));
+        }
+        let children = field_children_mut(root, first).map_err(|message| self.error(0, &message))?;
+        self.sum_children(children, |child| self.set_in(child, 1, &value))
+    }
+
+    /// Remove every matched value from a property map
+    pub fn remove_in_map(&self, root: &mut IndexMap<String, Property>) -> UnrealAssetResult<Vec<Property>> {
+        let first = self.first_segment()?;
+        if self.segments.len() == 1 {
+            return detach_field(root, first).map_err(|message| self.error(0, &message));
+        }
+        let children = field_children_mut(root, first).map_err(|message| self.error(0, &message))?;
+        let mut removed = Vec::new();
+        self.sum_children(children, |child| {
+            let values = self.remove_in(child, 1)?;
+            let count = values.len();
+            removed.extend(values);
+            Ok(count)
+        })?;
+        Ok(removed)
+    }
+
+    fn first_segment(&self) -> UnrealAssetResult<&QuerySegment> {
+        self.segments.first().ok_or_else(|| self.error(0, "empty path"))
+    }
+
+    /// Error for the segment at `depth`
+    fn error(&self, depth: usize, message: &str) -> UnrealAssetError {
+        let end = (depth + 1).min(self.segments.len());
+        UnrealAssetError::InvalidPath {
+            path: self.to_string(),
+            segment: Self::from_segments(self.segments[..end].to_vec()).to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    fn collect<'a>(&self, node: Cow<'a, Property>, depth: usize, out: &mut Vec<Cow<'a, Property>>) -> UnrealAssetResult<()> {
+        let Some(segment) = self.segments.get(depth) else {
+            out.push(node);
+            return Ok(());
+        };
+        let children: Vec<Cow<'a, Property>> = match node {
+            Cow::Borrowed(property) => children(property, segment),
+            Cow::Owned(property) => children(&property, segment)
+                .map(|children| children.into_iter().map(|child| Cow::Owned(child.into_owned())).collect()),
+        }
+        .map_err(|message| self.error(depth, &message))?;
+        self.collect_children(children, depth + 1, out, |query, child, depth, out| query.collect(child, depth, out))
+    }
+
+    fn collect_mut<'a>(&self, node: &'a mut Property, depth: usize, out: &mut Vec<&'a mut Property>) -> UnrealAssetResult<()> {
+        let Some(segment) = self.segments.get(depth) else {
+            out.push(node);
+            return Ok(());
+        };
+        let children = children_mut(node, segment).map_err(|message| self.error(depth, &message))?;
+        self.collect_children(children, depth + 1, out, |query, child, depth, out| query.collect_mut(child, depth, out))
+    }
+
+    /// Continue with each child, failing only if every child fails
+    fn collect_children<T, O>(
+        &self,
+        children: impl IntoIterator<Item = T>,
+        depth: usize,
+        out: &mut O,
+        mut next: impl FnMut(&Self, T, usize, &mut O) -> UnrealAssetResult<()>,
+    ) -> UnrealAssetResult<()> {
+        let mut first_error = None;
+        let mut resolved = false;
+        for child in children {
+            match next(self, child, depth, out) {
+                Ok(()) => resolved = true,
+                Err(error) => {
+                    first_error.get_or_insert(error);
+                }
+            }
+        }
+        match (resolved, first_error) {
+            (false, Some(error)) => Err(error),
+            _ => Ok(()),
+        }
+    }
+
+    /// Apply `apply` to each child and add up the results, failing only if every child fails
+    fn sum_children<'a>(
+        &self,
+        children: Vec<&'a mut Property>,
+        mut apply: impl FnMut(&'a mut Property) -> UnrealAssetResult<usize>,
+    ) -> UnrealAssetResult<usize> {
+        let mut total = 0;
+        self.collect_children(children, 0, &mut total, |_, child, _, total| {
+            *total += apply(child)?;
+            Ok(())
+        })?;
+        Ok(total)
+    }
+
+    fn set_in(&self, node: &mut Property, depth: usize, value: &Property) -> UnrealAssetResult<usize> {
+        let segment = &self.segments[depth];
+        if depth + 1 == self.segments.len() {
+            return assign(node, segment, value).map_err(|message| self.error(depth, &message));
+        }
+        if is_native_struct(node) {
+            // Components are values, so set the nested path on a copy and write it back
+            let mut total = 0;
+            for (name, mut component) in component_children(node, segment).map_err(|message| self.error(depth, &message))? {
+                total += self.set_in(&mut component, depth + 1, value)?;
+                set_component(node, name, component).map_err(|message| self.error(depth, &message))?;
+            }
+            return Ok(total);
+        }
+        let children = children_mut(node, segment).map_err(|message| self.error(depth, &message))?;
+        self.sum_children(children, |child| self.set_in(child, depth + 1, value))
+    }
+
+    fn remove_in(&self, node: &mut Property, depth: usize) -> UnrealAssetResult<Vec<Property>> {
+        let segment = &self.segments[depth];
+        if depth + 1 == self.segments.len() {
+            return detach(node, segment).map_err(|message| self.error(depth, &message));
+        }
+        let children = children_mut(node, segment).map_err(|message| self.error(depth, &message))?;
+        let mut removed = Vec::new();
+        self.sum_children(children, |child| {
+            let values = self.remove_in(child, depth + 1)?;
+            let count = values.len();
+            removed.extend(values);
+            Ok(count)
+        })?;
+        Ok(removed)
+    }
+}
+
+impl FromStr for PropertyQuery {
+    type Err = UnrealAssetError;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Self::parse(path)
+    }
+}
+
+impl fmt::Display for PropertyQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments.iter().enumerate() {
+            match segment {
+                QuerySegment::Name(name) if is_plain_name(name) && i == 0 => write!(f, "{name}")?,
+                QuerySegment::Name(name) if is_plain_name(name) => write!(f, ".{name}")?,
+                QuerySegment::Name(name) => write!(f, "[\"{}\"]", name.replace('\\', "\\\\").replace('"', "\\\""))?,
+                QuerySegment::Index(index) => write!(f, "[{index}]")?,
+                QuerySegment::Wildcard => write!(f, "[*]")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Path based access to nested properties, see [`PropertyQuery`]
+pub trait PropertyAccess {
+    /// Values at `path`
+    fn get_path(&self, path: &str) -> UnrealAssetResult<Vec<Cow<'_, Property>>>;
+
+    /// Values at `path`, borrowed mutably
+    fn get_path_mut(&mut self, path: &str) -> UnrealAssetResult<Vec<&mut Property>>;
+
+    /// Set the values at `path`, returning how many were set
+    fn set_path(&mut self, path: &str, value: Property) -> UnrealAssetResult<usize>;
+
+    /// Remove the values at `path`, returning them
+    fn remove_path(&mut self, path: &str) -> UnrealAssetResult<Vec<Property>>;
+}
+
+impl PropertyAccess for Property {
+    fn get_path(&self, path: &str) -> UnrealAssetResult<Vec<Cow<'_, Property>>> {
+        PropertyQuery::parse(path)?.get(self)
+    }
+
+    fn get_path_mut(&mut self, path: &str) -> UnrealAssetResult<Vec<&mut Property>> {
+        PropertyQuery::parse(path)?.get_mut(self)
+    }
+
+    fn set_path(&mut self, path: &str, value: Property) -> UnrealAssetResult<usize> {
+        PropertyQuery::parse(path)?.set(self, value)
+    }
+
+    fn remove_path(&mut self, path: &str) -> UnrealAssetResult<Vec<Property>> {
+        PropertyQuery::parse(path)?.remove(self)
+    }
+}
+
+impl PropertyAccess for IndexMap<String, Property> {
+    fn get_path(&self, path: &str) -> UnrealAssetResult<Vec<Cow<'_, Property>>> {
+        PropertyQuery::parse(path)?.get_in_map(self)
+    }
+
+    fn get_path_mut(&mut self, path: &str) -> UnrealAssetResult<Vec<&mut Property>> {
+        PropertyQuery::parse(path)?.get_mut_in_map(self)
+    }
+
+    fn set_path(&mut self, path: &str, value: Property) -> UnrealAssetResult<usize> {
+        PropertyQuery::parse(path)?.set_in_map(self, value)
+    }
+
+    fn remove_path(&mut self, path: &str) -> UnrealAssetResult<Vec<Property>> {
+        PropertyQuery::parse(path)?.remove_in_map(self)
+    }
+}
+
+impl PropertyAccess for Export {
+    fn get_path(&self, path: &str) -> UnrealAssetResult<Vec<Cow<'_, Property>>> {
+        self.properties.get_path(path)
+    }
+
+    fn get_path_mut(&mut self, path: &str) -> UnrealAssetResult<Vec<&mut Property>> {
+        self.properties.get_path_mut(path)
+    }
+
+    fn set_path(&mut self, path: &str, value: Property) -> UnrealAssetResult<usize> {
+        self.properties.set_path(path, value)
+    }
+
+    fn remove_path(&mut self, path: &str) -> UnrealAssetResult<Vec<Property>> {
+        self.properties.remove_path(path)
+    }
+}
+
+fn is_plain_name(name: &str) -> bool {
+    name != "*" && !name.is_empty() && !name.contains(['.', '[', ']', '"', '\\', ' '])
+}
+
+fn is_native_struct(property: &Property) -> bool {
+    native_components(property).is_some()
+}
+
+/// Components of native math structs, by their UE names
+pub(crate) fn native_components(property: &Property) -> Option<Vec<(&'static str, Property)>> {
+    Some(match property {
+        Property::Vector(v) => vec![("X", Property::Double(v.x)), ("Y", Property::Double(v.y)), ("Z", Property::Double(v.z))],
+        Property::Vector2D(v) => vec![("X", Property::Double(v.x)), ("Y", Property::Double(v.y))],
+        Property::Vector4(v) => vec![
+            ("X", Property::Double(v.x)),
+            ("Y", Property::Double(v.y)),
+            ("Z", Property::Double(v.z)),
+            ("W", Property::Double(v.w)),
+        ],
+        Property::Quat(q) => vec![
+            ("X", Property::Double(q.x)),
+            ("Y", Property::Double(q.y)),
+            ("Z", Property::Double(q.z)),
+            ("W", Property::Double(q.w)),
+        ],
+        Property::Rotator(r) => vec![
+            ("Pitch", Property::Double(r.pitch)),
+            ("Yaw", Property::Double(r.yaw)),
+            ("Roll", Property::Double(r.roll)),
+        ],
+        Property::LinearColor(c) => vec![
+            ("R", Property::Float(c.r)),
+            ("G", Property::Float(c.g)),
+            ("B", Property::Float(c.b)),
+            ("A", Property::Float(c.a)),
+        ],
+        Property::Transform(t) => vec![
+            ("Rotation", Property::Quat(t.rotation.clone())),
+            ("Translation", Property::Vector(t.location.clone())),
+            ("Scale3D", Property::Vector(t.scale.clone())),
+        ],
+        _ => return None,
+    })
+}
+
+fn component_children(property: &Property, segment: &QuerySegment) -> Result<Vec<(&'static str, Property)>, String> {
+    let components = native_components(property).unwrap_or_default();
+    match segment {
+        QuerySegment::Name(name) => components
+            .into_iter()
+            .find(|(component, _)| component == name)
+            .map(|component| vec![component])
+            .ok_or_else(|| format!("{} has no component `{}`", describe(property), name)),
+        QuerySegment::Wildcard => Ok(components),
+        QuerySegment::Index(index) => Err(format!("cannot index {} with [{}]", describe(property), index)),
+    }
+}
+
+fn set_component(property: &mut Property, name: &str, value: Property) -> Result<(), String> {
+    let missing = format!("{} has no component `{}`", describe(property), name);
+    let number = as_f64(&value).ok_or_else(|| format!("expected a number for `{}`, found {}", name, value.type_name()));
+    let field = match (property, name) {
+        (Property::Vector(v), "X") => &mut v.x,
+        (Property::Vector(v), "Y") => &mut v.y,
+        (Property::Vector(v), "Z") => &mut v.z,
+        (Property::Vector2D(v), "X") => &mut v.x,
+        (Property::Vector2D(v), "Y") => &mut v.y,
+        (Property::Vector4(v), "X") => &mut v.x,
+        (Property::Vector4(v), "Y") => &mut v.y,
+        (Property::Vector4(v), "Z") => &mut v.z,
+        (Property::Vector4(v), "W") => &mut v.w,
+        (Property::Quat(q), "X") => &mut q.x,
+        (Property::Quat(q), "Y") => &mut q.y,
+        (Property::Quat(q), "Z") => &mut q.z,
+        (Property::Quat(q), "W") => &mut q.w,
+        (Property::Rotator(r), "Pitch") => &mut r.pitch,
+        (Property::Rotator(r), "Yaw") => &mut r.yaw,
+        (Property::Rotator(r), "Roll") => &mut r.roll,
+        (Property::LinearColor(c), "R" | "G" | "B" | "A") => {
+            let number = number? as f32;
+            match name {
+                "R" => c.r = number,
+                "G" => c.g = number,
+                "B" => c.b = number,
+                _ => c.a = number,
+            }
+            return Ok(());
+        }
+        (Property::Transform(t), "Rotation") => {
+            t.rotation = match value {
+                Property::Quat(q) => q,
+                other => return Err(format!("expected a Quat for `Rotation`, found {}", describe(&other))),
+            };
+            return Ok(());
+        }
+        (Property::Transform(t), "Translation" | "Scale3D") => {
+            let vector: Vector = match value {
+                Property::Vector(v) => v,
+                other => return Err(format!("expected a Vector for `{}`, found {}", name, describe(&other))),
+            };
+            match name {
+                "Translation" => t.location = vector,
+                _ => t.scale = vector,
+            }
+            return Ok(());
+        }
+        _ => return Err(missing),
+    };
+    *field = number?;
+    Ok(())
+}
+
+/// Pattern for the variants holding a list of properties
+macro_rules! elements {
+    ($values:ident) => {
+        Property::Array($values)
+            | Property::ArrayProperty($values)
+            | Property::Set($values)
+            | Property::SetProperty($values)
+            | Property::MulticastDelegate { delegates: $values }
+            | Property::MulticastDelegateProperty { delegates: $values }
+            | Property::MulticastSparseDelegateProperty { delegates: $values }
+            | Property::MulticastInlineDelegateProperty { delegates: $values }
+    };
+}
+
+/// Pattern for the struct variants
+macro_rules! fields {
+    ($properties:ident) => {
+        Property::Struct { properties: $properties, .. } | Property::StructProperty { properties: $properties, .. }
+    };
+}
+
+/// Pattern for the map variants
+macro_rules! entries {
+    ($key_type:pat, $entries:ident) => {
+        Property::Map { key_type: $key_type, entries: $entries, .. }
+            | Property::MapProperty { key_type: $key_type, entries: $entries, .. }
+    };
+}
+
+fn children<'a>(property: &'a Property, segment: &QuerySegment) -> Result<Vec<Cow<'a, Property>>, String> {
+    let owned = |components: Vec<(&'static str, Property)>| components.into_iter().map(|(_, c)| Cow::Owned(c)).collect();
+    match (property, segment) {
+        (fields!(properties), QuerySegment::Name(name)) => {
+            properties.get(name).map(|p| vec![Cow::Borrowed(p)]).ok_or_else(|| format!("no field `{}`", name))
+        }
+        (Property::MaterialInstance(properties), QuerySegment::Name(name)) => {
+            properties.get(name).map(|p| vec![Cow::Borrowed(p)]).ok_or_else(|| format!("no field `{}`", name))
+        }
+        (entries!(_, entries), QuerySegment::Name(name)) => entries
+            .iter()
+            .find(|(key, _)| key_matches(key, name))
+            .map(|(_, value)| vec![Cow::Borrowed(value)])
+            .ok_or_else(|| format!("no key `{}`", name)),
+        (elements!(values), QuerySegment::Index(index)) => values
+            .get(*index)
+            .map(|value| vec![Cow::Borrowed(value)])
+            .ok_or_else(|| out_of_bounds(*index, values.len())),
+        (Property::PerPlatformBool(values), QuerySegment::Index(index)) => {
+            values.get(*index).map(|v| vec![Cow::Owned(Property::Bool(*v))]).ok_or_else(|| out_of_bounds(*index, values.len()))
+        }
+        (Property::PerPlatformInt(values), QuerySegment::Index(index)) => {
+            values.get(*index).map(|v| vec![Cow::Owned(Property::Int32(*v))]).ok_or_else(|| out_of_bounds(*index, values.len()))
+        }
+        (Property::PerPlatformFloat(values), QuerySegment::Index(index)) => {
+            values.get(*index).map(|v| vec![Cow::Owned(Property::Float(*v))]).ok_or_else(|| out_of_bounds(*index, values.len()))
+        }
+        (entries!(_, entries), QuerySegment::Index(index)) => entries
+            .iter()
+            .find(|(key, _)| integer_key(key) == Some(*index as i128))
+            .map(|(_, value)| vec![Cow::Borrowed(value)])
+            .ok_or_else(|| format!("no key {}", index)),
+        (fields!(properties), QuerySegment::Wildcard) => Ok(properties.values().map(Cow::Borrowed).collect()),
+        (Property::MaterialInstance(properties), QuerySegment::Wildcard) => Ok(properties.values().map(Cow::Borrowed).collect()),
+        (entries!(_, entries), QuerySegment::Wildcard) => Ok(entries.iter().map(|(_, value)| Cow::Borrowed(value)).collect()),
+        (elements!(values), QuerySegment::Wildcard) => Ok(values.iter().map(Cow::Borrowed).collect()),
+        (Property::PerPlatformBool(values), QuerySegment::Wildcard) => Ok(values.iter().map(|v| Cow::Owned(Property::Bool(*v))).collect()),
+        (Property::PerPlatformInt(values), QuerySegment::Wildcard) => Ok(values.iter().map(|v| Cow::Owned(Property::Int32(*v))).collect()),
+        (Property::PerPlatformFloat(values), QuerySegment::Wildcard) => Ok(values.iter().map(|v| Cow::Owned(Property::Float(*v))).collect()),
+        _ if is_native_struct(property) => component_children(property, segment).map(owned),
+        _ => Err(no_children(property, segment)),
+    }
+}
+
+fn children_mut<'a>(property: &'a mut Property, segment: &QuerySegment) -> Result<Vec<&'a mut Property>, String> {
+    if is_native_struct(property) && !matches!(segment, QuerySegment::Index(_)) {
+        let name = match segment {
+            QuerySegment::Name(name) => name.as_str(),
+            _ => "*",
+        };
+        return Err(format!("`{}` is a component of native {} and cannot be borrowed mutably", name, describe(property)));
+    }
+    let description = describe(property);
+    match (property, segment) {
+        (fields!(properties), QuerySegment::Name(name)) => {
+            properties.get_mut(name).map(|p| vec![p]).ok_or_else(|| format!("no field `{}`", name))
+        }
+        (Property::MaterialInstance(properties), QuerySegment::Name(name)) => {
+            properties.get_mut(name).map(|p| vec![p]).ok_or_else(|| format!("no field `{}`", name))
+        }
+        (entries!(_, entries), QuerySegment::Name(name)) => entries
+            .iter_mut()
+            .find(|(key, _)| key_matches(key, name))
+            .map(|(_, value)| vec![value])
+            .ok_or_else(|| format!("no key `{}`", name)),
+        (elements!(values), QuerySegment::Index(index)) => {
+            let len = values.len();
+            values.get_mut(*index).map(|value| vec![value]).ok_or_else(|| out_of_bounds(*index, len))
+        }
+        (entries!(_, entries), QuerySegment::Index(index)) => entries
+            .iter_mut()
+            .find(|(key, _)| integer_key(key) == Some(*index as i128))
+            .map(|(_, value)| vec![value])
+            .ok_or_else(|| format!("no key {}", index)),
+        (fields!(properties), QuerySegment::Wildcard) => Ok(properties.values_mut().collect()),
+        (Property::MaterialInstance(properties), QuerySegment::Wildcard) => Ok(properties.values_mut().collect()),
+        (entries!(_, entries), QuerySegment::Wildcard) => Ok(entries.iter_mut().map(|(_, value)| value).collect()),
+        (elements!(values), QuerySegment::Wildcard) => Ok(values.iter_mut().collect()),
+        (Property::PerPlatformBool(_) | Property::PerPlatformInt(_) | Property::PerPlatformFloat(_), _) => {
+            Err(format!("elements of {} cannot be borrowed mutably", description))
+        }
+        (property, segment) => Err(no_children(property, segment)),
+    }
+}
+
+fn field_children<'a>(properties: &'a IndexMap<String, Property>, segment: &QuerySegment) -> Result<Vec<&'a Property>, String> {
+    match segment {
+        QuerySegment::Name(name) => properties.get(name).map(|p| vec![p]).ok_or_else(|| format!("no property `{}`", name)),
+        QuerySegment::Wildcard => Ok(properties.values().collect()),
+        QuerySegment::Index(index) => Err(format!("properties are addressed by name, not [{}]", index)),
+    }
+}
+
+fn field_children_mut<'a>(
+    properties: &'a mut IndexMap<String, Property>,
+    segment: &QuerySegment,
+) -> Result<Vec<&'a mut Property>, String> {
+    match segment {
+        QuerySegment::Name(name) => properties.get_mut(name).map(|p| vec![p]).ok_or_else(|| format!("no property `{}`", name)),
+        QuerySegment::Wildcard => Ok(properties.values_mut().collect()),
+        QuerySegment::Index(index) => Err(format!("properties are addressed by name, not [{}]", index)),
+    }
+}
+
+fn assign_field(properties: &mut IndexMap<String, Property>, segment: &QuerySegment, value: &Property) -> Result<usize, String> {
+    match segment {
+        QuerySegment::Name(name) => {
+            properties.insert(name.clone(), value.clone());
+            Ok(1)
+        }
+        QuerySegment::Wildcard => {
+            properties.values_mut().for_each(|property| *property = value.clone());
+            Ok(properties.len())
+        }
+        QuerySegment::Index(index) => Err(format!("properties are addressed by name, not [{}]", index)),
+    }
+}
+
+fn detach_field(properties: &mut IndexMap<String, Property>, segment: &QuerySegment) -> Result<Vec<Property>, String> {
+    match segment {
+        QuerySegment::Name(name) => properties.shift_remove(name).map(|p| vec![p]).ok_or_else(|| format!("no property `{}`", name)),
+        QuerySegment::Wildcard => Ok(properties.drain(..).map(|(_, property)| property).collect()),
+        QuerySegment::Index(index) => Err(format!("properties are addressed by name, not [{}]", index)),
+    }
+}
+
+/// Set the child selected by the last segment
+fn assign(property: &mut Property, segment: &QuerySegment, value: &Property) -> Result<usize, String> {
+    if is_native_struct(property) {
+        let names: Vec<&str> = component_children(property, segment)?.into_iter().map(|(name, _)| name).collect();
+        for name in &names {
+            set_component(property, name, value.clone())?;
+        }
+        return Ok(names.len());
+    }
+    match (property, segment) {
+        (fields!(properties), segment) => assign_field(properties, segment, value),
+        (Property::MaterialInstance(properties), QuerySegment::Name(name)) => {
+            properties.insert(name.clone(), value.clone());
+            Ok(1)
+        }
+        (entries!(key_type, entries), QuerySegment::Name(name)) => {
+            match entries.iter_mut().find(|(key, _)| key_matches(key, name)) {
+                Some((_, existing)) => *existing = value.clone(),
+                None => {
+                    let key = match key_type.as_str() {
+                        "StrProperty" => Property::String(name.clone()),
+                        "NameProperty" => Property::Name(FName::new(name.clone())),
+                        _ => return Err(format!("no key `{}` and cannot add keys to a map of {}", name, key_type)),
+                    };
+                    entries.push((key, value.clone()));
+                }
+            }
+            Ok(1)
+        }
+        (elements!(values), QuerySegment::Index(index)) => {
+            match (*index).cmp(&values.len()) {
+                std::cmp::Ordering::Less => values[*index] = value.clone(),
+                std::cmp::Ordering::Equal => values.push(value.clone()),
+                std::cmp::Ordering::Greater => return Err(out_of_bounds(*index, values.len())),
+            }
+            Ok(1)
+        }
+        (entries!(_, entries), QuerySegment::Index(index)) => {
+            let (_, existing) = entries
+                .iter_mut()
+                .find(|(key, _)| integer_key(key) == Some(*index as i128))
+                .ok_or_else(|| format!("no key {}", index))?;
+            *existing = value.clone();
+            Ok(1)
+        }
+        (Property::MaterialInstance(properties), QuerySegment::Wildcard) => {
+            properties.values_mut().for_each(|property| *property = value.clone());
+            Ok(properties.len())
+        }
+        (entries!(_, entries), QuerySegment::Wildcard) => {
+            entries.iter_mut().for_each(|(_, existing)| *existing = value.clone());
+            Ok(entries.len())
+        }
+        (elements!(values), QuerySegment::Wildcard) => {
+            values.iter_mut().for_each(|existing| *existing = value.clone());
+            Ok(values.len())
+        }
+        (property, segment) => Err(no_children(property, segment)),
+    }
+}
+
+/// Remove the child selected by the last segment
+fn detach(property: &mut Property, segment: &QuerySegment) -> Result<Vec<Property>, String> {
+    if is_native_struct(property) {
+        return Err(format!("components of native {} cannot be removed", describe(property)));
+    }
+    match (property, segment) {
+        (fields!(properties), segment) => detach_field(properties, segment),
+        (Property::MaterialInstance(properties), QuerySegment::Name(name)) => {
+            properties.remove(name).map(|p| vec![p]).ok_or_else(|| format!("no field `{}`", name))
+        }
+        (entries!(_, entries), QuerySegment::Name(name)) => {
+            let position = entries.iter().position(|(key, _)| key_matches(key, name)).ok_or_else(|| format!("no key `{}`", name))?;
+            Ok(vec![entries.remove(position).1])
+        }
+        (elements!(values), QuerySegment::Index(index)) if *index < values.len() => Ok(vec![values.remove(*index)]),
+        (elements!(values), QuerySegment::Index(index)) => Err(out_of_bounds(*index, values.len())),
+        (entries!(_, entries), QuerySegment::Index(index)) => {
+            let position = entries
+                .iter()
+                .position(|(key, _)| integer_key(key) == Some(*index as i128))
+                .ok_or_else(|| format!("no key {}", index))?;
+            Ok(vec![entries.remove(position).1])
+        }
+        (Property::MaterialInstance(properties), QuerySegment::Wildcard) => Ok(properties.drain().map(|(_, p)| p).collect()),
+        (entries!(_, entries), QuerySegment::Wildcard) => Ok(entries.drain(..).map(|(_, value)| value).collect()),
+        (elements!(values), QuerySegment::Wildcard) => Ok(std::mem::take(values)),
+        (property, segment) => Err(no_children(property, segment)),
+    }
+}
+
+/// Whether a map key is the string, name or enum value `name`
+fn key_matches(key: &Property, name: &str) -> bool {
+    match key {
+        Property::String(value) => value == name,
+        Property::Name(value) | Property::SmartNameProperty(value) => value.name == name || value.to_string() == name,
+        Property::Enum { value, .. } | Property::EnumProperty { value, .. } | Property::ByteEnum { value, .. } => {
+            value.name == name || value.name.rsplit("::").next() == Some(name)
+        }
+        Property::Text(text) => text.to_string() == name,
+        _ => false,
+    }
+}
+
+pub(crate) fn integer_key(key: &Property) -> Option<i128> {
+    Some(match key {
+        Property::Int8(v) => *v as i128,
+        Property::Int16(v) => *v as i128,
+        Property::Int32(v) => *v as i128,
+        Property::Int64(v) => *v as i128,
+        Property::UInt8(v) | Property::Byte(v) => *v as i128,
+        Property::UInt16(v) => *v as i128,
+        Property::UInt32(v) => *v as i128,
+        Property::UInt64(v) => *v as i128,
+        _ => return None,
+    })
+}
+
+fn as_f64(value: &Property) -> Option<f64> {
+    match value {
+        Property::Float(v) => Some(*v as f64),
+        Property::Double(v) => Some(*v),
+        other => integer_key(other).map(|v| v as f64),
+    }
+}
+
+/// Name of the property's type for error messages, e.g. `` `Vector` struct ``
+fn describe(property: &Property) -> String {
+    match property.struct_type_name() {
+        Some(name) => format!("`{}` struct", name),
+        None => format!("`{}`", property.type_name()),
+    }
+}
+
+fn out_of_bounds(index: usize, len: usize) -> String {
+    format!("index {} out of bounds for length {}", index, len)
+}
+
+fn no_children(property: &Property, segment: &QuerySegment) -> String {
+    match segment {
+        QuerySegment::Name(name) => format!("{} has no field `{}`", describe(property), name),
+        QuerySegment::Index(index) => format!("cannot index {} with [{}]", describe(property), index),
+        QuerySegment::Wildcard => format!("{} has no children", describe(property)),
+    }
+}
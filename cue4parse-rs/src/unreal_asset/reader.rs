@@ -2,11 +2,14 @@
 use std::collections::HashMap;
 use std::io::{Read, Write, Seek};
 use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::unreal_asset::error::{Error, UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::error::{Error, ErrorContext, LimitKind, PathSegment, PropertyPath, UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::object_versions::{EUnrealEngineObjectUE4Version, EUnrealEngineObjectUE5Version};
 use crate::unreal_asset::types::{FName, PackageIndex, PackageIndexTrait, ToSerializedName};
 use crate::unreal_asset::versions::{CustomVersion, CustomVersionTrait, EngineVersion, ObjectVersion, ObjectVersionUE5};
 use crate::unreal_asset::containers::{NameMap, SharedResource};
+use crate::unreal_asset::package_summary::{PACKAGE_FILE_TAG, PACKAGE_FILE_TAG_SWAPPED};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum ArchiveType {
@@ -31,10 +34,111 @@ impl std::fmt::Display for ArchiveType {
     }
 }
 
+/// Limits applied while reading untrusted data
+///
+/// Lengths are also checked against the bytes left in the archive, so the
+/// defaults only matter for very large inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveLimits {
+    /// Maximum number of characters in an `FString`
+    pub max_string_length: usize,
+    /// Maximum number of elements in an array
+    pub max_array_length: usize,
+    /// Maximum nesting depth of recursive structures, see [`ArchiveReader::read_nested`]
+    pub max_depth: usize,
+}
+
+impl Default for ArchiveLimits {
+    fn default() -> Self {
+        Self {
+            max_string_length: 1 << 24,
+            max_array_length: 1 << 26,
+            max_depth: 128,
+        }
+    }
+}
+
+/// Byte order of the primitives stored in an archive
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Endianness {
+    /// Little-endian, used by PC and most console builds
+    #[default]
+    Little,
+    /// Big-endian, used by some legacy console builds
+    Big,
+}
+
+impl Endianness {
+    /// Detect the endianness of a package from its file tag read as little-endian
+    pub fn from_package_tag(tag: u32) -> Option<Self> {
+        match tag {
+            PACKAGE_FILE_TAG => Some(Endianness::Little),
+            PACKAGE_FILE_TAG_SWAPPED => Some(Endianness::Big),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Endianness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Endianness::Little => write!(f, "Little-endian"),
+            Endianness::Big => write!(f, "Big-endian"),
+        }
+    }
+}
+
+/// Generate primitive reads, e.g. `read_i32_endian`, that honour [`ArchiveTrait::get_endianness`]
+macro_rules! read_primitives {
+    ($($name:ident => $read:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Read `", stringify!($ty), "` in the archive's byte order")]
+            fn $name(&mut self) -> std::io::Result<$ty> {
+                use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+                match self.get_endianness() {
+                    Endianness::Little => ReadBytesExt::$read::<LittleEndian>(self),
+                    Endianness::Big => ReadBytesExt::$read::<BigEndian>(self),
+                }
+            }
+        )*
+    };
+}
+
+/// Generate primitive writes, e.g. `write_i32_endian`, that honour [`ArchiveTrait::get_endianness`]
+macro_rules! write_primitives {
+    ($($name:ident => $write:ident: $ty:ty),* $(,)?) => {
+        $(
+            #[doc = concat!("Write `", stringify!($ty), "` in the archive's byte order")]
+            fn $name(&mut self, value: $ty) -> std::io::Result<()> {
+                use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+                match self.get_endianness() {
+                    Endianness::Little => WriteBytesExt::$write::<LittleEndian>(self, value),
+                    Endianness::Big => WriteBytesExt::$write::<BigEndian>(self, value),
+                }
+            }
+        )*
+    };
+}
+
 pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
     /// Get archive type
     fn get_archive_type(&self) -> ArchiveType;
 
+    /// Byte order of the primitives in this archive
+    fn get_endianness(&self) -> Endianness {
+        Endianness::Little
+    }
+
+    /// Change the byte order, e.g. after detecting a byte swapped package tag
+    ///
+    /// Archives that only handle little-endian data ignore this.
+    fn set_endianness(&mut self, _endianness: Endianness) {}
+
+    /// Name of the archive used in error messages, usually its file name
+    fn get_archive_name(&self) -> Option<&str> {
+        None
+    }
+
     /// Get a custom version from this archive
     fn get_custom_version<T>(&self) -> CustomVersion
     where
@@ -64,28 +168,26 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
         Ok(())
     }
 
+    /// Add a name to this archive's name map, returning its index
+    fn add_name_reference(&mut self, value: &str, force_add_duplicates: bool) -> i32 {
+        self.get_name_map().get_mut().add_name_reference(value.to_string(), force_add_duplicates)
+    }
+
+    /// Find a name's index in this archive's name map
+    fn search_name_reference(&self, value: &str) -> Option<i32> {
+        self.get_name_map().get_ref().search_name_reference(value)
+    }
+
     /// Add a string slice to this archive as an `FName`, `FName` number will be 0
     fn add_fname(&mut self, value: &str) -> FName {
-        let mut binding = self.get_name_map();
-        let mut name_map = binding.get_mut();
-        if let Some(_index) = name_map.iter().position(|n| n == value) {
-            FName::new(value)
-        } else {
-            name_map.push(value.to_string());
-            FName::new(value)
-        }
+        self.add_name_reference(value, false);
+        FName::new(value)
     }
     
     /// Add a string slice to this archive as an `FName`
     fn add_fname_with_number(&mut self, value: &str, number: i32) -> FName {
-        let mut binding = self.get_name_map();
-        let mut name_map = binding.get_mut();
-        if let Some(_index) = name_map.iter().position(|n| n == value) {
-            FName::with_number(value, number as u32)
-        } else {
-            name_map.push(value.to_string());
-            FName::with_number(value, number as u32)
-        }
+        self.add_name_reference(value, false);
+        FName::with_number(value, number as u32)
     }
 
     /// Get FName name map
@@ -94,7 +196,8 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
     /// Get FName name reference by name map index and do something with it
     fn get_name_reference<T>(&self, index: i32, func: impl FnOnce(&str) -> T) -> T {
         let name_map = self.get_name_map();
-        let name_ref = name_map.get_ref().get(index as usize).map(|s| s.as_str()).unwrap_or("");
+        let name_map = name_map.get_ref();
+        let name_ref = name_map.get(index as usize).map(|s| s.as_str()).unwrap_or("");
         func(name_ref)
     }
     
@@ -121,6 +224,11 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
     /// Get archive's UE5 object version
     fn get_object_version_ue5(&self) -> ObjectVersionUE5;
 
+    /// Whether floating point math structs are stored as doubles
+    fn has_large_world_coordinates(&self) -> bool {
+        self.get_object_version_ue5() >= EUnrealEngineObjectUE5Version::LARGE_WORLD_COORDINATES
+    }
+
     /// Get .usmap mappings
     fn get_mappings(&self) -> Option<&()> { // TODO: Usmap
         None // Would need to be stored in the archive
@@ -145,9 +253,144 @@ pub trait ArchiveTrait<Index: PackageIndexTrait>: Seek {
 }
 
 pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
+    /// Get the limits checked by this archive
+    fn get_limits(&self) -> ArchiveLimits {
+        ArchiveLimits::default()
+    }
+
+    /// Current nesting depth, maintained by [`read_nested`](ArchiveReader::read_nested)
+    ///
+    /// Archives that don't track it return `None` and have no depth limit.
+    fn nesting_depth_mut(&mut self) -> Option<&mut usize> {
+        None
+    }
+
+    /// Property path being read, maintained by [`read_in`](ArchiveReader::read_in)
+    ///
+    /// Archives that don't track it return `None` and report errors without a path.
+    fn property_path_mut(&mut self) -> Option<&mut PropertyPath> {
+        None
+    }
+
+    /// Where the last error raised inside [`read_in`](ArchiveReader::read_in) came from
+    ///
+    /// Archives that don't track it return `None`.
+    fn error_context(&self) -> Option<&ErrorContext> {
+        None
+    }
+
+    /// Store the context of the last error, see [`error_context`](ArchiveReader::error_context)
+    fn set_error_context(&mut self, _context: Option<ErrorContext>) {}
+
+    /// Read a value under `segment` of the property path
+    ///
+    /// Errors are returned unchanged, the archive name, offset and property path
+    /// they were raised at are kept in [`error_context`](ArchiveReader::error_context).
+    fn read_in<T>(
+        &mut self,
+        segment: impl Into<PathSegment>,
+        getter: impl FnOnce(&mut Self) -> UnrealAssetResult<T>,
+    ) -> UnrealAssetResult<T> {
+        if let Some(path) = self.property_path_mut() {
+            if path.is_empty() {
+                self.set_error_context(None);
+            }
+        }
+        if let Some(path) = self.property_path_mut() {
+            path.push(segment);
+        }
+        let result = getter(self);
+        if let Err(error) = &result {
+            self.record_error_context(error);
+        }
+        if let Some(path) = self.property_path_mut() {
+            path.pop();
+        }
+        result
+    }
+
+    /// Record where `error` was raised, unless a nested [`read_in`](ArchiveReader::read_in) already did
+    fn record_error_context(&mut self, error: &UnrealAssetError) {
+        let path = self.property_path_mut().map(|path| path.clone()).unwrap_or_default();
+        if self.error_context().is_some_and(|context| context.path.segments().starts_with(path.segments())) {
+            return;
+        }
+        let offset = match error.root() {
+            UnrealAssetError::LimitExceeded { offset, .. } => *offset,
+            _ => self.position(),
+        };
+        let context = ErrorContext {
+            archive_name: self.get_archive_name().map(str::to_string),
+            offset,
+            path,
+        };
+        self.set_error_context(Some(context));
+    }
+
+    /// Number of bytes after the cursor
+    fn remaining(&mut self) -> std::io::Result<u64> {
+        let position = self.position();
+        Ok(self.data_length()?.saturating_sub(position))
+    }
+
+    /// Check a length read at `offset` against the archive limits and the remaining data
+    ///
+    /// Every element is expected to take at least `element_size` bytes.
+    fn check_length(&mut self, kind: LimitKind, value: i64, offset: u64, element_size: u64) -> UnrealAssetResult<usize> {
+        let limits = self.get_limits();
+        let max = match kind {
+            LimitKind::StringLength => limits.max_string_length,
+            LimitKind::ArrayLength => limits.max_array_length,
+            LimitKind::NestingDepth => limits.max_depth,
+        };
+        let remaining = self.remaining()?;
+        if value < 0 || value as u64 > max as u64 || (value as u64).saturating_mul(element_size) > remaining {
+            return Err(UnrealAssetError::LimitExceeded { kind, value, offset });
+        }
+        Ok(value as usize)
+    }
+
+    /// Read a nested structure, failing once `max_depth` levels are open
+    fn read_nested<T>(&mut self, getter: impl FnOnce(&mut Self) -> UnrealAssetResult<T>) -> UnrealAssetResult<T> {
+        let Some(depth) = self.nesting_depth_mut().map(|depth| *depth + 1) else {
+            return getter(self);
+        };
+        if depth > self.get_limits().max_depth {
+            let offset = self.position();
+            return Err(UnrealAssetError::LimitExceeded { kind: LimitKind::NestingDepth, value: depth as i64, offset });
+        }
+        if let Some(current) = self.nesting_depth_mut() {
+            *current = depth;
+        }
+        let result = getter(self);
+        if let Some(current) = self.nesting_depth_mut() {
+            *current = depth - 1;
+        }
+        result
+    }
+
+    read_primitives! {
+        read_u16_endian => read_u16: u16,
+        read_i16_endian => read_i16: i16,
+        read_u32_endian => read_u32: u32,
+        read_i32_endian => read_i32: i32,
+        read_u64_endian => read_u64: u64,
+        read_i64_endian => read_i64: i64,
+        read_f32_endian => read_f32: f32,
+        read_f64_endian => read_f64: f64,
+    }
+
+    /// Read a math struct component, a double with large world coordinates and a float before
+    fn read_lwc_float(&mut self) -> std::io::Result<f64> {
+        match self.has_large_world_coordinates() {
+            true => self.read_f64_endian(),
+            false => Ok(self.read_f32_endian()? as f64),
+        }
+    }
+
     /// Read a `Guid` property
     fn read_property_guid(&mut self) -> UnrealAssetResult<Option<Uuid>> {
-        if self.get_object_version().get() >= 522 { // VER_UE4_25
+        if self.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG {
             let has_property_guid = self.read_bool()?;
             if has_property_guid {
                 return Ok(Some(self.read_guid()?));
@@ -158,10 +401,8 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
 
     /// Read an `FName`
     fn read_fname(&mut self) -> UnrealAssetResult<FName> {
-        use byteorder::{ReadBytesExt, LittleEndian};
-        
-        let index = self.read_i32::<LittleEndian>()?;
-        let number = self.read_i32::<LittleEndian>()?;
+        let index = self.read_i32_endian()?;
+        let number = self.read_i32_endian()?;
 
         let name_map_size = self
             .get_name_map()
@@ -182,9 +423,11 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         length: i32,
         getter: impl Fn(&mut Self) -> UnrealAssetResult<T>,
     ) -> UnrealAssetResult<Vec<T>> {
-        let mut array = Vec::with_capacity(length as usize);
-        for _ in 0..length {
-            array.push(getter(self)?);
+        let offset = self.position();
+        let length = self.check_length(LimitKind::ArrayLength, length as i64, offset, 1)?;
+        let mut array = Vec::with_capacity(length);
+        for i in 0..length {
+            array.push(self.read_in(i, &getter)?);
         }
         Ok(array)
     }
@@ -194,34 +437,34 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
         &mut self,
         getter: impl Fn(&mut Self) -> UnrealAssetResult<T>,
     ) -> UnrealAssetResult<Vec<T>> {
-        use byteorder::{ReadBytesExt, LittleEndian};
-        let length = self.read_i32::<LittleEndian>()?;
-        self.read_array_with_length(length, getter)
+        let offset = self.position();
+        let length = self.read_i32_endian()?;
+        let length = self.check_length(LimitKind::ArrayLength, length as i64, offset, 1)?;
+        self.read_array_with_length(length as i32, getter)
     }
 
     /// Read an FString
     fn read_fstring(&mut self) -> UnrealAssetResult<Option<String>> {
-        use byteorder::{ReadBytesExt, LittleEndian};
-        
-        // Simple string reading implementation
-        let length = self.read_i32::<LittleEndian>()?;
+        let offset = self.position();
+        let length = self.read_i32_endian()?;
         if length == 0 {
             return Ok(None);
         }
         
         if length < 0 {
             // Unicode string (UCS-2)
-            let char_count = (-length) as usize;
+            let char_count = self.check_length(LimitKind::StringLength, -(length as i64), offset, 2)?;
             let mut buffer = vec![0u16; char_count];
             for i in 0..char_count {
-                buffer[i] = self.read_u16::<LittleEndian>()?;
+                buffer[i] = self.read_u16_endian()?;
             }
             // Convert UTF-16 to String, removing null terminator
             let s = String::from_utf16_lossy(&buffer[..char_count.saturating_sub(1)]);
             Ok(Some(s))
         } else {
             // ANSI string
-            let mut buffer = vec![0u8; length as usize];
+            let char_count = self.check_length(LimitKind::StringLength, length as i64, offset, 1)?;
+            let mut buffer = vec![0u8; char_count];
             self.read_exact(&mut buffer)?;
             // Remove null terminator if present
             if let Some(&0) = buffer.last() {
@@ -233,12 +476,10 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
     
     /// Read a guid
     fn read_guid(&mut self) -> std::io::Result<Uuid> {
-        use byteorder::{ReadBytesExt, LittleEndian};
-        
-        let a = self.read_u32::<LittleEndian>()?;
-        let b = self.read_u32::<LittleEndian>()?;
-        let c = self.read_u32::<LittleEndian>()?;
-        let d = self.read_u32::<LittleEndian>()?;
+        let a = self.read_u32_endian()?;
+        let b = self.read_u32_endian()?;
+        let c = self.read_u32_endian()?;
+        let d = self.read_u32_endian()?;
         
         // Convert UE4 GUID format to UUID
         let bytes = [
@@ -271,9 +512,28 @@ pub trait ArchiveReader<Index: PackageIndexTrait>: ArchiveTrait<Index> + Read {
 }
 
 pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
+    write_primitives! {
+        write_u16_endian => write_u16: u16,
+        write_i16_endian => write_i16: i16,
+        write_u32_endian => write_u32: u32,
+        write_i32_endian => write_i32: i32,
+        write_u64_endian => write_u64: u64,
+        write_i64_endian => write_i64: i64,
+        write_f32_endian => write_f32: f32,
+        write_f64_endian => write_f64: f64,
+    }
+
+    /// Write a math struct component, a double with large world coordinates and a float before
+    fn write_lwc_float(&mut self, value: f64) -> std::io::Result<()> {
+        match self.has_large_world_coordinates() {
+            true => self.write_f64_endian(value),
+            false => self.write_f32_endian(value as f32),
+        }
+    }
+
     /// Write a `Guid` property
     fn write_property_guid(&mut self, guid: Option<&Uuid>) -> UnrealAssetResult<()> {
-        if self.get_object_version().get() >= 522 { // VER_UE4_25
+        if self.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG {
             self.write_bool(guid.is_some())?;
             if let Some(data) = guid {
                 self.write_guid(data)?;
@@ -282,40 +542,43 @@ pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
         Ok(())
     }
 
-    /// Write an `FName`
+    /// Write an `FName`, adding its string to the name map if it is not there yet
     fn write_fname(&mut self, fname: &FName) -> UnrealAssetResult<()> {
-        use byteorder::{WriteBytesExt, LittleEndian};
-        
-        // For compatibility, we'll need to find the name in the map
-        let name_map = self.get_name_map();
-        let name_ref = name_map.get_ref();
+        let index = self.add_name_reference(&fname.name, false);
         
-        if let Some(index) = name_ref.iter().position(|n| n == &fname.name) {
-            self.write_i32::<LittleEndian>(index as i32)?;
-            self.write_i32::<LittleEndian>(fname.number as i32)?;
-        } else {
-            return Err(UnrealAssetError::new(&format!("FName '{}' not found in name map", fname.name)));
-        }
+        self.write_i32_endian(index)?;
+        self.write_i32_endian(fname.number as i32)?;
         
         Ok(())
     }
 
     /// Write an FString
+    ///
+    /// Pure ASCII strings are written as null-terminated ANSI, anything else as
+    /// null-terminated UTF-16 with a negative length, matching `FString` serialization.
+    /// Returns the number of payload bytes written after the length prefix.
     fn write_fstring(&mut self, value: Option<&str>) -> UnrealAssetResult<usize> {
-        use byteorder::{WriteBytesExt, LittleEndian};
-        
-        match value {
-            Some(s) if !s.is_empty() => {
-                // Write as ANSI string
+        use byteorder::WriteBytesExt;
+
+        match value.filter(|s| !s.is_empty()) {
+            Some(s) if s.is_ascii() => {
                 let bytes = s.as_bytes();
-                self.write_i32::<LittleEndian>((bytes.len() + 1) as i32)?; // +1 for null terminator
+                self.write_i32_endian((bytes.len() + 1) as i32)?; // +1 for null terminator
                 self.write_all(bytes)?;
                 self.write_u8(0)?; // null terminator
                 Ok(bytes.len() + 1)
             }
-            _ => {
-                // Empty string
-                self.write_i32::<LittleEndian>(0)?;
+            Some(s) => {
+                let units: Vec<u16> = s.encode_utf16().collect();
+                self.write_i32_endian(-((units.len() + 1) as i32))?;
+                for unit in &units {
+                    self.write_u16_endian(*unit)?;
+                }
+                self.write_u16_endian(0)?; // null terminator
+                Ok((units.len() + 1) * 2)
+            }
+            None => {
+                self.write_i32_endian(0)?;
                 Ok(0)
             }
         }
@@ -323,8 +586,6 @@ pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
     
     /// Write a guid
     fn write_guid(&mut self, guid: &Uuid) -> std::io::Result<()> {
-        use byteorder::{WriteBytesExt, LittleEndian};
-        
         let bytes = guid.as_bytes();
         
         // Convert UUID to UE4 GUID format (4 u32s)
@@ -333,10 +594,10 @@ pub trait ArchiveWriter<Index: PackageIndexTrait>: ArchiveTrait<Index> + Write {
         let c = u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]);
         let d = u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]);
         
-        self.write_u32::<LittleEndian>(a)?;
-        self.write_u32::<LittleEndian>(b)?;
-        self.write_u32::<LittleEndian>(c)?;
-        self.write_u32::<LittleEndian>(d)?;
+        self.write_u32_endian(a)?;
+        self.write_u32_endian(b)?;
+        self.write_u32_endian(c)?;
+        self.write_u32_endian(d)?;
         
         Ok(())
     }
@@ -355,6 +616,14 @@ pub struct BinaryArchive<R: Read + Seek> {
     object_version_ue5: ObjectVersionUE5,
     engine_version: EngineVersion,
     custom_versions: HashMap<String, i32>,
+    limits: ArchiveLimits,
+    nesting_depth: usize,
+    name: Option<String>,
+    property_path: PropertyPath,
+    error_context: Option<ErrorContext>,
+    endianness: Endianness,
+    /// Length of the reader, measured on first use
+    data_length: Option<u64>,
 }
 
 impl<R: Read + Seek> BinaryArchive<R> {
@@ -363,36 +632,80 @@ impl<R: Read + Seek> BinaryArchive<R> {
         Self {
             reader,
             name_map: SharedResource::new(NameMap::new()),
-            object_version: ObjectVersion::new(match engine_version {
-                EngineVersion::VerUe4_27 => 522,
-                EngineVersion::VerUe5_0 => 524,
-                EngineVersion::VerUe5_1 => 525,
-                EngineVersion::VerUe5_2 => 526,
-                EngineVersion::VerUe5_3 => 527,
-                _ => 522,
-            }),
-            object_version_ue5: ObjectVersionUE5::new(0),
+            object_version: engine_version.object_version(),
+            object_version_ue5: engine_version.object_version_ue5(),
             engine_version,
             custom_versions: HashMap::new(),
+            limits: ArchiveLimits::default(),
+            nesting_depth: 0,
+            name: None,
+            property_path: PropertyPath::new(),
+            error_context: None,
+            endianness: Endianness::Little,
+            data_length: None,
+        }
+    }
+    
+    /// Create a new binary archive that resolves names through an existing name map
+    pub fn with_name_map(reader: R, engine_version: EngineVersion, name_map: SharedResource<NameMap>) -> Self {
+        Self {
+            name_map,
+            ..Self::new(reader, engine_version)
         }
     }
     
-    /// Static method to read FString from a reader
-    pub fn read_fstring_static<R2: Read + Seek>(reader: &mut R2) -> UnrealAssetResult<Option<String>> {
-        use byteorder::{ReadBytesExt, LittleEndian};
+    /// Override the file versions taken from the engine version, e.g. with a package summary's
+    pub fn set_object_versions(&mut self, object_version: ObjectVersion, object_version_ue5: ObjectVersionUE5) {
+        self.object_version = object_version;
+        self.object_version_ue5 = object_version_ue5;
+    }
+    
+    /// Name the archive, usually after its file, for error messages
+    pub fn set_archive_name(&mut self, name: impl Into<String>) {
+        self.name = Some(name.into());
+    }
+    
+    /// Replace the default limits used to validate lengths read from the archive
+    pub fn set_limits(&mut self, limits: ArchiveLimits) {
+        self.limits = limits;
+    }
+    
+    /// Use the custom versions a package was saved with
+    pub fn set_custom_versions(&mut self, custom_versions: &[CustomVersion]) {
+        self.custom_versions = custom_versions.iter().map(|v| (v.guid.to_string(), v.version)).collect();
+    }
+    
+    /// Static method to read FString from a reader stored with the given byte order
+    pub fn read_fstring_static<R2: Read + Seek>(reader: &mut R2, endianness: Endianness) -> UnrealAssetResult<Option<String>> {
+        use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
         
-        let length = reader.read_i32::<LittleEndian>()?;
+        let offset = reader.stream_position()?;
+        let length = match endianness {
+            Endianness::Little => reader.read_i32::<LittleEndian>()?,
+            Endianness::Big => reader.read_i32::<BigEndian>()?,
+        };
         
         if length == 0 {
             return Ok(None);
         }
         
+        // Lengths past the end of the stream are rejected before allocating
+        let byte_count = if length < 0 { -(length as i64) * 2 } else { length as i64 };
+        let end = reader.seek(std::io::SeekFrom::End(0))?;
+        reader.seek(std::io::SeekFrom::Start(offset + 4))?;
+        if byte_count as u64 > end.saturating_sub(offset + 4) {
+            return Err(UnrealAssetError::LimitExceeded { kind: LimitKind::StringLength, value: length as i64, offset });
+        }
+        
         if length < 0 {
             // Unicode string (UTF-16)
-            let char_count = (-length) as usize;
+            let char_count = length.unsigned_abs() as usize;
             let mut buffer = vec![0u16; char_count];
             for i in 0..char_count {
-                buffer[i] = reader.read_u16::<LittleEndian>()?;
+                buffer[i] = match endianness {
+                    Endianness::Little => reader.read_u16::<LittleEndian>()?,
+                    Endianness::Big => reader.read_u16::<BigEndian>()?,
+                };
             }
             // Remove null terminator if present
             if !buffer.is_empty() && buffer[buffer.len() - 1] == 0 {
@@ -422,6 +735,30 @@ impl<R: Read + Seek> ArchiveTrait<PackageIndex> for BinaryArchive<R> {
         ArchiveType::UAsset
     }
     
+    fn get_endianness(&self) -> Endianness {
+        self.endianness
+    }
+    
+    fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+    
+    fn get_archive_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+    
+    fn data_length(&mut self) -> std::io::Result<u64> {
+        // The reader is never written to, so its length only has to be measured once
+        if let Some(length) = self.data_length {
+            return Ok(length);
+        }
+        let position = self.reader.stream_position()?;
+        let length = self.reader.seek(std::io::SeekFrom::End(0))?;
+        self.reader.seek(std::io::SeekFrom::Start(position))?;
+        self.data_length = Some(length);
+        Ok(length)
+    }
+    
     fn get_object_version(&self) -> ObjectVersion {
         self.object_version
     }
@@ -435,12 +772,11 @@ impl<R: Read + Seek> ArchiveTrait<PackageIndex> for BinaryArchive<R> {
     }
     
     fn get_custom_version<T: CustomVersionTrait>(&self) -> CustomVersion {
-        // Create a default CustomVersion - in a real implementation this would look up the actual version
-        CustomVersion {
-            guid: T::guid(),
-            version: self.custom_versions.get(&T::guid().to_string()).copied().unwrap_or(0),
-            friendly_name: "Unknown".to_string(),
-        }
+        // Fall back to the version the engine shipped with, -1 if it predates the custom version
+        let version = self.custom_versions.get(&T::guid().to_string()).copied().unwrap_or_else(|| {
+            T::from_engine_version(self.engine_version).map_or(-1, Into::into)
+        });
+        CustomVersion::new(T::guid(), version, T::friendly_name().to_string())
     }
     
     fn get_mappings(&self) -> Option<&()> { // TODO: Usmap
@@ -506,7 +842,27 @@ impl<R: Read + Seek> Seek for BinaryArchive<R> {
     }
 }
 
-impl<R: Read + Seek> ArchiveReader<PackageIndex> for BinaryArchive<R> {}
+impl<R: Read + Seek> ArchiveReader<PackageIndex> for BinaryArchive<R> {
+    fn get_limits(&self) -> ArchiveLimits {
+        self.limits
+    }
+    
+    fn nesting_depth_mut(&mut self) -> Option<&mut usize> {
+        Some(&mut self.nesting_depth)
+    }
+    
+    fn property_path_mut(&mut self) -> Option<&mut PropertyPath> {
+        Some(&mut self.property_path)
+    }
+    
+    fn error_context(&self) -> Option<&ErrorContext> {
+        self.error_context.as_ref()
+    }
+    
+    fn set_error_context(&mut self, context: Option<ErrorContext>) {
+        self.error_context = context;
+    }
+}
 
 pub struct BinaryArchiveWriter<W: Write + Seek> {
     writer: W,
@@ -515,6 +871,8 @@ pub struct BinaryArchiveWriter<W: Write + Seek> {
     object_version_ue5: ObjectVersionUE5,
     engine_version: EngineVersion,
     custom_versions: HashMap<String, i32>,
+    endianness: Endianness,
+    array_struct_type_override: IndexMap<String, String>,
 }
 
 impl<W: Write + Seek> BinaryArchiveWriter<W> {
@@ -523,19 +881,33 @@ impl<W: Write + Seek> BinaryArchiveWriter<W> {
         Self {
             writer,
             name_map: SharedResource::new(NameMap::new()),
-            object_version: ObjectVersion::new(match engine_version {
-                EngineVersion::VerUe4_27 => 522,
-                EngineVersion::VerUe5_0 => 524,
-                EngineVersion::VerUe5_1 => 525,
-                EngineVersion::VerUe5_2 => 526,
-                EngineVersion::VerUe5_3 => 527,
-                _ => 522,
-            }),
-            object_version_ue5: ObjectVersionUE5::new(0),
+            object_version: engine_version.object_version(),
+            object_version_ue5: engine_version.object_version_ue5(),
             engine_version,
             custom_versions: HashMap::new(),
+            endianness: Endianness::Little,
+            array_struct_type_override: IndexMap::new(),
+        }
+    }
+    
+    /// Create a new binary archive writer that adds names to an existing name map
+    pub fn with_name_map(writer: W, engine_version: EngineVersion, name_map: SharedResource<NameMap>) -> Self {
+        Self {
+            name_map,
+            ..Self::new(writer, engine_version)
         }
     }
+
+    /// Element struct types of array properties by property name, used for empty struct arrays
+    pub fn with_array_struct_type_override(mut self, overrides: IndexMap<String, String>) -> Self {
+        self.array_struct_type_override = overrides;
+        self
+    }
+    
+    /// Consume the archive and return the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
 }
 
 impl<W: Write + Seek> ArchiveTrait<PackageIndex> for BinaryArchiveWriter<W> {
@@ -543,6 +915,14 @@ impl<W: Write + Seek> ArchiveTrait<PackageIndex> for BinaryArchiveWriter<W> {
         ArchiveType::UAsset
     }
     
+    fn get_endianness(&self) -> Endianness {
+        self.endianness
+    }
+    
+    fn set_endianness(&mut self, endianness: Endianness) {
+        self.endianness = endianness;
+    }
+    
     fn get_object_version(&self) -> ObjectVersion {
         self.object_version
     }
@@ -556,12 +936,11 @@ impl<W: Write + Seek> ArchiveTrait<PackageIndex> for BinaryArchiveWriter<W> {
     }
     
     fn get_custom_version<T: CustomVersionTrait>(&self) -> CustomVersion {
-        // Create a default CustomVersion - in a real implementation this would look up the actual version
-        CustomVersion {
-            guid: T::guid(),
-            version: self.custom_versions.get(&T::guid().to_string()).copied().unwrap_or(0),
-            friendly_name: "Unknown".to_string(),
-        }
+        // Fall back to the version the engine shipped with, -1 if it predates the custom version
+        let version = self.custom_versions.get(&T::guid().to_string()).copied().unwrap_or_else(|| {
+            T::from_engine_version(self.engine_version).map_or(-1, Into::into)
+        });
+        CustomVersion::new(T::guid(), version, T::friendly_name().to_string())
     }
     
     fn get_mappings(&self) -> Option<&()> { // TODO: Usmap
@@ -597,9 +976,7 @@ impl<W: Write + Seek> ArchiveTrait<PackageIndex> for BinaryArchiveWriter<W> {
     }
     
     fn get_array_struct_type_override(&self) -> &IndexMap<String, String> {
-        // Return empty map - in a real implementation this would be populated
-        static EMPTY_MAP: std::sync::OnceLock<IndexMap<String, String>> = std::sync::OnceLock::new();
-        EMPTY_MAP.get_or_init(|| IndexMap::new())
+        &self.array_struct_type_override
     }
     
     fn get_map_key_override(&self) -> &IndexMap<String, String> {
@@ -0,0 +1,608 @@
+//! Serialize Rust types into properties
+//!
+//! [`to_properties`] turns a `#[derive(Serialize)]` struct into a property map that the
+//! property writer can save, e.g. as a DataTable row or the properties of a config export,
+//! and [`to_property`] converts a single value.
+//!
+//! Without mappings, property types follow the Rust types: `i32` becomes an `Int32`, `f32` a
+//! `Float`, strings a `Str` and unit enum variants an `Enum` named after the Rust enum. Structs
+//! named like an engine math struct (`Vector`, `Rotator`, `LinearColor`, ...) become native
+//! structs, and `None` fields are left out. [`to_properties_with_mappings`] and
+//! [`to_property_typed`] instead fit every value to the type the usmap declares, so a `String`
+//! field can become a `NameProperty` and a `u32` an `IntProperty`.
+
+use indexmap::IndexMap;
+use serde::ser::{
+    self, Impossible, Serialize, SerializeMap, SerializeSeq, SerializeStruct, SerializeTuple, SerializeTupleStruct,
+    Serializer,
+};
+
+use crate::unreal_asset::de::{number, Number, SerdeError};
+use crate::unreal_asset::error::UnrealAssetResult;
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::text::FText;
+use crate::unreal_asset::types::{FName, LinearColor, PackageIndex, Quat, Rotator, SoftObjectPath, Transform, Vector, Vector2D, Vector4};
+use crate::unreal_asset::usmap::{PropertyType, Usmap};
+
+/// Serialize `value` into a single property, typed after the Rust type
+pub fn to_property<T: Serialize + ?Sized>(value: &T) -> UnrealAssetResult<Property> {
+    value.serialize(PropertySerializer).map_err(Into::into)
+}
+
+/// Serialize a struct or string-keyed map into a property map, typed after the Rust types
+pub fn to_properties<T: Serialize + ?Sized>(value: &T) -> UnrealAssetResult<IndexMap<String, Property>> {
+    into_fields(value.serialize(PropertySerializer)?).map_err(Into::into)
+}
+
+/// Serialize `value` into a property of the given type
+///
+/// `mappings` supplies the field types of structs inside the value.
+pub fn to_property_typed<T: Serialize + ?Sized>(
+    value: &T,
+    property_type: &PropertyType,
+    mappings: Option<&Usmap>,
+) -> UnrealAssetResult<Property> {
+    retype(value.serialize(PropertySerializer)?, property_type, mappings).map_err(Into::into)
+}
+
+/// Serialize a struct into the properties of `schema`, typed after the mappings
+///
+/// Fields the schema does not declare keep the type of their Rust value.
+pub fn to_properties_with_mappings<T: Serialize + ?Sized>(
+    value: &T,
+    mappings: &Usmap,
+    schema: &str,
+) -> UnrealAssetResult<IndexMap<String, Property>> {
+    let fields = into_fields(value.serialize(PropertySerializer)?)?;
+    retype_fields(fields, schema, Some(mappings)).map_err(Into::into)
+}
+
+/// [`Serializer`] producing a [`Property`] typed after the serialized Rust value
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PropertySerializer;
+
+impl Serializer for PropertySerializer {
+    type Ok = Property;
+    type Error = SerdeError;
+    type SerializeSeq = SerializeElements;
+    type SerializeTuple = SerializeElements;
+    type SerializeTupleStruct = SerializeElements;
+    type SerializeTupleVariant = Impossible<Property, SerdeError>;
+    type SerializeMap = SerializeEntries;
+    type SerializeStruct = SerializeFields;
+    type SerializeStructVariant = Impossible<Property, SerdeError>;
+
+    fn serialize_bool(self, v: bool) -> Result<Property, SerdeError> {
+        Ok(Property::Bool(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Property, SerdeError> {
+        Ok(Property::Int8(v))
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Property, SerdeError> {
+        Ok(Property::Int16(v))
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Property, SerdeError> {
+        Ok(Property::Int32(v))
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Property, SerdeError> {
+        Ok(Property::Int64(v))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Property, SerdeError> {
+        Ok(Property::Byte(v))
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Property, SerdeError> {
+        Ok(Property::UInt16(v))
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Property, SerdeError> {
+        Ok(Property::UInt32(v))
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Property, SerdeError> {
+        Ok(Property::UInt64(v))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Property, SerdeError> {
+        Ok(Property::Float(v))
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Property, SerdeError> {
+        Ok(Property::Double(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Property, SerdeError> {
+        Ok(Property::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Property, SerdeError> {
+        Ok(Property::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Property, SerdeError> {
+        Ok(Property::Array(v.iter().copied().map(Property::Byte).collect()))
+    }
+
+    /// `None` becomes a null value, which struct fields and map entries leave out
+    fn serialize_none(self) -> Result<Property, SerdeError> {
+        Ok(Property::Unknown(serde_json::Value::Null))
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Property, SerdeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Property, SerdeError> {
+        Ok(Property::Unknown(serde_json::Value::Null))
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Property, SerdeError> {
+        Ok(Property::Struct { struct_type: FName::new(name), properties: IndexMap::new() })
+    }
+
+    fn serialize_unit_variant(self, name: &'static str, _index: u32, variant: &'static str) -> Result<Property, SerdeError> {
+        Ok(Property::Enum { enum_type: FName::new(name), value: FName::new(format!("{}::{}", name, variant)) })
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Property, SerdeError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _value: &T,
+    ) -> Result<Property, SerdeError> {
+        Err(variant_with_data(name, variant))
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeElements, SerdeError> {
+        Ok(SerializeElements { elements: Vec::with_capacity(len.unwrap_or_default()) })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SerializeElements, SerdeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<SerializeElements, SerdeError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeTupleVariant, SerdeError> {
+        Err(variant_with_data(name, variant))
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeEntries, SerdeError> {
+        Ok(SerializeEntries { entries: Vec::with_capacity(len.unwrap_or_default()), key: None })
+    }
+
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<SerializeFields, SerdeError> {
+        Ok(SerializeFields { name, properties: IndexMap::with_capacity(len) })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Self::SerializeStructVariant, SerdeError> {
+        Err(variant_with_data(name, variant))
+    }
+}
+
+fn variant_with_data(name: &str, variant: &str) -> SerdeError {
+    ser::Error::custom(format!("{}::{} carries data, which an enum property cannot hold", name, variant))
+}
+
+/// Arrays, tuples and sets
+pub struct SerializeElements {
+    elements: Vec<Property>,
+}
+
+impl SerializeSeq for SerializeElements {
+    type Ok = Property;
+    type Error = SerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        let element = value.serialize(PropertySerializer).map_err(|error| error.within(self.elements.len()))?;
+        self.elements.push(element);
+        Ok(())
+    }
+
+    fn end(self) -> Result<Property, SerdeError> {
+        Ok(Property::Array(self.elements))
+    }
+}
+
+impl SerializeTuple for SerializeElements {
+    type Ok = Property;
+    type Error = SerdeError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Property, SerdeError> {
+        SerializeSeq::end(self)
+    }
+}
+
+impl SerializeTupleStruct for SerializeElements {
+    type Ok = Property;
+    type Error = SerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<Property, SerdeError> {
+        SerializeSeq::end(self)
+    }
+}
+
+/// Maps, whose key and value types are taken from the first entry
+pub struct SerializeEntries {
+    entries: Vec<(Property, Property)>,
+    key: Option<Property>,
+}
+
+impl SerializeMap for SerializeEntries {
+    type Ok = Property;
+    type Error = SerdeError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerdeError> {
+        self.key = Some(key.serialize(PropertySerializer)?);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerdeError> {
+        let key = self.key.take().ok_or_else(|| ser::Error::custom("map value serialized before its key"))?;
+        let value = value.serialize(PropertySerializer).map_err(|error| error.within(key_label(&key)))?;
+        if !is_null(&value) {
+            self.entries.push((key, value));
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Property, SerdeError> {
+        // An empty map carries no types, and UE accepts any declared type when there are no
+        // entries to convert
+        let (key_type, value_type) = self.entries.first()
+            .map_or(("IntProperty", "IntProperty"), |(key, value)| (key.type_name(), value.type_name()));
+        Ok(Property::Map { key_type: key_type.to_string(), value_type: value_type.to_string(), entries: self.entries })
+    }
+}
+
+/// Structs, which become native structs when named like one
+pub struct SerializeFields {
+    name: &'static str,
+    properties: IndexMap<String, Property>,
+}
+
+impl SerializeStruct for SerializeFields {
+    type Ok = Property;
+    type Error = SerdeError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), SerdeError> {
+        let value = value.serialize(PropertySerializer).map_err(|error| error.within(key))?;
+        if !is_null(&value) {
+            self.properties.insert(key.to_string(), value);
+        }
+        Ok(())
+    }
+
+    fn end(self) -> Result<Property, SerdeError> {
+        Ok(match native_struct(self.name, &self.properties)? {
+            Some(native) => native,
+            None => Property::Struct { struct_type: FName::new(self.name), properties: self.properties },
+        })
+    }
+}
+
+fn is_null(property: &Property) -> bool {
+    matches!(property, Property::Unknown(serde_json::Value::Null))
+}
+
+fn key_label(key: &Property) -> String {
+    match key {
+        Property::String(value) => value.clone(),
+        Property::Name(value) => value.to_string(),
+        other => other.type_name().to_string(),
+    }
+}
+
+/// Fields of a struct property, or the entries of a map with string keys
+fn into_fields(property: Property) -> Result<IndexMap<String, Property>, SerdeError> {
+    match property {
+        Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => Ok(properties),
+        Property::Map { entries, .. } | Property::MapProperty { entries, .. } => entries
+            .into_iter()
+            .map(|(key, value)| match key {
+                Property::String(name) => Ok((name, value)),
+                Property::Name(name) => Ok((name.to_string(), value)),
+                other => Err(ser::Error::custom(format!("property names must be strings, found {}", other.type_name()))),
+            })
+            .collect(),
+        other => Err(ser::Error::custom(format!("expected a struct or map, found {}", other.type_name()))),
+    }
+}
+
+fn float(property: &Property) -> Option<f64> {
+    match number(property)? {
+        Number::Signed(v) => Some(v as f64),
+        Number::Unsigned(v) => Some(v as f64),
+        Number::Float(v) => Some(v),
+    }
+}
+
+/// Build a native math struct from fields named like its components, ignoring case
+fn native_struct(name: &str, fields: &IndexMap<String, Property>) -> Result<Option<Property>, SerdeError> {
+    let get = |names: &[&str]| {
+        fields.iter().find(|(key, _)| names.iter().any(|name| key.eq_ignore_ascii_case(name))).map(|(_, value)| value)
+    };
+    let component = |component: &str| {
+        get(&[component])
+            .and_then(float)
+            .ok_or_else(|| ser::Error::custom(format!("{} needs a numeric {} field", name, component)))
+    };
+    Ok(Some(match name {
+        "Vector" => Property::Vector(Vector::new(component("X")?, component("Y")?, component("Z")?)),
+        "Vector2D" => Property::Vector2D(Vector2D::new(component("X")?, component("Y")?)),
+        "Vector4" => Property::Vector4(Vector4::new(component("X")?, component("Y")?, component("Z")?, component("W")?)),
+        "Rotator" => Property::Rotator(Rotator::new(component("Pitch")?, component("Yaw")?, component("Roll")?)),
+        "Quat" => Property::Quat(Quat::new(component("X")?, component("Y")?, component("Z")?, component("W")?)),
+        "LinearColor" => Property::LinearColor(LinearColor::new(
+            component("R")? as f32,
+            component("G")? as f32,
+            component("B")? as f32,
+            component("A")? as f32,
+        )),
+        "Transform" => {
+            let missing = |part: &str| ser::Error::custom(format!("Transform needs a {} field", part));
+            let vector = |names: &[&str]| match get(names) {
+                Some(Property::Vector(vector)) => Ok(vector.clone()),
+                _ => Err(missing(names[0])),
+            };
+            let rotation = match get(&["Rotation"]) {
+                Some(Property::Quat(rotation)) => rotation.clone(),
+                _ => return Err(missing("Rotation")),
+            };
+            Property::Transform(Transform::new(vector(&["Translation", "Location"])?, rotation, vector(&["Scale3D", "Scale"])?))
+        }
+        _ => return Ok(None),
+    }))
+}
+
+fn mismatch(property: &Property, property_type: &PropertyType) -> SerdeError {
+    ser::Error::custom(format!("cannot store {} as {}", property.type_name(), property_type))
+}
+
+fn integer<T: TryFrom<i64> + TryFrom<u64>>(property: &Property, property_type: &PropertyType) -> Result<T, SerdeError> {
+    let out_of_range = |value: &dyn std::fmt::Display| {
+        ser::Error::custom(format!("{} is out of range for {}", value, property_type))
+    };
+    match number(property) {
+        Some(Number::Signed(v)) => T::try_from(v).map_err(|_| out_of_range(&v)),
+        Some(Number::Unsigned(v)) => T::try_from(v).map_err(|_| out_of_range(&v)),
+        _ => Err(mismatch(property, property_type)),
+    }
+}
+
+/// String form of strings, names, texts and enum values
+fn string(property: &Property) -> Option<String> {
+    match property {
+        Property::String(value) => Some(value.clone()),
+        Property::Name(value) => Some(value.to_string()),
+        Property::Text(text) => Some(text.to_string()),
+        _ => enum_variant(property).map(str::to_string),
+    }
+}
+
+/// Enum value without its `EType::` prefix
+fn enum_variant(property: &Property) -> Option<&str> {
+    let value = match property {
+        Property::Enum { value, .. } | Property::EnumProperty { value, .. } | Property::ByteEnum { value, .. } => &value.name,
+        Property::Name(value) => &value.name,
+        Property::String(value) => value,
+        _ => return None,
+    };
+    Some(value.rsplit_once("::").map_or(value.as_str(), |(_, variant)| variant))
+}
+
+/// Name of `variant` as the mappings spell it, qualified by the enum name otherwise
+fn enum_value(enum_name: &str, variant: &str, mappings: Option<&Usmap>) -> Result<FName, SerdeError> {
+    let qualified = format!("{}::{}", enum_name, variant);
+    match mappings.and_then(|mappings| mappings.enums.get(enum_name)) {
+        Some(names) if names.contains(&qualified) => Ok(FName::new(qualified)),
+        Some(names) if names.iter().any(|name| name == variant) => Ok(FName::new(variant)),
+        Some(_) => Err(ser::Error::custom(format!("{} is not a value of {}", variant, enum_name))),
+        None => Ok(FName::new(qualified)),
+    }
+}
+
+/// Parse `/Game/Path.Object:SubPath`, as written by `FSoftObjectPath::ToString`
+fn soft_object_path(property: &Property, property_type: &PropertyType) -> Result<SoftObjectPath, SerdeError> {
+    match property {
+        Property::SoftObjectPath(path)
+        | Property::SoftClassPath(path)
+        | Property::AssetObjectProperty(path)
+        | Property::SoftAssetPathProperty(path)
+        | Property::SoftObjectPathProperty(path)
+        | Property::SoftClassPathProperty(path) => Ok(path.clone()),
+        Property::Unknown(serde_json::Value::Null) => Ok(SoftObjectPath::default()),
+        Property::String(path) if path.is_empty() || path == "None" => Ok(SoftObjectPath::default()),
+        Property::String(path) => {
+            let (asset_path, sub_path) = path.split_once(':').unwrap_or((path, ""));
+            Ok(SoftObjectPath::new(asset_path, sub_path))
+        }
+        other => Err(mismatch(other, property_type)),
+    }
+}
+
+/// Convert an untyped property to the exact property type the mappings declare
+fn retype(property: Property, property_type: &PropertyType, mappings: Option<&Usmap>) -> Result<Property, SerdeError> {
+    let element_type = || {
+        property_type.inner.as_deref()
+            .ok_or_else(|| ser::Error::custom(format!("{} without an element type", property_type)))
+    };
+    Ok(match property_type.type_name.as_str() {
+        "BoolProperty" => match property {
+            Property::Bool(_) => property,
+            other => return Err(mismatch(&other, property_type)),
+        },
+        "Int8Property" => Property::Int8(integer(&property, property_type)?),
+        "Int16Property" => Property::Int16(integer(&property, property_type)?),
+        "IntProperty" => Property::Int32(integer(&property, property_type)?),
+        "Int64Property" => Property::Int64(integer(&property, property_type)?),
+        "UInt16Property" => Property::UInt16(integer(&property, property_type)?),
+        "UInt32Property" => Property::UInt32(integer(&property, property_type)?),
+        "UInt64Property" => Property::UInt64(integer(&property, property_type)?),
+        "FloatProperty" => Property::Float(float(&property).ok_or_else(|| mismatch(&property, property_type))? as f32),
+        "DoubleProperty" => Property::Double(float(&property).ok_or_else(|| mismatch(&property, property_type))?),
+        "StrProperty" | "Utf8StrProperty" | "AnsiStrProperty" => {
+            Property::String(string(&property).ok_or_else(|| mismatch(&property, property_type))?)
+        }
+        "NameProperty" => Property::Name(FName::new(string(&property).ok_or_else(|| mismatch(&property, property_type))?)),
+        "TextProperty" => match property {
+            Property::Text(_) => property,
+            other => Property::Text(FText::culture_invariant(string(&other).ok_or_else(|| mismatch(&other, property_type))?)),
+        },
+        "ByteProperty" => match (enum_variant(&property), property_type.enum_name.as_deref()) {
+            (Some(variant), Some(enum_name)) if enum_name != "None" => Property::ByteEnum {
+                enum_type: FName::new(enum_name),
+                value: enum_value(enum_name, variant, mappings)?,
+            },
+            _ => Property::Byte(integer(&property, property_type)?),
+        },
+        "EnumProperty" => {
+            let variant = enum_variant(&property).ok_or_else(|| mismatch(&property, property_type))?;
+            let enum_name = match (property_type.enum_name.as_deref(), &property) {
+                (Some(enum_name), _) => enum_name,
+                (None, Property::Enum { enum_type, .. }) => enum_type.name.as_str(),
+                _ => return Err(ser::Error::custom(format!("{} without an enum name", property_type))),
+            };
+            Property::Enum { enum_type: FName::new(enum_name), value: enum_value(enum_name, variant, mappings)? }
+        }
+        "ObjectProperty" | "ClassProperty" | "WeakObjectProperty" | "LazyObjectProperty" | "InterfaceProperty" => {
+            match property {
+                Property::Object(_) | Property::ObjectProperty(_) => property,
+                Property::Unknown(serde_json::Value::Null) => Property::Object(None),
+                other => {
+                    let index: i32 = integer(&other, property_type)?;
+                    Property::Object((index != 0).then_some(PackageIndex(index)))
+                }
+            }
+        }
+        "SoftObjectProperty" => Property::SoftObjectPath(soft_object_path(&property, property_type)?),
+        "SoftClassProperty" => Property::SoftClassPath(soft_object_path(&property, property_type)?),
+        "AssetObjectProperty" => Property::AssetObjectProperty(soft_object_path(&property, property_type)?),
+        "StructProperty" => {
+            let struct_type = property_type.struct_type.as_deref()
+                .ok_or_else(|| ser::Error::custom("StructProperty without a struct type"))?;
+            retype_struct(property, struct_type, property_type, mappings)?
+        }
+        "ArrayProperty" | "SetProperty" => {
+            let inner = element_type()?;
+            let elements = match property {
+                Property::Array(values) | Property::ArrayProperty(values) | Property::Set(values) | Property::SetProperty(values) => values,
+                other => return Err(mismatch(&other, property_type)),
+            };
+            let elements = elements
+                .into_iter()
+                .enumerate()
+                .map(|(index, element)| retype(element, inner, mappings).map_err(|error| error.within(index)))
+                .collect::<Result<Vec<_>, _>>()?;
+            match property_type.type_name.as_str() {
+                "SetProperty" => Property::Set(elements),
+                _ => Property::Array(elements),
+            }
+        }
+        "MapProperty" => {
+            let key_type = element_type()?;
+            let value_type = property_type.value.as_deref()
+                .ok_or_else(|| ser::Error::custom("MapProperty without a value type"))?;
+            let entries = match property {
+                Property::Map { entries, .. } | Property::MapProperty { entries, .. } => entries,
+                other => return Err(mismatch(&other, property_type)),
+            };
+            let entries = entries
+                .into_iter()
+                .map(|(key, value)| {
+                    let label = key_label(&key);
+                    let key = retype(key, key_type, mappings).map_err(|error| error.within(label.as_str()))?;
+                    let value = retype(value, value_type, mappings).map_err(|error| error.within(label))?;
+                    Ok((key, value))
+                })
+                .collect::<Result<Vec<_>, SerdeError>>()?;
+            Property::Map { key_type: key_type.type_name.clone(), value_type: value_type.type_name.clone(), entries }
+        }
+        _ => property,
+    })
+}
+
+fn retype_struct(
+    property: Property,
+    struct_type: &str,
+    property_type: &PropertyType,
+    mappings: Option<&Usmap>,
+) -> Result<Property, SerdeError> {
+    Ok(match property {
+        Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => {
+            match native_struct(struct_type, &properties)? {
+                Some(native) => native,
+                None => Property::Struct {
+                    struct_type: FName::new(struct_type),
+                    properties: retype_fields(properties, struct_type, mappings)?,
+                },
+            }
+        }
+        other => match struct_type {
+            _ if other.struct_type_name() == Some(struct_type) => other,
+            "SoftObjectPath" => Property::SoftObjectPath(soft_object_path(&other, property_type)?),
+            "SoftClassPath" => Property::SoftClassPath(soft_object_path(&other, property_type)?),
+            "Guid" => match &other {
+                Property::Array(parts) if parts.len() == 4 => {
+                    let mut guid = [0u32; 4];
+                    for (part, value) in guid.iter_mut().zip(parts) {
+                        *part = integer(value, property_type)?;
+                    }
+                    Property::Guid(guid)
+                }
+                _ => return Err(mismatch(&other, property_type)),
+            },
+            "DateTime" => Property::DateTime(integer(&other, property_type)?),
+            "Timespan" => Property::TimeSpan(integer(&other, property_type)?),
+            _ => return Err(mismatch(&other, property_type)),
+        },
+    })
+}
+
+fn retype_fields(
+    fields: IndexMap<String, Property>,
+    schema: &str,
+    mappings: Option<&Usmap>,
+) -> Result<IndexMap<String, Property>, SerdeError> {
+    let mut typed = IndexMap::with_capacity(fields.len());
+    for (name, property) in fields {
+        let property = match mappings.and_then(|mappings| mappings.find_property(schema, &name)) {
+            Some(field) => retype(property, &field.property_type, mappings).map_err(|error| error.within(name.as_str()))?,
+            None => property,
+        };
+        typed.insert(name, property);
+    }
+    Ok(typed)
+}
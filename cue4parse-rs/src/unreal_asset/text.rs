@@ -0,0 +1,1168 @@
+//! `FText` and its text histories
+//!
+//! An `FText` is serialized as its flags followed by the history that produced it:
+//! a localized string, a string table reference, a format pattern with arguments,
+//! a formatted number or date, or a transformed text. Histories that wrap other
+//! texts are read through [`ArchiveReader::read_nested`].
+//!
+//! Display strings are rendered against a [`LocalizationTable`]. Numbers and dates
+//! are formatted culture-invariantly, so the result matches UE's output for English
+//! only in the default styles.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use bitflags::bitflags;
+use byteorder::{ReadBytesExt, WriteBytesExt};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::unreal_asset::custom_versions::{FEditorObjectVersion, FUE5ReleaseStreamObjectVersion};
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::object_versions::EUnrealEngineObjectUE4Version;
+use crate::unreal_asset::reader::{ArchiveReader, ArchiveTrait, ArchiveWriter};
+use crate::unreal_asset::types::{FName, PackageIndexTrait};
+
+bitflags! {
+    /// `ETextFlag`
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+    pub struct TextFlags: u32 {
+        const TRANSIENT = 1 << 0;
+        const CULTURE_INVARIANT = 1 << 1;
+        const CONVERTED_PROPERTY = 1 << 2;
+        const IMMUTABLE = 1 << 3;
+        const INITIALIZED_FROM_STRING = 1 << 4;
+    }
+}
+
+/// `ETextHistoryType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(i8)]
+pub enum TextHistoryType {
+    None = -1,
+    Base = 0,
+    NamedFormat = 1,
+    OrderedFormat = 2,
+    ArgumentFormat = 3,
+    AsNumber = 4,
+    AsPercent = 5,
+    AsCurrency = 6,
+    AsDate = 7,
+    AsTime = 8,
+    AsDateTime = 9,
+    Transform = 10,
+    StringTableEntry = 11,
+    TextGenerator = 12,
+}
+
+impl TextHistoryType {
+    /// Convert a serialized history type
+    pub fn from_i8(value: i8) -> Option<Self> {
+        use TextHistoryType::*;
+        Some(match value {
+            -1 => None,
+            0 => Base,
+            1 => NamedFormat,
+            2 => OrderedFormat,
+            3 => ArgumentFormat,
+            4 => AsNumber,
+            5 => AsPercent,
+            6 => AsCurrency,
+            7 => AsDate,
+            8 => AsTime,
+            9 => AsDateTime,
+            10 => Transform,
+            11 => StringTableEntry,
+            12 => TextGenerator,
+            _ => return Option::None,
+        })
+    }
+}
+
+/// `ERoundingMode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[repr(i8)]
+pub enum RoundingMode {
+    #[default]
+    HalfToEven = 0,
+    HalfFromZero = 1,
+    HalfToZero = 2,
+    FromZero = 3,
+    ToZero = 4,
+    ToNegativeInfinity = 5,
+    ToPositiveInfinity = 6,
+}
+
+/// `EDateTimeStyle`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[repr(i8)]
+pub enum DateTimeStyle {
+    #[default]
+    Default = 0,
+    Short = 1,
+    Medium = 2,
+    Long = 3,
+    Full = 4,
+}
+
+/// `ETextTransformType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum TextTransformType {
+    #[default]
+    ToLower = 0,
+    ToUpper = 1,
+}
+
+/// `ETextGender`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum TextGender {
+    #[default]
+    Masculine = 0,
+    Feminine = 1,
+    Neuter = 2,
+}
+
+/// `FNumberFormattingOptions`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumberFormattingOptions {
+    pub always_sign: bool,
+    pub use_grouping: bool,
+    pub rounding_mode: RoundingMode,
+    pub minimum_integral_digits: i32,
+    pub maximum_integral_digits: i32,
+    pub minimum_fractional_digits: i32,
+    pub maximum_fractional_digits: i32,
+}
+
+impl Default for NumberFormattingOptions {
+    fn default() -> Self {
+        Self {
+            always_sign: false,
+            use_grouping: true,
+            rounding_mode: RoundingMode::HalfToEven,
+            minimum_integral_digits: 1,
+            maximum_integral_digits: 308 + 15 + 1, // DBL_MAX_10_EXP + DBL_DIG + 1
+            minimum_fractional_digits: 0,
+            maximum_fractional_digits: 3,
+        }
+    }
+}
+
+/// `FFormatArgumentValue`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum FormatArgumentValue {
+    Int(i64),
+    UInt(u64),
+    Float(f32),
+    Double(f64),
+    Text(Box<FText>),
+    Gender(TextGender),
+}
+
+/// Number passed to `AsNumber`, `AsPercent` and `AsCurrency` histories
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NumberFormat {
+    pub source_value: FormatArgumentValue,
+    pub format_options: Option<NumberFormattingOptions>,
+    pub target_culture: Option<String>,
+}
+
+/// `FTextHistory`, how a text was produced
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TextHistory {
+    /// Text without a key, usually culture invariant
+    None {
+        culture_invariant_string: Option<String>,
+    },
+    /// Localizable text identified by namespace and key
+    Base {
+        namespace: Option<String>,
+        key: Option<String>,
+        source_string: String,
+        /// Localized string taken from CUE4Parse JSON, never serialized
+        localized_string: Option<String>,
+    },
+    NamedFormat {
+        source_format: Box<FText>,
+        arguments: IndexMap<String, FormatArgumentValue>,
+    },
+    OrderedFormat {
+        source_format: Box<FText>,
+        arguments: Vec<FormatArgumentValue>,
+    },
+    /// Named format whose arguments are stored as a list
+    ArgumentFormat {
+        source_format: Box<FText>,
+        arguments: IndexMap<String, FormatArgumentValue>,
+    },
+    AsNumber(NumberFormat),
+    AsPercent(NumberFormat),
+    AsCurrency {
+        currency_code: Option<String>,
+        format: NumberFormat,
+    },
+    AsDate {
+        /// `FDateTime` ticks
+        source_date_time: i64,
+        date_style: DateTimeStyle,
+        time_zone: Option<String>,
+        target_culture: Option<String>,
+    },
+    AsTime {
+        source_date_time: i64,
+        time_style: DateTimeStyle,
+        time_zone: Option<String>,
+        target_culture: Option<String>,
+    },
+    AsDateTime {
+        source_date_time: i64,
+        date_style: DateTimeStyle,
+        time_style: DateTimeStyle,
+        time_zone: Option<String>,
+        target_culture: Option<String>,
+    },
+    Transform {
+        source_text: Box<FText>,
+        transform_type: TextTransformType,
+    },
+    /// Entry of a string table asset
+    StringTableEntry {
+        table_id: FName,
+        key: String,
+    },
+    TextGenerator {
+        generator_type: FName,
+        contents: Vec<u8>,
+    },
+}
+
+impl TextHistory {
+    /// Serialized history type
+    pub fn history_type(&self) -> TextHistoryType {
+        match self {
+            TextHistory::None { .. } => TextHistoryType::None,
+            TextHistory::Base { .. } => TextHistoryType::Base,
+            TextHistory::NamedFormat { .. } => TextHistoryType::NamedFormat,
+            TextHistory::OrderedFormat { .. } => TextHistoryType::OrderedFormat,
+            TextHistory::ArgumentFormat { .. } => TextHistoryType::ArgumentFormat,
+            TextHistory::AsNumber(_) => TextHistoryType::AsNumber,
+            TextHistory::AsPercent(_) => TextHistoryType::AsPercent,
+            TextHistory::AsCurrency { .. } => TextHistoryType::AsCurrency,
+            TextHistory::AsDate { .. } => TextHistoryType::AsDate,
+            TextHistory::AsTime { .. } => TextHistoryType::AsTime,
+            TextHistory::AsDateTime { .. } => TextHistoryType::AsDateTime,
+            TextHistory::Transform { .. } => TextHistoryType::Transform,
+            TextHistory::StringTableEntry { .. } => TextHistoryType::StringTableEntry,
+            TextHistory::TextGenerator { .. } => TextHistoryType::TextGenerator,
+        }
+    }
+}
+
+/// `FText`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FText {
+    pub flags: TextFlags,
+    pub history: TextHistory,
+}
+
+impl FText {
+    /// Create a text from its flags and history
+    pub fn new(flags: TextFlags, history: TextHistory) -> Self {
+        Self { flags, history }
+    }
+
+    /// Create a culture invariant text, `FText::AsCultureInvariant`
+    pub fn culture_invariant(value: impl Into<String>) -> Self {
+        Self::new(TextFlags::CULTURE_INVARIANT, TextHistory::None {
+            culture_invariant_string: Some(value.into()),
+        })
+    }
+
+    /// Create a localizable text
+    pub fn base(namespace: impl Into<String>, key: impl Into<String>, source_string: impl Into<String>) -> Self {
+        Self::new(TextFlags::empty(), TextHistory::Base {
+            namespace: Some(namespace.into()),
+            key: Some(key.into()),
+            source_string: source_string.into(),
+            localized_string: None,
+        })
+    }
+
+    /// Create a reference to a string table entry
+    pub fn string_table_entry(table_id: FName, key: impl Into<String>) -> Self {
+        Self::new(TextFlags::empty(), TextHistory::StringTableEntry { table_id, key: key.into() })
+    }
+
+    /// Namespace of a localizable text
+    pub fn namespace(&self) -> Option<&str> {
+        match &self.history {
+            TextHistory::Base { namespace, .. } => namespace.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Localization key of a localizable text or string table entry
+    pub fn key(&self) -> Option<&str> {
+        match &self.history {
+            TextHistory::Base { key, .. } => key.as_deref(),
+            TextHistory::StringTableEntry { key, .. } => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Source string of texts that store one
+    pub fn source_string(&self) -> Option<&str> {
+        match &self.history {
+            TextHistory::None { culture_invariant_string } => culture_invariant_string.as_deref(),
+            TextHistory::Base { source_string, .. } => Some(source_string),
+            _ => None,
+        }
+    }
+
+    /// Read an `FText`
+    pub fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        if ar.get_object_version() < EUnrealEngineObjectUE4Version::VER_UE4_FTEXT_HISTORY {
+            return Err(UnrealAssetError::UnsupportedVersion("FText without history is not supported".to_string()));
+        }
+        let flags = TextFlags::from_bits_retain(ar.read_u32_endian()?);
+        let history_type = ar.read_i8()?;
+        let history_type = TextHistoryType::from_i8(history_type)
+            .ok_or_else(|| UnrealAssetError::InvalidData(format!("Invalid text history type {}", history_type)))?;
+
+        let history = match history_type {
+            TextHistoryType::None => {
+                let editor_version = ar.get_custom_version::<FEditorObjectVersion>().version;
+                let culture_invariant_string = match editor_version >= FEditorObjectVersion::CultureInvariantTextSerializationKeyStability as i32 {
+                    true if ar.read_i32_endian()? != 0 => Some(ar.read_fstring()?.unwrap_or_default()),
+                    _ => None,
+                };
+                TextHistory::None { culture_invariant_string }
+            }
+            TextHistoryType::Base => TextHistory::Base {
+                namespace: ar.read_fstring()?,
+                key: ar.read_fstring()?,
+                source_string: ar.read_fstring()?.unwrap_or_default(),
+                localized_string: None,
+            },
+            TextHistoryType::NamedFormat => {
+                let source_format = Box::new(read_nested_text(ar)?);
+                let names_and_values = ArchiveReader::<Index>::read_array(ar, |ar| {
+                    let name = ar.read_fstring()?.unwrap_or_default();
+                    Ok((name, FormatArgumentValue::read(ar, false)?))
+                })?;
+                TextHistory::NamedFormat { source_format, arguments: names_and_values.into_iter().collect() }
+            }
+            TextHistoryType::OrderedFormat => TextHistory::OrderedFormat {
+                source_format: Box::new(read_nested_text(ar)?),
+                arguments: ArchiveReader::<Index>::read_array(ar, |ar| FormatArgumentValue::read(ar, false))?,
+            },
+            TextHistoryType::ArgumentFormat => {
+                let source_format = Box::new(read_nested_text(ar)?);
+                let names_and_values = ArchiveReader::<Index>::read_array(ar, |ar| {
+                    let name = ar.read_fstring()?.unwrap_or_default();
+                    Ok((name, FormatArgumentValue::read(ar, true)?))
+                })?;
+                TextHistory::ArgumentFormat { source_format, arguments: names_and_values.into_iter().collect() }
+            }
+            TextHistoryType::AsNumber => TextHistory::AsNumber(NumberFormat::read(ar)?),
+            TextHistoryType::AsPercent => TextHistory::AsPercent(NumberFormat::read(ar)?),
+            TextHistoryType::AsCurrency => {
+                let currency_code = match ar.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_ADDED_CURRENCY_CODE_TO_FTEXT {
+                    true => ar.read_fstring()?,
+                    false => None,
+                };
+                TextHistory::AsCurrency { currency_code, format: NumberFormat::read(ar)? }
+            }
+            TextHistoryType::AsDate => {
+                let source_date_time = ar.read_i64_endian()?;
+                let date_style = read_date_time_style(ar)?;
+                let time_zone = match ar.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_FTEXT_HISTORY_DATE_TIMEZONE {
+                    true => ar.read_fstring()?,
+                    false => None,
+                };
+                TextHistory::AsDate { source_date_time, date_style, time_zone, target_culture: ar.read_fstring()? }
+            }
+            TextHistoryType::AsTime => TextHistory::AsTime {
+                source_date_time: ar.read_i64_endian()?,
+                time_style: read_date_time_style(ar)?,
+                time_zone: ar.read_fstring()?,
+                target_culture: ar.read_fstring()?,
+            },
+            TextHistoryType::AsDateTime => TextHistory::AsDateTime {
+                source_date_time: ar.read_i64_endian()?,
+                date_style: read_date_time_style(ar)?,
+                time_style: read_date_time_style(ar)?,
+                time_zone: ar.read_fstring()?,
+                target_culture: ar.read_fstring()?,
+            },
+            TextHistoryType::Transform => TextHistory::Transform {
+                source_text: Box::new(read_nested_text(ar)?),
+                transform_type: match ar.read_u8()? {
+                    0 => TextTransformType::ToLower,
+                    1 => TextTransformType::ToUpper,
+                    other => return Err(UnrealAssetError::InvalidData(format!("Invalid text transform type {}", other))),
+                },
+            },
+            TextHistoryType::StringTableEntry => TextHistory::StringTableEntry {
+                table_id: ar.read_fname()?,
+                key: ar.read_fstring()?.unwrap_or_default(),
+            },
+            TextHistoryType::TextGenerator => {
+                let generator_type = ar.read_fname()?;
+                let contents = match generator_type.name != "None" {
+                    true => ArchiveReader::<Index>::read_array(ar, |ar| Ok(ar.read_u8()?))?,
+                    false => Vec::new(),
+                };
+                TextHistory::TextGenerator { generator_type, contents }
+            }
+        };
+        Ok(Self { flags, history })
+    }
+
+    /// Write an `FText`
+    pub fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        ar.write_u32_endian(self.flags.bits())?;
+        ar.write_i8(self.history.history_type() as i8)?;
+        match &self.history {
+            TextHistory::None { culture_invariant_string } => {
+                let editor_version = ar.get_custom_version::<FEditorObjectVersion>().version;
+                if editor_version >= FEditorObjectVersion::CultureInvariantTextSerializationKeyStability as i32 {
+                    ar.write_i32_endian(culture_invariant_string.is_some() as i32)?;
+                    if let Some(value) = culture_invariant_string {
+                        ar.write_fstring(Some(value))?;
+                    }
+                }
+            }
+            TextHistory::Base { namespace, key, source_string, .. } => {
+                ar.write_fstring(namespace.as_deref())?;
+                ar.write_fstring(key.as_deref())?;
+                ar.write_fstring(Some(source_string))?;
+            }
+            TextHistory::NamedFormat { source_format, arguments } | TextHistory::ArgumentFormat { source_format, arguments } => {
+                let is_argument_data = matches!(self.history, TextHistory::ArgumentFormat { .. });
+                source_format.write(ar)?;
+                ar.write_i32_endian(arguments.len() as i32)?;
+                for (name, value) in arguments {
+                    ar.write_fstring(Some(name))?;
+                    value.write(ar, is_argument_data)?;
+                }
+            }
+            TextHistory::OrderedFormat { source_format, arguments } => {
+                source_format.write(ar)?;
+                ar.write_i32_endian(arguments.len() as i32)?;
+                for value in arguments {
+                    value.write(ar, false)?;
+                }
+            }
+            TextHistory::AsNumber(format) | TextHistory::AsPercent(format) => format.write(ar)?,
+            TextHistory::AsCurrency { currency_code, format } => {
+                if ar.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_ADDED_CURRENCY_CODE_TO_FTEXT {
+                    ar.write_fstring(currency_code.as_deref())?;
+                }
+                format.write(ar)?;
+            }
+            TextHistory::AsDate { source_date_time, date_style, time_zone, target_culture } => {
+                ar.write_i64_endian(*source_date_time)?;
+                ar.write_i8(*date_style as i8)?;
+                if ar.get_object_version() >= EUnrealEngineObjectUE4Version::VER_UE4_FTEXT_HISTORY_DATE_TIMEZONE {
+                    ar.write_fstring(time_zone.as_deref())?;
+                }
+                ar.write_fstring(target_culture.as_deref())?;
+            }
+            TextHistory::AsTime { source_date_time, time_style, time_zone, target_culture } => {
+                ar.write_i64_endian(*source_date_time)?;
+                ar.write_i8(*time_style as i8)?;
+                ar.write_fstring(time_zone.as_deref())?;
+                ar.write_fstring(target_culture.as_deref())?;
+            }
+            TextHistory::AsDateTime { source_date_time, date_style, time_style, time_zone, target_culture } => {
+                ar.write_i64_endian(*source_date_time)?;
+                ar.write_i8(*date_style as i8)?;
+                ar.write_i8(*time_style as i8)?;
+                ar.write_fstring(time_zone.as_deref())?;
+                ar.write_fstring(target_culture.as_deref())?;
+            }
+            TextHistory::Transform { source_text, transform_type } => {
+                source_text.write(ar)?;
+                ar.write_u8(*transform_type as u8)?;
+            }
+            TextHistory::StringTableEntry { table_id, key } => {
+                ar.write_fname(table_id)?;
+                ar.write_fstring(Some(key))?;
+            }
+            TextHistory::TextGenerator { generator_type, contents } => {
+                ar.write_fname(generator_type)?;
+                if generator_type.name != "None" {
+                    ar.write_i32_endian(contents.len() as i32)?;
+                    ar.write_all(contents)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Convert CUE4Parse JSON, either a plain string or a serialized text history
+    ///
+    /// CUE4Parse does not write the history type, so it is inferred from the fields.
+    /// `AsPercent` histories cannot be told apart from `AsNumber` and read as the latter.
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        let object = match value {
+            Value::String(s) => return Ok(Self::culture_invariant(s.clone())),
+            Value::Object(object) => object,
+            _ => return Err(json_error("text", value)),
+        };
+        let get_str = |key: &str| object.get(key).and_then(Value::as_str).map(str::to_string);
+        let flags = TextFlags::from_bits_retain(object.get("Flags").and_then(Value::as_u64).unwrap_or_default() as u32);
+
+        let history = if let Some(source_format) = object.get("SourceFmt") {
+            let source_format = Box::new(Self::from_json(source_format)?);
+            match object.get("Arguments") {
+                Some(Value::Object(arguments)) => TextHistory::NamedFormat {
+                    source_format,
+                    arguments: arguments
+                        .iter()
+                        .map(|(name, value)| Ok((name.clone(), FormatArgumentValue::from_json(value)?)))
+                        .collect::<UnrealAssetResult<_>>()?,
+                },
+                Some(Value::Array(arguments)) if arguments.iter().any(|a| a.get("ArgumentName").is_some()) => {
+                    TextHistory::ArgumentFormat {
+                        source_format,
+                        arguments: arguments
+                            .iter()
+                            .map(|argument| {
+                                let name = argument.get("ArgumentName").and_then(Value::as_str).unwrap_or_default();
+                                let value = argument.get("ArgumentValue").unwrap_or(&Value::Null);
+                                Ok((name.to_string(), FormatArgumentValue::from_json(value)?))
+                            })
+                            .collect::<UnrealAssetResult<_>>()?,
+                    }
+                }
+                Some(Value::Array(arguments)) => TextHistory::OrderedFormat {
+                    source_format,
+                    arguments: arguments.iter().map(FormatArgumentValue::from_json).collect::<UnrealAssetResult<_>>()?,
+                },
+                _ => TextHistory::OrderedFormat { source_format, arguments: Vec::new() },
+            }
+        } else if let Some(source_value) = object.get("SourceValue") {
+            let format = NumberFormat {
+                source_value: FormatArgumentValue::from_json(source_value)?,
+                format_options: match object.get("FormatOptions") {
+                    Some(options) if !options.is_null() => Some(NumberFormattingOptions::from_json(options)),
+                    _ => None,
+                },
+                target_culture: get_str("TargetCulture"),
+            };
+            match get_str("CurrencyCode") {
+                Some(currency_code) => TextHistory::AsCurrency { currency_code: Some(currency_code), format },
+                None => TextHistory::AsNumber(format),
+            }
+        } else if let Some(date_time) = object.get("SourceDateTime") {
+            let source_date_time = date_time.get("Ticks").unwrap_or(date_time).as_i64().ok_or_else(|| json_error("date time", date_time))?;
+            let style = |key: &str| object.get(key).map(date_time_style_from_json);
+            let (time_zone, target_culture) = (get_str("TimeZone"), get_str("TargetCulture"));
+            match (style("DateStyle"), style("TimeStyle")) {
+                (Some(date_style), Some(time_style)) => {
+                    TextHistory::AsDateTime { source_date_time, date_style, time_style, time_zone, target_culture }
+                }
+                (None, Some(time_style)) => TextHistory::AsTime { source_date_time, time_style, time_zone, target_culture },
+                (date_style, None) => TextHistory::AsDate {
+                    source_date_time,
+                    date_style: date_style.unwrap_or_default(),
+                    time_zone,
+                    target_culture,
+                },
+            }
+        } else if let Some(source_text) = object.get("SourceText") {
+            let transform_type = match object.get("TransformType") {
+                Some(Value::String(s)) if s.ends_with("ToUpper") => TextTransformType::ToUpper,
+                Some(Value::Number(n)) if n.as_u64() == Some(1) => TextTransformType::ToUpper,
+                _ => TextTransformType::ToLower,
+            };
+            TextHistory::Transform { source_text: Box::new(Self::from_json(source_text)?), transform_type }
+        } else if let (Some(table_id), Some(key)) = (get_str("TableId"), get_str("Key")) {
+            TextHistory::StringTableEntry { table_id: FName::new(table_id), key }
+        } else if let Some(generator_type) = get_str("GeneratorTypeID") {
+            let contents = object
+                .get("GeneratorContents")
+                .and_then(Value::as_array)
+                .map(|bytes| bytes.iter().filter_map(Value::as_u64).map(|b| b as u8).collect())
+                .unwrap_or_default();
+            TextHistory::TextGenerator { generator_type: FName::new(generator_type), contents }
+        } else if let Some(source_string) = get_str("SourceString") {
+            TextHistory::Base {
+                namespace: get_str("Namespace"),
+                key: get_str("Key"),
+                source_string,
+                localized_string: get_str("LocalizedString"),
+            }
+        } else if object.contains_key("CultureInvariantString") {
+            TextHistory::None { culture_invariant_string: get_str("CultureInvariantString") }
+        } else {
+            return Err(json_error("text", value));
+        };
+        Ok(Self { flags, history })
+    }
+
+    /// Render the display string, looking localized strings up in `localization`
+    pub fn display_string(&self, localization: &LocalizationTable) -> String {
+        match &self.history {
+            TextHistory::None { culture_invariant_string } => culture_invariant_string.clone().unwrap_or_default(),
+            TextHistory::Base { namespace, key, source_string, localized_string } => key
+                .as_deref()
+                .and_then(|key| localization.get(namespace.as_deref().unwrap_or_default(), key))
+                .or(localized_string.as_deref())
+                .unwrap_or(source_string)
+                .to_string(),
+            TextHistory::NamedFormat { source_format, arguments } | TextHistory::ArgumentFormat { source_format, arguments } => {
+                let pattern = source_format.display_string(localization);
+                format_pattern(&pattern, |name| {
+                    arguments
+                        .iter()
+                        .find(|(argument, _)| argument.eq_ignore_ascii_case(name))
+                        .map(|(_, value)| value.display_string(localization))
+                })
+            }
+            TextHistory::OrderedFormat { source_format, arguments } => {
+                let pattern = source_format.display_string(localization);
+                format_pattern(&pattern, |name| {
+                    let index: usize = name.parse().ok()?;
+                    arguments.get(index).map(|value| value.display_string(localization))
+                })
+            }
+            TextHistory::AsNumber(format) => format.display_string(localization, 1.0, None),
+            TextHistory::AsPercent(format) => format!("{}%", format.display_string(localization, 100.0, None)),
+            TextHistory::AsCurrency { currency_code, format } => {
+                let options = NumberFormattingOptions {
+                    minimum_fractional_digits: 2,
+                    maximum_fractional_digits: 2,
+                    ..Default::default()
+                };
+                let value = format.display_string(localization, 1.0, Some(&options));
+                match currency_code.as_deref().filter(|code| !code.is_empty()) {
+                    Some(code) => format!("{} {}", code, value),
+                    None => value,
+                }
+            }
+            TextHistory::AsDate { source_date_time, .. } => format_ticks(*source_date_time, true, false),
+            TextHistory::AsTime { source_date_time, .. } => format_ticks(*source_date_time, false, true),
+            TextHistory::AsDateTime { source_date_time, .. } => format_ticks(*source_date_time, true, true),
+            TextHistory::Transform { source_text, transform_type } => {
+                let text = source_text.display_string(localization);
+                match transform_type {
+                    TextTransformType::ToLower => text.to_lowercase(),
+                    TextTransformType::ToUpper => text.to_uppercase(),
+                }
+            }
+            TextHistory::StringTableEntry { table_id, key } => localization
+                .string_table_entry(&table_id.name, key)
+                .unwrap_or("<MISSING STRING TABLE ENTRY>")
+                .to_string(),
+            TextHistory::TextGenerator { generator_type, .. } => generator_type.name.clone(),
+        }
+    }
+}
+
+impl Default for FText {
+    fn default() -> Self {
+        Self::new(TextFlags::empty(), TextHistory::None { culture_invariant_string: None })
+    }
+}
+
+/// Renders the display string without localization
+impl fmt::Display for FText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.display_string(&LocalizationTable::default()))
+    }
+}
+
+impl FormatArgumentValue {
+    /// Read an `FFormatArgumentValue`, `is_argument_data` for `FFormatArgumentData` values
+    pub fn read<Index, A>(ar: &mut A, is_argument_data: bool) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        let editor_version = ar.get_custom_version::<FEditorObjectVersion>().version;
+        let argument_type = match is_argument_data && editor_version < FEditorObjectVersion::TextFormatArgumentDataIsVariant as i32 {
+            true => 4, // EFormatArgumentType::Text
+            false => ar.read_i8()?,
+        };
+        Ok(match argument_type {
+            0 if is_argument_data && !has_64bit_argument_data(ar) => FormatArgumentValue::Int(ar.read_i32_endian()? as i64),
+            0 => FormatArgumentValue::Int(ar.read_i64_endian()?),
+            1 => FormatArgumentValue::UInt(ar.read_u64_endian()?),
+            2 => FormatArgumentValue::Float(ar.read_f32_endian()?),
+            3 => FormatArgumentValue::Double(ar.read_f64_endian()?),
+            4 => FormatArgumentValue::Text(Box::new(read_nested_text(ar)?)),
+            5 => FormatArgumentValue::Gender(match ar.read_u8()? {
+                1 => TextGender::Feminine,
+                2 => TextGender::Neuter,
+                _ => TextGender::Masculine,
+            }),
+            other => return Err(UnrealAssetError::InvalidData(format!("Invalid format argument type {}", other))),
+        })
+    }
+
+    /// Write an `FFormatArgumentValue`, `is_argument_data` for `FFormatArgumentData` values
+    pub fn write<Index, W>(&self, ar: &mut W, is_argument_data: bool) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        let editor_version = ar.get_custom_version::<FEditorObjectVersion>().version;
+        let legacy_text_only = is_argument_data && editor_version < FEditorObjectVersion::TextFormatArgumentDataIsVariant as i32;
+        let argument_type: i8 = match self {
+            FormatArgumentValue::Int(_) => 0,
+            FormatArgumentValue::UInt(_) => 1,
+            FormatArgumentValue::Float(_) => 2,
+            FormatArgumentValue::Double(_) => 3,
+            FormatArgumentValue::Text(_) => 4,
+            FormatArgumentValue::Gender(_) => 5,
+        };
+        if legacy_text_only {
+            let FormatArgumentValue::Text(text) = self else {
+                return Err(UnrealAssetError::UnsupportedVersion("Format argument data before TextFormatArgumentDataIsVariant only holds texts".to_string()));
+            };
+            return text.write(ar);
+        }
+        ar.write_i8(argument_type)?;
+        match self {
+            FormatArgumentValue::Int(value) if is_argument_data && !has_64bit_argument_data(ar) => ar.write_i32_endian(*value as i32)?,
+            FormatArgumentValue::Int(value) => ar.write_i64_endian(*value)?,
+            FormatArgumentValue::UInt(value) => ar.write_u64_endian(*value)?,
+            FormatArgumentValue::Float(value) => ar.write_f32_endian(*value)?,
+            FormatArgumentValue::Double(value) => ar.write_f64_endian(*value)?,
+            FormatArgumentValue::Text(text) => text.write(ar)?,
+            FormatArgumentValue::Gender(gender) => ar.write_u8(*gender as u8)?,
+        }
+        Ok(())
+    }
+
+    /// Convert CUE4Parse's `{ "Type": ..., "Value": ... }`
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        let inner = value.get("Value").unwrap_or(&Value::Null);
+        let argument_type = match value.get("Type") {
+            Some(Value::String(s)) => s.rsplit("::").next().unwrap_or_default().to_string(),
+            Some(Value::Number(n)) => ["Int", "UInt", "Float", "Double", "Text", "Gender"]
+                .get(n.as_u64().unwrap_or(u64::MAX) as usize)
+                .map(|s| s.to_string())
+                .unwrap_or_default(),
+            _ => String::new(),
+        };
+        Ok(match argument_type.as_str() {
+            "Int" => FormatArgumentValue::Int(inner.as_i64().ok_or_else(|| json_error("Int argument", inner))?),
+            "UInt" => FormatArgumentValue::UInt(inner.as_u64().ok_or_else(|| json_error("UInt argument", inner))?),
+            "Float" => FormatArgumentValue::Float(inner.as_f64().ok_or_else(|| json_error("Float argument", inner))? as f32),
+            "Double" => FormatArgumentValue::Double(inner.as_f64().ok_or_else(|| json_error("Double argument", inner))?),
+            "Text" => FormatArgumentValue::Text(Box::new(FText::from_json(inner)?)),
+            "Gender" => FormatArgumentValue::Gender(match inner.as_str().or(inner.as_u64().map(|_| "")) {
+                Some(s) if s.ends_with("Feminine") || inner.as_u64() == Some(1) => TextGender::Feminine,
+                Some(s) if s.ends_with("Neuter") || inner.as_u64() == Some(2) => TextGender::Neuter,
+                _ => TextGender::Masculine,
+            }),
+            _ => return Err(json_error("format argument", value)),
+        })
+    }
+
+    /// Render the argument as it appears in a formatted text
+    pub fn display_string(&self, localization: &LocalizationTable) -> String {
+        let options = NumberFormattingOptions::default();
+        match self {
+            FormatArgumentValue::Int(value) => format_integer(*value as i128, &options),
+            FormatArgumentValue::UInt(value) => format_integer(*value as i128, &options),
+            FormatArgumentValue::Float(value) => format_float(*value as f64, &options),
+            FormatArgumentValue::Double(value) => format_float(*value, &options),
+            FormatArgumentValue::Text(text) => text.display_string(localization),
+            FormatArgumentValue::Gender(gender) => format!("{:?}", gender),
+        }
+    }
+}
+
+impl NumberFormattingOptions {
+    /// Read `FNumberFormattingOptions`
+    pub fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        let editor_version = ar.get_custom_version::<FEditorObjectVersion>().version;
+        let always_sign = match editor_version >= FEditorObjectVersion::AddedAlwaysSignNumberFormattingOption as i32 {
+            true => ar.read_i32_endian()? != 0,
+            false => false,
+        };
+        Ok(Self {
+            always_sign,
+            use_grouping: ar.read_i32_endian()? != 0,
+            rounding_mode: match ar.read_i8()? {
+                1 => RoundingMode::HalfFromZero,
+                2 => RoundingMode::HalfToZero,
+                3 => RoundingMode::FromZero,
+                4 => RoundingMode::ToZero,
+                5 => RoundingMode::ToNegativeInfinity,
+                6 => RoundingMode::ToPositiveInfinity,
+                _ => RoundingMode::HalfToEven,
+            },
+            minimum_integral_digits: ar.read_i32_endian()?,
+            maximum_integral_digits: ar.read_i32_endian()?,
+            minimum_fractional_digits: ar.read_i32_endian()?,
+            maximum_fractional_digits: ar.read_i32_endian()?,
+        })
+    }
+
+    /// Write `FNumberFormattingOptions`
+    pub fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        let editor_version = ar.get_custom_version::<FEditorObjectVersion>().version;
+        if editor_version >= FEditorObjectVersion::AddedAlwaysSignNumberFormattingOption as i32 {
+            ar.write_i32_endian(self.always_sign as i32)?;
+        }
+        ar.write_i32_endian(self.use_grouping as i32)?;
+        ar.write_i8(self.rounding_mode as i8)?;
+        for value in [
+            self.minimum_integral_digits,
+            self.maximum_integral_digits,
+            self.minimum_fractional_digits,
+            self.maximum_fractional_digits,
+        ] {
+            ar.write_i32_endian(value)?;
+        }
+        Ok(())
+    }
+
+    fn from_json(value: &Value) -> Self {
+        let defaults = Self::default();
+        let bool_field = |key: &str, default: bool| value.get(key).and_then(Value::as_bool).unwrap_or(default);
+        let int_field = |key: &str, default: i32| value.get(key).and_then(Value::as_i64).map_or(default, |v| v as i32);
+        Self {
+            always_sign: bool_field("AlwaysSign", defaults.always_sign),
+            use_grouping: bool_field("UseGrouping", defaults.use_grouping),
+            rounding_mode: defaults.rounding_mode,
+            minimum_integral_digits: int_field("MinimumIntegralDigits", defaults.minimum_integral_digits),
+            maximum_integral_digits: int_field("MaximumIntegralDigits", defaults.maximum_integral_digits),
+            minimum_fractional_digits: int_field("MinimumFractionalDigits", defaults.minimum_fractional_digits),
+            maximum_fractional_digits: int_field("MaximumFractionalDigits", defaults.maximum_fractional_digits),
+        }
+    }
+}
+
+impl NumberFormat {
+    fn read<Index, A>(ar: &mut A) -> UnrealAssetResult<Self>
+    where
+        Index: PackageIndexTrait,
+        A: ArchiveReader<Index> + ?Sized,
+    {
+        let source_value = FormatArgumentValue::read(ar, false)?;
+        let format_options = match ar.read_i32_endian()? != 0 {
+            true => Some(NumberFormattingOptions::read(ar)?),
+            false => None,
+        };
+        Ok(Self { source_value, format_options, target_culture: ar.read_fstring()? })
+    }
+
+    fn write<Index, W>(&self, ar: &mut W) -> UnrealAssetResult<()>
+    where
+        Index: PackageIndexTrait,
+        W: ArchiveWriter<Index> + ?Sized,
+    {
+        self.source_value.write(ar, false)?;
+        ar.write_i32_endian(self.format_options.is_some() as i32)?;
+        if let Some(options) = &self.format_options {
+            options.write(ar)?;
+        }
+        ar.write_fstring(self.target_culture.as_deref())?;
+        Ok(())
+    }
+
+    fn display_string(&self, localization: &LocalizationTable, scale: f64, defaults: Option<&NumberFormattingOptions>) -> String {
+        let fallback = NumberFormattingOptions::default();
+        let options = self.format_options.as_ref().or(defaults).unwrap_or(&fallback);
+        match &self.source_value {
+            FormatArgumentValue::Int(value) if scale == 1.0 => format_integer(*value as i128, options),
+            FormatArgumentValue::UInt(value) if scale == 1.0 => format_integer(*value as i128, options),
+            FormatArgumentValue::Int(value) => format_float(*value as f64 * scale, options),
+            FormatArgumentValue::UInt(value) => format_float(*value as f64 * scale, options),
+            FormatArgumentValue::Float(value) => format_float(*value as f64 * scale, options),
+            FormatArgumentValue::Double(value) => format_float(*value * scale, options),
+            other => other.display_string(localization),
+        }
+    }
+}
+
+/// String table contents, `FStringTable`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StringTable {
+    /// Namespace the entries are localized under
+    pub namespace: String,
+    /// Source strings by key
+    pub entries: IndexMap<String, String>,
+}
+
+impl StringTable {
+    /// Convert the `StringTable` property CUE4Parse writes for `UStringTable` exports
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        let value = value.get("StringTable").unwrap_or(value);
+        let namespace = value.get("TableNamespace").and_then(Value::as_str).unwrap_or_default();
+        let entries = value
+            .get("KeysToEntries")
+            .and_then(Value::as_object)
+            .ok_or_else(|| json_error("string table", value))?
+            .iter()
+            .map(|(key, entry)| (key.clone(), entry.as_str().unwrap_or_default().to_string()))
+            .collect();
+        Ok(Self { namespace: namespace.to_string(), entries })
+    }
+}
+
+/// Localized strings and string tables texts are rendered against
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LocalizationTable {
+    /// Localized strings by namespace and key
+    entries: HashMap<String, HashMap<String, String>>,
+    /// String tables by table id, e.g. `/Game/Text/ST_Items.ST_Items`
+    string_tables: HashMap<String, StringTable>,
+}
+
+impl LocalizationTable {
+    /// Create an empty table
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read a localization resource exported by CUE4Parse as `{ namespace: { key: string } }`
+    pub fn from_json(value: &Value) -> UnrealAssetResult<Self> {
+        let namespaces = value.as_object().ok_or_else(|| json_error("localization resource", value))?;
+        let mut table = Self::new();
+        for (namespace, keys) in namespaces {
+            let keys: &Map<String, Value> = keys.as_object().ok_or_else(|| json_error("localization namespace", keys))?;
+            for (key, localized) in keys {
+                if let Some(localized) = localized.as_str() {
+                    table.insert(namespace.clone(), key.clone(), localized);
+                }
+            }
+        }
+        Ok(table)
+    }
+
+    /// Add a localized string
+    pub fn insert(&mut self, namespace: impl Into<String>, key: impl Into<String>, localized: impl Into<String>) {
+        self.entries.entry(namespace.into()).or_default().insert(key.into(), localized.into());
+    }
+
+    /// Localized string of a namespace and key
+    pub fn get(&self, namespace: &str, key: &str) -> Option<&str> {
+        self.entries.get(namespace)?.get(key).map(String::as_str)
+    }
+
+    /// Register a string table under its table id
+    pub fn add_string_table(&mut self, table_id: impl Into<String>, table: StringTable) {
+        self.string_tables.insert(table_id.into(), table);
+    }
+
+    /// Localized string of a string table entry, falling back to the table's source string
+    pub fn string_table_entry(&self, table_id: &str, key: &str) -> Option<&str> {
+        let table = self.string_tables.get(table_id)?;
+        self.get(&table.namespace, key).or_else(|| table.entries.get(key).map(String::as_str))
+    }
+}
+
+fn read_nested_text<Index, A>(ar: &mut A) -> UnrealAssetResult<FText>
+where
+    Index: PackageIndexTrait,
+    A: ArchiveReader<Index> + ?Sized,
+{
+    ar.read_nested(|ar| FText::read(ar))
+}
+
+fn read_date_time_style<Index, A>(ar: &mut A) -> UnrealAssetResult<DateTimeStyle>
+where
+    Index: PackageIndexTrait,
+    A: ArchiveReader<Index> + ?Sized,
+{
+    Ok(match ar.read_i8()? {
+        1 => DateTimeStyle::Short,
+        2 => DateTimeStyle::Medium,
+        3 => DateTimeStyle::Long,
+        4 => DateTimeStyle::Full,
+        _ => DateTimeStyle::Default,
+    })
+}
+
+fn date_time_style_from_json(value: &Value) -> DateTimeStyle {
+    match value.as_str().map(|s| s.rsplit("::").next().unwrap_or_default()) {
+        Some("Short") => DateTimeStyle::Short,
+        Some("Medium") => DateTimeStyle::Medium,
+        Some("Long") => DateTimeStyle::Long,
+        Some("Full") => DateTimeStyle::Full,
+        Some(_) => DateTimeStyle::Default,
+        None => match value.as_u64() {
+            Some(1) => DateTimeStyle::Short,
+            Some(2) => DateTimeStyle::Medium,
+            Some(3) => DateTimeStyle::Long,
+            Some(4) => DateTimeStyle::Full,
+            _ => DateTimeStyle::Default,
+        },
+    }
+}
+
+fn has_64bit_argument_data<Index: PackageIndexTrait, T: ArchiveTrait<Index> + ?Sized>(ar: &T) -> bool {
+    ar.get_custom_version::<FUE5ReleaseStreamObjectVersion>().version
+        >= FUE5ReleaseStreamObjectVersion::TextFormatArgumentData64bitSupport as i32
+}
+
+fn json_error(expected: &str, value: &Value) -> UnrealAssetError {
+    UnrealAssetError::InvalidData(format!("Expected {} JSON, found {}", expected, value))
+}
+
+/// Replace `{Argument}` placeholders, keeping unknown ones and backtick escapes as UE does
+fn format_pattern(pattern: &str, mut argument: impl FnMut(&str) -> Option<String>) -> String {
+    let mut result = String::with_capacity(pattern.len());
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '`' if matches!(chars.peek(), Some('{') | Some('}') | Some('`')) => result.extend(chars.next()),
+            '{' => {
+                let mut name = String::new();
+                let mut closed = false;
+                for c in chars.by_ref() {
+                    if c == '}' {
+                        closed = true;
+                        break;
+                    }
+                    name.push(c);
+                }
+                // Argument modifiers such as `|plural(...)` are not evaluated
+                let key = name.split('|').next().unwrap_or_default().trim();
+                match argument(key).filter(|_| closed) {
+                    Some(value) => result.push_str(&value),
+                    None => {
+                        result.push('{');
+                        result.push_str(&name);
+                        if closed {
+                            result.push('}');
+                        }
+                    }
+                }
+            }
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+fn group_digits(digits: &str, options: &NumberFormattingOptions) -> String {
+    let min_digits = options.minimum_integral_digits.max(1) as usize;
+    let mut digits = format!("{:0>width$}", digits, width = min_digits);
+    let max_digits = options.maximum_integral_digits.max(1) as usize;
+    if digits.len() > max_digits {
+        digits = digits[digits.len() - max_digits..].to_string();
+    }
+    if !options.use_grouping {
+        return digits;
+    }
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
+fn sign(negative: bool, options: &NumberFormattingOptions) -> &'static str {
+    match (negative, options.always_sign) {
+        (true, _) => "-",
+        (false, true) => "+",
+        (false, false) => "",
+    }
+}
+
+fn format_integer(value: i128, options: &NumberFormattingOptions) -> String {
+    let integral = group_digits(&value.unsigned_abs().to_string(), options);
+    let fraction = "0".repeat(options.minimum_fractional_digits.max(0) as usize);
+    match fraction.is_empty() {
+        true => format!("{}{}", sign(value < 0, options), integral),
+        false => format!("{}{}.{}", sign(value < 0, options), integral, fraction),
+    }
+}
+
+fn format_float(value: f64, options: &NumberFormattingOptions) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let max_fraction = options.maximum_fractional_digits.clamp(0, 17) as usize;
+    let min_fraction = (options.minimum_fractional_digits.max(0) as usize).min(max_fraction);
+    let scale = 10f64.powi(max_fraction as i32);
+    let scaled = value.abs() * scale;
+    let rounded = match options.rounding_mode {
+        RoundingMode::HalfToEven => {
+            let floor = scaled.floor();
+            match scaled - floor {
+                diff if diff > 0.5 => floor + 1.0,
+                diff if diff < 0.5 => floor,
+                _ if floor % 2.0 == 0.0 => floor,
+                _ => floor + 1.0,
+            }
+        }
+        RoundingMode::HalfFromZero => scaled.round(),
+        RoundingMode::HalfToZero => (scaled - 0.5).ceil(),
+        RoundingMode::FromZero => scaled.ceil(),
+        RoundingMode::ToZero => scaled.trunc(),
+        RoundingMode::ToNegativeInfinity if value < 0.0 => scaled.ceil(),
+        RoundingMode::ToNegativeInfinity => scaled.floor(),
+        RoundingMode::ToPositiveInfinity if value < 0.0 => scaled.floor(),
+        RoundingMode::ToPositiveInfinity => scaled.ceil(),
+    };
+    let text = format!("{:.*}", max_fraction, rounded / scale);
+    let (integral, fraction) = text.split_once('.').unwrap_or((&text, ""));
+    let fraction = fraction.trim_end_matches('0');
+    let fraction = format!("{:0<width$}", fraction, width = min_fraction);
+    let negative = value < 0.0 && rounded != 0.0;
+    let integral = group_digits(integral, options);
+    match fraction.is_empty() {
+        true => format!("{}{}", sign(negative, options), integral),
+        false => format!("{}{}.{}", sign(negative, options), integral, fraction),
+    }
+}
+
+/// Format `FDateTime` ticks as an ISO 8601 date, time or both
+fn format_ticks(ticks: i64, date: bool, time: bool) -> String {
+    const TICKS_PER_SECOND: i64 = 10_000_000;
+    const SECONDS_PER_DAY: i64 = 86_400;
+    let seconds = ticks.div_euclid(TICKS_PER_SECOND);
+    let days = seconds.div_euclid(SECONDS_PER_DAY);
+    let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY);
+
+    // Days since 0001-01-01 to a proleptic Gregorian date
+    let z = days + 306; // Shift the epoch to 0000-03-01
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+    let year = year_of_era + era * 400 + (month <= 2) as i64;
+
+    let date_string = format!("{:04}-{:02}-{:02}", year, month, day);
+    let time_string = format!("{:02}:{:02}:{:02}", second_of_day / 3600, second_of_day / 60 % 60, second_of_day % 60);
+    match (date, time) {
+        (true, true) => format!("{} {}", date_string, time_string),
+        (true, false) => date_string,
+        _ => time_string,
+    }
+}
@@ -0,0 +1,577 @@
+//! Typed views over common export classes
+//!
+//! [`TypedExport::from_json`] decodes an export written by
+//! [`Provider::export_object_json`](crate::Provider::export_object_json) into a view of its
+//! class, so callers read `texture.size_x` instead of indexing `json["SizeX"]`:
+//!
+//! ```ignore
+//! for export in TypedExport::all_from_json(&provider.export_object_json(path)?) {
+//!     if let TypedExport::Texture2D(texture) = export? {
+//!         println!("{}: {:?}x{:?} {:?}", texture.name, texture.size_x, texture.size_y, texture.pixel_format);
+//!     }
+//! }
+//! ```
+//!
+//! Every field is optional: the engine leaves properties at their default value out of the
+//! export, and CUE4Parse versions differ in where they write native data. Enum values are
+//! returned without their `EType::` prefix.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::unreal_asset::curve::{CurveFloat, CurveLinearColor, CurveVector};
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::text::StringTable;
+use crate::unreal_asset::types::{ClassQualifiedPath, LinearColor, Vector};
+
+/// Export decoded into a view of its class
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum TypedExport {
+    Texture2D(Texture2DView),
+    StaticMesh(StaticMeshView),
+    SkeletalMesh(SkeletalMeshView),
+    SoundWave(SoundWaveView),
+    DataTable(DataTableView),
+    CurveTable(CurveTableView),
+    CurveFloat(CurveFloat),
+    CurveVector(CurveVector),
+    CurveLinearColor(CurveLinearColor),
+    StringTable(StringTableView),
+    MaterialInstanceConstant(MaterialInstanceView),
+    /// `Blueprint` and `BlueprintGeneratedClass`, including widget and animation blueprints
+    Blueprint(BlueprintView),
+    /// Class without a view
+    Other { class_name: String, json: Value },
+}
+
+impl TypedExport {
+    /// Decode one export, dispatching on its `Type`
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let class_name = object.get("Type").and_then(Value::as_str).unwrap_or_default();
+        Ok(match class_name {
+            "Texture2D" | "LightMapTexture2D" | "ShadowMapTexture2D" => {
+                TypedExport::Texture2D(Texture2DView::from_json(export)?)
+            }
+            "StaticMesh" => TypedExport::StaticMesh(StaticMeshView::from_json(export)?),
+            "SkeletalMesh" => TypedExport::SkeletalMesh(SkeletalMeshView::from_json(export)?),
+            "SoundWave" => TypedExport::SoundWave(SoundWaveView::from_json(export)?),
+            "DataTable" | "CompositeDataTable" => TypedExport::DataTable(DataTableView::from_json(export)?),
+            "CurveTable" | "CompositeCurveTable" => TypedExport::CurveTable(CurveTableView::from_json(export)?),
+            "CurveFloat" => TypedExport::CurveFloat(CurveFloat::from_json(export)?),
+            "CurveVector" => TypedExport::CurveVector(CurveVector::from_json(export)?),
+            "CurveLinearColor" => TypedExport::CurveLinearColor(CurveLinearColor::from_json(export)?),
+            "StringTable" => TypedExport::StringTable(StringTableView::from_json(export)?),
+            "MaterialInstanceConstant" => TypedExport::MaterialInstanceConstant(MaterialInstanceView::from_json(export)?),
+            "Blueprint" | "WidgetBlueprint" | "AnimBlueprint" | "BlueprintGeneratedClass"
+            | "WidgetBlueprintGeneratedClass" | "AnimBlueprintGeneratedClass" => {
+                TypedExport::Blueprint(BlueprintView::from_json(export)?)
+            }
+            _ => TypedExport::Other { class_name: class_name.to_string(), json: export.clone() },
+        })
+    }
+
+    /// Decode every export of the array `export_object_json` returns, or a single export
+    pub fn all_from_json(exports: &Value) -> Vec<UnrealAssetResult<Self>> {
+        match exports {
+            Value::Array(exports) => exports.iter().map(Self::from_json).collect(),
+            export => vec![Self::from_json(export)],
+        }
+    }
+
+    /// Export name
+    pub fn name(&self) -> &str {
+        match self {
+            TypedExport::Texture2D(view) => &view.name,
+            TypedExport::StaticMesh(view) => &view.name,
+            TypedExport::SkeletalMesh(view) => &view.name,
+            TypedExport::SoundWave(view) => &view.name,
+            TypedExport::DataTable(view) => &view.name,
+            TypedExport::CurveTable(view) => &view.name,
+            TypedExport::CurveFloat(curve) => &curve.name,
+            TypedExport::CurveVector(curve) => &curve.name,
+            TypedExport::CurveLinearColor(curve) => &curve.name,
+            TypedExport::StringTable(view) => &view.name,
+            TypedExport::MaterialInstanceConstant(view) => &view.name,
+            TypedExport::Blueprint(view) => &view.name,
+            TypedExport::Other { json, .. } => json.get("Name").and_then(Value::as_str).unwrap_or_default(),
+        }
+    }
+}
+
+/// `UTexture2D`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Texture2DView {
+    pub name: String,
+    pub size_x: Option<u32>,
+    pub size_y: Option<u32>,
+    /// Pixel format, e.g. `PF_DXT5`
+    pub pixel_format: Option<String>,
+    pub mips: Vec<TextureMip>,
+    /// Texture group, e.g. `TEXTUREGROUP_UI`
+    pub lod_group: Option<String>,
+    pub srgb: Option<bool>,
+    /// Compression settings, e.g. `TC_Normalmap`
+    pub compression_settings: Option<String>,
+}
+
+/// Mip of a texture's platform data
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextureMip {
+    pub size_x: u32,
+    pub size_y: u32,
+    pub size_z: u32,
+    /// Size of the mip's bulk data in bytes
+    pub data_size: Option<u64>,
+}
+
+impl Texture2DView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let properties = properties(object);
+        // Newer CUE4Parse versions nest the platform data
+        let platform = object.get("PlatformData").and_then(Value::as_object).unwrap_or(object);
+        let mips = platform
+            .get("Mips")
+            .and_then(Value::as_array)
+            .map(|mips| mips.iter().filter_map(TextureMip::from_json).collect())
+            .unwrap_or_default();
+        Ok(Self {
+            name: export_name(object),
+            size_x: u32_of(platform.get("SizeX")),
+            size_y: u32_of(platform.get("SizeY")),
+            pixel_format: enum_of(platform.get("PixelFormat")),
+            mips,
+            lod_group: enum_of(properties.get("LODGroup")),
+            srgb: properties.get("SRGB").and_then(Value::as_bool),
+            compression_settings: enum_of(properties.get("CompressionSettings")),
+        })
+    }
+}
+
+impl TextureMip {
+    fn from_json(mip: &Value) -> Option<Self> {
+        Some(Self {
+            size_x: u32_of(mip.get("SizeX"))?,
+            size_y: u32_of(mip.get("SizeY"))?,
+            size_z: u32_of(mip.get("SizeZ")).unwrap_or(1),
+            data_size: mip
+                .get("BulkData")
+                .and_then(|data| data.get("ElementCount").or(data.get("SizeOnDisk")))
+                .and_then(Value::as_u64),
+        })
+    }
+}
+
+/// Bounding box and sphere, `FBoxSphereBounds`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bounds {
+    pub origin: Vector,
+    pub box_extent: Vector,
+    pub sphere_radius: f64,
+}
+
+impl Bounds {
+    fn from_json(bounds: &Value) -> Option<Self> {
+        Some(Self {
+            origin: vector_of(bounds.get("Origin")?)?,
+            box_extent: vector_of(bounds.get("BoxExtent")?)?,
+            sphere_radius: bounds.get("SphereRadius").and_then(Value::as_f64).unwrap_or_default(),
+        })
+    }
+}
+
+/// Material slot of a mesh
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MaterialSlot {
+    pub slot_name: Option<String>,
+    pub material: Option<ClassQualifiedPath>,
+}
+
+impl MaterialSlot {
+    fn from_json(slot: &Value) -> Self {
+        Self {
+            slot_name: string_of(slot.get("MaterialSlotName")).filter(|name| name != "None"),
+            material: reference_of(slot.get("MaterialInterface")),
+        }
+    }
+}
+
+/// `UStaticMesh`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StaticMeshView {
+    pub name: String,
+    pub lod_count: Option<usize>,
+    pub materials: Vec<MaterialSlot>,
+    pub bounds: Option<Bounds>,
+}
+
+impl StaticMeshView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let properties = properties(object);
+        let render_data = object.get("RenderData");
+        Ok(Self {
+            name: export_name(object),
+            lod_count: render_data.and_then(|data| data.get("LODs")).and_then(Value::as_array).map(Vec::len),
+            materials: slots(properties.get("StaticMaterials")),
+            bounds: render_data
+                .and_then(|data| data.get("Bounds"))
+                .or(properties.get("ExtendedBounds"))
+                .and_then(Bounds::from_json),
+        })
+    }
+}
+
+/// `USkeletalMesh`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SkeletalMeshView {
+    pub name: String,
+    pub lod_count: Option<usize>,
+    pub materials: Vec<MaterialSlot>,
+    pub skeleton: Option<ClassQualifiedPath>,
+    pub bone_count: Option<usize>,
+    pub bounds: Option<Bounds>,
+}
+
+impl SkeletalMeshView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let properties = properties(object);
+        let lod_count = ["LODModels", "LODRenderData"]
+            .iter()
+            .find_map(|key| object.get(*key).and_then(Value::as_array))
+            .or(properties.get("LODInfo").and_then(Value::as_array))
+            .map(Vec::len);
+        Ok(Self {
+            name: export_name(object),
+            lod_count,
+            materials: slots(object.get("Materials").or(properties.get("Materials"))),
+            skeleton: reference_of(properties.get("Skeleton")),
+            bone_count: object
+                .get("ReferenceSkeleton")
+                .and_then(|skeleton| skeleton.get("FinalRefBoneInfo"))
+                .and_then(Value::as_array)
+                .map(Vec::len),
+            bounds: object.get("ImportedBounds").or(properties.get("ImportedBounds")).and_then(Bounds::from_json),
+        })
+    }
+}
+
+/// `USoundWave`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SoundWaveView {
+    pub name: String,
+    /// Duration in seconds
+    pub duration: Option<f32>,
+    pub sample_rate: Option<u32>,
+    pub num_channels: Option<u32>,
+    pub looping: Option<bool>,
+    pub streaming: Option<bool>,
+    pub compression_quality: Option<i32>,
+}
+
+impl SoundWaveView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let properties = properties(object);
+        Ok(Self {
+            name: export_name(object),
+            duration: properties.get("Duration").and_then(Value::as_f64).map(|v| v as f32),
+            sample_rate: u32_of(properties.get("SampleRate")),
+            num_channels: u32_of(properties.get("NumChannels")),
+            looping: properties.get("bLooping").and_then(Value::as_bool),
+            streaming: properties.get("bStreaming").or(object.get("bStreaming")).and_then(Value::as_bool),
+            compression_quality: properties.get("CompressionQuality").and_then(Value::as_i64).and_then(|v| i32::try_from(v).ok()),
+        })
+    }
+}
+
+/// `UDataTable`
+///
+/// Rows and their fields keep the order of the JSON they are deserialized from. A `Value`
+/// sorts its keys, so deserialize the view from the export text, e.g. with
+/// `serde_json::from_str`, to keep the order of the file. Fields of nested structs are
+/// `Value`s and come out sorted.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataTableView {
+    pub name: String,
+    pub row_struct: Option<ClassQualifiedPath>,
+    /// Row fields by row name
+    pub rows: IndexMap<String, IndexMap<String, Value>>,
+}
+
+impl DataTableView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        Self::deserialize(export_object(export).map(|_| export)?).map_err(table_error)
+    }
+}
+
+impl<'de> Deserialize<'de> for DataTableView {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let export = TableExport::deserialize(deserializer)?;
+        Ok(Self {
+            name: export.name,
+            row_struct: reference_of(export.properties.get("RowStruct")),
+            rows: export.rows.unwrap_or_default(),
+        })
+    }
+}
+
+/// `UCurveTable`
+///
+/// Rows keep the order of the JSON they are deserialized from, as in [`DataTableView`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CurveTableView {
+    pub name: String,
+    /// `RichCurves` or `SimpleCurves`
+    pub mode: Option<String>,
+    /// Curve JSON by row name
+    pub rows: IndexMap<String, Value>,
+}
+
+impl CurveTableView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        Self::deserialize(export_object(export).map(|_| export)?).map_err(table_error)
+    }
+}
+
+impl<'de> Deserialize<'de> for CurveTableView {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let export = TableExport::deserialize(deserializer)?;
+        Ok(Self {
+            name: export.name,
+            mode: enum_of(export.curve_table_mode.as_ref()),
+            rows: export.rows.unwrap_or_default(),
+        })
+    }
+}
+
+/// Fields of a DataTable or CurveTable export, with the rows in the order they are read
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct TableExport<R> {
+    #[serde(default)]
+    name: String,
+    #[serde(default)]
+    properties: Value,
+    #[serde(default)]
+    curve_table_mode: Option<Value>,
+    #[serde(default)]
+    rows: Option<IndexMap<String, R>>,
+}
+
+/// `UStringTable`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StringTableView {
+    pub name: String,
+    /// Table id texts refer to the table by, e.g. `/Game/Text/ST_Items.ST_Items`
+    pub table_id: Option<String>,
+    pub table: StringTable,
+}
+
+impl StringTableView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        Ok(Self {
+            name: export_name(object),
+            table_id: string_of(object.get("StringTableId")),
+            table: StringTable::from_json(export)?,
+        })
+    }
+}
+
+/// `UMaterialInstanceConstant`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MaterialInstanceView {
+    pub name: String,
+    pub parent: Option<ClassQualifiedPath>,
+    pub scalar_parameters: IndexMap<String, f32>,
+    pub vector_parameters: IndexMap<String, LinearColor>,
+    pub texture_parameters: IndexMap<String, Option<ClassQualifiedPath>>,
+    pub static_switches: IndexMap<String, bool>,
+}
+
+impl MaterialInstanceView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let properties = properties(object);
+        // UE5.1 moved static parameters out of the editor-only `StaticParameters`
+        let static_switches = ["StaticParametersRuntime", "StaticParameters"]
+            .iter()
+            .find_map(|key| properties.get(*key).or(object.get(*key)))
+            .map(|parameters| parameters_of(parameters.get("StaticSwitchParameters"), "Value", Value::as_bool))
+            .unwrap_or_default();
+        Ok(Self {
+            name: export_name(object),
+            parent: reference_of(properties.get("Parent")),
+            scalar_parameters: parameters_of(properties.get("ScalarParameterValues"), "ParameterValue", |v| {
+                v.as_f64().map(|v| v as f32)
+            }),
+            vector_parameters: parameters_of(properties.get("VectorParameterValues"), "ParameterValue", color_of),
+            texture_parameters: parameters_of(properties.get("TextureParameterValues"), "ParameterValue", |v| {
+                Some(reference_of(Some(v)))
+            }),
+            static_switches,
+        })
+    }
+}
+
+/// Variable declared by a blueprint class
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlueprintVariable {
+    pub name: String,
+    /// Property type, e.g. `IntProperty`
+    pub type_name: String,
+}
+
+/// `UBlueprint` or `UBlueprintGeneratedClass`
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BlueprintView {
+    pub name: String,
+    /// `Blueprint`, `BlueprintGeneratedClass` or one of their subclasses
+    pub class_name: String,
+    pub parent_class: Option<ClassQualifiedPath>,
+    pub generated_class: Option<ClassQualifiedPath>,
+    pub class_default_object: Option<ClassQualifiedPath>,
+    pub functions: Vec<String>,
+    pub variables: Vec<BlueprintVariable>,
+    pub interfaces: Vec<ClassQualifiedPath>,
+}
+
+impl BlueprintView {
+    pub fn from_json(export: &Value) -> UnrealAssetResult<Self> {
+        let object = export_object(export)?;
+        let properties = properties(object);
+        let functions = match object.get("FuncMap").and_then(Value::as_object) {
+            Some(functions) => functions.keys().cloned().collect(),
+            None => object
+                .get("Children")
+                .and_then(Value::as_array)
+                .map(|children| {
+                    children.iter().filter_map(|child| reference_of(Some(child))).map(|child| object_name(&child.path)).collect()
+                })
+                .unwrap_or_default(),
+        };
+        let variables = object
+            .get("ChildProperties")
+            .and_then(Value::as_array)
+            .map(|children| {
+                children
+                    .iter()
+                    .filter_map(|child| Some(BlueprintVariable {
+                        name: string_of(child.get("Name"))?,
+                        type_name: string_of(child.get("Type")).unwrap_or_default(),
+                    }))
+                    .collect()
+            })
+            .unwrap_or_default();
+        let interfaces = object
+            .get("Interfaces")
+            .and_then(Value::as_array)
+            .map(|interfaces| interfaces.iter().filter_map(|interface| reference_of(interface.get("Class"))).collect())
+            .unwrap_or_default();
+        Ok(Self {
+            name: export_name(object),
+            class_name: object.get("Type").and_then(Value::as_str).unwrap_or_default().to_string(),
+            parent_class: reference_of(object.get("SuperStruct").or(properties.get("ParentClass"))),
+            generated_class: reference_of(properties.get("GeneratedClass")),
+            class_default_object: reference_of(object.get("ClassDefaultObject")),
+            functions,
+            variables,
+            interfaces,
+        })
+    }
+}
+
+fn export_object(export: &Value) -> UnrealAssetResult<&Map<String, Value>> {
+    export
+        .as_object()
+        .ok_or_else(|| UnrealAssetError::InvalidData(format!("Expected export JSON object, found {}", export)))
+}
+
+fn export_name(object: &Map<String, Value>) -> String {
+    object.get("Name").and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// The export's tagged properties, or null when it has none
+fn properties(object: &Map<String, Value>) -> &Value {
+    object.get("Properties").unwrap_or(&Value::Null)
+}
+
+fn table_error(error: serde_json::Error) -> UnrealAssetError {
+    UnrealAssetError::InvalidData(format!("Invalid table export: {}", error))
+}
+
+fn string_of(value: Option<&Value>) -> Option<String> {
+    value.and_then(Value::as_str).map(str::to_string)
+}
+
+fn u32_of(value: Option<&Value>) -> Option<u32> {
+    value.and_then(Value::as_u64).and_then(|v| u32::try_from(v).ok())
+}
+
+/// Enum value without its `EType::` prefix
+fn enum_of(value: Option<&Value>) -> Option<String> {
+    let value = value?.as_str()?;
+    Some(value.rsplit_once("::").map_or(value, |(_, variant)| variant).to_string())
+}
+
+fn vector_of(value: &Value) -> Option<Vector> {
+    Some(Vector::new(value.get("X")?.as_f64()?, value.get("Y")?.as_f64()?, value.get("Z")?.as_f64()?))
+}
+
+fn color_of(value: &Value) -> Option<LinearColor> {
+    let channel = |name: &str| value.get(name).and_then(Value::as_f64).map(|v| v as f32);
+    Some(LinearColor::new(channel("R")?, channel("G")?, channel("B")?, channel("A").unwrap_or(1.0)))
+}
+
+/// `Class'Name'` and `/Game/Path.Index` as CUE4Parse writes references, as `Class'/Game/Path.Name'`
+///
+/// Subobject names such as `Outer:Sub.Inner` are kept whole.
+fn reference_of(value: Option<&Value>) -> Option<ClassQualifiedPath> {
+    let value = value?;
+    let object_name = value.get("ObjectName")?.as_str()?;
+    let object_path = value.get("ObjectPath")?.as_str()?;
+    let (class_name, name) = ClassQualifiedPath::split(object_name).unwrap_or(("Object", object_name));
+    // The suffix is the export index within the package
+    let package = match object_path.rsplit_once('.') {
+        Some((package, index)) if index.chars().all(|c| c.is_ascii_digit()) => package,
+        _ => object_path,
+    };
+    // A full path name repeats the package
+    let name = match name.starts_with('/') {
+        true => name.split_once('.').map_or(name, |(_, object)| object),
+        false => name,
+    };
+    Some(ClassQualifiedPath::new(class_name, format!("{}.{}", package, name)))
+}
+
+fn object_name(path: &str) -> String {
+    path.rsplit(['.', ':']).next().unwrap_or(path).to_string()
+}
+
+fn slots(value: Option<&Value>) -> Vec<MaterialSlot> {
+    value
+        .and_then(Value::as_array)
+        .map(|slots| slots.iter().map(MaterialSlot::from_json).collect())
+        .unwrap_or_default()
+}
+
+/// Material parameters by `ParameterInfo.Name`
+fn parameters_of<T>(value: Option<&Value>, key: &str, decode: impl Fn(&Value) -> Option<T>) -> IndexMap<String, T> {
+    value
+        .and_then(Value::as_array)
+        .map(|parameters| {
+            parameters
+                .iter()
+                .filter_map(|parameter| {
+                    let name = parameter.get("ParameterInfo").and_then(|info| info.get("Name")).and_then(Value::as_str)?;
+                    Some((name.to_string(), decode(parameter.get(key)?)?))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
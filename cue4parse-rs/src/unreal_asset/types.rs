@@ -1,4 +1,6 @@
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use crate::unreal_asset::error::UnrealAssetError;
@@ -351,6 +353,87 @@ impl Default for Transform {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub fn new(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub struct IntPoint {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl IntPoint {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+}
+
+/// Plane `X * x + Y * y + Z * z = W`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct Plane {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    pub w: f64,
+}
+
+impl Plane {
+    pub fn new(x: f64, y: f64, z: f64, w: f64) -> Self {
+        Self { x, y, z, w }
+    }
+}
+
+/// Axis-aligned bounding box, `FBox`
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct BoundingBox {
+    pub min: Vector,
+    pub max: Vector,
+    pub is_valid: bool,
+}
+
+impl BoundingBox {
+    pub fn new(min: Vector, max: Vector) -> Self {
+        Self { min, max, is_valid: true }
+    }
+}
+
+/// Row-major 4x4 matrix
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Matrix {
+    pub m: [[f64; 4]; 4],
+}
+
+impl Matrix {
+    pub fn new(m: [[f64; 4]; 4]) -> Self {
+        Self { m }
+    }
+
+    pub fn identity() -> Self {
+        let mut m = [[0.0; 4]; 4];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        Self { m }
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SoftObjectPath {
     pub asset_path: FName,
@@ -427,10 +510,56 @@ impl ObjectReference {
     }
 }
 
+/// Object path qualified with its class, e.g. `StaticMesh'/Game/Meshes/Cube.Cube'`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClassQualifiedPath {
+    /// Class name, or a class path such as `/Script/Engine.StaticMesh`
+    pub class_name: String,
+    /// Full object path
+    pub path: String,
+}
+
+impl ClassQualifiedPath {
+    /// Create a new `ClassQualifiedPath`
+    pub fn new(class_name: impl Into<String>, path: impl Into<String>) -> Self {
+        Self {
+            class_name: class_name.into(),
+            path: path.into(),
+        }
+    }
+    
+    /// Split `Class'Path'` into its class and path without allocating
+    pub fn split(value: &str) -> Option<(&str, &str)> {
+        let (class, rest) = value.split_once('\'')?;
+        let path = rest.strip_suffix('\'')?;
+        if class.is_empty() || path.contains('\'') {
+            return None;
+        }
+        Some((class, path))
+    }
+}
+
+impl std::fmt::Display for ClassQualifiedPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}'{}'", self.class_name, self.path)
+    }
+}
+
+impl std::str::FromStr for ClassQualifiedPath {
+    type Err = UnrealAssetError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (class_name, path) = Self::split(value)
+            .ok_or_else(|| UnrealAssetError::Parse(format!("Invalid class qualified path: {}", value)))?;
+        Ok(Self::new(class_name, path))
+    }
+}
+
 pub struct PackageIndexResolver<'a> {
     imports: &'a [Import],
     exports: &'a [Export],
     name_map: &'a [String],
+    package_name: Option<&'a str>,
 }
 
 impl<'a> PackageIndexResolver<'a> {
@@ -440,9 +569,16 @@ impl<'a> PackageIndexResolver<'a> {
             imports,
             exports,
             name_map,
+            package_name: None,
         }
     }
     
+    /// Set the name of the package owning the exports, e.g. `/Game/Maps/Level`
+    pub fn with_package_name(mut self, package_name: &'a str) -> Self {
+        self.package_name = Some(package_name);
+        self
+    }
+    
     /// Resolve a package index to object information
     pub fn resolve(&self, index: PackageIndex) -> UnrealAssetResult<ObjectReference> {
         if index.is_null() {
@@ -488,4 +624,122 @@ impl<'a> PackageIndexResolver<'a> {
             Err(UnrealAssetError::new("Invalid package index"))
         }
     }
+    
+    /// Every import and export index
+    fn indices(&self) -> impl Iterator<Item = PackageIndex> {
+        let imports = (0..self.imports.len()).map(PackageIndex::from_import);
+        let exports = (0..self.exports.len()).map(PackageIndex::from_export);
+        imports.chain(exports)
+    }
+    
+    /// Object names from the outermost object down to `index`, and whether each one is a package
+    fn outer_chain(&self, index: PackageIndex) -> UnrealAssetResult<Vec<(String, bool)>> {
+        let mut chain = Vec::new();
+        let mut current = index;
+        while !current.is_null() {
+            if chain.len() > self.imports.len() + self.exports.len() {
+                return Err(UnrealAssetError::InvalidData(format!("Circular outer chain at index {}", index.0)));
+            }
+            match self.resolve(current)? {
+                ObjectReference::Import { class_name, object_name, outer_index, .. } => {
+                    let is_package = outer_index.is_null() && class_name.as_str() == "Package";
+                    chain.push((object_name.to_string(), is_package));
+                    current = outer_index;
+                }
+                ObjectReference::Export { object_name, outer_index, .. } => {
+                    chain.push((object_name.to_string(), false));
+                    if outer_index.is_null() {
+                        if let Some(package_name) = self.package_name {
+                            chain.push((package_name.to_string(), true));
+                        }
+                    }
+                    current = outer_index;
+                }
+                ObjectReference::Null => break,
+            }
+        }
+        chain.reverse();
+        Ok(chain)
+    }
+    
+    /// Resolve a package index to its full object path, e.g. `/Game/Maps/Level.Level:PersistentLevel.Actor_3`
+    ///
+    /// Objects directly inside a package are separated by `.`, subobjects of those by `:`
+    /// and deeper subobjects by `.` again.
+    pub fn resolve_path(&self, index: PackageIndex) -> UnrealAssetResult<String> {
+        let chain = self.outer_chain(index)?;
+        let mut path = String::new();
+        for (i, (name, _)) in chain.iter().enumerate() {
+            if i > 0 {
+                let outer_is_package = chain[i - 1].1;
+                let in_top_level_object = i >= 2 && chain[i - 2].1;
+                path.push(if !outer_is_package && in_top_level_object { ':' } else { '.' });
+            }
+            path.push_str(name);
+        }
+        Ok(path)
+    }
+    
+    /// Resolve the package owning the object at `index`
+    ///
+    /// Imports resolve to the package at the top of their outer chain, exports to this package.
+    pub fn resolve_package(&self, index: PackageIndex) -> UnrealAssetResult<String> {
+        if let Some(import) = index.import_index().and_then(|i| self.imports.get(i)) {
+            let package_name = import.package_name.as_str();
+            if !package_name.is_empty() && package_name != "None" {
+                return Ok(package_name.to_string());
+            }
+        }
+        self.outer_chain(index)?
+            .into_iter()
+            .next()
+            .map(|(name, _)| name)
+            .ok_or_else(|| UnrealAssetError::InvalidIndex(format!("No package for index {}", index.0)))
+    }
+    
+    /// Resolve the class name of the object at `index`
+    pub fn resolve_class_name(&self, index: PackageIndex) -> UnrealAssetResult<String> {
+        match self.resolve(index)? {
+            ObjectReference::Import { class_name, .. } => Ok(class_name.to_string()),
+            // Exports without a class are classes themselves
+            ObjectReference::Export { class_index, .. } if class_index.is_null() => Ok("Class".to_string()),
+            ObjectReference::Export { class_index, .. } => self
+                .resolve(class_index)?
+                .get_name()
+                .map(|name| name.to_string())
+                .ok_or_else(|| UnrealAssetError::InvalidIndex(format!("Null class for index {}", index.0))),
+            ObjectReference::Null => Err(UnrealAssetError::InvalidIndex("Null package index".to_string())),
+        }
+    }
+    
+    /// Resolve a package index to its class qualified path, e.g. `StaticMesh'/Game/Meshes/Cube.Cube'`
+    pub fn resolve_full_name(&self, index: PackageIndex) -> UnrealAssetResult<ClassQualifiedPath> {
+        Ok(ClassQualifiedPath::new(self.resolve_class_name(index)?, self.resolve_path(index)?))
+    }
+    
+    /// Find the package index of an object path
+    ///
+    /// Accepts plain paths and class qualified paths; the class of a qualified path
+    /// must match the object's class name or end with `.ClassName`.
+    pub fn find_index(&self, path: &str) -> Option<PackageIndex> {
+        let (class_name, path) = match ClassQualifiedPath::split(path) {
+            Some((class_name, path)) => (Some(class_name), path),
+            None => (None, path),
+        };
+        self.indices().find(|&index| {
+            self.resolve_path(index).is_ok_and(|p| p == path)
+                && class_name.is_none_or(|class_name| {
+                    self.resolve_class_name(index).is_ok_and(|c| {
+                        class_name == c || class_name.rsplit_once('.').is_some_and(|(_, short)| short == c)
+                    })
+                })
+        })
+    }
+    
+    /// Map every object path in the package to its package index
+    pub fn path_map(&self) -> HashMap<String, PackageIndex> {
+        self.indices()
+            .filter_map(|index| Some((self.resolve_path(index).ok()?, index)))
+            .collect()
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,491 @@
+//! Reader for `.usmap` type mappings
+//!
+//! Mappings describe the property layout of every class and struct in a game and
+//! are needed to read unversioned properties or to type property JSON that
+//! carries no annotations.
+
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::versions::CustomVersion;
+
+/// Magic at the start of every `.usmap` file
+pub const USMAP_MAGIC: u16 = 0x30C4;
+
+/// Usmap format version
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum UsmapVersion {
+    /// Initial format
+    Initial = 0,
+    /// Adds package versioning
+    PackageVersioning = 1,
+    /// Adds 16-bit name lengths
+    LongFName = 2,
+    /// Adds enums with more than 255 values
+    LargeEnums = 3,
+}
+
+impl UsmapVersion {
+    /// Latest supported version
+    pub const LATEST: UsmapVersion = UsmapVersion::LargeEnums;
+
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(UsmapVersion::Initial),
+            1 => Some(UsmapVersion::PackageVersioning),
+            2 => Some(UsmapVersion::LongFName),
+            3 => Some(UsmapVersion::LargeEnums),
+            _ => None,
+        }
+    }
+}
+
+/// Compression applied to the usmap payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[repr(u8)]
+pub enum UsmapCompression {
+    None = 0,
+    Oodle = 1,
+    Brotli = 2,
+    ZStandard = 3,
+}
+
+/// Property type ids used by usmap files
+const USMAP_PROPERTY_TYPES: &[&str] = &[
+    "ByteProperty",
+    "BoolProperty",
+    "IntProperty",
+    "FloatProperty",
+    "ObjectProperty",
+    "NameProperty",
+    "DelegateProperty",
+    "DoubleProperty",
+    "ArrayProperty",
+    "StructProperty",
+    "StrProperty",
+    "TextProperty",
+    "InterfaceProperty",
+    "MulticastDelegateProperty",
+    "WeakObjectProperty",
+    "LazyObjectProperty",
+    "AssetObjectProperty",
+    "SoftObjectProperty",
+    "UInt64Property",
+    "UInt32Property",
+    "UInt16Property",
+    "Int64Property",
+    "Int16Property",
+    "Int8Property",
+    "MapProperty",
+    "SetProperty",
+    "EnumProperty",
+    "FieldPathProperty",
+    "OptionalProperty",
+    "Utf8StrProperty",
+    "AnsiStrProperty",
+];
+
+/// Full type of a property, including container and struct/enum parameters
+///
+/// Renders as `ArrayProperty<StructProperty<Vector>>`, `MapProperty<NameProperty, IntProperty>`
+/// or `EnumProperty<EMyEnum, ByteProperty>`, the same form [`PropertyType::parse`] accepts.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PropertyType {
+    /// Property type name, e.g. `IntProperty`
+    pub type_name: String,
+    /// Struct name for `StructProperty`
+    pub struct_type: Option<String>,
+    /// Enum name for `EnumProperty` and enum-backed `ByteProperty`
+    pub enum_name: Option<String>,
+    /// Element type for arrays, sets and optionals, key type for maps, underlying type for enums
+    pub inner: Option<Box<PropertyType>>,
+    /// Value type for maps
+    pub value: Option<Box<PropertyType>>,
+}
+
+impl PropertyType {
+    /// Create a property type without parameters
+    pub fn new(type_name: impl Into<String>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            struct_type: None,
+            enum_name: None,
+            inner: None,
+            value: None,
+        }
+    }
+
+    /// Create a `StructProperty` of the given struct
+    pub fn struct_of(struct_type: impl Into<String>) -> Self {
+        Self { struct_type: Some(struct_type.into()), ..Self::new("StructProperty") }
+    }
+
+    /// Create a container property with the given element type
+    pub fn container(type_name: impl Into<String>, inner: PropertyType) -> Self {
+        Self { inner: Some(Box::new(inner)), ..Self::new(type_name) }
+    }
+
+    /// Create a `MapProperty` with the given key and value types
+    pub fn map(key: PropertyType, value: PropertyType) -> Self {
+        Self {
+            inner: Some(Box::new(key)),
+            value: Some(Box::new(value)),
+            ..Self::new("MapProperty")
+        }
+    }
+
+    /// Parse a type annotation such as `ArrayProperty<StructProperty<Vector>>`
+    ///
+    /// Short names (`Int`, `Str`, `Vector`) are accepted; a bare name that isn't a
+    /// property type is treated as a struct name.
+    pub fn parse(annotation: &str) -> UnrealAssetResult<Self> {
+        let (property_type, rest) = Self::parse_prefix(annotation.trim())?;
+        if !rest.trim().is_empty() {
+            return Err(UnrealAssetError::Parse(format!("Unexpected '{}' in type '{}'", rest, annotation)));
+        }
+        Ok(property_type)
+    }
+
+    fn parse_prefix(input: &str) -> UnrealAssetResult<(Self, &str)> {
+        let end = input.find(['<', '>', ',']).unwrap_or(input.len());
+        let name = input[..end].trim();
+        if name.is_empty() {
+            return Err(UnrealAssetError::Parse(format!("Missing type name in '{}'", input)));
+        }
+        let mut property_type = match canonical_type_name(name) {
+            Some(type_name) => PropertyType::new(type_name),
+            None => PropertyType::struct_of(name),
+        };
+        let mut rest = &input[end..];
+
+        let Some(params) = rest.strip_prefix('<') else {
+            return Ok((property_type, rest));
+        };
+        rest = params;
+        match property_type.type_name.as_str() {
+            "StructProperty" => {
+                let end = rest.find('>').ok_or_else(|| unterminated(input))?;
+                property_type.struct_type = Some(rest[..end].trim().to_string());
+                rest = &rest[end..];
+            }
+            "ByteProperty" | "EnumProperty" => {
+                let end = rest.find([',', '>']).ok_or_else(|| unterminated(input))?;
+                property_type.enum_name = Some(rest[..end].trim().to_string());
+                rest = &rest[end..];
+                if let Some(underlying) = rest.strip_prefix(',') {
+                    let (inner, remaining) = Self::parse_prefix(underlying.trim_start())?;
+                    property_type.inner = Some(Box::new(inner));
+                    rest = remaining;
+                }
+            }
+            _ => {
+                let (inner, remaining) = Self::parse_prefix(rest.trim_start())?;
+                property_type.inner = Some(Box::new(inner));
+                rest = remaining;
+                if let Some(value) = rest.strip_prefix(',') {
+                    let (value, remaining) = Self::parse_prefix(value.trim_start())?;
+                    property_type.value = Some(Box::new(value));
+                    rest = remaining;
+                }
+            }
+        }
+        let rest = rest.trim_start().strip_prefix('>').ok_or_else(|| unterminated(input))?;
+        Ok((property_type, rest))
+    }
+}
+
+impl fmt::Display for PropertyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.type_name)?;
+        if let Some(struct_type) = &self.struct_type {
+            return write!(f, "<{}>", struct_type);
+        }
+        if let Some(enum_name) = &self.enum_name {
+            return match &self.inner {
+                Some(inner) => write!(f, "<{}, {}>", enum_name, inner),
+                None => write!(f, "<{}>", enum_name),
+            };
+        }
+        match (&self.inner, &self.value) {
+            (Some(inner), Some(value)) => write!(f, "<{}, {}>", inner, value),
+            (Some(inner), None) => write!(f, "<{}>", inner),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn unterminated(annotation: &str) -> UnrealAssetError {
+    UnrealAssetError::Parse(format!("Unterminated type parameters in '{}'", annotation))
+}
+
+/// Resolve a full or short property type name to its UE name
+pub fn canonical_type_name(name: &str) -> Option<&'static str> {
+    if let Some(known) = USMAP_PROPERTY_TYPES.iter().find(|t| **t == name) {
+        return Some(known);
+    }
+    Some(match name {
+        "Bool" => "BoolProperty",
+        "Int8" => "Int8Property",
+        "Int16" => "Int16Property",
+        "Int" | "Int32" => "IntProperty",
+        "Int64" => "Int64Property",
+        "Byte" | "UInt8" => "ByteProperty",
+        "UInt16" => "UInt16Property",
+        "UInt32" => "UInt32Property",
+        "UInt64" => "UInt64Property",
+        "Float" => "FloatProperty",
+        "Double" => "DoubleProperty",
+        "Str" | "String" => "StrProperty",
+        "Name" => "NameProperty",
+        "Text" => "TextProperty",
+        "Object" => "ObjectProperty",
+        "ClassProperty" => "ClassProperty",
+        "SoftObject" | "SoftObjectPath" => "SoftObjectProperty",
+        "SoftClassProperty" => "SoftClassProperty",
+        "Array" => "ArrayProperty",
+        "Set" => "SetProperty",
+        "Map" => "MapProperty",
+        "Enum" => "EnumProperty",
+        "Struct" => "StructProperty",
+        "Delegate" => "DelegateProperty",
+        "MulticastDelegate" => "MulticastDelegateProperty",
+        "MulticastInlineDelegateProperty" => "MulticastInlineDelegateProperty",
+        "MulticastSparseDelegateProperty" => "MulticastSparseDelegateProperty",
+        _ => return None,
+    })
+}
+
+/// A property described by a usmap schema
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsmapProperty {
+    /// Property name
+    pub name: String,
+    /// Index of the property in its schema, used by unversioned serialization
+    pub schema_index: u16,
+    /// Static array size
+    pub array_size: u8,
+    /// Property type
+    pub property_type: PropertyType,
+}
+
+/// Property layout of a class or struct
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsmapSchema {
+    /// Class or struct name
+    pub name: String,
+    /// Parent class or struct
+    pub super_type: Option<String>,
+    /// Number of properties including static array elements
+    pub property_count: u16,
+    /// Serializable properties
+    pub properties: Vec<UsmapProperty>,
+}
+
+/// Parsed `.usmap` mappings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Usmap {
+    /// Format version
+    pub version: Option<UsmapVersion>,
+    /// UE4 object version recorded in the mappings, if any
+    pub object_version: Option<i32>,
+    /// UE5 object version recorded in the mappings, if any
+    pub object_version_ue5: Option<i32>,
+    /// Custom versions recorded in the mappings
+    pub custom_versions: Vec<CustomVersion>,
+    /// Changelist the mappings were dumped from
+    pub net_cl: u32,
+    /// Name table
+    pub names: Vec<String>,
+    /// Enum names and their values, in declaration order
+    pub enums: IndexMap<String, Vec<String>>,
+    /// Class and struct schemas
+    pub schemas: IndexMap<String, UsmapSchema>,
+}
+
+impl Usmap {
+    /// Read mappings from a file
+    pub fn from_file(path: impl AsRef<Path>) -> UnrealAssetResult<Self> {
+        let data = std::fs::read(path)?;
+        Self::read(&mut Cursor::new(data))
+    }
+
+    /// Read mappings from a reader
+    ///
+    /// Only uncompressed mappings are supported; compressed payloads return
+    /// `UnsupportedVersion` naming the compression method.
+    pub fn read<R: Read>(reader: &mut R) -> UnrealAssetResult<Self> {
+        let magic = reader.read_u16::<LittleEndian>()?;
+        if magic != USMAP_MAGIC {
+            return Err(UnrealAssetError::InvalidData(format!("Invalid usmap magic {:#06x}", magic)));
+        }
+        let raw_version = reader.read_u8()?;
+        let version = UsmapVersion::from_u8(raw_version)
+            .ok_or_else(|| UnrealAssetError::UnsupportedVersion(format!("Usmap version {}", raw_version)))?;
+
+        let mut usmap = Usmap { version: Some(version), ..Usmap::default() };
+        if version >= UsmapVersion::PackageVersioning && reader.read_i32::<LittleEndian>()? != 0 {
+            usmap.object_version = Some(reader.read_i32::<LittleEndian>()?);
+            usmap.object_version_ue5 = Some(reader.read_i32::<LittleEndian>()?);
+            let count = reader.read_i32::<LittleEndian>()?;
+            for _ in 0..count.max(0) {
+                let mut guid = [0u8; 16];
+                reader.read_exact(&mut guid)?;
+                let value = reader.read_i32::<LittleEndian>()?;
+                usmap.custom_versions.push(CustomVersion::new(Uuid::from_bytes(guid), value, String::new()));
+            }
+            usmap.net_cl = reader.read_u32::<LittleEndian>()?;
+        }
+
+        let compression = reader.read_u8()?;
+        let compressed_size = reader.read_u32::<LittleEndian>()?;
+        let decompressed_size = reader.read_u32::<LittleEndian>()?;
+        match compression {
+            0 if compressed_size == decompressed_size => {}
+            0 => return Err(UnrealAssetError::InvalidData(
+                "Uncompressed usmap sizes don't match".to_string(),
+            )),
+            1 => return Err(UnrealAssetError::UnsupportedVersion("Oodle compressed usmap".to_string())),
+            2 => return Err(UnrealAssetError::UnsupportedVersion("Brotli compressed usmap".to_string())),
+            3 => return Err(UnrealAssetError::UnsupportedVersion("ZStandard compressed usmap".to_string())),
+            other => return Err(UnrealAssetError::InvalidData(format!("Invalid usmap compression {}", other))),
+        }
+        // The size comes from the file, so the buffer only grows as data actually arrives
+        let mut data = Vec::new();
+        reader.take(decompressed_size as u64).read_to_end(&mut data)?;
+        if data.len() != decompressed_size as usize {
+            return Err(UnrealAssetError::InvalidData(format!(
+                "Usmap payload is {} bytes, expected {}",
+                data.len(),
+                decompressed_size
+            )));
+        }
+        usmap.read_payload(&mut Cursor::new(data), version)?;
+        Ok(usmap)
+    }
+
+    fn read_payload(&mut self, reader: &mut Cursor<Vec<u8>>, version: UsmapVersion) -> UnrealAssetResult<()> {
+        let name_count = reader.read_u32::<LittleEndian>()?;
+        for _ in 0..name_count {
+            let length = if version >= UsmapVersion::LongFName {
+                reader.read_u16::<LittleEndian>()? as usize
+            } else {
+                reader.read_u8()? as usize
+            };
+            let mut bytes = vec![0u8; length];
+            reader.read_exact(&mut bytes)?;
+            self.names.push(String::from_utf8_lossy(&bytes).into_owned());
+        }
+
+        let enum_count = reader.read_u32::<LittleEndian>()?;
+        for _ in 0..enum_count {
+            let name = self.read_name(reader)?;
+            let value_count = if version >= UsmapVersion::LargeEnums {
+                reader.read_u16::<LittleEndian>()?
+            } else {
+                reader.read_u8()? as u16
+            };
+            let mut values = Vec::with_capacity(value_count as usize);
+            for _ in 0..value_count {
+                values.push(self.read_name(reader)?);
+            }
+            // Duplicated enums keep their first definition
+            self.enums.entry(name).or_insert(values);
+        }
+
+        let schema_count = reader.read_u32::<LittleEndian>()?;
+        for _ in 0..schema_count {
+            let name = self.read_name(reader)?;
+            let super_type = self.read_optional_name(reader)?;
+            let property_count = reader.read_u16::<LittleEndian>()?;
+            let serializable_count = reader.read_u16::<LittleEndian>()?;
+            let mut properties = Vec::with_capacity(serializable_count as usize);
+            for _ in 0..serializable_count {
+                let schema_index = reader.read_u16::<LittleEndian>()?;
+                let array_size = reader.read_u8()?;
+                let name = self.read_name(reader)?;
+                let property_type = self.read_property_type(reader)?;
+                properties.push(UsmapProperty { name, schema_index, array_size, property_type });
+            }
+            self.schemas.insert(name.clone(), UsmapSchema { name, super_type, property_count, properties });
+        }
+        Ok(())
+    }
+
+    fn read_optional_name(&self, reader: &mut Cursor<Vec<u8>>) -> UnrealAssetResult<Option<String>> {
+        let index = reader.read_i32::<LittleEndian>()?;
+        if index == -1 {
+            return Ok(None);
+        }
+        self.names.get(index as usize).cloned().map(Some).ok_or_else(|| {
+            UnrealAssetError::InvalidIndex(format!("Usmap name index {} out of range", index))
+        })
+    }
+
+    fn read_name(&self, reader: &mut Cursor<Vec<u8>>) -> UnrealAssetResult<String> {
+        self.read_optional_name(reader)?
+            .ok_or_else(|| UnrealAssetError::InvalidData("Unexpected empty usmap name".to_string()))
+    }
+
+    fn read_property_type(&self, reader: &mut Cursor<Vec<u8>>) -> UnrealAssetResult<PropertyType> {
+        let id = reader.read_u8()?;
+        let type_name = USMAP_PROPERTY_TYPES
+            .get(id as usize)
+            .copied()
+            .ok_or_else(|| UnrealAssetError::InvalidData(format!("Unknown usmap property type id {}", id)))?;
+        let mut property_type = PropertyType::new(type_name);
+        match type_name {
+            "EnumProperty" => {
+                property_type.inner = Some(Box::new(self.read_property_type(reader)?));
+                property_type.enum_name = self.read_optional_name(reader)?;
+            }
+            "StructProperty" => property_type.struct_type = self.read_optional_name(reader)?,
+            "ArrayProperty" | "SetProperty" | "OptionalProperty" => {
+                property_type.inner = Some(Box::new(self.read_property_type(reader)?));
+            }
+            "MapProperty" => {
+                property_type.inner = Some(Box::new(self.read_property_type(reader)?));
+                property_type.value = Some(Box::new(self.read_property_type(reader)?));
+            }
+            _ => {}
+        }
+        Ok(property_type)
+    }
+
+    /// Get a schema by name, ignoring case like the engine does
+    pub fn get_schema(&self, name: &str) -> Option<&UsmapSchema> {
+        self.schemas.get(name).or_else(|| {
+            self.schemas.values().find(|s| s.name.eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// Find a property on a class or struct, searching its parents
+    pub fn find_property(&self, schema_name: &str, property_name: &str) -> Option<&UsmapProperty> {
+        let mut current = self.get_schema(schema_name);
+        while let Some(schema) = current {
+            if let Some(property) = schema.properties.iter().find(|p| p.name.eq_ignore_ascii_case(property_name)) {
+                return Some(property);
+            }
+            current = schema.super_type.as_deref().and_then(|s| self.get_schema(s));
+        }
+        None
+    }
+
+    /// Get every serializable property of a class or struct, parents first
+    pub fn get_all_properties(&self, schema_name: &str) -> Vec<&UsmapProperty> {
+        let mut chain = Vec::new();
+        let mut current = self.get_schema(schema_name);
+        while let Some(schema) = current {
+            chain.push(schema);
+            current = schema.super_type.as_deref().and_then(|s| self.get_schema(s));
+        }
+        chain.iter().rev().flat_map(|s| s.properties.iter()).collect()
+    }
+}
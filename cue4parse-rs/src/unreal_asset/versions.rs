@@ -2,6 +2,13 @@
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::GameVersion;
+use crate::unreal_asset::object_versions::EUnrealEngineObjectUE5Version;
+use crate::unreal_asset::custom_versions::{
+    engine_custom_versions, FAssetRegistryVersion, FFortniteMainBranchObjectVersion,
+    FFortniteReleaseBranchCustomObjectVersion, FFortniteSeasonBranchObjectVersion,
+};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ObjectVersionUE5(pub i32);
 
@@ -21,134 +28,226 @@ impl ObjectVersionUE5 {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UE5Feature {
-    /// Enhanced name map with hashing
-    OptimizedNameMap,
+    /// Names only referenced from export data can be stripped
+    NamesReferencedFromExportData,
+    /// Payload table of contents in the package summary
+    PayloadToc,
+    /// References from and to optional packages
+    OptionalResources,
     /// Large world coordinates
     LargeWorldCoordinates,
-    /// Improved bulk data handling
-    BulkDataV2,
-    /// New property serialization
-    PropertySerializationV2,
-    /// Enhanced dependency tracking
-    DependencyTracking,
+    /// No package GUID in `FObjectExport`
+    RemoveObjectExportPackageGuid,
+    /// `bIsInherited` in `FObjectExport`
+    TrackObjectExportIsInherited,
+    /// `FSoftObjectPath` stores an `FTopLevelAssetPath` instead of an asset path name
+    SoftObjectPathRemoveAssetPathNames,
+    /// Soft object path list in the package summary
+    SoftObjectPathList,
+    /// Bulk data resource table
+    DataResources,
+    /// Script serialization offsets in export entries
+    ScriptSerializationOffset,
+    /// Property tag extensions and overridable serialization
+    PropertyTagExtension,
+    /// Complete type names in property tags
+    PropertyTagCompleteTypeName,
+    /// Metadata serialization offset in the package summary
+    MetadataSerializationOffset,
+    /// Verse cells in the object graph
+    VerseCells,
+    /// `PackageSavedHash` instead of the package GUID in the package summary
+    PackageSavedHash,
 }
 
 impl UE5Feature {
-    pub fn min_version(self) -> i32 {
+    /// File version introducing this feature
+    pub fn version(self) -> EUnrealEngineObjectUE5Version {
         match self {
-            UE5Feature::OptimizedNameMap => 1001,
-            UE5Feature::LargeWorldCoordinates => 1002,
-            UE5Feature::BulkDataV2 => 1003,
-            UE5Feature::PropertySerializationV2 => 1004,
-            UE5Feature::DependencyTracking => 1005,
+            UE5Feature::NamesReferencedFromExportData => EUnrealEngineObjectUE5Version::NAMES_REFERENCED_FROM_EXPORT_DATA,
+            UE5Feature::PayloadToc => EUnrealEngineObjectUE5Version::PAYLOAD_TOC,
+            UE5Feature::OptionalResources => EUnrealEngineObjectUE5Version::OPTIONAL_RESOURCES,
+            UE5Feature::LargeWorldCoordinates => EUnrealEngineObjectUE5Version::LARGE_WORLD_COORDINATES,
+            UE5Feature::RemoveObjectExportPackageGuid => EUnrealEngineObjectUE5Version::REMOVE_OBJECT_EXPORT_PACKAGE_GUID,
+            UE5Feature::TrackObjectExportIsInherited => EUnrealEngineObjectUE5Version::TRACK_OBJECT_EXPORT_IS_INHERITED,
+            UE5Feature::SoftObjectPathRemoveAssetPathNames => {
+                EUnrealEngineObjectUE5Version::FSOFTOBJECTPATH_REMOVE_ASSET_PATH_FNAMES
+            }
+            UE5Feature::SoftObjectPathList => EUnrealEngineObjectUE5Version::ADD_SOFTOBJECTPATH_LIST,
+            UE5Feature::DataResources => EUnrealEngineObjectUE5Version::DATA_RESOURCES,
+            UE5Feature::ScriptSerializationOffset => EUnrealEngineObjectUE5Version::SCRIPT_SERIALIZATION_OFFSET,
+            UE5Feature::PropertyTagExtension => {
+                EUnrealEngineObjectUE5Version::PROPERTY_TAG_EXTENSION_AND_OVERRIDABLE_SERIALIZATION
+            }
+            UE5Feature::PropertyTagCompleteTypeName => EUnrealEngineObjectUE5Version::PROPERTY_TAG_COMPLETE_TYPE_NAME,
+            UE5Feature::MetadataSerializationOffset => EUnrealEngineObjectUE5Version::METADATA_SERIALIZATION_OFFSET,
+            UE5Feature::VerseCells => EUnrealEngineObjectUE5Version::VERSE_CELLS,
+            UE5Feature::PackageSavedHash => EUnrealEngineObjectUE5Version::PACKAGE_SAVED_HASH,
         }
     }
+
+    pub fn min_version(self) -> i32 {
+        self.version() as i32
+    }
 }
 
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
-#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize)]
 pub enum EngineVersion {
     // UE4 versions
-    VerUe4_0 = 342,
-    VerUe4_1 = 352,
-    VerUe4_2 = 363,
-    VerUe4_3 = 382,
-    VerUe4_4 = 385,
-    VerUe4_5 = 401,
-    VerUe4_6 = 413,
-    VerUe4_7 = 434,
-    VerUe4_8 = 451,
-    VerUe4_9 = 482,
-    VerUe4_10 = 491,
-    VerUe4_11 = 498,
-    VerUe4_12 = 504,
-    VerUe4_13 = 505,
-    VerUe4_14 = 508,
-    VerUe4_15 = 509,
-    VerUe4_16 = 510,
-    VerUe4_17 = 513,
-    VerUe4_18 = 514,
-    VerUe4_19 = 516,
-    VerUe4_20 = 517,
-    VerUe4_21 = 518,
-    VerUe4_22 = 519,
-    VerUe4_23 = 520,
-    VerUe4_24 = 521,
-    VerUe4_25 = 522,
-    VerUe4_26 = 523,
-    VerUe4_27 = 524,
+    VerUe4_0,
+    VerUe4_1,
+    VerUe4_2,
+    VerUe4_3,
+    VerUe4_4,
+    VerUe4_5,
+    VerUe4_6,
+    VerUe4_7,
+    VerUe4_8,
+    VerUe4_9,
+    VerUe4_10,
+    VerUe4_11,
+    VerUe4_12,
+    VerUe4_13,
+    VerUe4_14,
+    VerUe4_15,
+    VerUe4_16,
+    VerUe4_17,
+    VerUe4_18,
+    VerUe4_19,
+    VerUe4_20,
+    VerUe4_21,
+    VerUe4_22,
+    VerUe4_23,
+    VerUe4_24,
+    VerUe4_25,
+    VerUe4_26,
+    VerUe4_27,
     
-    /// Automatic version plus one (used for version detection)
-    VerUe4AutomaticVersionPlusOne = 525,
+    /// Latest UE4 file version, for packages newer than any UE4 release
+    VerUe4AutomaticVersionPlusOne,
     
     // UE5 versions
-    VerUe5_0 = 1001,
-    VerUe5_1 = 1002,
-    VerUe5_2 = 1003,
-    VerUe5_3 = 1004,
-    VerUe5_4 = 1005,
-    VerUe5_5 = 1006,
+    VerUe5_0,
+    VerUe5_1,
+    VerUe5_2,
+    VerUe5_3,
+    VerUe5_4,
+    VerUe5_5,
 }
 
 impl EngineVersion {
-    /// Create an `EngineVersion` from a raw version number
-    pub fn from_version(version: i32) -> Option<Self> {
-        match version {
-            342 => Some(EngineVersion::VerUe4_0),
-            352 => Some(EngineVersion::VerUe4_1),
-            363 => Some(EngineVersion::VerUe4_2),
-            382 => Some(EngineVersion::VerUe4_3),
-            385 => Some(EngineVersion::VerUe4_4),
-            401 => Some(EngineVersion::VerUe4_5),
-            413 => Some(EngineVersion::VerUe4_6),
-            434 => Some(EngineVersion::VerUe4_7),
-            451 => Some(EngineVersion::VerUe4_8),
-            482 => Some(EngineVersion::VerUe4_9),
-            491 => Some(EngineVersion::VerUe4_10),
-            498 => Some(EngineVersion::VerUe4_11),
-            504 => Some(EngineVersion::VerUe4_12),
-            505 => Some(EngineVersion::VerUe4_13),
-            508 => Some(EngineVersion::VerUe4_14),
-            509 => Some(EngineVersion::VerUe4_15),
-            510 => Some(EngineVersion::VerUe4_16),
-            513 => Some(EngineVersion::VerUe4_17),
-            514 => Some(EngineVersion::VerUe4_18),
-            516 => Some(EngineVersion::VerUe4_19),
-            517 => Some(EngineVersion::VerUe4_20),
-            518 => Some(EngineVersion::VerUe4_21),
-            519 => Some(EngineVersion::VerUe4_22),
-            520 => Some(EngineVersion::VerUe4_23),
-            521 => Some(EngineVersion::VerUe4_24),
-            522 => Some(EngineVersion::VerUe4_25),
-            523 => Some(EngineVersion::VerUe4_26),
-            524 => Some(EngineVersion::VerUe4_27),
-            525 => Some(EngineVersion::VerUe4AutomaticVersionPlusOne),
-            1001 => Some(EngineVersion::VerUe5_0),
-            1002 => Some(EngineVersion::VerUe5_1),
-            1003 => Some(EngineVersion::VerUe5_2),
-            1004 => Some(EngineVersion::VerUe5_3),
-            1005 => Some(EngineVersion::VerUe5_4),
-            1006 => Some(EngineVersion::VerUe5_5),
-            _ => None,
-        }
+    /// Every engine version, oldest first
+    pub const ALL: [EngineVersion; 35] = [
+        EngineVersion::VerUe4_0,
+        EngineVersion::VerUe4_1,
+        EngineVersion::VerUe4_2,
+        EngineVersion::VerUe4_3,
+        EngineVersion::VerUe4_4,
+        EngineVersion::VerUe4_5,
+        EngineVersion::VerUe4_6,
+        EngineVersion::VerUe4_7,
+        EngineVersion::VerUe4_8,
+        EngineVersion::VerUe4_9,
+        EngineVersion::VerUe4_10,
+        EngineVersion::VerUe4_11,
+        EngineVersion::VerUe4_12,
+        EngineVersion::VerUe4_13,
+        EngineVersion::VerUe4_14,
+        EngineVersion::VerUe4_15,
+        EngineVersion::VerUe4_16,
+        EngineVersion::VerUe4_17,
+        EngineVersion::VerUe4_18,
+        EngineVersion::VerUe4_19,
+        EngineVersion::VerUe4_20,
+        EngineVersion::VerUe4_21,
+        EngineVersion::VerUe4_22,
+        EngineVersion::VerUe4_23,
+        EngineVersion::VerUe4_24,
+        EngineVersion::VerUe4_25,
+        EngineVersion::VerUe4_26,
+        EngineVersion::VerUe4_27,
+        EngineVersion::VerUe4AutomaticVersionPlusOne,
+        EngineVersion::VerUe5_0,
+        EngineVersion::VerUe5_1,
+        EngineVersion::VerUe5_2,
+        EngineVersion::VerUe5_3,
+        EngineVersion::VerUe5_4,
+        EngineVersion::VerUe5_5,
+    ];
+
+    /// UE4 file version written by this engine release
+    pub fn object_version(self) -> ObjectVersion {
+        ObjectVersion::new(match self {
+            EngineVersion::VerUe4_0 => 342,
+            EngineVersion::VerUe4_1 => 352,
+            EngineVersion::VerUe4_2 => 363,
+            EngineVersion::VerUe4_3 => 382,
+            EngineVersion::VerUe4_4 => 385,
+            EngineVersion::VerUe4_5 => 401,
+            EngineVersion::VerUe4_6 => 413,
+            EngineVersion::VerUe4_7 => 434,
+            EngineVersion::VerUe4_8 => 451,
+            EngineVersion::VerUe4_9 | EngineVersion::VerUe4_10 => 482,
+            EngineVersion::VerUe4_11 => 498,
+            EngineVersion::VerUe4_12 => 504,
+            EngineVersion::VerUe4_13 => 505,
+            EngineVersion::VerUe4_14 => 508,
+            EngineVersion::VerUe4_15 => 510,
+            EngineVersion::VerUe4_16 | EngineVersion::VerUe4_17 => 513,
+            EngineVersion::VerUe4_18 => 514,
+            EngineVersion::VerUe4_19 | EngineVersion::VerUe4_20 => 516,
+            EngineVersion::VerUe4_21 | EngineVersion::VerUe4_22 | EngineVersion::VerUe4_23 => 517,
+            EngineVersion::VerUe4_24 | EngineVersion::VerUe4_25 => 518,
+            // UE5 keeps writing the last UE4 version
+            _ => 522, // VER_UE4_CORRECT_LICENSEE_FLAG
+        })
     }
     
-    /// Get the raw version number
-    pub fn version(&self) -> i32 {
-        *self as i32
+    /// UE5 file version written by this engine release, 0 before UE5
+    pub fn object_version_ue5(self) -> ObjectVersionUE5 {
+        ObjectVersionUE5::new(match self {
+            EngineVersion::VerUe5_0 => EUnrealEngineObjectUE5Version::LARGE_WORLD_COORDINATES as i32,
+            EngineVersion::VerUe5_1 => EUnrealEngineObjectUE5Version::ADD_SOFTOBJECTPATH_LIST as i32,
+            EngineVersion::VerUe5_2 | EngineVersion::VerUe5_3 => EUnrealEngineObjectUE5Version::DATA_RESOURCES as i32,
+            EngineVersion::VerUe5_4 => EUnrealEngineObjectUE5Version::PROPERTY_TAG_COMPLETE_TYPE_NAME as i32,
+            EngineVersion::VerUe5_5 => EUnrealEngineObjectUE5Version::ASSETREGISTRY_PACKAGEBUILDDEPENDENCIES as i32,
+            _ => 0,
+        })
+    }
+    
+    /// Newest engine release whose file versions don't exceed the given ones
+    pub fn from_object_versions(object_version: i32, object_version_ue5: i32) -> Option<Self> {
+        EngineVersion::ALL.iter().rev().copied().find(|engine| {
+            *engine != EngineVersion::VerUe4AutomaticVersionPlusOne
+                && engine.object_version().get() <= object_version
+                && engine.object_version_ue5().get() <= object_version_ue5
+        })
     }
     
     /// Check if this version is from Unreal Engine 4
     pub fn is_ue4(&self) -> bool {
-        let version = self.version();
-        version >= 342 && version <= 525
+        *self < EngineVersion::VerUe5_0
     }
     
     /// Check if this version is from Unreal Engine 5
     pub fn is_ue5(&self) -> bool {
-        let version = self.version();
-        version >= 1001 && version <= 1006
+        *self >= EngineVersion::VerUe5_0
+    }
+}
+
+impl From<GameVersion> for EngineVersion {
+    fn from(version: GameVersion) -> Self {
+        match version {
+            GameVersion::UE4_0 => EngineVersion::VerUe4_0,
+            GameVersion::UE4_27 => EngineVersion::VerUe4_27,
+            GameVersion::UE5_0 => EngineVersion::VerUe5_0,
+            GameVersion::UE5_1 => EngineVersion::VerUe5_1,
+            GameVersion::UE5_2 => EngineVersion::VerUe5_2,
+            GameVersion::UE5_3 => EngineVersion::VerUe5_3,
+            GameVersion::UE5_4 => EngineVersion::VerUe5_4,
+            GameVersion::UE5_5 => EngineVersion::VerUe5_5,
+        }
     }
 }
 
@@ -193,7 +292,7 @@ impl ObjectVersion {
         self.0
     }
     
-    pub const VER_UE4_STATIC_MESH_STORE_NAV_COLLISION: i32 = 1_000_000;
+    pub const VER_UE4_STATIC_MESH_STORE_NAV_COLLISION: i32 = 216;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
@@ -216,14 +315,29 @@ impl CustomVersion {
         }
     }
     
+    /// Create a `CustomVersion` from a typed custom version
+    pub fn from_typed<T: CustomVersionTrait>(version: T) -> Self {
+        Self::new(T::guid(), version.into(), T::friendly_name().to_string())
+    }
+    
     /// Check if this custom version is compatible with another
     pub fn is_compatible_with(&self, other: &CustomVersion) -> bool {
         self.guid == other.guid && self.version >= other.version
     }
 }
 
-pub trait CustomVersionTrait {
+/// Typed custom version stream
+pub trait CustomVersionTrait: Sized + Copy + Into<i32> {
+    /// GUID identifying the custom version
     fn guid() -> Uuid;
+    /// Friendly name of the custom version
+    fn friendly_name() -> &'static str;
+    /// Latest known version
+    fn latest() -> Self;
+    /// Version shipped with `engine`, `None` if the custom version didn't exist yet
+    fn from_engine_version(engine: EngineVersion) -> Option<Self>;
+    /// Typed version for a serialized value
+    fn from_version(version: i32) -> Option<Self>;
 }
 
 /// Registry for managing game-specific custom versions
@@ -231,6 +345,8 @@ pub trait CustomVersionTrait {
 pub struct CustomVersionRegistry {
     /// Registered custom versions by GUID
     versions: HashMap<Uuid, CustomVersion>,
+    /// Custom versions shipped with each engine release
+    engine_versions: HashMap<EngineVersion, Vec<CustomVersion>>,
     /// Game-specific version mappings
     game_versions: HashMap<String, Vec<CustomVersion>>,
 }
@@ -240,6 +356,7 @@ impl CustomVersionRegistry {
     pub fn new() -> Self {
         let mut registry = Self {
             versions: HashMap::new(),
+            engine_versions: HashMap::new(),
             game_versions: HashMap::new(),
         };
         registry.register_unreal_engine_versions();
@@ -257,6 +374,11 @@ impl CustomVersionRegistry {
         self.versions.get(guid)
     }
     
+    /// Get the custom versions shipped with an engine release
+    pub fn get_engine_versions(&self, engine: EngineVersion) -> Option<&Vec<CustomVersion>> {
+        self.engine_versions.get(&engine)
+    }
+    
     /// Get all custom versions for a specific game
     pub fn get_game_versions(&self, game: &str) -> Option<&Vec<CustomVersion>> {
         self.game_versions.get(game)
@@ -264,15 +386,24 @@ impl CustomVersionRegistry {
     
     /// Register all standard Unreal Engine custom versions
     fn register_unreal_engine_versions(&mut self) {
-        // Example: self.register(CustomVersion::new(guid, version, name));
-        // This would be populated with known UE GUIDs
+        for version in engine_custom_versions(None) {
+            self.register(version);
+        }
+        self.register(CustomVersion::from_typed(FAssetRegistryVersion::LATEST_VERSION));
+
+        for engine in EngineVersion::ALL {
+            if engine != EngineVersion::VerUe4AutomaticVersionPlusOne {
+                self.engine_versions.insert(engine, engine_custom_versions(Some(engine)));
+            }
+        }
     }
     
     /// Register custom versions for known games
     fn register_game_specific_versions(&mut self) {
-        // Example for Fortnite
         let fortnite_versions = vec![
-            // Populate with Fortnite-specific versions
+            CustomVersion::from_typed(FFortniteMainBranchObjectVersion::LATEST_VERSION),
+            CustomVersion::from_typed(FFortniteReleaseBranchCustomObjectVersion::LATEST_VERSION),
+            CustomVersion::from_typed(FFortniteSeasonBranchObjectVersion::LATEST_VERSION),
         ];
         self.game_versions.insert("Fortnite".to_string(), fortnite_versions);
     }
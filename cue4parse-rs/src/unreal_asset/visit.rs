@@ -0,0 +1,343 @@
+//! Property visitors
+//!
+//! [`Visit`] and [`VisitMut`] walk an [`Asset`], [`Export`] or [`Property`] down to every
+//! object reference it holds: package indices in the import and export tables and in
+//! object, delegate and typed reference properties, and soft object paths. Every
+//! container variant is descended into.
+//!
+//! Implementors override the `visit_*` methods they care about. An override that should
+//! keep descending calls the matching `walk_*` function, as the default methods do.
+
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+
+use crate::unreal_asset::asset::Asset;
+use crate::unreal_asset::exports::{Export, Import};
+use crate::unreal_asset::properties::Property;
+use crate::unreal_asset::types::{PackageIndex, SoftObjectPath};
+
+/// Read-only visitor
+pub trait Visit {
+    fn visit_asset<R>(&mut self, asset: &Asset<R>) {
+        walk_asset(self, asset)
+    }
+
+    fn visit_import(&mut self, import: &Import) {
+        walk_import(self, import)
+    }
+
+    fn visit_export(&mut self, export: &Export) {
+        walk_export(self, export)
+    }
+
+    fn visit_property(&mut self, property: &Property) {
+        walk_property(self, property)
+    }
+
+    /// Called for every package index, including null ones in the export table
+    fn visit_package_index(&mut self, _index: &PackageIndex) {}
+
+    fn visit_soft_object_path(&mut self, _path: &SoftObjectPath) {}
+}
+
+/// Mutating visitor
+pub trait VisitMut {
+    fn visit_asset_mut<R>(&mut self, asset: &mut Asset<R>) {
+        walk_asset_mut(self, asset)
+    }
+
+    fn visit_import_mut(&mut self, import: &mut Import) {
+        walk_import_mut(self, import)
+    }
+
+    fn visit_export_mut(&mut self, export: &mut Export) {
+        walk_export_mut(self, export)
+    }
+
+    fn visit_property_mut(&mut self, property: &mut Property) {
+        walk_property_mut(self, property)
+    }
+
+    /// Called for every package index, including null ones in the export table
+    fn visit_package_index_mut(&mut self, _index: &mut PackageIndex) {}
+
+    fn visit_soft_object_path_mut(&mut self, _path: &mut SoftObjectPath) {}
+}
+
+/// Visit the imports, exports and depends map of an asset
+pub fn walk_asset<V: Visit + ?Sized, R>(visitor: &mut V, asset: &Asset<R>) {
+    for import in &asset.asset_data.imports {
+        visitor.visit_import(import);
+    }
+    for export in &asset.asset_data.exports {
+        visitor.visit_export(export);
+    }
+    for index in asset.depends_map.iter().flatten().flatten() {
+        visitor.visit_package_index(&PackageIndex(*index));
+    }
+}
+
+pub fn walk_import<V: Visit + ?Sized>(visitor: &mut V, import: &Import) {
+    visitor.visit_package_index(&import.outer_index);
+}
+
+/// Visit an export's table indices, its dependencies and its properties
+pub fn walk_export<V: Visit + ?Sized>(visitor: &mut V, export: &Export) {
+    for index in [&export.class_index, &export.super_index, &export.template_index, &export.outer_index] {
+        visitor.visit_package_index(index);
+    }
+    for index in &export.create_before_serialization_dependencies {
+        visitor.visit_package_index(index);
+    }
+    for property in export.properties.values() {
+        visitor.visit_property(property);
+    }
+}
+
+pub fn walk_property<V: Visit + ?Sized>(visitor: &mut V, property: &Property) {
+    match property {
+        Property::Object(Some(index))
+        | Property::ObjectProperty(Some(index))
+        | Property::MaterialInterface(Some(index))
+        | Property::StaticMesh(Some(index))
+        | Property::SkeletalMesh(Some(index))
+        | Property::Texture2D(Some(index))
+        | Property::LevelSequence(Some(index))
+        | Property::ComponentReference(Some(index))
+        | Property::Blueprint(Some(index))
+        | Property::WorldContext(Some(index))
+        | Property::LandscapeComponent(Some(index))
+        | Property::Delegate { object: Some(index), .. }
+        | Property::DelegateProperty { object: Some(index), .. } => visitor.visit_package_index(index),
+        Property::SoftObjectPath(path)
+        | Property::SoftClassPath(path)
+        | Property::AssetObjectProperty(path)
+        | Property::SoftAssetPathProperty(path)
+        | Property::SoftObjectPathProperty(path)
+        | Property::SoftClassPathProperty(path) => visitor.visit_soft_object_path(path),
+        Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => {
+            for value in properties.values() {
+                visitor.visit_property(value);
+            }
+        }
+        Property::MaterialInstance(properties) => {
+            for value in properties.values() {
+                visitor.visit_property(value);
+            }
+        }
+        Property::Array(values)
+        | Property::ArrayProperty(values)
+        | Property::Set(values)
+        | Property::SetProperty(values)
+        | Property::MulticastDelegate { delegates: values }
+        | Property::MulticastDelegateProperty { delegates: values }
+        | Property::MulticastSparseDelegateProperty { delegates: values }
+        | Property::MulticastInlineDelegateProperty { delegates: values } => {
+            for value in values {
+                visitor.visit_property(value);
+            }
+        }
+        Property::Map { entries, .. } | Property::MapProperty { entries, .. } => {
+            for (key, value) in entries {
+                visitor.visit_property(key);
+                visitor.visit_property(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+pub fn walk_asset_mut<V: VisitMut + ?Sized, R>(visitor: &mut V, asset: &mut Asset<R>) {
+    for import in &mut asset.asset_data.imports {
+        visitor.visit_import_mut(import);
+    }
+    for export in &mut asset.asset_data.exports {
+        visitor.visit_export_mut(export);
+    }
+    for index in asset.depends_map.iter_mut().flatten().flatten() {
+        let mut package_index = PackageIndex(*index);
+        visitor.visit_package_index_mut(&mut package_index);
+        *index = package_index.0;
+    }
+}
+
+pub fn walk_import_mut<V: VisitMut + ?Sized>(visitor: &mut V, import: &mut Import) {
+    visitor.visit_package_index_mut(&mut import.outer_index);
+}
+
+pub fn walk_export_mut<V: VisitMut + ?Sized>(visitor: &mut V, export: &mut Export) {
+    for index in [
+        &mut export.class_index,
+        &mut export.super_index,
+        &mut export.template_index,
+        &mut export.outer_index,
+    ] {
+        visitor.visit_package_index_mut(index);
+    }
+    for index in &mut export.create_before_serialization_dependencies {
+        visitor.visit_package_index_mut(index);
+    }
+    for property in export.properties.values_mut() {
+        visitor.visit_property_mut(property);
+    }
+}
+
+pub fn walk_property_mut<V: VisitMut + ?Sized>(visitor: &mut V, property: &mut Property) {
+    match property {
+        Property::Object(Some(index))
+        | Property::ObjectProperty(Some(index))
+        | Property::MaterialInterface(Some(index))
+        | Property::StaticMesh(Some(index))
+        | Property::SkeletalMesh(Some(index))
+        | Property::Texture2D(Some(index))
+        | Property::LevelSequence(Some(index))
+        | Property::ComponentReference(Some(index))
+        | Property::Blueprint(Some(index))
+        | Property::WorldContext(Some(index))
+        | Property::LandscapeComponent(Some(index))
+        | Property::Delegate { object: Some(index), .. }
+        | Property::DelegateProperty { object: Some(index), .. } => visitor.visit_package_index_mut(index),
+        Property::SoftObjectPath(path)
+        | Property::SoftClassPath(path)
+        | Property::AssetObjectProperty(path)
+        | Property::SoftAssetPathProperty(path)
+        | Property::SoftObjectPathProperty(path)
+        | Property::SoftClassPathProperty(path) => visitor.visit_soft_object_path_mut(path),
+        Property::Struct { properties, .. } | Property::StructProperty { properties, .. } => {
+            for value in properties.values_mut() {
+                visitor.visit_property_mut(value);
+            }
+        }
+        Property::MaterialInstance(properties) => {
+            for value in properties.values_mut() {
+                visitor.visit_property_mut(value);
+            }
+        }
+        Property::Array(values)
+        | Property::ArrayProperty(values)
+        | Property::Set(values)
+        | Property::SetProperty(values)
+        | Property::MulticastDelegate { delegates: values }
+        | Property::MulticastDelegateProperty { delegates: values }
+        | Property::MulticastSparseDelegateProperty { delegates: values }
+        | Property::MulticastInlineDelegateProperty { delegates: values } => {
+            for value in values {
+                visitor.visit_property_mut(value);
+            }
+        }
+        Property::Map { entries, .. } | Property::MapProperty { entries, .. } => {
+            for (key, value) in entries {
+                visitor.visit_property_mut(key);
+                visitor.visit_property_mut(value);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Reference found by [`Visitable::collect_references`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum PropertyReference {
+    /// Import or export of the same package
+    Package(PackageIndex),
+    /// Soft reference by path
+    Soft(SoftObjectPath),
+}
+
+/// Values a [`Visit`] or [`VisitMut`] can walk
+pub trait Visitable {
+    fn accept<V: Visit>(&self, visitor: &mut V);
+
+    fn accept_mut<V: VisitMut>(&mut self, visitor: &mut V);
+
+    /// Every non-null package index and soft object path, in visiting order
+    fn collect_references(&self) -> Vec<PropertyReference> {
+        let mut collector = ReferenceCollector::default();
+        self.accept(&mut collector);
+        collector.references
+    }
+
+    /// Replace every non-null package index with `remap(index)`, returning how many changed
+    fn remap_package_indices(&mut self, remap: impl FnMut(PackageIndex) -> PackageIndex) -> usize {
+        let mut remapper = PackageIndexRemapper { remap, changed: 0 };
+        self.accept_mut(&mut remapper);
+        remapper.changed
+    }
+}
+
+impl Visitable for Property {
+    fn accept<V: Visit>(&self, visitor: &mut V) {
+        visitor.visit_property(self)
+    }
+
+    fn accept_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+        visitor.visit_property_mut(self)
+    }
+}
+
+impl Visitable for IndexMap<String, Property> {
+    fn accept<V: Visit>(&self, visitor: &mut V) {
+        self.values().for_each(|property| visitor.visit_property(property))
+    }
+
+    fn accept_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+        self.values_mut().for_each(|property| visitor.visit_property_mut(property))
+    }
+}
+
+impl Visitable for Export {
+    fn accept<V: Visit>(&self, visitor: &mut V) {
+        visitor.visit_export(self)
+    }
+
+    fn accept_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+        visitor.visit_export_mut(self)
+    }
+}
+
+impl<R> Visitable for Asset<R> {
+    fn accept<V: Visit>(&self, visitor: &mut V) {
+        visitor.visit_asset(self)
+    }
+
+    fn accept_mut<V: VisitMut>(&mut self, visitor: &mut V) {
+        visitor.visit_asset_mut(self)
+    }
+}
+
+#[derive(Default)]
+struct ReferenceCollector {
+    references: Vec<PropertyReference>,
+}
+
+impl Visit for ReferenceCollector {
+    fn visit_package_index(&mut self, index: &PackageIndex) {
+        if !index.is_null() {
+            self.references.push(PropertyReference::Package(*index));
+        }
+    }
+
+    fn visit_soft_object_path(&mut self, path: &SoftObjectPath) {
+        if !path.is_null() {
+            self.references.push(PropertyReference::Soft(path.clone()));
+        }
+    }
+}
+
+struct PackageIndexRemapper<F> {
+    remap: F,
+    changed: usize,
+}
+
+impl<F: FnMut(PackageIndex) -> PackageIndex> VisitMut for PackageIndexRemapper<F> {
+    fn visit_package_index_mut(&mut self, index: &mut PackageIndex) {
+        if index.is_null() {
+            return;
+        }
+        let remapped = (self.remap)(*index);
+        if remapped != *index {
+            *index = remapped;
+            self.changed += 1;
+        }
+    }
+}
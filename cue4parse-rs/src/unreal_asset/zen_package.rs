@@ -0,0 +1,692 @@
+//! Zen (IoStore) package header reading
+//!
+//! IoStore packages replace `FPackageFileSummary` with `FZenPackageSummary`, a name
+//! batch and 64-bit `FPackageObjectIndex` references. Script imports resolve through
+//! the `ScriptObjects` chunk of the global container, see [`IoGlobalData`].
+
+use std::collections::HashMap;
+use std::io::{Read, Seek, SeekFrom};
+
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+
+use crate::unreal_asset::error::{UnrealAssetError, UnrealAssetResult};
+use crate::unreal_asset::exports::{Export, Import};
+use crate::unreal_asset::name_map::NameMap;
+use crate::unreal_asset::object_versions::EUnrealEngineObjectUE5Version;
+use crate::unreal_asset::package_summary::{read_custom_versions, ExportFlags, PKG_UNVERSIONED_PROPERTIES};
+use crate::unreal_asset::reader::{ArchiveReader, ArchiveTrait, BinaryArchive};
+use crate::unreal_asset::types::{FName, PackageIndex};
+use crate::unreal_asset::versions::{CustomVersion, EngineVersion};
+
+/// Kind of object referenced by a [`PackageObjectIndex`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PackageObjectIndexType {
+    Export,
+    ScriptImport,
+    PackageImport,
+    Null,
+}
+
+/// Reference to an object from a Zen package, `FPackageObjectIndex`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PackageObjectIndex(pub u64);
+
+impl PackageObjectIndex {
+    const INDEX_BITS: u32 = 62;
+    const INDEX_MASK: u64 = (1 << Self::INDEX_BITS) - 1;
+
+    /// Null reference
+    pub const NULL: Self = Self(u64::MAX);
+
+    /// Kind of the referenced object
+    pub fn kind(&self) -> PackageObjectIndexType {
+        match self.0 >> Self::INDEX_BITS {
+            0 => PackageObjectIndexType::Export,
+            1 => PackageObjectIndexType::ScriptImport,
+            2 => PackageObjectIndexType::PackageImport,
+            _ => PackageObjectIndexType::Null,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.0 == u64::MAX
+    }
+
+    pub fn is_export(&self) -> bool {
+        self.kind() == PackageObjectIndexType::Export
+    }
+
+    pub fn is_script_import(&self) -> bool {
+        self.kind() == PackageObjectIndexType::ScriptImport
+    }
+
+    pub fn is_package_import(&self) -> bool {
+        self.kind() == PackageObjectIndexType::PackageImport
+    }
+
+    /// Index into the export map
+    pub fn as_export(&self) -> u32 {
+        self.0 as u32
+    }
+
+    /// Index into the imported packages and the imported public export hashes
+    pub fn as_package_import(&self) -> (u32, u32) {
+        (((self.0 & Self::INDEX_MASK) >> 32) as u32, self.0 as u32)
+    }
+
+    fn read<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        Ok(Self(reader.read_u64::<LittleEndian>()?))
+    }
+}
+
+/// Name stored as a name map index, `FMappedName`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct MappedName {
+    index: u32,
+    /// FName number
+    pub number: u32,
+}
+
+impl MappedName {
+    const INDEX_BITS: u32 = 30;
+    const INDEX_MASK: u32 = (1 << Self::INDEX_BITS) - 1;
+
+    pub fn new(name_index: u32, number: u32, global: bool) -> Self {
+        let kind = if global { 2 << Self::INDEX_BITS } else { 0 };
+        Self {
+            index: (name_index & Self::INDEX_MASK) | kind,
+            number,
+        }
+    }
+
+    /// Index into the package or global name map
+    pub fn name_index(&self) -> u32 {
+        self.index & Self::INDEX_MASK
+    }
+
+    /// Whether the name lives in the global name map
+    pub fn is_global(&self) -> bool {
+        self.index >> Self::INDEX_BITS != 0
+    }
+
+    fn read<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        Ok(Self {
+            index: reader.read_u32::<LittleEndian>()?,
+            number: reader.read_u32::<LittleEndian>()?,
+        })
+    }
+}
+
+/// Entry of the global script object table, `FScriptObjectEntry`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScriptObjectEntry {
+    pub object_name: MappedName,
+    pub global_index: PackageObjectIndex,
+    pub outer_index: PackageObjectIndex,
+    pub cdo_class_index: PackageObjectIndex,
+}
+
+/// Global name map and script objects of an IoStore installation
+#[derive(Debug, Clone, Default)]
+pub struct IoGlobalData {
+    pub global_name_map: NameMap,
+    pub script_objects: HashMap<PackageObjectIndex, ScriptObjectEntry>,
+}
+
+impl IoGlobalData {
+    /// Read the `ScriptObjects` chunk of a UE5 global container
+    pub fn read<R: Read>(reader: &mut R) -> UnrealAssetResult<Self> {
+        let global_name_map = NameMap::read_name_batch(reader)?;
+        let count = reader.read_i32::<LittleEndian>()?;
+        let mut script_objects = HashMap::new();
+        for _ in 0..count {
+            let entry = ScriptObjectEntry {
+                object_name: MappedName::read(reader)?,
+                global_index: PackageObjectIndex::read(reader)?,
+                outer_index: PackageObjectIndex::read(reader)?,
+                cdo_class_index: PackageObjectIndex::read(reader)?,
+            };
+            script_objects.insert(entry.global_index, entry);
+        }
+        Ok(Self {
+            global_name_map,
+            script_objects,
+        })
+    }
+}
+
+/// Zen package summary, `FZenPackageSummary`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZenPackageSummary {
+    pub has_versioning_info: bool,
+    /// Size of the header, exports follow it
+    pub header_size: u32,
+    pub name: MappedName,
+    pub package_flags: u32,
+    pub cooked_header_size: u32,
+    pub imported_public_export_hashes_offset: i32,
+    pub import_map_offset: i32,
+    pub export_map_offset: i32,
+    pub export_bundle_entries_offset: i32,
+    /// Offset of the export bundle headers and graph data, before UE5.3
+    pub graph_data_offset: i32,
+    pub dependency_bundle_headers_offset: i32,
+    pub dependency_bundle_entries_offset: i32,
+    pub imported_package_names_offset: i32,
+}
+
+impl ZenPackageSummary {
+    /// Read an `FZenPackageSummary`
+    pub fn read<R: Read>(reader: &mut R, engine_version: EngineVersion) -> UnrealAssetResult<Self> {
+        let mut summary = Self {
+            has_versioning_info: reader.read_u32::<LittleEndian>()? != 0,
+            header_size: reader.read_u32::<LittleEndian>()?,
+            name: MappedName::read(reader)?,
+            package_flags: reader.read_u32::<LittleEndian>()?,
+            cooked_header_size: reader.read_u32::<LittleEndian>()?,
+            imported_public_export_hashes_offset: reader.read_i32::<LittleEndian>()?,
+            import_map_offset: reader.read_i32::<LittleEndian>()?,
+            export_map_offset: reader.read_i32::<LittleEndian>()?,
+            export_bundle_entries_offset: reader.read_i32::<LittleEndian>()?,
+            ..Self::default()
+        };
+        if engine_version >= EngineVersion::VerUe5_3 {
+            summary.dependency_bundle_headers_offset = reader.read_i32::<LittleEndian>()?;
+            summary.dependency_bundle_entries_offset = reader.read_i32::<LittleEndian>()?;
+            summary.imported_package_names_offset = reader.read_i32::<LittleEndian>()?;
+        } else {
+            summary.graph_data_offset = reader.read_i32::<LittleEndian>()?;
+        }
+        Ok(summary)
+    }
+}
+
+/// Versions of a Zen package saved with versioning info, `FZenPackageVersioningInfo`
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZenPackageVersioningInfo {
+    /// `EZenPackageVersion`
+    pub zen_version: u32,
+    pub file_version_ue4: i32,
+    pub file_version_ue5: i32,
+    pub file_licensee_version: i32,
+    pub custom_versions: Vec<CustomVersion>,
+}
+
+/// Entry of a Zen export map, `FExportMapEntry`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ZenExportMapEntry {
+    pub cooked_serial_offset: u64,
+    pub cooked_serial_size: u64,
+    pub object_name: MappedName,
+    pub outer_index: PackageObjectIndex,
+    pub class_index: PackageObjectIndex,
+    pub super_index: PackageObjectIndex,
+    pub template_index: PackageObjectIndex,
+    pub public_export_hash: u64,
+    pub object_flags: u32,
+    /// `EExportFilterFlags`
+    pub filter_flags: u8,
+}
+
+impl ZenExportMapEntry {
+    /// Serialized size of an entry
+    pub const SIZE: u64 = 72;
+
+    fn read<R: Read + Seek>(reader: &mut R) -> UnrealAssetResult<Self> {
+        let start = reader.stream_position()?;
+        let entry = Self {
+            cooked_serial_offset: reader.read_u64::<LittleEndian>()?,
+            cooked_serial_size: reader.read_u64::<LittleEndian>()?,
+            object_name: MappedName::read(reader)?,
+            outer_index: PackageObjectIndex::read(reader)?,
+            class_index: PackageObjectIndex::read(reader)?,
+            super_index: PackageObjectIndex::read(reader)?,
+            template_index: PackageObjectIndex::read(reader)?,
+            public_export_hash: reader.read_u64::<LittleEndian>()?,
+            object_flags: reader.read_u32::<LittleEndian>()?,
+            filter_flags: reader.read_u8()?,
+        };
+        reader.seek(SeekFrom::Start(start + Self::SIZE))?;
+        Ok(entry)
+    }
+}
+
+/// Export bundle command, `EExportCommandType`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ExportCommandType {
+    Create,
+    Serialize,
+}
+
+/// Entry of an export bundle, `FExportBundleEntry`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportBundleEntry {
+    pub local_export_index: u32,
+    pub command_type: ExportCommandType,
+}
+
+/// Export bundle of packages saved before UE5.3, `FExportBundleHeader`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportBundleHeader {
+    pub serial_offset: u64,
+    pub first_entry_index: u32,
+    pub entry_count: u32,
+}
+
+/// Dependencies of one export, `FDependencyBundleHeader`
+///
+/// Entries are stored create-before-create, serialize-before-create,
+/// create-before-serialize and serialize-before-serialize.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DependencyBundleHeader {
+    pub first_entry_index: i32,
+    pub create_before_create: u32,
+    pub serialize_before_create: u32,
+    pub create_before_serialize: u32,
+    pub serialize_before_serialize: u32,
+}
+
+/// Parsed header of a Zen package
+#[derive(Debug, Clone)]
+pub struct ZenPackageHeader {
+    pub summary: ZenPackageSummary,
+    pub versioning_info: Option<ZenPackageVersioningInfo>,
+    pub name_map: NameMap,
+    /// Name of the package, e.g. "/Game/Maps/Arena"
+    pub package_name: FName,
+    pub imported_public_export_hashes: Vec<u64>,
+    pub import_map: Vec<PackageObjectIndex>,
+    pub export_map: Vec<ZenExportMapEntry>,
+    pub export_bundle_entries: Vec<ExportBundleEntry>,
+    /// Export bundles, before UE5.3
+    pub export_bundle_headers: Vec<ExportBundleHeader>,
+    /// Dependency bundles, one per export since UE5.3
+    pub dependency_bundle_headers: Vec<DependencyBundleHeader>,
+    pub dependency_bundle_entries: Vec<PackageIndex>,
+    /// Names of the imported packages, since UE5.3
+    pub imported_package_names: Vec<FName>,
+    /// Import table in the legacy representation
+    ///
+    /// The first `import_map.len()` entries match the import map; outers of
+    /// script imports follow. Package imports are named after their public
+    /// export hash, as the real name lives in the imported package.
+    pub imports: Vec<Import>,
+    /// Export table in the legacy representation, serial offsets relative to the package start
+    pub exports: Vec<Export>,
+}
+
+impl ZenPackageHeader {
+    /// Read the header of an IoStore package
+    ///
+    /// `engine_version` selects the header layout and supplies the versions of unversioned packages.
+    pub fn read<R: Read + Seek>(
+        reader: R,
+        engine_version: EngineVersion,
+        global_data: &IoGlobalData,
+    ) -> UnrealAssetResult<Self> {
+        Self::from_archive(&mut BinaryArchive::new(reader, engine_version), global_data)
+    }
+
+    /// Read the header of an IoStore package from the start of an archive
+    ///
+    /// Errors carry the archive name, offset and the table entry being read; use
+    /// [`UnrealAssetError::root`] to match on the underlying error.
+    pub fn from_archive<R: Read + Seek>(ar: &mut BinaryArchive<R>, global_data: &IoGlobalData) -> UnrealAssetResult<Self> {
+        ar.set_error_context(None);
+        Self::read_tables(ar, global_data).map_err(|error| {
+            ar.record_error_context(&error);
+            match ar.error_context() {
+                Some(context) => error.with_context(context.clone()),
+                None => error,
+            }
+        })
+    }
+
+    fn read_tables<R: Read + Seek>(ar: &mut BinaryArchive<R>, global_data: &IoGlobalData) -> UnrealAssetResult<Self> {
+        let engine_version = ar.get_engine_version();
+        if engine_version < EngineVersion::VerUe5_0 {
+            return Err(UnrealAssetError::UnsupportedVersion(format!(
+                "Zen package summaries are not used by {:?}",
+                engine_version
+            )));
+        }
+        ar.seek(SeekFrom::Start(0))?;
+        let summary = ar.read_in("Summary", |ar| ZenPackageSummary::read(ar, engine_version))?;
+
+        let mut versioning_info = None;
+        if summary.has_versioning_info {
+            let zen_version = ar.read_u32::<LittleEndian>()?;
+            let file_version_ue4 = ar.read_i32::<LittleEndian>()?;
+            let file_version_ue5 = ar.read_i32::<LittleEndian>()?;
+            let file_licensee_version = ar.read_i32::<LittleEndian>()?;
+            let custom_versions = ar.read_in("CustomVersions", |ar| read_custom_versions(ar, -8))?;
+            versioning_info = Some(ZenPackageVersioningInfo {
+                zen_version,
+                file_version_ue4,
+                file_version_ue5,
+                file_licensee_version,
+                custom_versions,
+            });
+        }
+        let ue5 = match &versioning_info {
+            Some(info) => info.file_version_ue5,
+            None => ar.get_object_version_ue5().get(),
+        };
+
+        let mut cell_import_map_offset = summary.export_bundle_entries_offset;
+        if ue5 >= EUnrealEngineObjectUE5Version::VERSE_CELLS as i32 {
+            cell_import_map_offset = ar.read_i32::<LittleEndian>()?;
+            ar.read_i32::<LittleEndian>()?; // CellExportMapOffset
+        }
+
+        let name_map = ar.read_in("NameMap", NameMap::read_name_batch)?;
+        let resolve_name = |name: MappedName| -> UnrealAssetResult<FName> {
+            let names = match name.is_global() {
+                true => &global_data.global_name_map,
+                false => &name_map,
+            };
+            names
+                .get_name_reference(name.name_index() as i32)
+                .map(|n| FName::with_number(n, name.number))
+                .ok_or_else(|| UnrealAssetError::InvalidIndex(format!("Name index {} out of range", name.name_index())))
+        };
+        let package_name = resolve_name(summary.name)?;
+
+        let imported_public_export_hashes_count =
+            summary.import_map_offset.saturating_sub(summary.imported_public_export_hashes_offset).max(0) / 8;
+        ar.seek(SeekFrom::Start(summary.imported_public_export_hashes_offset as u64))?;
+        let imported_public_export_hashes = ar.read_in("ImportedPublicExportHashes", |ar| {
+            ar.read_array_with_length(imported_public_export_hashes_count, |ar| Ok(ar.read_u64::<LittleEndian>()?))
+        })?;
+
+        let import_count = summary.export_map_offset.saturating_sub(summary.import_map_offset).max(0) / 8;
+        ar.seek(SeekFrom::Start(summary.import_map_offset as u64))?;
+        let import_map = ar.read_in("ImportMap", |ar| {
+            ar.read_array_with_length(import_count, |ar| Ok(PackageObjectIndex::read(ar)?))
+        })?;
+
+        let export_count = cell_import_map_offset.saturating_sub(summary.export_map_offset).max(0) / ZenExportMapEntry::SIZE as i32;
+        ar.seek(SeekFrom::Start(summary.export_map_offset as u64))?;
+        let export_map = ar.read_in("ExportMap", |ar| ar.read_array_with_length(export_count, ZenExportMapEntry::read))?;
+
+        ar.seek(SeekFrom::Start(summary.export_bundle_entries_offset as u64))?;
+        let export_bundle_entries = ar.read_in("ExportBundleEntries", |ar| {
+            ar.read_array_with_length(export_count * 2, |ar| {
+                let local_export_index = ar.read_u32::<LittleEndian>()?;
+                let command_type = match ar.read_u32::<LittleEndian>()? {
+                    0 => ExportCommandType::Create,
+                    1 => ExportCommandType::Serialize,
+                    other => {
+                        return Err(UnrealAssetError::InvalidData(format!("Invalid export command type {other}")));
+                    }
+                };
+                Ok(ExportBundleEntry {
+                    local_export_index,
+                    command_type,
+                })
+            })
+        })?;
+
+        let mut export_bundle_headers = Vec::new();
+        let mut dependency_bundle_headers = Vec::new();
+        let mut dependency_bundle_entries = Vec::new();
+        let mut imported_package_names = Vec::new();
+        if engine_version >= EngineVersion::VerUe5_3 {
+            ar.seek(SeekFrom::Start(summary.dependency_bundle_headers_offset as u64))?;
+            dependency_bundle_headers = ar.read_in("DependencyBundleHeaders", |ar| {
+                ar.read_array_with_length(export_count, |ar| {
+                    Ok(DependencyBundleHeader {
+                        first_entry_index: ar.read_i32::<LittleEndian>()?,
+                        create_before_create: ar.read_u32::<LittleEndian>()?,
+                        serialize_before_create: ar.read_u32::<LittleEndian>()?,
+                        create_before_serialize: ar.read_u32::<LittleEndian>()?,
+                        serialize_before_serialize: ar.read_u32::<LittleEndian>()?,
+                    })
+                })
+            })?;
+
+            let entry_count =
+                summary.imported_package_names_offset.saturating_sub(summary.dependency_bundle_entries_offset).max(0) / 4;
+            ar.seek(SeekFrom::Start(summary.dependency_bundle_entries_offset as u64))?;
+            dependency_bundle_entries = ar.read_in("DependencyBundleEntries", |ar| {
+                ar.read_array_with_length(entry_count, |ar| {
+                    Ok(PackageIndex::new(ar.read_i32::<LittleEndian>()?))
+                })
+            })?;
+
+            if (summary.imported_package_names_offset as u32) < summary.header_size {
+                ar.seek(SeekFrom::Start(summary.imported_package_names_offset as u64))?;
+                imported_package_names = ar.read_in("ImportedPackageNames", |ar| {
+                    let names = NameMap::read_name_batch(ar)?;
+                    let mut package_names = Vec::with_capacity(names.len());
+                    for name in names.get_name_map_index_list() {
+                        package_names.push(FName::with_number(name.as_str(), ar.read_i32::<LittleEndian>()? as u32));
+                    }
+                    Ok(package_names)
+                })?;
+            }
+        } else {
+            // Bundles are read until they cover every bundle entry
+            ar.seek(SeekFrom::Start(summary.graph_data_offset as u64))?;
+            let mut covered = 0;
+            while covered < export_bundle_entries.len() {
+                let header = ExportBundleHeader {
+                    serial_offset: ar.read_u64::<LittleEndian>()?,
+                    first_entry_index: ar.read_u32::<LittleEndian>()?,
+                    entry_count: ar.read_u32::<LittleEndian>()?,
+                };
+                if header.entry_count == 0 {
+                    return Err(UnrealAssetError::InvalidData("Empty export bundle".to_string()));
+                }
+                covered += header.entry_count as usize;
+                export_bundle_headers.push(header);
+            }
+        }
+
+        let mut header = Self {
+            summary,
+            versioning_info,
+            name_map: name_map.clone(),
+            package_name,
+            imported_public_export_hashes,
+            import_map,
+            export_map,
+            export_bundle_entries,
+            export_bundle_headers,
+            dependency_bundle_headers,
+            dependency_bundle_entries,
+            imported_package_names,
+            imports: Vec::new(),
+            exports: Vec::new(),
+        };
+        header.build_tables(global_data, &resolve_name)?;
+        Ok(header)
+    }
+
+    /// Whether properties of the package are serialized without tags
+    pub fn has_unversioned_properties(&self) -> bool {
+        self.summary.package_flags & PKG_UNVERSIONED_PROPERTIES != 0
+    }
+
+    /// Convert the import and export maps to the legacy `Import`/`Export` tables
+    fn build_tables(
+        &mut self,
+        global_data: &IoGlobalData,
+        resolve_name: &impl Fn(MappedName) -> UnrealAssetResult<FName>,
+    ) -> UnrealAssetResult<()> {
+        let mut builder = ImportBuilder {
+            global_data,
+            resolve_name,
+            imported_package_names: &self.imported_package_names,
+            imported_public_export_hashes: &self.imported_public_export_hashes,
+            imports: Vec::new(),
+            indices: HashMap::new(),
+        };
+        // Reserve the import map's slots first so its indices stay stable
+        for (i, index) in self.import_map.iter().enumerate() {
+            if !index.is_null() {
+                builder.indices.entry(*index).or_insert(PackageIndex::from_import(i));
+            }
+        }
+        builder.imports.resize_with(self.import_map.len(), || Import {
+            class_package: FName::new("/Script/CoreUObject"),
+            class_name: FName::new("Object"),
+            outer_index: PackageIndex::null(),
+            object_name: FName::new("None"),
+            package_guid: None,
+            package_name: FName::default(),
+        });
+        for (i, index) in self.import_map.iter().enumerate() {
+            if !index.is_null() {
+                builder.imports[i] = builder.build_import(*index)?;
+            }
+        }
+
+        let mut exports = Vec::with_capacity(self.export_map.len());
+        for entry in &self.export_map {
+            let mut export = Export::new(resolve_name(entry.object_name)?, builder.resolve(entry.class_index)?);
+            export.super_index = builder.resolve(entry.super_index)?;
+            export.template_index = builder.resolve(entry.template_index)?;
+            export.outer_index = builder.resolve(entry.outer_index)?;
+            export.object_flags = entry.object_flags;
+            export.serial_size = entry.cooked_serial_size;
+            export.serial_offset = (self.summary.header_size as u64).saturating_add(entry.cooked_serial_offset);
+            let mut flags = ExportFlags::empty();
+            flags.set(ExportFlags::NOT_FOR_CLIENT, entry.filter_flags & 1 != 0);
+            flags.set(ExportFlags::NOT_FOR_SERVER, entry.filter_flags & 2 != 0);
+            export.export_flags = flags.bits();
+            exports.push(export);
+        }
+
+        // Before UE5.3 exports are laid out back to back in bundle order
+        if !self.export_bundle_headers.is_empty() {
+            let mut offset = self.summary.header_size as u64;
+            for bundle in &self.export_bundle_headers {
+                let start = bundle.first_entry_index as usize;
+                for entry in self.export_bundle_entries.iter().skip(start).take(bundle.entry_count as usize) {
+                    if entry.command_type != ExportCommandType::Serialize {
+                        continue;
+                    }
+                    if let Some(export) = exports.get_mut(entry.local_export_index as usize) {
+                        export.serial_offset = offset;
+                        offset = offset.saturating_add(export.serial_size);
+                    }
+                }
+            }
+        }
+
+        for (export, bundle) in exports.iter_mut().zip(&self.dependency_bundle_headers) {
+            let start = bundle.first_entry_index.max(0) as usize
+                + bundle.create_before_create as usize
+                + bundle.serialize_before_create as usize;
+            let end = start + bundle.create_before_serialize as usize;
+            if let Some(range) = self.dependency_bundle_entries.get(start..end) {
+                export.create_before_serialization_dependencies = range.to_vec();
+            }
+        }
+
+        self.imports = builder.imports;
+        self.exports = exports;
+        Ok(())
+    }
+}
+
+/// Builds legacy imports for Zen object references
+struct ImportBuilder<'a, F: Fn(MappedName) -> UnrealAssetResult<FName>> {
+    global_data: &'a IoGlobalData,
+    resolve_name: &'a F,
+    imported_package_names: &'a [FName],
+    imported_public_export_hashes: &'a [u64],
+    imports: Vec<Import>,
+    indices: HashMap<PackageObjectIndex, PackageIndex>,
+}
+
+impl<F: Fn(MappedName) -> UnrealAssetResult<FName>> ImportBuilder<'_, F> {
+    /// Legacy index of a Zen reference, adding an import if needed
+    fn resolve(&mut self, index: PackageObjectIndex) -> UnrealAssetResult<PackageIndex> {
+        if index.is_null() {
+            return Ok(PackageIndex::null());
+        }
+        if index.is_export() {
+            return Ok(PackageIndex::from_export(index.as_export() as usize));
+        }
+        if let Some(resolved) = self.indices.get(&index) {
+            return Ok(*resolved);
+        }
+        let import = self.build_import(index)?;
+        Ok(self.push(index, import))
+    }
+
+    fn push(&mut self, index: PackageObjectIndex, import: Import) -> PackageIndex {
+        let resolved = PackageIndex::from_import(self.imports.len());
+        self.imports.push(import);
+        self.indices.insert(index, resolved);
+        resolved
+    }
+
+    /// Import for a script or package import
+    fn build_import(&mut self, index: PackageObjectIndex) -> UnrealAssetResult<Import> {
+        match index.kind() {
+            PackageObjectIndexType::ScriptImport => {
+                let entry = *self.global_data.script_objects.get(&index).ok_or_else(|| {
+                    UnrealAssetError::InvalidIndex(format!("Missing script import 0x{:X}", index.0))
+                })?;
+                let outer_index = self.resolve(entry.outer_index)?;
+                // Script structs are reported as classes, script objects don't distinguish them
+                let class_name = match entry.outer_index.is_null() {
+                    true => "Package",
+                    false => "Class",
+                };
+                Ok(Import {
+                    class_package: FName::new("/Script/CoreUObject"),
+                    class_name: FName::new(class_name),
+                    outer_index,
+                    object_name: (self.resolve_name)(entry.object_name)?,
+                    package_guid: None,
+                    package_name: FName::default(),
+                })
+            }
+            PackageObjectIndexType::PackageImport => {
+                let (package_index, hash_index) = index.as_package_import();
+                let hash = self.imported_public_export_hashes.get(hash_index as usize).ok_or_else(|| {
+                    UnrealAssetError::InvalidIndex(format!("Public export hash index {hash_index} out of range"))
+                })?;
+                let package_name = self.imported_package_names.get(package_index as usize).cloned();
+                let outer_index = match &package_name {
+                    Some(name) => self.package_import(name),
+                    None => PackageIndex::null(),
+                };
+                Ok(Import {
+                    class_package: FName::new("/Script/CoreUObject"),
+                    class_name: FName::new("Object"),
+                    outer_index,
+                    object_name: FName::new(format!("{hash:016X}")),
+                    package_guid: None,
+                    package_name: package_name.unwrap_or_default(),
+                })
+            }
+            _ => Err(UnrealAssetError::InvalidIndex(format!("0x{:X} is not an import", index.0))),
+        }
+    }
+
+    /// Import of an imported package, shared by every import from it
+    fn package_import(&mut self, name: &FName) -> PackageIndex {
+        let existing = self.imports.iter().position(|import| {
+            import.class_name.as_str() == "Package" && import.outer_index.is_null() && &import.object_name == name
+        });
+        if let Some(i) = existing {
+            return PackageIndex::from_import(i);
+        }
+        let resolved = PackageIndex::from_import(self.imports.len());
+        self.imports.push(Import {
+            class_package: FName::new("/Script/CoreUObject"),
+            class_name: FName::new("Package"),
+            outer_index: PackageIndex::null(),
+            object_name: name.clone(),
+            package_guid: None,
+            package_name: FName::default(),
+        });
+        resolved
+    }
+}
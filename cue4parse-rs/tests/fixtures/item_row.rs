@@ -0,0 +1,29 @@
+// @generated from usmap mappings by cue4parse-rs, do not edit
+
+/// `ERarity`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, ::serde::Serialize, ::serde::Deserialize)]
+pub enum ERarity {
+    #[default]
+    Common,
+    Epic,
+}
+
+/// `ItemRow`, child of `TableRowBase`
+#[derive(Debug, Clone, Default, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+#[serde(default)]
+pub struct ItemRow {
+    #[serde(rename = "bIsStackable")]
+    pub is_stackable: bool,
+    #[serde(rename = "MaxHP")]
+    pub max_hp: i32,
+    #[serde(rename = "Rarity")]
+    pub rarity: ERarity,
+    #[serde(rename = "Offset")]
+    pub offset: cue4parse_rs::unreal_asset::Vector,
+    #[serde(rename = "type")]
+    pub r#type: Vec<String>,
+}
+
+impl cue4parse_rs::unreal_asset::SchemaStruct for ItemRow {
+    const SCHEMA: &'static str = "ItemRow";
+}
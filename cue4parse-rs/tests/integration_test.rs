@@ -158,16 +158,12 @@ mod compatibility_tests {
         }
         
         // Text property
-        let text_prop = Property::Text {
-            text: "Hello World".to_string(),
-            namespace: Some("Game".to_string()),
-            key: Some("Greeting".to_string()),
-        };
+        let text_prop = Property::Text(FText::base("Game", "Greeting", "Hello World"));
         
-        if let Property::Text { text, namespace, key } = &text_prop {
-            assert_eq!(text, "Hello World");
-            assert_eq!(namespace.as_ref().unwrap(), "Game");
-            assert_eq!(key.as_ref().unwrap(), "Greeting");
+        if let Property::Text(text) = &text_prop {
+            assert_eq!(text.source_string(), Some("Hello World"));
+            assert_eq!(text.namespace(), Some("Game"));
+            assert_eq!(text.key(), Some("Greeting"));
         } else {
             panic!("Expected Text property");
         }
@@ -254,29 +250,30 @@ mod compatibility_tests {
     
     #[test]
     fn test_json_to_property_conversion() {
-        // Test basic JSON to Property conversion
+        // Test JSON to Property conversion with type annotations
         let bool_json = json!(true);
-        let prop = Asset::<std::io::Cursor<Vec<u8>>>::json_to_property(&bool_json, None);
+        let prop = Asset::<std::io::Cursor<Vec<u8>>>::json_to_property(&bool_json, "BoolProperty").unwrap();
         assert!(matches!(prop, Property::Bool(true)));
         
         let number_json = json!(42);
-        let prop = Asset::<std::io::Cursor<Vec<u8>>>::json_to_property(&number_json, None);
+        let prop = Asset::<std::io::Cursor<Vec<u8>>>::json_to_property(&number_json, "IntProperty").unwrap();
         assert!(matches!(prop, Property::Int32(42)));
         
         let large_number_json = json!(9223372036854775807i64);
-        let prop = Asset::<std::io::Cursor<Vec<u8>>>::json_to_property(&large_number_json, None);
+        let prop = Asset::<std::io::Cursor<Vec<u8>>>::json_to_property(&large_number_json, "Int64Property").unwrap();
         assert!(matches!(prop, Property::Int64(9223372036854775807)));
+        assert!(Asset::json_to_property(&large_number_json, "IntProperty").is_err());
         
         let float_json = json!(3.14159);
-        let prop = Asset::json_to_property(&float_json, None);
+        let prop = Asset::json_to_property(&float_json, "DoubleProperty").unwrap();
         assert!(matches!(prop, Property::Double(f) if (f - 3.14159).abs() < f64::EPSILON));
         
         let string_json = json!("test_string");
-        let prop = Asset::json_to_property(&string_json, None);
+        let prop = Asset::json_to_property(&string_json, "StrProperty").unwrap();
         assert!(matches!(prop, Property::String(ref s) if s == "test_string"));
         
         let name_json = json!("test_name");
-        let prop = Asset::json_to_property(&name_json, Some("Name"));
+        let prop = Asset::json_to_property(&name_json, "NameProperty").unwrap();
         if let Property::Name(fname) = prop {
             assert_eq!(fname.as_str(), "test_name");
         } else {
@@ -284,7 +281,7 @@ mod compatibility_tests {
         }
         
         let array_json = json!([1, 2, 3]);
-        let prop = Asset::json_to_property(&array_json, None);
+        let prop = Asset::json_to_property(&array_json, "ArrayProperty<IntProperty>").unwrap();
         if let Property::Array(arr) = prop {
             assert_eq!(arr.len(), 3);
             assert!(matches!(arr[0], Property::Int32(1)));
@@ -295,25 +292,26 @@ mod compatibility_tests {
         }
         
         let struct_json = json!({
-            "$type": "Vector",
+            "$types": { "X": "FloatProperty", "Y": "FloatProperty", "Scale": "FloatProperty" },
             "X": 1.0,
             "Y": 2.0,
-            "Z": 3.0
+            "Scale": 3.0
         });
-        let prop = Asset::json_to_property(&struct_json, None);
+        let prop = Asset::json_to_property(&struct_json, "StructProperty<SpawnOffset>").unwrap();
         if let Property::Struct { struct_type, properties } = prop {
-            assert_eq!(struct_type.as_str(), "Vector");
+            assert_eq!(struct_type.as_str(), "SpawnOffset");
             assert_eq!(properties.len(), 3);
             assert!(properties.contains_key("X"));
             assert!(properties.contains_key("Y"));
-            assert!(properties.contains_key("Z"));
+            assert!(properties.contains_key("Scale"));
         } else {
             panic!("Expected Struct property");
         }
         
         let null_json = json!(null);
-        let prop = Asset::json_to_property(&null_json, None);
+        let prop = Asset::json_to_property(&null_json, "ObjectProperty").unwrap();
         assert!(matches!(prop, Property::Object(None)));
+        assert!(Asset::json_to_property(&null_json, "IntProperty").is_err());
     }
     
     #[test]
@@ -330,27 +328,27 @@ mod compatibility_tests {
         
         // Test typed property conversion
         let bool_json = json!(true);
-        let prop = ConversionUtils::json_to_property_typed(&bool_json, "BoolProperty");
+        let prop = ConversionUtils::json_to_property_typed(&bool_json, "BoolProperty").unwrap();
         assert!(matches!(prop, Property::Bool(true)));
         
         let int_json = json!(42);
-        let prop = ConversionUtils::json_to_property_typed(&int_json, "IntProperty");
+        let prop = ConversionUtils::json_to_property_typed(&int_json, "IntProperty").unwrap();
         assert!(matches!(prop, Property::Int32(42)));
         
         let int64_json = json!(9223372036854775807i64);
-        let prop = ConversionUtils::json_to_property_typed(&int64_json, "Int64Property");
+        let prop = ConversionUtils::json_to_property_typed(&int64_json, "Int64Property").unwrap();
         assert!(matches!(prop, Property::Int64(9223372036854775807)));
         
         let float_json = json!(3.14);
-        let prop = ConversionUtils::json_to_property_typed(&float_json, "FloatProperty");
+        let prop = ConversionUtils::json_to_property_typed(&float_json, "FloatProperty").unwrap();
         assert!(matches!(prop, Property::Float(f) if (f - 3.14).abs() < 0.01));
         
         let double_json = json!(3.14159265359);
-        let prop = ConversionUtils::json_to_property_typed(&double_json, "DoubleProperty");
+        let prop = ConversionUtils::json_to_property_typed(&double_json, "DoubleProperty").unwrap();
         assert!(matches!(prop, Property::Double(f) if (f - 3.14159265359).abs() < f64::EPSILON));
         
         let str_json = json!("test_string");
-        let prop = ConversionUtils::json_to_property_typed(&str_json, "StrProperty");
+        let prop = ConversionUtils::json_to_property_typed(&str_json, "StrProperty").unwrap();
         if let Property::String(s) = prop {
             assert_eq!(s, "test_string");
         } else {
@@ -358,7 +356,7 @@ mod compatibility_tests {
         }
         
         let name_json = json!("test_name");
-        let prop = ConversionUtils::json_to_property_typed(&name_json, "NameProperty");
+        let prop = ConversionUtils::json_to_property_typed(&name_json, "NameProperty").unwrap();
         if let Property::Name(fname) = prop {
             assert_eq!(fname.as_str(), "test_name");
         } else {
@@ -366,7 +364,7 @@ mod compatibility_tests {
         }
         
         let null_obj_json = json!(null);
-        let prop = ConversionUtils::json_to_property_typed(&null_obj_json, "ObjectProperty");
+        let prop = ConversionUtils::json_to_property_typed(&null_obj_json, "ObjectProperty").unwrap();
         assert!(matches!(prop, Property::Object(None)));
     }
     
@@ -475,6 +473,2220 @@ mod compatibility_tests {
         let not_found_import = asset.get_import_by_name("NonExistent");
         assert!(not_found_import.is_none());
     }
+    
+    #[test]
+    fn test_property_writer_tagged_output() {
+        use byteorder::{LittleEndian, ReadBytesExt};
+        use indexmap::IndexMap;
+        use std::io::Cursor;
+        
+        let name_map = SharedResource::new(NameMap::new());
+        let mut writer = BinaryArchiveWriter::with_name_map(
+            Cursor::new(Vec::new()),
+            EngineVersion::VerUe4_27,
+            name_map.clone(),
+        );
+        
+        let mut properties = IndexMap::new();
+        properties.insert("Health".to_string(), Property::Int32(100));
+        properties.insert("bEnabled".to_string(), Property::Bool(true));
+        properties.insert("DisplayName".to_string(), Property::String("Ünïcode".to_string()));
+        writer.write_properties(&properties).expect("Should write properties");
+        
+        // Every name written by the tags ends up in the shared name map
+        assert!(name_map.get_ref().iter().any(|n| n == "IntProperty"));
+        assert!(name_map.get_ref().iter().any(|n| n == "None"));
+        
+        let bytes = writer.into_inner().into_inner();
+        let mut reader = BinaryArchive::with_name_map(Cursor::new(bytes), EngineVersion::VerUe4_27, name_map);
+        
+        assert_eq!(reader.read_fname().unwrap().as_str(), "Health");
+        assert_eq!(reader.read_fname().unwrap().as_str(), "IntProperty");
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 4);
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert!(reader.read_property_guid().unwrap().is_none());
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 100);
+        
+        // Bool values live in the tag and have no payload
+        assert_eq!(reader.read_fname().unwrap().as_str(), "bEnabled");
+        assert_eq!(reader.read_fname().unwrap().as_str(), "BoolProperty");
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert!(reader.read_bool().unwrap());
+        assert!(reader.read_property_guid().unwrap().is_none());
+        
+        // Non-ASCII strings are written as UTF-16
+        assert_eq!(reader.read_fname().unwrap().as_str(), "DisplayName");
+        assert_eq!(reader.read_fname().unwrap().as_str(), "StrProperty");
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 4 + 8 * 2);
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert!(reader.read_property_guid().unwrap().is_none());
+        assert_eq!(reader.read_fstring().unwrap().as_deref(), Some("Ünïcode"));
+        
+        assert_eq!(reader.read_fname().unwrap().as_str(), "None");
+    }
+    
+    #[test]
+    fn test_asset_from_cue4parse_json() {
+        use cue4parse_rs::{ExportInfo, GameVersion, PackageInfo};
+        
+        let package = PackageInfo {
+            name: "/Game/Props/BP_Crate".to_string(),
+            exports: vec![ExportInfo {
+                name: "BP_Crate".to_string(),
+                class_name: "StaticMesh".to_string(),
+                outer_index: 0,
+            }],
+        };
+        let objects = json!([{
+            "Type": "StaticMesh",
+            "Name": "BP_Crate",
+            "Class": "UScriptClass'/Script/Engine.StaticMesh'",
+            "$types": { "LightMapResolution": "IntProperty", "Material": "ObjectProperty" },
+            "Properties": {
+                "LightMapResolution": 64,
+                "LODGroup": 2,
+                "Material": {
+                    "ObjectName": "MaterialInstanceConstant'MI_Wood'",
+                    "ObjectPath": "/Game/Materials/MI_Wood.0"
+                }
+            }
+        }]);
+        
+        let asset = Asset::from_cue4parse_json(&package, &objects, GameVersion::UE5_1);
+        assert_eq!(asset.asset_data.object_name, "BP_Crate");
+        assert_eq!(asset.asset_data.engine_version, "UE5.1");
+        assert_eq!(asset.asset_data.object_version_ue5, 1008);
+        
+        let export = asset.get_main_export().expect("Should have an export");
+        let class = asset.get_import(export.class_index).expect("Class should be imported");
+        assert_eq!(class.object_name.as_str(), "StaticMesh");
+        assert_eq!(asset.get_import(class.outer_index).unwrap().object_name.as_str(), "/Script/Engine");
+        
+        assert!(matches!(export.properties.get("LightMapResolution"), Some(Property::Int32(64))));
+        // Values without a type are kept as they are
+        assert!(matches!(export.properties.get("LODGroup"), Some(Property::Unknown(value)) if *value == json!(2)));
+        let material = match export.properties.get("Material") {
+            Some(Property::Object(Some(index))) => asset.get_import(*index).unwrap(),
+            other => panic!("Expected object reference, got {:?}", other),
+        };
+        assert_eq!(material.object_name.as_str(), "MI_Wood");
+        assert_eq!(material.package_name.as_str(), "/Game/Materials/MI_Wood");
+        
+        for name in ["/Game/Props/BP_Crate", "LightMapResolution", "IntProperty", "None"] {
+            assert!(asset.asset_data.name_map.iter().any(|n| n == name), "missing {}", name);
+        }
+    }
+    
+    #[test]
+    fn test_typed_conversion_with_annotations_and_mappings() {
+        let object = json!({
+            "$types": {
+                "Tags": "ArrayProperty<NameProperty>",
+                "Scores": "MapProperty<StrProperty, FloatProperty>",
+                "Mode": "EnumProperty<EGameMode>",
+                "Spawn": "StructProperty<Vector>",
+                "Icon": "SoftObjectProperty"
+            },
+            "Tags": ["Boss", "Flying"],
+            "Scores": [{"Key": "Alice", "Value": 1.5}],
+            "Mode": "EGameMode::Arena",
+            "Spawn": {"X": 1.0, "Y": 2.0, "Z": 3.0},
+            "Icon": {"AssetPathName": "/Game/UI/T_Icon.T_Icon", "SubPathString": ""},
+            "bHidden": false
+        });
+        let properties = ConversionUtils::json_to_properties(&object, None, None).expect("Should convert");
+        assert!(matches!(&properties["Tags"], Property::Array(tags) if matches!(&tags[0], Property::Name(n) if n.as_str() == "Boss")));
+        assert!(matches!(&properties["Scores"], Property::Map { key_type, entries, .. } if key_type == "StrProperty" && entries.len() == 1));
+        assert!(matches!(&properties["Mode"], Property::Enum { enum_type, .. } if enum_type.as_str() == "EGameMode"));
+        assert!(matches!(&properties["Spawn"], Property::Vector(v) if v.z == 3.0));
+        assert!(matches!(&properties["Icon"], Property::SoftObjectPath(p) if p.asset_path.as_str() == "/Game/UI/T_Icon.T_Icon"));
+        assert!(matches!(properties["bHidden"], Property::Bool(false)));
+        
+        // Numbers without a type are ambiguous
+        let untyped = json!({"Health": 100});
+        assert!(ConversionUtils::json_to_properties(&untyped, None, None).is_err());
+        assert!(ConversionUtils::json_to_property_typed(&json!(1.5), "IntProperty").is_err());
+        assert!(ConversionUtils::json_to_property_typed(&json!(300), "ByteProperty").is_err());
+        
+        // Class and delegate flavours keep their own property types
+        let class_path = json!({"AssetPathName": "/Game/BP_Enemy.BP_Enemy_C", "SubPathString": ""});
+        assert!(matches!(ConversionUtils::json_to_property_typed(&class_path, "SoftClassProperty").unwrap(), Property::SoftClassPath(_)));
+        let delegates = json!([{"Object": null, "FunctionName": "OnHit"}]);
+        assert!(matches!(
+            ConversionUtils::json_to_property_typed(&delegates, "MulticastInlineDelegateProperty").unwrap(),
+            Property::MulticastInlineDelegateProperty { delegates } if delegates.len() == 1
+        ));
+        assert!(matches!(
+            ConversionUtils::json_to_property_typed(&delegates, "MulticastSparseDelegateProperty").unwrap(),
+            Property::MulticastSparseDelegateProperty { .. }
+        ));
+        
+        // Mappings fill in types for unannotated properties
+        let mut mappings = Usmap::default();
+        mappings.schemas.insert("Pawn".to_string(), UsmapSchema {
+            name: "Pawn".to_string(),
+            super_type: None,
+            property_count: 1,
+            properties: vec![UsmapProperty {
+                name: "Health".to_string(),
+                schema_index: 0,
+                array_size: 1,
+                property_type: PropertyType::new("Int64Property"),
+            }],
+        });
+        let properties = ConversionUtils::json_to_properties(&untyped, Some("Pawn"), Some(&mappings)).expect("Should convert");
+        assert!(matches!(properties["Health"], Property::Int64(100)));
+    }
+    
+    #[test]
+    fn test_usmap_reader() {
+        use byteorder::{LittleEndian, WriteBytesExt};
+        
+        let mut payload = Vec::new();
+        let names = ["EColor", "Red", "Green", "Widget", "Tint", "Children"];
+        payload.write_u32::<LittleEndian>(names.len() as u32).unwrap();
+        for name in names {
+            payload.write_u16::<LittleEndian>(name.len() as u16).unwrap();
+            payload.extend_from_slice(name.as_bytes());
+        }
+        // Enums: EColor { Red, Green }
+        payload.write_u32::<LittleEndian>(1).unwrap();
+        payload.write_i32::<LittleEndian>(0).unwrap();
+        payload.write_u16::<LittleEndian>(2).unwrap();
+        payload.write_i32::<LittleEndian>(1).unwrap();
+        payload.write_i32::<LittleEndian>(2).unwrap();
+        // Schemas: Widget { EnumProperty<EColor> Tint; ArrayProperty<ObjectProperty> Children }
+        payload.write_u32::<LittleEndian>(1).unwrap();
+        payload.write_i32::<LittleEndian>(3).unwrap();
+        payload.write_i32::<LittleEndian>(-1).unwrap();
+        payload.write_u16::<LittleEndian>(2).unwrap();
+        payload.write_u16::<LittleEndian>(2).unwrap();
+        payload.write_u16::<LittleEndian>(0).unwrap();
+        payload.write_u8(1).unwrap();
+        payload.write_i32::<LittleEndian>(4).unwrap();
+        payload.extend_from_slice(&[26, 0]);
+        payload.write_i32::<LittleEndian>(0).unwrap();
+        payload.write_u16::<LittleEndian>(1).unwrap();
+        payload.write_u8(1).unwrap();
+        payload.write_i32::<LittleEndian>(5).unwrap();
+        payload.extend_from_slice(&[8, 4]);
+        
+        let mut file = Vec::new();
+        file.write_u16::<LittleEndian>(USMAP_MAGIC).unwrap();
+        file.write_u8(UsmapVersion::LargeEnums as u8).unwrap();
+        file.write_i32::<LittleEndian>(0).unwrap();
+        file.write_u8(0).unwrap();
+        file.write_u32::<LittleEndian>(payload.len() as u32).unwrap();
+        file.write_u32::<LittleEndian>(payload.len() as u32).unwrap();
+        file.extend_from_slice(&payload);
+        
+        let usmap = Usmap::read(&mut std::io::Cursor::new(file.clone())).expect("Should parse usmap");
+        assert_eq!(usmap.enums["EColor"], vec!["Red".to_string(), "Green".to_string()]);
+        let tint = usmap.find_property("widget", "Tint").expect("Lookup should ignore case");
+        assert_eq!(tint.property_type.to_string(), "EnumProperty<EColor, ByteProperty>");
+        let children = usmap.find_property("Widget", "Children").unwrap();
+        assert_eq!(children.property_type, PropertyType::parse("ArrayProperty<ObjectProperty>").unwrap());
+        
+        // Type ids outside the known table are rejected rather than read as Unknown
+        let mut unknown_type = file.clone();
+        *unknown_type.last_mut().unwrap() = 0xC8;
+        assert!(Usmap::read(&mut std::io::Cursor::new(unknown_type)).is_err());
+        
+        // A payload shorter than its declared size fails instead of allocating the declared size
+        let mut truncated = file.clone();
+        truncated.truncate(file.len() - 8);
+        assert!(Usmap::read(&mut std::io::Cursor::new(truncated)).is_err());
+        
+        assert_eq!(PropertyType::parse("SoftClassProperty").unwrap().type_name, "SoftClassProperty");
+        assert_eq!(PropertyType::parse("MulticastSparseDelegateProperty").unwrap().type_name, "MulticastSparseDelegateProperty");
+    }
+
+    #[test]
+    fn test_dependency_graph() {
+        let hard = |name: &str| PackageDependency {
+            package_name: name.to_string(),
+            dependency_type: DependencyType::Hard,
+            import_map: vec![],
+        };
+        let mut graph = DependencyGraph::new();
+        graph.add_dependency("/Game/Level".to_string(), hard("/Game/Mesh"));
+        graph.add_dependency("/Game/Mesh".to_string(), hard("/Game/Material"));
+        graph.add_dependency("/Game/Level".to_string(), PackageDependency {
+            package_name: "/Game/Music".to_string(),
+            dependency_type: DependencyType::Soft,
+            import_map: vec!["Music".to_string()],
+        });
+        
+        let order = graph.resolve_dependencies("/Game/Level").unwrap();
+        assert_eq!(order, vec!["/Game/Material".to_string(), "/Game/Mesh".to_string()]);
+        assert!(graph.transitive_dependencies("/Game/Level", true).contains("/Game/Music"));
+        assert_eq!(graph.transitive_dependents("/Game/Material", false).len(), 2);
+        assert_eq!(graph.dependents_of("/Game/Mesh")[0].0, "/Game/Level");
+        assert!(!graph.has_cycles());
+        
+        let stats = graph.get_statistics();
+        assert_eq!(stats.total_packages, 4);
+        assert_eq!(stats.soft_dependencies, 1);
+        assert_eq!(stats.root_packages, 1);
+        assert!(graph.to_dot().contains("\"/Game/Level\" -> \"/Game/Music\" [style=dashed];"));
+        assert_eq!(graph.to_json()["edges"].as_array().unwrap().len(), 3);
+        
+        graph.add_dependency("/Game/Material".to_string(), hard("/Game/Level"));
+        assert_eq!(graph.find_cycles(), vec![vec![
+            "/Game/Level".to_string(), "/Game/Mesh".to_string(), "/Game/Material".to_string(),
+        ]]);
+        let err = graph.resolve_dependencies("/Game/Level").unwrap_err();
+        assert!(err.to_string().contains("/Game/Level -> /Game/Mesh -> /Game/Material -> /Game/Level"));
+    }
+
+    #[test]
+    fn test_dependency_graph_from_asset() {
+        let mut asset: Asset = Asset::new();
+        let package = asset.find_or_add_import("/Script/CoreUObject", "Package", PackageIndex::null(), "/Game/Materials/M_Rock");
+        asset.find_or_add_import("/Script/Engine", "Material", package, "M_Rock");
+        let mut export = Export::new(FName::new("Rock"), PackageIndex::null());
+        export.properties.insert("Sound".to_string(), Property::Array(vec![
+            Property::SoftObjectPath(SoftObjectPath::new("/Game/Audio/Rock.Rock", "")),
+        ]));
+        asset.asset_data.exports.push(export);
+        
+        let mut graph = DependencyGraph::new();
+        graph.add_asset("/Game/Rock", &asset);
+        let deps = graph.dependencies_of("/Game/Rock");
+        assert_eq!(deps.len(), 2);
+        assert_eq!(deps[0].package_name, "/Game/Materials/M_Rock");
+        assert_eq!(deps[0].import_map, vec!["M_Rock".to_string()]);
+        assert_eq!(deps[1].package_name, "/Game/Audio/Rock");
+        assert_eq!(deps[1].dependency_type, DependencyType::Soft);
+    }
+
+    #[test]
+    fn test_dependency_graph_from_provider_paths() {
+        let paths = [
+            "MyGame/Content/Maps/Level.umap",
+            "MyGame/Content/Meshes/Rock.uasset",
+            "MyGame/Plugins/Weather/Content/Rain.uasset",
+            "Engine/Content/Broken.uasset",
+        ];
+        let graph = DependencyGraph::from_packages(paths, |package| {
+            let mut asset: Asset = Asset::new();
+            match package {
+                "/Game/Maps/Level" => {
+                    let rock = asset.find_or_add_import("/Script/CoreUObject", "Package", PackageIndex::null(), "/Game/Meshes/Rock");
+                    asset.find_or_add_import("/Script/Engine", "StaticMesh", rock, "Rock");
+                    asset.find_or_add_import("/Script/CoreUObject", "Package", PackageIndex::null(), "/Weather/Rain");
+                }
+                "/Engine/Broken" => return Err("missing export"),
+                _ => {}
+            }
+            Ok(asset)
+        });
+        
+        let packages: Vec<&str> = graph.packages().collect();
+        assert_eq!(packages, vec!["/Game/Maps/Level", "/Game/Meshes/Rock", "/Weather/Rain", "/Engine/Broken"]);
+        assert_eq!(graph.dependents_of("/Game/Meshes/Rock")[0].0, "/Game/Maps/Level");
+        assert_eq!(graph.dependencies_of("/Game/Maps/Level")[0].import_map, vec!["Rock".to_string()]);
+        assert_eq!(graph.get_statistics().hard_dependencies, 2);
+        assert_eq!(graph.failed_packages, vec![("/Engine/Broken".to_string(), "missing export".to_string())]);
+        
+        // An import whose outer chain loops must not hang the graph build
+        let mut looping: Asset = Asset::new();
+        let a = looping.find_or_add_import("/Script/CoreUObject", "Package", PackageIndex::null(), "/Game/A");
+        looping.find_or_add_import("/Script/Engine", "Object", a, "B");
+        looping.asset_data.imports[0].outer_index = PackageIndex::from_import(1);
+        let mut graph = DependencyGraph::new();
+        graph.add_asset("/Game/Loop", &looping);
+        assert!(graph.dependencies_of("/Game/Loop").is_empty());
+        
+        // Long hard chains resolve without recursion
+        let mut chain = DependencyGraph::new();
+        for i in 0..100_000 {
+            chain.add_dependency(format!("/Game/P{}", i), PackageDependency {
+                package_name: format!("/Game/P{}", i + 1),
+                dependency_type: DependencyType::Hard,
+                import_map: vec![],
+            });
+        }
+        let order = chain.resolve_dependencies("/Game/P0").unwrap();
+        assert_eq!(order.len(), 100_000);
+        assert_eq!(order[0], "/Game/P100000");
+    }
+
+    #[test]
+    fn test_decompression() {
+        fn hex(s: &str) -> Vec<u8> {
+            (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap()).collect()
+        }
+        
+        let fixed = hex("78dacb48cdc9c957c8402701680308b1");
+        assert_eq!(zlib_decompress(&fixed, 23).unwrap(), b"hello hello hello hello");
+        assert!(zlib_decompress(&fixed, 22).is_err());
+        let gzip = hex("1f8b0800000000000203cb48cdc9c957c8402701e3513d8d17000000");
+        assert_eq!(decompress(CompressionMethod::Gzip, &gzip, 23).unwrap(), b"hello hello hello hello");
+        
+        let dynamic = hex(concat!(
+            "78da7550410e80200cfb0a5fc3b844124003c3f87c13063aaa5c80365d5bc6747149643c9de4dbc98d0b962939",
+            "dbe9bc97b89ac5173a928bdcd84079d34f51c16877ecf76b22729d2e0c86cd1d78f801ced54e00c7894a3d7d47",
+            "a4db6167c4ffeb11f6b35189d165264248b901d2f0a5e5",
+        ));
+        let text = decompress(CompressionMethod::Zlib, &dynamic, 431).unwrap();
+        assert!(text.starts_with(b"texture level level texture material"));
+        
+        let mut corrupt = fixed.clone();
+        *corrupt.last_mut().unwrap() ^= 1;
+        assert!(zlib_decompress(&corrupt, 23).is_err());
+        assert!(zlib_decompress(&fixed[..8], 23).is_err());
+        assert!(gzip_decompress(&gzip, 22).is_err());
+        assert!(gzip_decompress(&gzip[..20], 23).is_err());
+        assert!(decompress(CompressionMethod::Zlib, &dynamic, 100).is_err());
+        
+        let lz4 = [0x35, b'a', b'b', b'c', 3, 0, 0x10, b'x'];
+        assert_eq!(lz4_decompress(&lz4, 13).unwrap(), b"abcabcabcabcx");
+        assert!(lz4_decompress(&lz4, 5).is_err());
+        assert!(decompress(CompressionMethod::Oodle, &lz4, 13).is_err());
+    }
+
+    #[test]
+    fn test_bulk_data() {
+        use byteorder::{BigEndian, LittleEndian, WriteBytesExt};
+        use std::io::Cursor;
+        use std::sync::Arc;
+        
+        let mut inline = Vec::new();
+        inline.write_u32::<LittleEndian>(BulkDataFlags::FORCE_INLINE_PAYLOAD.bits()).unwrap();
+        inline.write_i32::<LittleEndian>(4).unwrap();
+        inline.write_u32::<LittleEndian>(4).unwrap();
+        inline.write_i64::<LittleEndian>(0).unwrap();
+        inline.extend_from_slice(&[1, 2, 3, 4]);
+        let mut ar = BinaryArchive::new(Cursor::new(inline), EngineVersion::VerUe4_27);
+        let entry = BulkDataEntry::read(&mut ar, "Inline", 0, &[]).unwrap();
+        assert_eq!(entry.location(), BulkDataLocation::Inline);
+        assert_eq!(entry.data, Some(vec![1, 2, 3, 4]));
+        
+        // FArchive::SerializeCompressed layout: tag, chunk size, summary, chunk table, chunks
+        let compressed = [
+            0x78, 0x9c, 0x2b, 0x4d, 0x2a, 0xcd, 0xc9, 0x56, 0x28, 0x48, 0xac, 0xcc, 0xc9, 0x4f, 0x4c, 0x51,
+            0x28, 0x1d, 0x50, 0x1e, 0x00, 0xad, 0xa8, 0x35, 0x03,
+        ];
+        let mut payload = Vec::new();
+        payload.write_i64::<LittleEndian>(0x9E2A83C1).unwrap();
+        payload.write_i64::<LittleEndian>(131072).unwrap();
+        for _ in 0..2 {
+            payload.write_i64::<LittleEndian>(compressed.len() as i64).unwrap();
+            payload.write_i64::<LittleEndian>(140).unwrap();
+        }
+        payload.extend_from_slice(&compressed);
+        
+        let mut header = Vec::new();
+        header.write_u32::<LittleEndian>((BulkDataFlags::PAYLOAD_IN_SEPARATE_FILE | BulkDataFlags::COMPRESS_ZLIB).bits()).unwrap();
+        header.write_i32::<LittleEndian>(140).unwrap();
+        header.write_u32::<LittleEndian>(payload.len() as u32).unwrap();
+        header.write_i64::<LittleEndian>(8).unwrap();
+        let mut ar = BinaryArchive::new(Cursor::new(header), EngineVersion::VerUe4_27);
+        let entry = BulkDataEntry::read(&mut ar, "Texture", 100, &[]).unwrap();
+        assert_eq!(entry.location(), BulkDataLocation::SeparateFile);
+        assert_eq!(entry.offset, 108);
+        assert_eq!(entry.compression_method, Some(CompressionMethod::Zlib));
+        assert!(entry.data.is_none());
+        
+        let mut manager = BulkDataManager::new();
+        manager.add_bulk_data("Texture".to_string(), entry.clone()).unwrap();
+        assert!(manager.add_bulk_data("Texture".to_string(), entry).is_err());
+        assert!(manager.load("Texture").is_err());
+        
+        let mut ubulk = vec![0u8; 8];
+        ubulk.extend_from_slice(&payload);
+        manager.register_source(BulkDataLocation::SeparateFile, BulkDataSource::Memory {
+            data: Arc::new(ubulk),
+            base_offset: 100,
+        });
+        let data = manager.load("Texture").unwrap();
+        assert_eq!(data.as_slice(), b"ubulk payload ".repeat(10).as_slice());
+        manager.load("Texture").unwrap();
+        
+        let stats = manager.get_cache_stats();
+        assert_eq!((stats.entries_count, stats.hits, stats.misses), (1, 1, 2));
+        assert_eq!(stats.cached_bytes, 140);
+        
+        // Sizes from the file are checked before anything is allocated for them
+        let mut oversized = Vec::new();
+        oversized.write_u32::<LittleEndian>(BulkDataFlags::FORCE_INLINE_PAYLOAD.bits()).unwrap();
+        oversized.write_i32::<LittleEndian>(1 << 30).unwrap();
+        oversized.write_u32::<LittleEndian>(1 << 30).unwrap();
+        oversized.write_i64::<LittleEndian>(0).unwrap();
+        let mut ar = BinaryArchive::new(Cursor::new(oversized), EngineVersion::VerUe4_27);
+        assert!(BulkDataEntry::read(&mut ar, "Oversized", 0, &[]).is_err());
+        
+        let chunked = |chunk_size: i64, chunks: &[(i64, i64)]| {
+            let mut raw = Vec::new();
+            raw.write_i64::<LittleEndian>(0x9E2A83C1).unwrap();
+            raw.write_i64::<LittleEndian>(chunk_size).unwrap();
+            raw.write_i64::<LittleEndian>(chunks.iter().map(|c| c.0).fold(0, i64::wrapping_add)).unwrap();
+            raw.write_i64::<LittleEndian>(chunks.iter().map(|c| c.1).fold(0, i64::wrapping_add)).unwrap();
+            for &(compressed_size, uncompressed_size) in chunks {
+                raw.write_i64::<LittleEndian>(compressed_size).unwrap();
+                raw.write_i64::<LittleEndian>(uncompressed_size).unwrap();
+            }
+            raw.extend_from_slice(&compressed);
+            BulkDataEntry {
+                name: "Bad".to_string(),
+                size_on_disk: raw.len() as u64,
+                size_in_memory: 140,
+                offset: 0,
+                flags: BulkDataFlags::PAYLOAD_IN_SEPARATE_FILE | BulkDataFlags::COMPRESS_ZLIB,
+                compression_method: Some(CompressionMethod::Zlib),
+                data: Some(raw),
+            }
+        };
+        for entry in [
+            chunked(1 << 40, &[(compressed.len() as i64, 1 << 40)]),
+            chunked(131072, &[(i64::MAX, 140)]),
+            chunked(131072, &[(compressed.len() as i64, -1)]),
+        ] {
+            let mut manager = BulkDataManager::new();
+            manager.add_bulk_data("Bad".to_string(), entry).unwrap();
+            assert!(manager.load("Bad").is_err());
+        }
+        
+        // Big-endian platforms byte swap the header but not the compressed chunks
+        let mut swapped = Vec::new();
+        swapped.write_i64::<BigEndian>(0x9E2A83C1).unwrap();
+        swapped.write_i64::<BigEndian>(131072).unwrap();
+        for _ in 0..2 {
+            swapped.write_i64::<BigEndian>(compressed.len() as i64).unwrap();
+            swapped.write_i64::<BigEndian>(140).unwrap();
+        }
+        swapped.extend_from_slice(&compressed);
+        let mut big_endian = chunked(131072, &[]);
+        big_endian.data = Some(swapped);
+        let mut manager = BulkDataManager::new();
+        manager.add_bulk_data("Swapped".to_string(), big_endian).unwrap();
+        assert_eq!(manager.load("Swapped").unwrap().as_slice(), b"ubulk payload ".repeat(10).as_slice());
+        
+        let mut manager = BulkDataManager::new();
+        manager.register_source(BulkDataLocation::SeparateFile, BulkDataSource::Memory {
+            data: Arc::new(vec![0; 16]),
+            base_offset: 0,
+        });
+        let mut past_end = chunked(131072, &[]);
+        (past_end.data, past_end.offset, past_end.size_on_disk) = (None, 8, u64::MAX);
+        manager.add_bulk_data("Bad".to_string(), past_end).unwrap();
+        assert!(manager.load("Bad").is_err());
+    }
+
+    #[test]
+    fn test_name_map() {
+        let mut names = NameMap::new();
+        assert_eq!(names.add_name_reference("None".to_string(), false), 0);
+        assert_eq!(names.add_name_reference("StaticMesh".to_string(), false), 1);
+        assert_eq!(names.add_name_reference("staticmesh".to_string(), false), 1);
+        assert_eq!(names.add_name_reference("staticmesh".to_string(), true), 2);
+        assert_eq!(names.search_name_reference("STATICMESH"), Some(1));
+        assert_eq!(names.search_name_reference("staticmesh"), Some(2));
+        assert_eq!(names.search_name_reference("Material"), None);
+        assert_eq!(names.get_name_reference(2), Some("staticmesh"));
+        assert_eq!(names.len(), 3);
+        
+        names.add_name_reference("Größe".to_string(), false);
+        names.add_name_reference("Odd".to_string(), false);
+        names.add_name_reference("日本".to_string(), false);
+        let mut batch = Vec::new();
+        names.write_name_batch(&mut batch).unwrap();
+        assert_eq!(&batch[8..16], &NAME_BATCH_HASH_VERSION.to_le_bytes());
+        assert_eq!(&batch[16..24], &0x75370f5ba6352dc2u64.to_le_bytes());
+        let read = NameMap::read_name_batch(&mut std::io::Cursor::new(batch)).unwrap();
+        assert_eq!(read, names);
+        
+        assert_eq!(name_hash("None"), name_hash("NONE"));
+        
+        // Latin-1 names are stored as one byte per character, not as UTF-8 or UTF-16
+        let mut latin1 = NameMap::new();
+        latin1.add_name_reference("Café".to_string(), false);
+        let mut batch = Vec::new();
+        latin1.write_name_batch(&mut batch).unwrap();
+        assert_eq!(&batch[24..26], &4u16.to_be_bytes());
+        assert_eq!(&batch[26..], b"Caf\xe9");
+        assert_eq!(&batch[16..24], &city_hash_64(b"caf\xe9").to_le_bytes());
+        assert_eq!(NameMap::read_name_batch(&mut std::io::Cursor::new(batch)).unwrap(), latin1);
+        assert_eq!(city_hash_64(b""), 0x9ae16a3b2f90404f);
+    }
+
+    #[test]
+    fn test_archive_name_references() {
+        use std::io::Cursor;
+        
+        let name_map = SharedResource::new(NameMap::new());
+        let mut writer = BinaryArchiveWriter::with_name_map(Cursor::new(Vec::new()), EngineVersion::VerUe4_27, name_map.clone());
+        assert_eq!(writer.add_name_reference("Health", false), 0);
+        writer.add_fname("Armor");
+        assert_eq!(writer.search_name_reference("armor"), Some(1));
+        writer.write_fname(&FName::new("HEALTH")).unwrap();
+        assert_eq!(writer.into_inner().into_inner(), vec![0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(name_map.get_ref().len(), 2);
+    }
+
+    #[test]
+    fn test_custom_versions() {
+        assert_eq!(
+            FCoreObjectVersion::GUID.to_string().to_uppercase(),
+            "3CC15E37-FB48-E406-F084-00B57E712A26"
+        );
+        assert_eq!(FCoreObjectVersion::LATEST_VERSION, FCoreObjectVersion::FProperties);
+        assert_eq!(
+            FCoreObjectVersion::from_engine_version(EngineVersion::VerUe4_24),
+            Some(FCoreObjectVersion::SkeletalMaterialEditorDataStripping)
+        );
+        assert_eq!(
+            FRigVMObjectVersion::from_engine_version(EngineVersion::VerUe5_2),
+            None
+        );
+        assert_eq!(FCoreObjectVersion::from_version(2), Some(FCoreObjectVersion::EnumProperties));
+        assert!(FCoreObjectVersion::try_from(99).is_err());
+        assert_eq!(i32::from(FUE5MainStreamObjectVersion::BeforeCustomVersionWasAdded), 0);
+
+        let registry = get_custom_version_registry();
+        let core = registry.get(&FCoreObjectVersion::GUID).unwrap();
+        assert_eq!(core.friendly_name, "FCoreObjectVersion");
+        assert_eq!(core.version, FCoreObjectVersion::LATEST_VERSION as i32);
+
+        let ue4_24 = registry.get_engine_versions(EngineVersion::VerUe4_24).unwrap();
+        assert!(ue4_24
+            .iter()
+            .any(|v| v.guid == FCoreObjectVersion::GUID && v.version == 3));
+        assert!(!ue4_24.iter().any(|v| v.guid == FUE5MainStreamObjectVersion::GUID && v.version > 0));
+
+        let known = vec![CustomVersion::from_typed(FAnimObjectVersion::StoreMarkerNamesOnSkeleton)];
+        assert!(registry.validate_versions(&known).is_empty());
+        let too_new = vec![CustomVersion::new(FCoreObjectVersion::GUID, 100, String::new())];
+        assert_eq!(registry.validate_versions(&too_new).len(), 1);
+        assert_eq!(registry.get_game_versions("Fortnite").unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_object_versions() {
+        assert_eq!(EngineVersion::VerUe4_27.object_version().get(), 522);
+        assert_eq!(EngineVersion::VerUe4_15.object_version().get(), 510);
+        assert_eq!(EngineVersion::VerUe4_27.object_version_ue5().get(), 0);
+        assert_eq!(EngineVersion::VerUe5_0.object_version().get(), 522);
+        assert_eq!(EngineVersion::VerUe5_0.object_version_ue5(), EUnrealEngineObjectUE5Version::LARGE_WORLD_COORDINATES);
+        assert_eq!(EngineVersion::VerUe5_3.object_version_ue5().get(), 1009);
+        assert_eq!(
+            EngineVersion::from(cue4parse_rs::GameVersion::UE5_4).object_version_ue5(),
+            EUnrealEngineObjectUE5Version::PROPERTY_TAG_COMPLETE_TYPE_NAME
+        );
+
+        let ue4_25 = EngineVersion::VerUe4_25.object_version();
+        assert!(ue4_25 >= EUnrealEngineObjectUE4Version::VER_UE4_PROPERTY_GUID_IN_PROPERTY_TAG);
+        assert!(ue4_25 < EUnrealEngineObjectUE4Version::VER_UE4_CORRECT_LICENSEE_FLAG);
+        assert_eq!(EUnrealEngineObjectUE4Version::VER_UE4_64BIT_EXPORTMAP_SERIALSIZES as i32, 511);
+        assert_eq!(
+            EUnrealEngineObjectUE4Version::try_from(216).unwrap(),
+            EUnrealEngineObjectUE4Version::VER_UE4_STATIC_MESH_STORE_NAV_COLLISION
+        );
+        assert!(EUnrealEngineObjectUE5Version::try_from(999).is_err());
+
+        let ue5_2 = EngineVersion::VerUe5_2.object_version_ue5();
+        assert!(ue5_2.supports_feature(UE5Feature::LargeWorldCoordinates));
+        assert!(ue5_2.supports_feature(UE5Feature::DataResources));
+        assert!(!ue5_2.supports_feature(UE5Feature::PropertyTagCompleteTypeName));
+        assert!(!EngineVersion::VerUe4_27.object_version_ue5().supports_feature(UE5Feature::OptionalResources));
+
+        assert_eq!(EngineVersion::from_object_versions(522, 1009), Some(EngineVersion::VerUe5_3));
+        assert_eq!(EngineVersion::from_object_versions(522, 0), Some(EngineVersion::VerUe4_27));
+        assert_eq!(EngineVersion::from_object_versions(513, 0), Some(EngineVersion::VerUe4_17));
+        assert_eq!(EngineVersion::from_object_versions(300, 0), None);
+        assert!(EngineVersion::VerUe4_27.is_ue4() && EngineVersion::VerUe5_0.is_ue5());
+    }
+
+    #[test]
+    fn test_package_index_resolver_paths() {
+        let import = |class_name: &str, outer: i32, name: &str| Import {
+            class_package: FName::new("/Script/CoreUObject"),
+            class_name: FName::new(class_name),
+            outer_index: PackageIndex::new(outer),
+            object_name: FName::new(name),
+            package_guid: None,
+            package_name: FName::new(""),
+        };
+        let imports = vec![
+            import("Package", 0, "/Script/Engine"),
+            import("Class", -1, "World"),
+            import("Class", -1, "Level"),
+            import("Class", -1, "StaticMeshActor"),
+            import("Package", 0, "/Game/Meshes/Cube"),
+            import("StaticMesh", -5, "Cube"),
+        ];
+        let mut exports = vec![
+            Export::new(FName::new("Level"), PackageIndex::new(-2)),
+            Export::new(FName::new("PersistentLevel"), PackageIndex::new(-3)),
+            Export::new(FName::with_number("Actor", 3), PackageIndex::new(-4)),
+        ];
+        exports[1].outer_index = PackageIndex::new(1);
+        exports[2].outer_index = PackageIndex::new(2);
+        let names = vec!["None".to_string()];
+
+        let resolver = PackageIndexResolver::new(&imports, &exports, &names).with_package_name("/Game/Maps/Level");
+        assert_eq!(resolver.resolve_path(PackageIndex::new(1)).unwrap(), "/Game/Maps/Level.Level");
+        assert_eq!(
+            resolver.resolve_path(PackageIndex::new(3)).unwrap(),
+            "/Game/Maps/Level.Level:PersistentLevel.Actor_3"
+        );
+        assert_eq!(resolver.resolve_path(PackageIndex::new(-2)).unwrap(), "/Script/Engine.World");
+        assert_eq!(resolver.resolve_package(PackageIndex::new(-6)).unwrap(), "/Game/Meshes/Cube");
+        assert_eq!(resolver.resolve_package(PackageIndex::new(3)).unwrap(), "/Game/Maps/Level");
+
+        let full_name = resolver.resolve_full_name(PackageIndex::new(-6)).unwrap();
+        assert_eq!(full_name.to_string(), "StaticMesh'/Game/Meshes/Cube.Cube'");
+        assert_eq!(full_name, "StaticMesh'/Game/Meshes/Cube.Cube'".parse::<ClassQualifiedPath>().unwrap());
+        assert!("/Game/Meshes/Cube.Cube".parse::<ClassQualifiedPath>().is_err());
+        assert_eq!(
+            resolver.resolve_full_name(PackageIndex::new(2)).unwrap().to_string(),
+            "Level'/Game/Maps/Level.Level:PersistentLevel'"
+        );
+
+        assert_eq!(resolver.find_index("/Game/Maps/Level.Level:PersistentLevel.Actor_3"), Some(PackageIndex::new(3)));
+        assert_eq!(resolver.find_index("StaticMesh'/Game/Meshes/Cube.Cube'"), Some(PackageIndex::new(-6)));
+        assert_eq!(resolver.find_index("/Script/Engine.StaticMesh'/Game/Meshes/Cube.Cube'"), Some(PackageIndex::new(-6)));
+        assert_eq!(resolver.find_index("Texture2D'/Game/Meshes/Cube.Cube'"), None);
+        assert_eq!(resolver.path_map().len(), imports.len() + exports.len());
+
+        exports[0].outer_index = PackageIndex::new(3);
+        let resolver = PackageIndexResolver::new(&imports, &exports, &names);
+        assert!(resolver.resolve_path(PackageIndex::new(3)).is_err());
+    }
+
+    #[test]
+    fn test_package_header_reader() {
+        use std::io::Cursor;
+
+        // The same package saved in both byte orders reads identically
+        for endianness in [Endianness::Little, Endianness::Big] {
+            let names = ["None", "/Script/Engine", "Package", "Class", "StaticMesh", "/Script/CoreUObject", "Cube"];
+            let package_guid = uuid::Uuid::from_u128(0x0123_4567_89AB_CDEF_0123_4567_89AB_CDEF);
+            let finish = |w: BinaryArchiveWriter<Cursor<Vec<u8>>>| w.into_inner().into_inner();
+            let new_writer = || {
+                let mut writer = BinaryArchiveWriter::new(Cursor::new(Vec::new()), EngineVersion::VerUe4_27);
+                writer.set_endianness(endianness);
+                for name in names {
+                    writer.add_fname(name);
+                }
+                writer
+            };
+
+            // Summary of a cooked 4.27 package, written with the given table offsets
+            let write_summary = |offsets: [i32; 4]| {
+                let [name_offset, import_offset, export_offset, preload_offset] = offsets;
+                let mut w = new_writer();
+                w.write_u32_endian(PACKAGE_FILE_TAG).unwrap();
+                for value in [-7, 864, 522, 0, 1] {
+                    w.write_i32_endian(value).unwrap();
+                }
+                w.write_guid(&FCoreObjectVersion::GUID).unwrap();
+                w.write_i32_endian(3).unwrap();
+                w.write_i32_endian(preload_offset + 4).unwrap(); // TotalHeaderSize
+                w.write_fstring(Some("None")).unwrap();
+                w.write_u32_endian(PKG_FILTER_EDITOR_ONLY).unwrap();
+                for value in [names.len() as i32, name_offset, 0, 0, 1, export_offset, 2, import_offset, 0, 0, 0, 0, 0] {
+                    w.write_i32_endian(value).unwrap();
+                }
+                w.write_guid(&package_guid).unwrap();
+                w.write_i32_endian(1).unwrap();
+                w.write_i32_endian(1).unwrap();
+                w.write_i32_endian(names.len() as i32).unwrap();
+                for _ in 0..2 {
+                    for value in [4u16, 27, 2] {
+                        w.write_u16_endian(value).unwrap();
+                    }
+                    w.write_u32_endian(0x80000000 | 18319896).unwrap();
+                    w.write_fstring(Some("++UE4+Release-4.27")).unwrap();
+                }
+                for value in [0, 0, 0, 0, 0] { // Compression, chunks, source, packages to cook, asset registry
+                    w.write_i32_endian(value).unwrap();
+                }
+                w.write_i64_endian(1024).unwrap(); // BulkDataStartOffset
+                for value in [0, 1, 3, 1, preload_offset] {
+                    w.write_i32_endian(value).unwrap();
+                }
+                finish(w)
+            };
+            let summary_size = write_summary([0; 4]).len() as i32;
+
+            let mut w = new_writer();
+            for name in names {
+                w.write_fstring(Some(name)).unwrap();
+                w.write_u32_endian(0).unwrap();
+            }
+            let name_table = finish(w);
+            let mut w = new_writer();
+            for (class_package, class_name, outer, object_name) in [
+                ("/Script/CoreUObject", "Package", 0, "/Script/Engine"),
+                ("/Script/CoreUObject", "Class", -1, "StaticMesh"),
+            ] {
+                w.write_fname(&FName::new(class_package)).unwrap();
+                w.write_fname(&FName::new(class_name)).unwrap();
+                w.write_i32_endian(outer).unwrap();
+                w.write_fname(&FName::new(object_name)).unwrap();
+            }
+            let import_table = finish(w);
+            let mut w = new_writer();
+            for value in [-2, 0, 0, 0] {
+                w.write_i32_endian(value).unwrap();
+            }
+            w.write_fname(&FName::new("Cube")).unwrap();
+            w.write_u32_endian(0x1 | 0x8).unwrap(); // RF_Public | RF_Standalone
+            w.write_i64_endian(128).unwrap();
+            w.write_i64_endian(summary_size as i64).unwrap();
+            for value in [0, 0, 0] {
+                w.write_i32_endian(value).unwrap();
+            }
+            w.write_guid(&uuid::Uuid::nil()).unwrap();
+            for value in [0, 0, 1, 0, 0, 1, 0, 0] { // Package flags, bools and dependency ranges
+                w.write_i32_endian(value).unwrap();
+            }
+            let export_table = finish(w);
+
+            let name_offset = summary_size;
+            let import_offset = name_offset + name_table.len() as i32;
+            let export_offset = import_offset + import_table.len() as i32;
+            let preload_offset = export_offset + export_table.len() as i32;
+            let mut data = write_summary([name_offset, import_offset, export_offset, preload_offset]);
+            assert_eq!(data.len() as i32, summary_size);
+            data.extend(name_table);
+            data.extend(import_table);
+            data.extend(export_table);
+            let mut w = new_writer();
+            w.write_i32_endian(-2).unwrap(); // Preload dependency on the StaticMesh class import
+            data.extend(finish(w));
+
+            let header = PackageHeader::read(Cursor::new(data), EngineVersion::VerUe5_3).unwrap();
+            let summary = &header.summary;
+            assert_eq!(summary.endianness, endianness);
+            assert!(!summary.unversioned);
+            assert!(summary.is_filter_editor_only());
+            assert_eq!(summary.engine_version(), Some(EngineVersion::VerUe4_27));
+            assert_eq!(summary.custom_versions.len(), 1);
+            assert_eq!(summary.custom_versions[0].friendly_name, "FCoreObjectVersion");
+            assert_eq!(summary.guid, Some(package_guid));
+            assert_eq!(summary.persistent_guid, None);
+            assert_eq!(summary.generations, vec![GenerationInfo { export_count: 1, name_count: 7 }]);
+            let saved_by = summary.saved_by_engine_version.as_ref().unwrap();
+            assert_eq!(saved_by.to_string(), "4.27.2-18319896+++UE4+Release-4.27");
+            assert!(saved_by.is_licensee_version());
+            assert_eq!(summary.bulk_data_start_offset, 1024);
+            assert_eq!(summary.chunk_ids, vec![3]);
+            assert_eq!(summary.names_referenced_from_export_data_count, 7);
+            assert_eq!((summary.payload_toc_offset, summary.data_resource_offset), (-1, -1));
+
+            assert_eq!(header.name_map.get_name_map_index_list(), names);
+            assert_eq!(header.imports[1].object_name.as_str(), "StaticMesh");
+            assert_eq!(header.imports[1].outer_index, PackageIndex::new(-1));
+            let export = &header.exports[0];
+            assert_eq!(export.object_name.as_str(), "Cube");
+            assert_eq!(export.class_index, PackageIndex::new(-2));
+            assert_eq!((export.serial_size, export.serial_offset), (128, summary_size as u64));
+            assert_eq!(ExportFlags::from_bits_truncate(export.export_flags), ExportFlags::IS_ASSET);
+            assert_eq!(export.create_before_serialization_dependencies, vec![PackageIndex::new(-2)]);
+
+        }
+
+        // UE3 packages are rejected
+        let ue3 = [PACKAGE_FILE_TAG.to_le_bytes(), 868u32.to_le_bytes()].concat();
+        let result = PackageHeader::read(Cursor::new(ue3), EngineVersion::VerUe4_27);
+        assert!(matches!(result.as_ref().map_err(UnrealAssetError::root), Err(UnrealAssetError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn test_zen_package_header() {
+        use std::io::Cursor;
+
+        let script = |index: u64| (1u64 << 62) | index;
+        let mapped = |index: u32, global: bool| {
+            let name = MappedName::new(index, 0, global);
+            assert_eq!((name.name_index(), name.is_global()), (index, global));
+            [(index | if global { 2 << 30 } else { 0 }).to_le_bytes(), 0u32.to_le_bytes()].concat()
+        };
+        let name_batch = |names: &[&str]| {
+            let mut bytes = Vec::new();
+            NameMap::from_names(names.iter().map(|n| n.to_string())).write_name_batch(&mut bytes).unwrap();
+            bytes
+        };
+
+        // ScriptObjects chunk with /Script/Engine and its StaticMesh class
+        let mut global = name_batch(&["/Script/Engine", "StaticMesh"]);
+        global.extend(2i32.to_le_bytes());
+        for (name, index, outer) in [(0, script(1), u64::MAX), (1, script(2), script(1))] {
+            global.extend(mapped(name, true));
+            for value in [index, outer, u64::MAX] {
+                global.extend(value.to_le_bytes());
+            }
+        }
+        let global_data = IoGlobalData::read(&mut Cursor::new(global)).unwrap();
+        assert_eq!(global_data.script_objects.len(), 2);
+
+        let names = name_batch(&["/Game/Meshes/Cube", "Cube"]);
+        let hashes_offset = 52 + names.len() as i32 + 8;
+        let import_map_offset = hashes_offset + 8;
+        let export_map_offset = import_map_offset + 16;
+        let bundle_entries_offset = export_map_offset + 72;
+        let dependency_headers_offset = bundle_entries_offset + 16;
+        let dependency_entries_offset = dependency_headers_offset + 20;
+        let imported_names_offset = dependency_entries_offset + 40;
+        let imported_names = name_batch(&["/Game/Materials/M_Base"]);
+        let header_size = imported_names_offset + imported_names.len() as i32 + 4;
+
+        let mut data = Vec::new();
+        data.extend(0u32.to_le_bytes()); // bHasVersioningInfo
+        data.extend((header_size as u32).to_le_bytes());
+        data.extend(mapped(0, false));
+        data.extend(PKG_UNVERSIONED_PROPERTIES.to_le_bytes());
+        data.extend((header_size as u32).to_le_bytes()); // CookedHeaderSize
+        for offset in [
+            hashes_offset,
+            import_map_offset,
+            export_map_offset,
+            bundle_entries_offset,
+            dependency_headers_offset,
+            dependency_entries_offset,
+            imported_names_offset,
+        ] {
+            data.extend(offset.to_le_bytes());
+        }
+        assert_eq!(data.len(), 52);
+        data.extend(names);
+        data.extend(0i64.to_le_bytes()); // Bulk data map size
+        data.extend(0xDEADBEEFu64.to_le_bytes());
+        data.extend(script(2).to_le_bytes());
+        data.extend((2u64 << 62).to_le_bytes()); // First public export of the first imported package
+
+        let export_start = data.len();
+        data.extend(0u64.to_le_bytes());
+        data.extend(64u64.to_le_bytes());
+        data.extend(mapped(1, false));
+        for index in [u64::MAX, script(2), u64::MAX, u64::MAX] { // Outer, class, super, template
+            data.extend(index.to_le_bytes());
+        }
+        data.extend(0x1234u64.to_le_bytes()); // PublicExportHash
+        data.extend(0x9u32.to_le_bytes()); // RF_Public | RF_Standalone
+        data.push(1); // NotForClient
+        data.resize(export_start + 72, 0);
+
+        for (index, command) in [(0u32, 0u32), (0, 1)] {
+            data.extend(index.to_le_bytes());
+            data.extend(command.to_le_bytes());
+        }
+        // First entry, then create-before-create, serialize-before-create, create-before-serialize
+        // and serialize-before-serialize counts
+        for value in [0i32, 1, 2, 3, 4] {
+            data.extend(value.to_le_bytes());
+        }
+        for entry in [-1i32, -3, -3, -2, -4, -1, -3, -3, -3, -3] {
+            data.extend(entry.to_le_bytes());
+        }
+        data.extend(imported_names);
+        data.extend(0i32.to_le_bytes());
+        assert_eq!(data.len() as i32, header_size);
+
+        let header = ZenPackageHeader::read(Cursor::new(data), EngineVersion::VerUe5_3, &global_data).unwrap();
+        assert_eq!(header.package_name.as_str(), "/Game/Meshes/Cube");
+        assert!(header.has_unversioned_properties());
+        assert!(header.import_map[0].is_script_import());
+        assert_eq!(header.import_map[1].as_package_import(), (0, 0));
+        assert_eq!(header.imported_package_names[0].as_str(), "/Game/Materials/M_Base");
+
+        let import_names: Vec<_> = header.imports.iter().map(|i| i.object_name.as_str().to_string()).collect();
+        assert_eq!(import_names, ["StaticMesh", "00000000DEADBEEF", "/Script/Engine", "/Game/Materials/M_Base"]);
+        assert_eq!(header.imports[0].class_name.as_str(), "Class");
+        assert_eq!(header.imports[0].outer_index, PackageIndex::new(-3));
+        assert_eq!(header.imports[1].outer_index, PackageIndex::new(-4));
+        assert_eq!(header.imports[1].package_name.as_str(), "/Game/Materials/M_Base");
+        assert_eq!(header.imports[2].class_name.as_str(), "Package");
+
+        let export = &header.exports[0];
+        assert_eq!(export.object_name.as_str(), "Cube");
+        assert_eq!(export.class_index, PackageIndex::new(-1));
+        assert!(export.outer_index.is_null());
+        assert_eq!((export.serial_offset, export.serial_size), (header_size as u64, 64));
+        assert_eq!(ExportFlags::from_bits_truncate(export.export_flags), ExportFlags::NOT_FOR_CLIENT);
+        assert_eq!(header.dependency_bundle_headers[0], DependencyBundleHeader {
+            first_entry_index: 0,
+            create_before_create: 1,
+            serialize_before_create: 2,
+            create_before_serialize: 3,
+            serialize_before_serialize: 4,
+        });
+        assert_eq!(
+            export.create_before_serialization_dependencies,
+            vec![PackageIndex::new(-2), PackageIndex::new(-4), PackageIndex::new(-1)]
+        );
+        assert_eq!(header.export_bundle_entries[1].command_type, ExportCommandType::Serialize);
+
+        // UE4 IoStore packages use FPackageSummary instead
+        let result = ZenPackageHeader::read(Cursor::new(Vec::new()), EngineVersion::VerUe4_27, &global_data);
+        assert!(matches!(result.as_ref().map_err(UnrealAssetError::root), Err(UnrealAssetError::UnsupportedVersion(_))));
+    }
+
+    #[test]
+    fn test_archive_limits() {
+        use byteorder::{LittleEndian, ReadBytesExt};
+        use std::io::Cursor;
+
+        // Negative and oversized lengths fail before allocating
+        let mut ar = BinaryArchive::new(Cursor::new(i32::MIN.to_le_bytes().to_vec()), EngineVersion::VerUe4_27);
+        let err = ar.read_fstring().unwrap_err();
+        assert!(matches!(err, UnrealAssetError::LimitExceeded { kind: LimitKind::StringLength, offset: 0, .. }));
+
+        let data = [8u32.to_le_bytes(), 1000u32.to_le_bytes()].concat();
+        let mut ar = BinaryArchive::new(Cursor::new(data), EngineVersion::VerUe4_27);
+        ar.read_u32::<LittleEndian>().unwrap();
+        let err = ar.read_array(|ar| Ok(ar.read_u8()?)).unwrap_err();
+        assert_eq!(err.to_string(), "Array length 1000 out of range at offset 0x4");
+        let mut ar = BinaryArchive::new(Cursor::new((-1i32).to_le_bytes().to_vec()), EngineVersion::VerUe4_27);
+        assert!(ar.read_array(|ar| Ok(ar.read_u8()?)).is_err());
+
+        // Configured limits apply even when the data is there
+        let data = [3i32.to_le_bytes().to_vec(), b"ab\0".to_vec()].concat();
+        let mut ar = BinaryArchive::new(Cursor::new(data), EngineVersion::VerUe4_27);
+        ar.set_limits(ArchiveLimits { max_string_length: 2, ..ArchiveLimits::default() });
+        assert!(matches!(ar.read_fstring(), Err(UnrealAssetError::LimitExceeded { value: 3, .. })));
+        ar.set_position(0).unwrap();
+        ar.set_limits(ArchiveLimits::default());
+        assert_eq!(ar.read_fstring().unwrap().as_deref(), Some("ab"));
+
+        fn nest(ar: &mut BinaryArchive<Cursor<Vec<u8>>>, levels: usize) -> UnrealAssetResult<usize> {
+            match levels {
+                0 => Ok(0),
+                _ => ar.read_nested(|ar| Ok(nest(ar, levels - 1)? + 1)),
+            }
+        }
+        let mut ar = BinaryArchive::new(Cursor::new(Vec::new()), EngineVersion::VerUe4_27);
+        ar.set_limits(ArchiveLimits { max_depth: 4, ..ArchiveLimits::default() });
+        assert_eq!(nest(&mut ar, 4).unwrap(), 4);
+        assert!(matches!(nest(&mut ar, 5), Err(UnrealAssetError::LimitExceeded { kind: LimitKind::NestingDepth, .. })));
+        assert_eq!(nest(&mut ar, 3).unwrap(), 3);
+
+        // Corrupt headers produce errors rather than panics or huge allocations
+        let mut seed = 0x2545F4914F6CDD1Du64;
+        let mut next = move || {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        };
+        let global_data = IoGlobalData::default();
+        for _ in 0..2000 {
+            let length = (next() % 512) as usize;
+            let mut data: Vec<u8> = (0..length).map(|_| next() as u8).collect();
+            let prefix = [PACKAGE_FILE_TAG as i32, -7, 864, 522, 0];
+            for (i, value) in prefix.iter().enumerate().take(length / 4) {
+                if next() % 8 != 0 {
+                    data[i * 4..i * 4 + 4].copy_from_slice(&value.to_le_bytes());
+                }
+            }
+            let _ = PackageHeader::read(Cursor::new(data.clone()), EngineVersion::VerUe4_27);
+            let _ = ZenPackageHeader::read(Cursor::new(data.clone()), EngineVersion::VerUe5_3, &global_data);
+            let _ = IoGlobalData::read(&mut Cursor::new(data.clone()));
+            let _ = NameMap::read_name_batch(&mut Cursor::new(data));
+        }
+    }
+
+    #[test]
+    fn test_error_context() {
+        use std::io::Cursor;
+
+        let mut path = PropertyPath::new();
+        for segment in [PathSegment::from("Export"), 12.into(), "Properties".into(), "Mesh".into(), "LODs".into(), 2.into()] {
+            path.push(segment);
+        }
+        path.push("Sections");
+        assert_eq!(path.to_string(), "Export[12].Properties.Mesh.LODs[2].Sections");
+
+        // Entries 0 and 1 are fine, the second string of entry 2 claims 1000 bytes
+        let mut data = Vec::new();
+        for (i, name) in ["None", "Mesh", "LODs"].iter().enumerate() {
+            data.extend(5i32.to_le_bytes());
+            data.extend(name.as_bytes());
+            data.push(0);
+            if i < 2 {
+                data.extend(0u32.to_le_bytes());
+            }
+        }
+        data.extend(1000i32.to_le_bytes());
+        let mut ar = BinaryArchive::new(Cursor::new(data), EngineVersion::VerUe4_27);
+        ar.set_archive_name("Cube.uasset");
+        let result = ar.read_in("NameMap", |ar| {
+            ar.read_array_with_length(3, |ar| {
+                let name = ar.read_fstring()?;
+                ar.read_in("Hash", |ar| ar.read_fstring())?;
+                Ok(name)
+            })
+        });
+        let err = result.unwrap_err();
+        assert!(matches!(err, UnrealAssetError::LimitExceeded { kind: LimitKind::StringLength, .. }));
+        let context = ar.error_context().unwrap();
+        assert_eq!(context.archive_name.as_deref(), Some("Cube.uasset"));
+        assert_eq!(context.offset, 0x23);
+        assert_eq!(context.path.to_string(), "NameMap[2].Hash");
+        assert_eq!(err.to_string(), "String length 1000 out of range at offset 0x23");
+        // The breadcrumb is unwound after the error
+        assert!(ar.property_path_mut().unwrap().is_empty());
+
+        // Package readers return errors with their context attached
+        let data = [PACKAGE_FILE_TAG.to_le_bytes(), (-7i32).to_le_bytes()].concat();
+        let mut ar = BinaryArchive::new(Cursor::new(data), EngineVersion::VerUe4_27);
+        ar.set_archive_name("Truncated.uasset");
+        let err = PackageHeader::from_archive(&mut ar).unwrap_err();
+        assert!(matches!(err.root(), UnrealAssetError::Io(_)));
+        assert_eq!(err.to_string(), "IO error: failed to fill whole buffer (offset 0x8 in Truncated.uasset, reading Summary)");
+        let report = err.report();
+        assert_eq!(report.kind, "Io");
+        assert_eq!(report.archive_name.as_deref(), Some("Truncated.uasset"));
+        assert_eq!(report.offset, Some(8));
+        assert_eq!(serde_json::to_value(&report.segments).unwrap(), serde_json::json!(["Summary"]));
+
+        // Without an archive name the offset and breadcrumb are still reported
+        let data = [PACKAGE_FILE_TAG.to_le_bytes(), 868u32.to_le_bytes()].concat();
+        let err = PackageHeader::read(Cursor::new(data), EngineVersion::VerUe4_27).unwrap_err();
+        assert!(matches!(err.root(), UnrealAssetError::UnsupportedVersion(_)));
+        assert_eq!(err.report().path, "Summary");
+        assert!(err.to_string().ends_with("(offset 0x8, reading Summary)"), "{}", err);
+    }
+
+    #[test]
+    fn test_big_endian_archive() {
+        use std::io::Cursor;
+
+        let guid = uuid::Uuid::from_u128(0x0123_4567_89AB_CDEF_0123_4567_89AB_CDEF);
+        let write = |endianness: Endianness| {
+            let mut w = BinaryArchiveWriter::new(Cursor::new(Vec::new()), EngineVersion::VerUe4_27);
+            w.set_endianness(endianness);
+            w.write_i32_endian(-2).unwrap();
+            w.write_u16_endian(0x1234).unwrap();
+            w.write_u64_endian(u64::MAX - 1).unwrap();
+            w.write_f32_endian(1.5).unwrap();
+            w.write_f64_endian(-0.25).unwrap();
+            w.write_fstring(Some("Ünïcode")).unwrap();
+            w.write_fname(&FName::with_number("Cube", 3)).unwrap();
+            w.write_guid(&guid).unwrap();
+            w.write_property("Health", &Property::Int32(100)).unwrap();
+            w.into_inner().into_inner()
+        };
+
+        let little = write(Endianness::Little);
+        let big = write(Endianness::Big);
+        assert_eq!(little.len(), big.len());
+        assert_eq!(&little[..4], &[0xFE, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(&big[..6], &[0xFF, 0xFF, 0xFF, 0xFE, 0x12, 0x34]);
+        assert_eq!(&big[big.len() - 4..], &100i32.to_be_bytes());
+
+        let mut ar = BinaryArchive::new(Cursor::new(big.clone()), EngineVersion::VerUe4_27);
+        ar.set_endianness(Endianness::Big);
+        ar.get_name_map().get_mut().add_name_reference("Cube".to_string(), false);
+        assert_eq!(ar.read_i32_endian().unwrap(), -2);
+        assert_eq!(ar.read_u16_endian().unwrap(), 0x1234);
+        assert_eq!(ar.read_u64_endian().unwrap(), u64::MAX - 1);
+        assert_eq!(ar.read_f32_endian().unwrap(), 1.5);
+        assert_eq!(ar.read_f64_endian().unwrap(), -0.25);
+        assert_eq!(ar.read_fstring().unwrap().as_deref(), Some("Ünïcode"));
+        assert_eq!(ar.read_fname().unwrap(), FName::with_number("Cube", 3));
+        assert_eq!(ar.read_guid().unwrap(), guid);
+
+        let mut cursor = Cursor::new(big);
+        cursor.set_position(4 + 2 + 8 + 4 + 8);
+        let value = BinaryArchive::<Cursor<Vec<u8>>>::read_fstring_static(&mut cursor, Endianness::Big).unwrap();
+        assert_eq!(value.as_deref(), Some("Ünïcode"));
+
+        assert_eq!(Endianness::from_package_tag(PACKAGE_FILE_TAG), Some(Endianness::Little));
+        assert_eq!(Endianness::from_package_tag(PACKAGE_FILE_TAG.swap_bytes()), Some(Endianness::Big));
+        assert_eq!(Endianness::from_package_tag(0), None);
+    }
+
+    #[test]
+    fn test_native_struct_lwc() {
+        use std::io::Cursor;
+
+        fn round_trip<T: NativeStruct + PartialEq + std::fmt::Debug>(value: &T, engine_version: EngineVersion) -> usize {
+            let mut w = BinaryArchiveWriter::new(Cursor::new(Vec::new()), engine_version);
+            value.write(&mut w).unwrap();
+            let data = w.into_inner().into_inner();
+            let mut ar = BinaryArchive::new(Cursor::new(data.clone()), engine_version);
+            assert_eq!(&T::read(&mut ar).unwrap(), value, "{}", T::STRUCT_NAME);
+            assert_eq!(ar.position(), data.len() as u64);
+            data.len()
+        }
+
+        let vector = Vector::new(1.5, -2.0, 4096.25);
+        let transform = Transform::new(vector.clone(), Quat::new(0.0, 0.0, 0.5, 0.5), Vector::new(1.0, 1.0, 2.0));
+        let bounds = BoundingBox::new(Vector::new(-1.0, -1.0, -1.0), vector.clone());
+        let mut matrix = Matrix::identity();
+        matrix.m[3] = [10.0, 20.0, 30.0, 1.0];
+
+        // Floats before large world coordinates, doubles after
+        for (engine_version, width) in [(EngineVersion::VerUe4_27, 4), (EngineVersion::VerUe5_3, 8)] {
+            assert_eq!(round_trip(&vector, engine_version), 3 * width);
+            assert_eq!(round_trip(&Vector2D::new(0.5, 8.0), engine_version), 2 * width);
+            assert_eq!(round_trip(&Vector4::new(1.0, 2.0, 3.0, 4.0), engine_version), 4 * width);
+            assert_eq!(round_trip(&Rotator::new(90.0, 45.0, -180.0), engine_version), 3 * width);
+            assert_eq!(round_trip(&Quat::identity(), engine_version), 4 * width);
+            assert_eq!(round_trip(&Plane::new(0.0, 0.0, 1.0, 100.0), engine_version), 4 * width);
+            assert_eq!(round_trip(&transform, engine_version), 10 * width);
+            assert_eq!(round_trip(&bounds, engine_version), 6 * width + 1);
+            assert_eq!(round_trip(&matrix, engine_version), 16 * width);
+
+            // Formats that do not depend on the version
+            assert_eq!(round_trip(&LinearColor::new(0.25, 0.5, 0.75, 1.0), engine_version), 16);
+            assert_eq!(round_trip(&Color::new(255, 128, 0, 64), engine_version), 4);
+            assert_eq!(round_trip(&IntPoint::new(-3, 7), engine_version), 8);
+            assert_eq!(round_trip(&[1u32, 2, 3, 4], engine_version), 16);
+        }
+
+        // Colors are stored as BGRA
+        let mut w = BinaryArchiveWriter::new(Cursor::new(Vec::new()), EngineVersion::VerUe5_3);
+        Color::new(1, 2, 3, 4).write(&mut w).unwrap();
+        assert_eq!(w.into_inner().into_inner(), vec![3, 2, 1, 4]);
+
+        // Tagged vectors follow the archive's version too
+        let mut w = BinaryArchiveWriter::new(Cursor::new(Vec::new()), EngineVersion::VerUe4_27);
+        let size = w.write_property("Location", &Property::Vector(vector)).unwrap();
+        assert_eq!(size, 12);
+    }
+
+    #[test]
+    fn test_text_histories() {
+        use indexmap::IndexMap;
+        use std::io::Cursor;
+
+        let name = FText::base("Items", "Sword_Name", "Sword");
+        let mut arguments = IndexMap::new();
+        arguments.insert("Item".to_string(), FormatArgumentValue::Text(Box::new(name.clone())));
+        arguments.insert("Count".to_string(), FormatArgumentValue::Int(1500));
+        let named = FText::new(TextFlags::empty(), TextHistory::NamedFormat {
+            source_format: Box::new(FText::base("UI", "Pickup", "Picked up {Count} x {Item}")),
+            arguments,
+        });
+        let ordered = FText::new(TextFlags::TRANSIENT, TextHistory::OrderedFormat {
+            source_format: Box::new(FText::culture_invariant("{0} / {1}")),
+            arguments: vec![FormatArgumentValue::Double(0.5), FormatArgumentValue::UInt(2)],
+        });
+        let percent = FText::new(TextFlags::empty(), TextHistory::AsPercent(NumberFormat {
+            source_value: FormatArgumentValue::Float(0.25),
+            format_options: Some(NumberFormattingOptions { always_sign: true, ..Default::default() }),
+            target_culture: None,
+        }));
+        let currency = FText::new(TextFlags::empty(), TextHistory::AsCurrency {
+            currency_code: Some("USD".to_string()),
+            format: NumberFormat { source_value: FormatArgumentValue::Int(1234), format_options: None, target_culture: None },
+        });
+        let date_time = FText::new(TextFlags::empty(), TextHistory::AsDateTime {
+            source_date_time: 638397614450000000,
+            date_style: DateTimeStyle::Short,
+            time_style: DateTimeStyle::Default,
+            time_zone: Some("UTC".to_string()),
+            target_culture: None,
+        });
+        let upper = FText::new(TextFlags::empty(), TextHistory::Transform {
+            source_text: Box::new(name.clone()),
+            transform_type: TextTransformType::ToUpper,
+        });
+        let table_entry = FText::string_table_entry(FName::new("/Game/ST_Items.ST_Items"), "Shield");
+        let generator = FText::new(TextFlags::empty(), TextHistory::TextGenerator {
+            generator_type: FName::new("MyGenerator"),
+            contents: vec![1, 2, 3],
+        });
+        let texts = [
+            FText::culture_invariant("Plain"), name.clone(), named.clone(), ordered.clone(), percent.clone(),
+            currency.clone(), date_time.clone(), upper.clone(), table_entry.clone(), generator,
+        ];
+
+        for engine_version in [EngineVersion::VerUe4_27, EngineVersion::VerUe5_3] {
+            let name_map = SharedResource::new(NameMap::new());
+            let mut writer = BinaryArchiveWriter::with_name_map(Cursor::new(Vec::new()), engine_version, name_map.clone());
+            for text in &texts {
+                text.write(&mut writer).unwrap();
+            }
+            writer.write_property("Name", &Property::Text(name.clone())).unwrap();
+            let bytes = writer.into_inner().into_inner();
+            let mut reader = BinaryArchive::with_name_map(Cursor::new(bytes), engine_version, name_map);
+            for text in &texts {
+                assert_eq!(&FText::read(&mut reader).unwrap(), text, "{:?}", engine_version);
+            }
+
+            // Tagged text properties carry the same payload
+            assert_eq!(reader.read_fname().unwrap().as_str(), "Name");
+            assert_eq!(reader.read_fname().unwrap().as_str(), "TextProperty");
+            reader.read_i64_endian().unwrap(); // size and array index
+            assert!(reader.read_property_guid().unwrap().is_none());
+            assert_eq!(FText::read(&mut reader).unwrap(), name);
+        }
+
+        // Rendering against localized strings and string tables
+        let mut localization = LocalizationTable::from_json(&serde_json::json!({
+            "Items": { "Sword_Name": "Schwert" },
+            "UI": { "Pickup": "{Item} x{Count} aufgehoben" }
+        }))
+        .unwrap();
+        let string_table = StringTable::from_json(&serde_json::json!({
+            "StringTable": { "TableNamespace": "ST_Items", "KeysToEntries": { "Shield": "Shield" } }
+        }))
+        .unwrap();
+        localization.add_string_table("/Game/ST_Items.ST_Items", string_table);
+        localization.insert("ST_Items", "Shield", "Schild");
+
+        assert_eq!(named.to_string(), "Picked up 1,500 x Sword");
+        assert_eq!(named.display_string(&localization), "Schwert x1,500 aufgehoben");
+        assert_eq!(ordered.to_string(), "0.5 / 2");
+        assert_eq!(percent.to_string(), "+25%");
+        assert_eq!(currency.to_string(), "USD 1,234.00");
+        assert_eq!(date_time.to_string(), "2024-01-02 03:04:05");
+        assert_eq!(upper.display_string(&localization), "SCHWERT");
+        assert_eq!(table_entry.to_string(), "<MISSING STRING TABLE ENTRY>");
+        assert_eq!(table_entry.display_string(&localization), "Schild");
+
+        // CUE4Parse JSON
+        let json = serde_json::json!({
+            "SourceFmt": { "Namespace": "UI", "Key": "Pickup", "SourceString": "Picked up {Count} x {Item}" },
+            "Arguments": {
+                "Item": { "Type": "Text", "Value": { "Namespace": "Items", "Key": "Sword_Name", "SourceString": "Sword" } },
+                "Count": { "Type": "Int", "Value": 1500 }
+            }
+        });
+        assert_eq!(FText::from_json(&json).unwrap(), named);
+        let json = serde_json::json!({ "TableId": "/Game/ST_Items.ST_Items", "Key": "Shield" });
+        assert_eq!(FText::from_json(&json).unwrap(), table_entry);
+        let json = serde_json::json!({ "SourceDateTime": { "Ticks": 638397614450000000i64 }, "DateStyle": "Short", "TimeStyle": 0, "TimeZone": "UTC" });
+        assert_eq!(FText::from_json(&json).unwrap(), date_time);
+        let json = serde_json::json!({ "SourceText": { "Namespace": "Items", "Key": "Sword_Name", "SourceString": "Sword" }, "TransformType": "ETextTransformType::ToUpper" });
+        assert_eq!(FText::from_json(&json).unwrap(), upper);
+        assert_eq!(FText::from_json(&serde_json::json!("Plain")).unwrap(), FText::culture_invariant("Plain"));
+    }
+
+    #[test]
+    fn test_property_aliases() {
+        use cue4parse_rs::cast;
+        use indexmap::IndexMap;
+
+        let mut fields = IndexMap::new();
+        fields.insert("Mesh".to_string(), Property::ObjectProperty(Some(PackageIndex::new(-1))));
+        fields.insert("Tags".to_string(), Property::ArrayProperty(vec![Property::UInt8(1), Property::Byte(2)]));
+        let aliased = Property::StructProperty { struct_type: FName::new("MyStruct"), properties: fields };
+
+        let mut canonical_fields = IndexMap::new();
+        canonical_fields.insert("Mesh".to_string(), Property::Object(Some(PackageIndex::new(-1))));
+        canonical_fields.insert("Tags".to_string(), Property::Array(vec![Property::Byte(1), Property::Byte(2)]));
+        let canonical = Property::Struct { struct_type: FName::new("MyStruct"), properties: canonical_fields };
+
+        // Aliases compare equal without normalizing
+        assert_eq!(aliased, canonical);
+        assert_ne!(aliased, Property::Struct { struct_type: FName::new("Other"), properties: IndexMap::new() });
+        assert_eq!(
+            Property::SoftAssetPathProperty(SoftObjectPath::new("/Game/A.A", "")),
+            Property::SoftObjectPath(SoftObjectPath::new("/Game/A.A", "")),
+        );
+        assert_ne!(Property::Object(None), Property::MaterialInterface(None));
+
+        // Normalizing rewrites nested aliases too
+        let normalized = aliased.clone().normalized();
+        let Property::Struct { properties, .. } = &normalized else {
+            panic!("Expected canonical struct, got {:?}", normalized);
+        };
+        assert!(matches!(properties["Mesh"], Property::Object(Some(_))));
+        assert!(matches!(&properties["Tags"], Property::Array(values) if matches!(values[0], Property::Byte(1))));
+
+        // cast! accepts either spelling
+        let array = Property::ArrayProperty(vec![Property::Int32(7)]);
+        assert_eq!(cast!(&array, Array).map(Vec::len), Some(1));
+        assert_eq!(cast!(&array, ArrayProperty).map(Vec::len), Some(1));
+        assert_eq!(cast!(Property::UInt8(5), Byte as i32), Some(5));
+        assert_eq!(cast!(&Property::Object(None), ObjectProperty), Some(&None));
+        assert!(cast!(&array, Set).is_none());
+        assert_eq!(cast!(Property::Int32(3), Int32), Some(3));
+    }
+
+    #[test]
+    fn test_property_paths() {
+        use indexmap::IndexMap;
+
+        fn component(x: f64) -> Property {
+            let mut fields = IndexMap::new();
+            fields.insert("RelativeLocation".to_string(), Property::Vector(Vector::new(x, 0.0, 0.0)));
+            Property::Struct { struct_type: FName::new("SceneComponent"), properties: fields }
+        }
+        let mut entry = IndexMap::new();
+        entry.insert("Value".to_string(), Property::Int32(10));
+        let mut export = Export::new(FName::new("Default__Actor"), PackageIndex::null());
+        export.properties.insert("Components".to_string(), Property::Array((0..3).map(|i| component(i as f64)).collect()));
+        export.properties.insert("Tags".to_string(), Property::Array(vec![Property::Name(FName::new("A")), Property::Name(FName::new("B"))]));
+        export.properties.insert("MyMap".to_string(), Property::Map {
+            key_type: "StrProperty".to_string(),
+            value_type: "StructProperty".to_string(),
+            entries: vec![(Property::String("Key".to_string()), Property::Struct { struct_type: FName::new("Entry"), properties: entry })],
+        });
+
+        // Queries
+        let x = export.get_path("Components[2].RelativeLocation.X").unwrap();
+        assert_eq!(x.len(), 1);
+        assert_eq!(*x[0], Property::Double(2.0));
+        assert_eq!(export.get_path("Tags[*]").unwrap().len(), 2);
+        assert_eq!(*export.get_path("MyMap[\"Key\"].Value").unwrap()[0], Property::Int32(10));
+        assert_eq!(export.get_path("Components[*].RelativeLocation.X").unwrap().len(), 3);
+
+        // Mutation
+        if let Property::Int32(value) = export.get_path_mut("MyMap[\"Key\"].Value").unwrap().remove(0) {
+            *value += 1;
+        }
+        assert_eq!(*export.get_path("MyMap.Key.Value").unwrap()[0], Property::Int32(11));
+        assert_eq!(export.set_path("Components[*].RelativeLocation.Z", Property::Double(5.0)).unwrap(), 3);
+        assert_eq!(*export.get_path("Components[1].RelativeLocation").unwrap()[0], Property::Vector(Vector::new(1.0, 0.0, 5.0)));
+        export.set_path("Tags[2]", Property::Name(FName::new("C"))).unwrap();
+        export.set_path("MyMap[\"Other Key\"]", Property::Int32(0)).unwrap();
+        assert_eq!(export.remove_path("Tags[0]").unwrap(), vec![Property::Name(FName::new("A"))]);
+        assert_eq!(export.get_path("Tags[*]").unwrap().len(), 2);
+        assert_eq!(export.remove_path("MyMap[*]").unwrap().len(), 2);
+
+        // Paths round-trip through their display form
+        let query = PropertyQuery::parse("Components[2][\"Odd.Name\"][*].X").unwrap();
+        assert_eq!(query.to_string(), "Components[2][\"Odd.Name\"][*].X");
+        assert_eq!(query.segments().len(), 5);
+        assert!(query.has_wildcard());
+
+        // Errors name the failing segment
+        let error = export.get_path("Components[7].RelativeLocation").unwrap_err();
+        assert_eq!(error.kind(), "InvalidPath");
+        assert!(error.to_string().contains("`Components[7]`"), "{}", error);
+        assert!(error.to_string().contains("out of bounds"), "{}", error);
+        let error = export.get_path("Components[0].RelativeLocation.Q").unwrap_err();
+        assert!(error.to_string().contains("`Components[0].RelativeLocation.Q`"), "{}", error);
+        assert!(export.get_path_mut("Components[0].RelativeLocation.X").is_err());
+        assert!(PropertyQuery::parse("Tags[x]").is_err());
+        assert!(PropertyQuery::parse("Tags[\"open").is_err());
+        // Multi-byte characters after a segment are reported whole
+        for (path, segment) in [("Tags[0]é", "Tags[0]é"), ("[\"a\"é]", "[\"a\"é"), ("Tags[*é]", "Tags[*é")] {
+            match PropertyQuery::parse(path) {
+                Err(UnrealAssetError::InvalidPath { segment: failed, .. }) => assert_eq!(failed, segment, "{}", path),
+                result => panic!("{}: {:?}", path, result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_reference_visitors() {
+        use indexmap::IndexMap;
+
+        let mut asset: Asset<std::io::Cursor<Vec<u8>>> = Asset::new();
+        let package = asset.find_or_add_import("/Script/CoreUObject", "Package", PackageIndex::null(), "/Game/Meshes/Cube");
+        let mesh = asset.find_or_add_import("/Script/Engine", "StaticMesh", package, "Cube");
+
+        let mut fields = IndexMap::new();
+        fields.insert("Material".to_string(), Property::MaterialInterface(Some(PackageIndex::new(1))));
+        fields.insert("Icon".to_string(), Property::SoftObjectPath(SoftObjectPath::new("/Game/UI/Icon.Icon", "")));
+        let mut export = Export::new(FName::new("Actor"), mesh);
+        export.properties.insert("Mesh".to_string(), Property::StaticMesh(Some(mesh)));
+        export.properties.insert("Nested".to_string(), Property::Array(vec![Property::Struct {
+            struct_type: FName::new("Slot"),
+            properties: fields,
+        }]));
+        export.properties.insert("Lookup".to_string(), Property::Map {
+            key_type: "ObjectProperty".to_string(),
+            value_type: "SoftClassProperty".to_string(),
+            entries: vec![(Property::Object(Some(package)), Property::SoftClassPath(SoftObjectPath::default()))],
+        });
+        export.properties.insert("OnHit".to_string(), Property::MulticastInlineDelegateProperty {
+            delegates: vec![Property::Delegate { object: Some(PackageIndex::new(1)), function_name: FName::new("Hit") }],
+        });
+        asset.asset_data.exports.push(export);
+
+        // Property references are collected in order, null soft paths skipped
+        let references = asset.asset_data.exports[0].properties.collect_references();
+        assert_eq!(references, vec![
+            PropertyReference::Package(mesh),
+            PropertyReference::Package(PackageIndex::new(1)),
+            PropertyReference::Soft(SoftObjectPath::new("/Game/UI/Icon.Icon", "")),
+            PropertyReference::Package(package),
+            PropertyReference::Package(PackageIndex::new(1)),
+        ]);
+        // Assets add the import and export tables
+        let references = asset.collect_references();
+        assert_eq!(references.first(), Some(&PropertyReference::Package(package)));
+        assert_eq!(references.len(), 7);
+
+        // Swap the two imports everywhere
+        let changed = asset.remap_package_indices(|index| match index {
+            index if index == package => mesh,
+            index if index == mesh => package,
+            index => index,
+        });
+        assert_eq!(changed, 4);
+        assert_eq!(asset.asset_data.imports[1].outer_index, mesh);
+        let export = &asset.asset_data.exports[0];
+        assert_eq!(export.class_index, package);
+        assert!(matches!(export.properties["Mesh"], Property::StaticMesh(Some(index)) if index == package));
+        assert_eq!(export.get_path("Lookup[*]").unwrap().len(), 1);
+
+        // Custom visitors only override what they need
+        struct SoftPathRenamer;
+        impl VisitMut for SoftPathRenamer {
+            fn visit_soft_object_path_mut(&mut self, path: &mut SoftObjectPath) {
+                if !path.is_null() {
+                    path.asset_path = FName::new(path.asset_path.as_str().replace("/Game/", "/MyMod/"));
+                }
+            }
+        }
+        asset.accept_mut(&mut SoftPathRenamer);
+        assert_eq!(
+            *asset.asset_data.exports[0].get_path("Nested[0].Icon").unwrap()[0],
+            Property::SoftObjectPath(SoftObjectPath::new("/MyMod/UI/Icon.Icon", "")),
+        );
+    }
+
+    #[test]
+    fn test_property_deserializer() {
+        use indexmap::IndexMap;
+        use serde::Deserialize;
+        use std::collections::HashMap;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        enum FireMode {
+            Single,
+            Burst,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "PascalCase")]
+        struct Recoil {
+            pitch: f32,
+        }
+
+        #[derive(Debug, Deserialize)]
+        #[serde(rename_all = "PascalCase")]
+        struct WeaponStats {
+            damage: f64,
+            magazine: i64,
+            fire_mode: FireMode,
+            display_name: String,
+            ammo_type: Option<u32>,
+            tags: Vec<String>,
+            multipliers: HashMap<String, f32>,
+            recoil: Recoil,
+        }
+
+        let mut recoil = IndexMap::new();
+        recoil.insert("Pitch".to_string(), Property::Float(1.5));
+        let mut properties = IndexMap::new();
+        properties.insert("Damage".to_string(), Property::Int32(40));
+        properties.insert("Magazine".to_string(), Property::UInt8(30));
+        properties.insert("FireMode".to_string(), Property::Enum { enum_type: FName::new("EFireMode"), value: FName::new("EFireMode::Burst") });
+        properties.insert("DisplayName".to_string(), Property::Name(FName::new("Rifle")));
+        properties.insert("Tags".to_string(), Property::Array(vec![Property::Name(FName::new("Auto")), Property::String("Heavy".to_string())]));
+        properties.insert("Multipliers".to_string(), Property::Map {
+            key_type: "NameProperty".to_string(),
+            value_type: "FloatProperty".to_string(),
+            entries: vec![(Property::Name(FName::new("Head")), Property::Float(2.0))],
+        });
+        properties.insert("Recoil".to_string(), Property::Struct { struct_type: FName::new("Recoil"), properties: recoil });
+
+        let stats: WeaponStats = from_properties(&properties).unwrap();
+        assert_eq!(stats.damage, 40.0);
+        assert_eq!(stats.magazine, 30);
+        assert_eq!(stats.fire_mode, FireMode::Burst);
+        assert_eq!(stats.display_name, "Rifle");
+        assert_eq!(stats.ammo_type, None);
+        assert_eq!(stats.tags, vec!["Auto", "Heavy"]);
+        assert_eq!(stats.multipliers["Head"], 2.0);
+        assert_eq!(stats.recoil, Recoil { pitch: 1.5 });
+        assert_eq!(from_property::<FireMode>(&Property::Byte(0)).unwrap(), FireMode::Single);
+        assert_eq!(from_property::<Vector>(&Property::Vector(Vector::new(1.0, 2.0, 3.0))).unwrap(), Vector::new(1.0, 2.0, 3.0));
+
+        // Out of range and mistyped values report where they failed
+        assert!(from_property::<u8>(&Property::Int32(300)).is_err());
+        properties.get_mut("Recoil").unwrap().set_path("Pitch", Property::String("high".to_string())).unwrap();
+        let error = from_properties::<WeaponStats>(&properties).unwrap_err();
+        assert!(error.to_string().contains("Recoil.Pitch: invalid type"), "{}", error);
+        match &error {
+            UnrealAssetError::Serde(serde_error) => assert_eq!(serde_error.path().to_string(), "Recoil.Pitch"),
+            other => panic!("{:?}", other),
+        }
+        assert_eq!(error.report().path, "Recoil.Pitch");
+
+        // Null soft paths are `None`
+        let null_asset = Property::AssetObjectProperty(SoftObjectPath::new("", ""));
+        assert_eq!(from_property::<Option<String>>(&null_asset).unwrap(), None);
+    }
+
+    #[test]
+    fn test_property_serializer() {
+        use serde::{Deserialize, Serialize};
+
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        enum Rarity {
+            Common,
+            Epic,
+        }
+
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        #[serde(rename_all = "PascalCase")]
+        struct ItemRow {
+            id: String,
+            stack_size: u32,
+            weight: f32,
+            rarity: Rarity,
+            icon: Option<String>,
+            offset: Vector,
+            tags: Vec<String>,
+        }
+
+        let row = ItemRow {
+            id: "Sword".to_string(),
+            stack_size: 1,
+            weight: 2.5,
+            rarity: Rarity::Epic,
+            icon: None,
+            offset: Vector::new(1.0, 2.0, 3.0),
+            tags: vec!["Melee".to_string()],
+        };
+
+        // Types follow the Rust types, and the result reads back
+        let properties = to_properties(&row).unwrap();
+        assert_eq!(properties["StackSize"], Property::UInt32(1));
+        assert_eq!(properties["Rarity"], Property::Enum { enum_type: FName::new("Rarity"), value: FName::new("Rarity::Epic") });
+        assert_eq!(properties["Offset"], Property::Vector(Vector::new(1.0, 2.0, 3.0)));
+        assert!(!properties.contains_key("Icon"));
+        assert_eq!(from_properties::<ItemRow>(&properties).unwrap(), row);
+
+        // Mappings pick the exact engine types
+        let mut mappings = Usmap::default();
+        mappings.enums.insert("ERarity".to_string(), vec!["ERarity::Common".to_string(), "ERarity::Epic".to_string()]);
+        let field = |name: &str, property_type: PropertyType| UsmapProperty {
+            name: name.to_string(),
+            schema_index: 0,
+            array_size: 1,
+            property_type,
+        };
+        mappings.schemas.insert("ItemRow".to_string(), UsmapSchema {
+            name: "ItemRow".to_string(),
+            super_type: None,
+            property_count: 4,
+            properties: vec![
+                field("Id", PropertyType::new("NameProperty")),
+                field("StackSize", PropertyType::new("IntProperty")),
+                field("Rarity", PropertyType { enum_name: Some("ERarity".to_string()), ..PropertyType::new("EnumProperty") }),
+                field("Tags", PropertyType::container("ArrayProperty", PropertyType::new("NameProperty"))),
+            ],
+        });
+        let typed = to_properties_with_mappings(&row, &mappings, "ItemRow").unwrap();
+        assert_eq!(typed["Id"], Property::Name(FName::new("Sword")));
+        assert_eq!(typed["StackSize"], Property::Int32(1));
+        assert_eq!(typed["Weight"], Property::Float(2.5));
+        assert_eq!(typed["Rarity"], Property::Enum { enum_type: FName::new("ERarity"), value: FName::new("ERarity::Epic") });
+        assert_eq!(typed["Tags"], Property::Array(vec![Property::Name(FName::new("Melee"))]));
+        assert_eq!(from_properties::<ItemRow>(&typed).unwrap(), row);
+        assert!(to_property_typed(&300u32, &PropertyType::new("Int8Property"), None).is_err());
+        let error = to_properties_with_mappings(&ItemRow { stack_size: u32::MAX, ..row }, &mappings, "ItemRow").unwrap_err();
+        assert!(error.to_string().contains("StackSize: 4294967295 is out of range for IntProperty"), "{}", error);
+
+        // Empty typed maps declare plain type names, which the property writer needs for the tag
+        let empty = std::collections::BTreeMap::<String, Vector>::new();
+        let map_type = PropertyType::map(PropertyType::new("NameProperty"), PropertyType::struct_of("Vector"));
+        let property = to_property_typed(&empty, &map_type, None).unwrap();
+        assert_eq!(
+            property,
+            Property::Map { key_type: "NameProperty".to_string(), value_type: "StructProperty".to_string(), entries: vec![] }
+        );
+        let mut w = BinaryArchiveWriter::new(std::io::Cursor::new(Vec::new()), EngineVersion::VerUe4_27);
+        w.write_property("Resistances", &property).unwrap();
+    }
+
+    #[test]
+    fn test_usmap_codegen() {
+        let mut mappings = Usmap::default();
+        mappings.enums.insert("ERarity".to_string(), vec!["ERarity::Common".to_string(), "ERarity::Epic".to_string()]);
+        mappings.enums.insert("EUnused".to_string(), vec!["Value".to_string()]);
+        let field = |name: &str, property_type: PropertyType| UsmapProperty {
+            name: name.to_string(),
+            schema_index: 0,
+            array_size: 1,
+            property_type,
+        };
+        mappings.schemas.insert("TableRowBase".to_string(), UsmapSchema {
+            name: "TableRowBase".to_string(),
+            super_type: None,
+            property_count: 0,
+            properties: vec![],
+        });
+        mappings.schemas.insert("ItemRow".to_string(), UsmapSchema {
+            name: "ItemRow".to_string(),
+            super_type: Some("TableRowBase".to_string()),
+            property_count: 5,
+            properties: vec![
+                field("bIsStackable", PropertyType::new("BoolProperty")),
+                field("MaxHP", PropertyType::new("IntProperty")),
+                field("Rarity", PropertyType { enum_name: Some("ERarity".to_string()), ..PropertyType::new("EnumProperty") }),
+                field("Offset", PropertyType::struct_of("Vector")),
+                field("type", PropertyType::container("ArrayProperty", PropertyType::new("NameProperty"))),
+            ],
+        });
+
+        // The checked-in fixture is compiled below, so the generated code has to build
+        let source = RustGenerator::new(&mappings).with_types(["ItemRow"]).generate();
+        assert_eq!(source, include_str!("fixtures/item_row.rs"));
+        assert!(!source.contains("EUnused"));
+        assert!(generate_rust(&mappings).contains("pub enum EUnused"));
+
+        mod generated {
+            include!("fixtures/item_row.rs");
+        }
+        use generated::{ERarity, ItemRow};
+
+        let row = ItemRow {
+            is_stackable: true,
+            max_hp: 250,
+            rarity: ERarity::Epic,
+            offset: Vector::new(1.0, 0.0, 0.0),
+            r#type: vec!["Potion".to_string()],
+        };
+        let properties = row.to_properties_with_mappings(&mappings).unwrap();
+        assert_eq!(properties["bIsStackable"], Property::Bool(true));
+        assert_eq!(properties["MaxHP"], Property::Int32(250));
+        assert_eq!(properties["Rarity"], Property::Enum { enum_type: FName::new("ERarity"), value: FName::new("ERarity::Epic") });
+        assert_eq!(properties["Offset"], Property::Vector(Vector::new(1.0, 0.0, 0.0)));
+        assert_eq!(properties["type"], Property::Array(vec![Property::Name(FName::new("Potion"))]));
+        assert_eq!(ItemRow::from_properties(&properties).unwrap(), row);
+
+        // Missing fields take their defaults
+        let mut partial = properties.clone();
+        partial.retain(|name, _| name == "MaxHP");
+        assert_eq!(ItemRow::from_properties(&partial).unwrap(), ItemRow { max_hp: 250, ..ItemRow::default() });
+
+        let help = std::process::Command::new(env!("CARGO_BIN_EXE_usmap-codegen")).arg("--help").output().unwrap();
+        assert!(help.status.success());
+        assert!(String::from_utf8_lossy(&help.stdout).starts_with("usage: usmap-codegen"));
+        let missing = std::process::Command::new(env!("CARGO_BIN_EXE_usmap-codegen")).output().unwrap();
+        assert!(!missing.status.success());
+    }
+
+    #[test]
+    fn test_typed_exports() {
+        use serde_json::json;
+
+        let exports = json!([
+            {
+                "Type": "Texture2D",
+                "Name": "T_Logo",
+                "Properties": { "LODGroup": "TextureGroup::TEXTUREGROUP_UI", "SRGB": false },
+                "SizeX": 256,
+                "SizeY": 128,
+                "PixelFormat": "EPixelFormat::PF_DXT5",
+                "Mips": [{ "SizeX": 256, "SizeY": 128, "SizeZ": 1, "BulkData": { "ElementCount": 32768 } }]
+            },
+            {
+                "Type": "StaticMesh",
+                "Name": "SM_Cube",
+                "Properties": {
+                    "StaticMaterials": [{
+                        "MaterialSlotName": "Body",
+                        "MaterialInterface": { "ObjectName": "MaterialInstanceConstant'MI_Cube'", "ObjectPath": "/Game/Materials/MI_Cube.0" }
+                    }, {
+                        "MaterialSlotName": "Trim",
+                        "MaterialInterface": {
+                            "ObjectName": "MaterialInstanceDynamic'SM_Cube:Overrides.MID_Trim'",
+                            "ObjectPath": "/Game/Meshes/SM_Cube.3"
+                        }
+                    }]
+                },
+                "RenderData": {
+                    "LODs": [{}, {}],
+                    "Bounds": { "Origin": { "X": 0, "Y": 0, "Z": 50 }, "BoxExtent": { "X": 50, "Y": 50, "Z": 50 }, "SphereRadius": 86.6 }
+                }
+            },
+            {
+                "Type": "MaterialInstanceConstant",
+                "Name": "MI_Cube",
+                "Properties": {
+                    "Parent": { "ObjectName": "Material'M_Base'", "ObjectPath": "/Game/Materials/M_Base.0" },
+                    "ScalarParameterValues": [{ "ParameterInfo": { "Name": "Roughness" }, "ParameterValue": 0.5 }],
+                    "VectorParameterValues": [{ "ParameterInfo": { "Name": "Tint" }, "ParameterValue": { "R": 1, "G": 0, "B": 0, "A": 1 } }]
+                }
+            },
+            {
+                "Type": "StringTable",
+                "Name": "ST_Items",
+                "StringTable": { "TableNamespace": "Items", "KeysToEntries": { "Sword": "Sword of Dawn" } },
+                "StringTableId": "/Game/Text/ST_Items.ST_Items"
+            },
+            { "Type": "BlueprintGeneratedClass", "Name": "BP_Door_C", "SuperStruct": { "ObjectName": "Class'Actor'", "ObjectPath": "/Script/Engine" },
+              "FuncMap": { "Open": {}, "Close": {} }, "ChildProperties": [{ "Type": "BoolProperty", "Name": "bLocked" }] },
+            { "Type": "World", "Name": "Map" }
+        ]);
+
+        let typed: Vec<TypedExport> = TypedExport::all_from_json(&exports).into_iter().collect::<Result<_, _>>().unwrap();
+        let TypedExport::Texture2D(texture) = &typed[0] else { panic!("expected a texture") };
+        assert_eq!((texture.size_x, texture.size_y), (Some(256), Some(128)));
+        assert_eq!(texture.pixel_format.as_deref(), Some("PF_DXT5"));
+        assert_eq!(texture.lod_group.as_deref(), Some("TEXTUREGROUP_UI"));
+        assert_eq!(texture.srgb, Some(false));
+        assert_eq!(texture.mips[0].data_size, Some(32768));
+
+        let TypedExport::StaticMesh(mesh) = &typed[1] else { panic!("expected a static mesh") };
+        assert_eq!(mesh.lod_count, Some(2));
+        assert_eq!(mesh.materials[0].slot_name.as_deref(), Some("Body"));
+        assert_eq!(mesh.materials[0].material.as_ref().unwrap().to_string(), "MaterialInstanceConstant'/Game/Materials/MI_Cube.MI_Cube'");
+        // Subobjects keep their outer chain
+        assert_eq!(mesh.materials[1].material.as_ref().unwrap().path, "/Game/Meshes/SM_Cube.SM_Cube:Overrides.MID_Trim");
+        assert_eq!(mesh.bounds.as_ref().unwrap().box_extent, Vector::new(50.0, 50.0, 50.0));
+
+        let TypedExport::MaterialInstanceConstant(material) = &typed[2] else { panic!("expected a material instance") };
+        assert_eq!(material.parent.as_ref().unwrap().path, "/Game/Materials/M_Base.M_Base");
+        assert_eq!(material.scalar_parameters["Roughness"], 0.5);
+        assert_eq!(material.vector_parameters["Tint"], LinearColor::new(1.0, 0.0, 0.0, 1.0));
+
+        let TypedExport::StringTable(table) = &typed[3] else { panic!("expected a string table") };
+        assert_eq!(table.table.entries["Sword"], "Sword of Dawn");
+        assert_eq!(table.table_id.as_deref(), Some("/Game/Text/ST_Items.ST_Items"));
+
+        let TypedExport::Blueprint(blueprint) = &typed[4] else { panic!("expected a blueprint") };
+        assert_eq!(blueprint.functions.len(), 2);
+        assert!(blueprint.functions.contains(&"Open".to_string()));
+        assert_eq!(blueprint.variables[0].name, "bLocked");
+        assert_eq!(blueprint.parent_class.as_ref().unwrap().class_name, "Class");
+
+        assert!(matches!(&typed[5], TypedExport::Other { class_name, .. } if class_name == "World"));
+        assert_eq!(typed[5].name(), "Map");
+        assert!(TypedExport::from_json(&json!(42)).is_err());
+    }
+
+    #[test]
+    fn test_data_table() {
+        use serde::Deserialize;
+        use serde_json::json;
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "PascalCase")]
+        struct Stats {
+            damage: i32,
+            speed: f64,
+        }
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        #[serde(rename_all = "PascalCase")]
+        struct WeaponRow {
+            display_name: String,
+            stats: Stats,
+            tags: Vec<String>,
+        }
+
+        let stats_types = json!({ "Damage": "IntProperty", "Speed": "FloatProperty" });
+        let export = r#"{
+            "Type": "DataTable",
+            "Name": "DT_Weapons",
+            "Properties": {
+                "RowStruct": { "ObjectName": "ScriptStruct'WeaponRow'", "ObjectPath": "/Script/Game.0" }
+            },
+            "Rows": {
+                "Sword": {
+                    "$types": { "Tags": "ArrayProperty<StrProperty>", "DisplayName": "StrProperty", "Stats": "StructProperty<WeaponStats>" },
+                    "Tags": ["Melee", "Blade"],
+                    "DisplayName": "Sword",
+                    "Stats": { "$types": { "Damage": "IntProperty", "Speed": "FloatProperty" }, "Damage": 25, "Speed": 1.5 }
+                },
+                "Axe": {
+                    "$types": { "Tags": "ArrayProperty<StrProperty>", "DisplayName": "StrProperty", "Stats": "StructProperty<WeaponStats>" },
+                    "Tags": ["Melee"],
+                    "DisplayName": "Axe, Heavy",
+                    "Stats": { "$types": { "Damage": "IntProperty", "Speed": "FloatProperty" }, "Damage": 40, "Speed": 0.5 }
+                }
+            }
+        }"#;
+
+        // Views deserialized from the export text keep its row and field order
+        let view: DataTableView = serde_json::from_str(export).unwrap();
+        let table = DataTable::from_view(&view, None).unwrap();
+        assert_eq!(table.row_struct.as_deref(), Some("WeaponRow"));
+        assert_eq!(table.len(), 2);
+        let unordered = DataTable::from_json(&serde_json::from_str(export).unwrap(), None).unwrap();
+        assert_eq!(unordered.rows, table.rows);
+        assert_eq!(table.row_names().map(|name| name.as_str()).collect::<Vec<_>>(), ["Sword", "Axe"]);
+        assert_eq!(table.row("Sword").unwrap().keys().collect::<Vec<_>>(), ["Tags", "DisplayName", "Stats"]);
+        assert_eq!(table.cell("Sword", "DisplayName"), Some(&Property::String("Sword".to_string())));
+        let sword: WeaponRow = table.row_as("Sword").unwrap();
+        assert_eq!(sword.stats, Stats { damage: 25, speed: 1.5 });
+        assert_eq!(sword.tags, vec!["Melee", "Blade"]);
+        assert!(table.row_as::<WeaponRow>("Bow").is_err());
+
+        // Nested structs and arrays flatten into their own columns
+        let csv = table.to_csv(&CsvOptions::default());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "Name,Tags[0],Tags[1],DisplayName,Stats.Damage,Stats.Speed");
+        assert_eq!(lines[1], "Sword,Melee,Blade,Sword,25,1.5");
+        assert_eq!(lines[2], "Axe,Melee,,\"Axe, Heavy\",40,0.5");
+
+        let joined = table.to_csv(&CsvOptions { delimiter: ';', array_separator: Some("|".to_string()), ..Default::default() });
+        assert_eq!(joined.lines().nth(1), Some("Sword;Melee|Blade;Sword;25;1.5"));
+
+        // Newline-delimited JSON keeps table and field order
+        let mut ndjson = Vec::new();
+        table.write_ndjson(&mut ndjson).unwrap();
+        let ndjson = String::from_utf8(ndjson).unwrap();
+        assert_eq!(
+            ndjson.lines().next(),
+            Some(r#"{"Name":"Sword","Tags":["Melee","Blade"],"DisplayName":"Sword","Stats":{"Damage":25,"Speed":1.5}}"#)
+        );
+        assert_eq!(table.to_json()["Sword"]["Stats"]["Damage"], 25);
+        let mut json = Vec::new();
+        table.write_json(&mut json, false).unwrap();
+        assert!(String::from_utf8(json).unwrap().starts_with(r#"{"Sword":{"Tags":["Melee","Blade"],"DisplayName":"Sword","#));
+
+        // Integer map keys flatten to index columns, which resolve as queries
+        let mut resists = DataTable::new("ArmorRow");
+        let resist = Property::Map {
+            key_type: "IntProperty".to_string(),
+            value_type: "FloatProperty".to_string(),
+            entries: vec![(Property::Int32(5), Property::Float(0.5)), (Property::Int32(-1), Property::Float(0.25))],
+        };
+        resists.insert_row("Plate", indexmap::IndexMap::from([("Resist".to_string(), resist)]));
+        let csv = resists.to_csv(&CsvOptions::default());
+        assert_eq!(csv.lines().next(), Some("Name,Resist[5],Resist.-1"));
+        assert_eq!(*resists.row("Plate").unwrap().get_path("Resist[5]").unwrap()[0], Property::Float(0.5));
+
+        // Balance changes between two versions of the table
+        let mut patched = table.clone();
+        *patched.cell_mut("Sword", "Stats").unwrap() =
+            Asset::json_to_property(&json!({ "$types": stats_types, "Damage": 30, "Speed": 1.5 }), "StructProperty<WeaponStats>").unwrap();
+        patched.remove_row("Axe");
+        patched.insert_row("Bow", table.row("Sword").unwrap().clone());
+        let diff = table.diff(&patched);
+        assert_eq!(diff.added, vec![FName::new("Bow")]);
+        assert_eq!(diff.removed, vec![FName::new("Axe")]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].changes[0].column, "Stats.Damage");
+        assert_eq!(diff.to_string(), "+ Bow\n- Axe\n~ Sword.Stats.Damage: 25 -> 30\n");
+        assert!(table.diff(&table).is_empty());
+    }
+
+    #[test]
+    fn test_curve_evaluation() {
+        use serde_json::json;
+
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-4;
+
+        // Linear keys with every kind of infinity extrapolation
+        let keys = vec![RichCurveKey::new(0.0, 0.0), RichCurveKey::new(1.0, 10.0), RichCurveKey::new(2.0, 0.0)];
+        let curve = RichCurve::new(keys.clone());
+        assert_eq!(curve.eval(0.5), 5.0);
+        assert_eq!(curve.eval(1.5), 5.0);
+        assert_eq!(curve.eval(-1.0), 0.0);
+        assert_eq!(curve.eval(3.0), 0.0);
+
+        let linear = curve.clone().with_extrapolation(RichCurveExtrapolation::Linear, RichCurveExtrapolation::Linear);
+        assert_eq!(linear.eval(-1.0), -10.0);
+        assert_eq!(linear.eval(3.0), -10.0);
+
+        let cycle = curve.clone().with_extrapolation(RichCurveExtrapolation::Cycle, RichCurveExtrapolation::Cycle);
+        assert_eq!(cycle.eval(2.5), 5.0);
+        assert_eq!(cycle.eval(-1.5), 5.0);
+
+        let ramp = RichCurve::new(keys[..2].to_vec());
+        let offset = ramp.clone().with_extrapolation(RichCurveExtrapolation::CycleWithOffset, RichCurveExtrapolation::CycleWithOffset);
+        assert_eq!(offset.eval(1.5), 15.0);
+        assert_eq!(offset.eval(-0.5), -5.0);
+        let oscillate = ramp.with_extrapolation(RichCurveExtrapolation::Oscillate, RichCurveExtrapolation::Oscillate);
+        assert_eq!(oscillate.eval(1.25), 7.5);
+        assert_eq!(oscillate.eval(2.25), 2.5);
+
+        // Constant, cubic and weighted cubic segments
+        let constant = RichCurve::new(vec![
+            RichCurveKey::new(0.0, 1.0).with_interp_mode(RichCurveInterpMode::Constant),
+            RichCurveKey::new(1.0, 2.0),
+        ]);
+        assert_eq!(constant.eval(0.99), 1.0);
+
+        let cubic_key = |time, value| RichCurveKey::new(time, value).with_interp_mode(RichCurveInterpMode::Cubic);
+        let cubic = RichCurve::new(vec![cubic_key(0.0, 0.0), cubic_key(1.0, 1.0)]);
+        assert!(close(cubic.eval(0.25), 0.15625));
+        assert!(close(cubic.eval(0.5), 0.5));
+        let sloped = RichCurve::new(vec![cubic_key(0.0, 0.0).with_tangents(0.0, 1.0), cubic_key(1.0, 1.0).with_tangents(1.0, 0.0)]);
+        assert!(close(sloped.eval(0.25), 0.25));
+
+        let weighted = |weight| {
+            RichCurve::new(vec![
+                cubic_key(0.0, 0.0).with_tangent_weights(RichCurveTangentWeightMode::WeightedBoth, weight, weight),
+                cubic_key(1.0, 1.0).with_tangent_weights(RichCurveTangentWeightMode::WeightedBoth, weight, weight),
+            ])
+        };
+        // Weights of a third of the segment are the unweighted handles
+        assert!(close(weighted(1.0 / 3.0).eval(0.25), 0.15625));
+        assert!(close(weighted(0.5).eval(0.5), 0.5));
+        assert!(weighted(0.5).eval(0.25) < cubic.eval(0.25));
+
+        // Default values
+        assert_eq!(RichCurve::default().eval(1.0), 0.0);
+        assert_eq!(RichCurve::default().eval_or(1.0, 7.0), 7.0);
+        assert_eq!(RichCurve::default().with_default_value(3.0).eval_or(1.0, 7.0), 3.0);
+
+        // Simple curves share one interpolation mode
+        let simple_keys = vec![SimpleCurveKey::new(1.0, 100.0), SimpleCurveKey::new(3.0, 300.0)];
+        assert_eq!(SimpleCurve::new(RichCurveInterpMode::Linear, simple_keys.clone()).eval(2.0), 200.0);
+        assert_eq!(SimpleCurve::new(RichCurveInterpMode::Cubic, simple_keys.clone()).eval(2.0), 200.0);
+        assert_eq!(SimpleCurve::new(RichCurveInterpMode::Constant, simple_keys).eval(2.0), 100.0);
+
+        // Curve tables as CUE4Parse writes them
+        let view: CurveTableView = serde_json::from_str(r#"{
+            "Type": "CurveTable",
+            "Name": "CT_Damage",
+            "CurveTableMode": "ECurveTableMode::RichCurves",
+            "Rows": {
+                "Damage": {
+                    "Keys": [
+                        { "InterpMode": "ERichCurveInterpMode::RCIM_Linear", "Time": 1, "Value": 10 },
+                        { "InterpMode": 0, "Time": 10, "Value": 100 }
+                    ],
+                    "DefaultValue": 3.4028234663852886e38,
+                    "PreInfinityExtrap": "RCCE_Constant",
+                    "PostInfinityExtrap": "ERichCurveExtrapolation::RCCE_Linear"
+                },
+                "Armor": { "Keys": [{ "InterpMode": "RCIM_Constant", "Time": 0, "Value": 1 }] }
+            }
+        }"#)
+        .unwrap();
+        let table = CurveTable::from_view(&view).unwrap();
+        assert_eq!(table.mode, CurveTableMode::RichCurves);
+        assert_eq!(table.rows.keys().map(|name| name.as_str()).collect::<Vec<_>>(), ["Damage", "Armor"]);
+        assert_eq!(table.eval("Armor", 5.0), Some(1.0));
+        assert_eq!(table.eval("Damage", 5.0), Some(50.0));
+        assert_eq!(table.eval("Damage", 20.0), Some(200.0));
+        assert_eq!(table.eval("Missing", 1.0), None);
+
+        let simple = CurveTable::from_json(&json!({
+            "Type": "CurveTable",
+            "CurveTableMode": "SimpleCurves",
+            "Rows": { "Cost": { "InterpMode": "RCIM_Constant", "Keys": [{ "Time": 0, "Value": 5 }, { "Time": 2, "Value": 9 }] } }
+        }))
+        .unwrap();
+        assert!(matches!(simple.row("Cost"), Some(Curve::Simple(_))));
+        assert_eq!(simple.eval("Cost", 1.0), Some(5.0));
+        assert!(CurveTable::from_json(&json!({ "CurveTableMode": "RichCurves", "Rows": { "Bad": { "Keys": [{ "InterpMode": "RCIM_Spline" }] } } })).is_err());
+
+        // Curve assets
+        let exports = json!([
+            { "Type": "CurveFloat", "Name": "C_Falloff", "Properties": { "FloatCurve": { "Keys": [{ "Time": 0, "Value": 1 }, { "Time": 1, "Value": 0 }] } } },
+            {
+                "Type": "CurveLinearColor",
+                "Name": "C_Fade",
+                "Properties": {
+                    "FloatCurves": { "Keys": [{ "Time": 0, "Value": 1 }] },
+                    "FloatCurves[2]": { "Keys": [{ "Time": 0, "Value": 0 }, { "Time": 1, "Value": 1 }] }
+                }
+            },
+            { "Type": "CurveVector", "Name": "C_Path", "Properties": { "FloatCurves": [{ "Keys": [{ "Time": 0, "Value": 2 }] }, {}, { "DefaultValue": 4 }] } }
+        ]);
+        let exports: Vec<TypedExport> = TypedExport::all_from_json(&exports).into_iter().map(Result::unwrap).collect();
+        let TypedExport::CurveFloat(falloff) = &exports[0] else { panic!("expected a CurveFloat") };
+        assert_eq!(falloff.eval(0.25), 0.75);
+        let TypedExport::CurveLinearColor(fade) = &exports[1] else { panic!("expected a CurveLinearColor") };
+        assert_eq!(fade.eval(0.5), LinearColor::new(1.0, 0.0, 0.5, 1.0));
+        let TypedExport::CurveVector(path) = &exports[2] else { panic!("expected a CurveVector") };
+        assert_eq!(path.eval(1.0), Vector::new(2.0, 0.0, 4.0));
+        assert_eq!(exports[2].name(), "C_Path");
+
+        // Curves inside properties read through serde
+        let key_types = json!({ "InterpMode": "EnumProperty<ERichCurveInterpMode>", "Time": "FloatProperty", "Value": "FloatProperty" });
+        let keys = json!([
+            { "$types": key_types, "InterpMode": "RCIM_Cubic", "Time": 0.0, "Value": 0.0 },
+            { "$types": key_types, "Time": 1.0, "Value": 1.0 }
+        ]);
+        let property = Asset::json_to_property(
+            &json!({ "$types": { "Keys": "ArrayProperty<StructProperty<RichCurveKey>>" }, "Keys": keys }),
+            "StructProperty<RichCurve>",
+        )
+        .unwrap();
+        let curve: RichCurve = from_property(&property).unwrap();
+        assert_eq!(curve.keys[0].interp_mode, RichCurveInterpMode::Cubic);
+        assert!(close(curve.eval(0.25), 0.15625));
+    }
+
+    #[test]
+    fn test_property_writer_array_tags() {
+        use byteorder::{LittleEndian, ReadBytesExt};
+        use indexmap::IndexMap;
+        use std::io::Cursor;
+
+        fn assert_send<T: Send + Sync>() {}
+        assert_send::<SharedResource<NameMap>>();
+
+        let name_map = SharedResource::new(NameMap::new());
+        let overrides = IndexMap::from([("Spawns".to_string(), "SpawnInfo".to_string())]);
+        let mut writer = BinaryArchiveWriter::with_name_map(Cursor::new(Vec::new()), EngineVersion::VerUe4_27, name_map.clone())
+            .with_array_struct_type_override(overrides);
+
+        let mut point = IndexMap::new();
+        point.insert("Weight".to_string(), Property::Int32(3));
+        let mut properties = IndexMap::new();
+        properties.insert(
+            "Points".to_string(),
+            Property::Array(vec![Property::Struct { struct_type: FName::new("SpawnPoint"), properties: point }]),
+        );
+        properties.insert("Spawns".to_string(), Property::Array(Vec::new()));
+        properties.insert("Tags".to_string(), Property::Array(Vec::new()));
+        writer.write_properties(&properties).unwrap();
+
+        let bytes = writer.into_inner().into_inner();
+        let mut reader = BinaryArchive::with_name_map(Cursor::new(bytes), EngineVersion::VerUe4_27, name_map);
+        let read_header = |reader: &mut BinaryArchive<Cursor<Vec<u8>>>, name: &str, inner: &str| {
+            assert_eq!(reader.read_fname().unwrap().as_str(), name);
+            assert_eq!(reader.read_fname().unwrap().as_str(), "ArrayProperty");
+            let size = reader.read_i32::<LittleEndian>().unwrap();
+            assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+            assert_eq!(reader.read_fname().unwrap().as_str(), inner);
+            assert!(reader.read_property_guid().unwrap().is_none());
+            size
+        };
+
+        // The element tag of a struct array is named after the array
+        read_header(&mut reader, "Points", "StructProperty");
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 1);
+        assert_eq!(reader.read_fname().unwrap().as_str(), "Points");
+        assert_eq!(reader.read_fname().unwrap().as_str(), "StructProperty");
+        reader.read_i32::<LittleEndian>().unwrap();
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert_eq!(reader.read_fname().unwrap().as_str(), "SpawnPoint");
+        let mut guid = [0u8; 16];
+        std::io::Read::read_exact(&mut reader, &mut guid).unwrap();
+        assert!(reader.read_property_guid().unwrap().is_none());
+        assert_eq!(reader.read_fname().unwrap().as_str(), "Weight");
+        reader.read_fname().unwrap();
+        let mut rest = [0u8; 8];
+        std::io::Read::read_exact(&mut reader, &mut rest).unwrap();
+        assert!(reader.read_property_guid().unwrap().is_none());
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 3);
+        assert_eq!(reader.read_fname().unwrap().as_str(), "None");
+
+        // Empty arrays take their struct from the overrides, or leave the element type unknown
+        read_header(&mut reader, "Spawns", "StructProperty");
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert_eq!(reader.read_fname().unwrap().as_str(), "Spawns");
+        assert_eq!(reader.read_fname().unwrap().as_str(), "StructProperty");
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert_eq!(reader.read_fname().unwrap().as_str(), "SpawnInfo");
+        std::io::Read::read_exact(&mut reader, &mut guid).unwrap();
+        assert!(reader.read_property_guid().unwrap().is_none());
+        assert_eq!(read_header(&mut reader, "Tags", "None"), 4);
+        assert_eq!(reader.read_i32::<LittleEndian>().unwrap(), 0);
+        assert_eq!(reader.read_fname().unwrap().as_str(), "None");
+    }
+
+    #[test]
+    fn test_asset_references_match_full_package_path() {
+        use cue4parse_rs::{ExportInfo, GameVersion, PackageInfo};
+
+        let package = PackageInfo {
+            name: "/Game/A/Foo".to_string(),
+            exports: vec![ExportInfo { name: "Foo".to_string(), class_name: "StaticMesh".to_string(), outer_index: 0 }],
+        };
+        let objects = json!([{
+            "Type": "StaticMesh",
+            "Name": "Foo",
+            "$types": { "Own": "ObjectProperty", "Other": "ObjectProperty" },
+            "Properties": {
+                "Own": { "ObjectName": "StaticMesh'Foo'", "ObjectPath": "/Game/A/Foo.0" },
+                "Other": { "ObjectName": "StaticMesh'Foo'", "ObjectPath": "/Game/B/Foo.0" }
+            }
+        }]);
+
+        let asset = Asset::from_cue4parse_json(&package, &objects, GameVersion::UE5_1);
+        assert_eq!(asset.asset_data.package_name, "/Game/A/Foo");
+        let export = asset.get_main_export().unwrap();
+        assert!(matches!(export.properties.get("Own"), Some(Property::Object(Some(index))) if index.0 == 1));
+        let other = match export.properties.get("Other") {
+            Some(Property::Object(Some(index))) if index.0 < 0 => asset.get_import(*index).unwrap(),
+            other => panic!("Expected an import, got {:?}", other),
+        };
+        assert_eq!(other.object_name.as_str(), "Foo");
+        assert_eq!(other.package_name.as_str(), "/Game/B/Foo");
+    }
+
+    #[test]
+    fn test_external_archive_reader() {
+        use indexmap::IndexMap;
+        use std::io::{Cursor, Read, Seek, SeekFrom};
+
+        // Reader implemented outside the crate with only the required trait methods
+        struct PlainReader {
+            cursor: Cursor<Vec<u8>>,
+            overrides: IndexMap<String, String>,
+        }
+
+        impl Read for PlainReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                self.cursor.read(buf)
+            }
+        }
+
+        impl Seek for PlainReader {
+            fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+                self.cursor.seek(pos)
+            }
+        }
+
+        impl ArchiveTrait<PackageIndex> for PlainReader {
+            fn get_archive_type(&self) -> ArchiveType {
+                ArchiveType::Raw
+            }
+            fn get_custom_version<T: CustomVersionTrait>(&self) -> CustomVersion {
+                CustomVersion::new(T::guid(), -1, T::friendly_name().to_string())
+            }
+            fn has_unversioned_properties(&self) -> bool {
+                false
+            }
+            fn use_event_driven_loader(&self) -> bool {
+                false
+            }
+            fn position(&mut self) -> u64 {
+                self.cursor.position()
+            }
+            fn get_name_map(&self) -> SharedResource<NameMap> {
+                SharedResource::new(NameMap::new())
+            }
+            fn get_array_struct_type_override(&self) -> &IndexMap<String, String> {
+                &self.overrides
+            }
+            fn get_map_key_override(&self) -> &IndexMap<String, String> {
+                &self.overrides
+            }
+            fn get_map_value_override(&self) -> &IndexMap<String, String> {
+                &self.overrides
+            }
+            fn get_engine_version(&self) -> EngineVersion {
+                EngineVersion::VerUe4_27
+            }
+            fn get_object_version(&self) -> ObjectVersion {
+                EngineVersion::VerUe4_27.object_version()
+            }
+            fn get_object_version_ue5(&self) -> ObjectVersionUE5 {
+                ObjectVersionUE5::new(0)
+            }
+            fn get_parent_class_export_name(&self) -> Option<FName> {
+                None
+            }
+            fn get_object_name(&self, _index: PackageIndex) -> Option<FName> {
+                None
+            }
+            fn get_object_name_packageindex(&self, _index: PackageIndex) -> Option<FName> {
+                None
+            }
+        }
+
+        impl ArchiveReader<PackageIndex> for PlainReader {}
+
+        let data = [3i32.to_le_bytes().to_vec(), b"ab\0".to_vec()].concat();
+        let mut ar = PlainReader { cursor: Cursor::new(data), overrides: IndexMap::new() };
+        assert_eq!(ar.remaining().unwrap(), 7);
+        let name = ar.read_nested(|ar| ar.read_in("Name", |ar| ar.read_fstring())).unwrap();
+        assert_eq!(name.as_deref(), Some("ab"));
+        assert!(ar.property_path_mut().is_none());
+        assert_eq!(ar.get_endianness(), Endianness::Little);
+        let err = ar.read_in("Missing", |ar| ar.read_fstring()).unwrap_err();
+        assert!(matches!(err, UnrealAssetError::Io(_)));
+        assert!(ar.error_context().is_none());
+    }
 }
 
 #[cfg(not(feature = "unrealmodding-compat"))]